@@ -20,19 +20,25 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
 
     $completions = @(switch ($command) {
         'hurl'
-         {[CompletionResult]::new('--aws-sigv4', 'aws-sigv4', [CompletionResultType]::ParameterName, 'Use AWS V4 signature authentication in the transfer')
+         {[CompletionResult]::new('--anyauth', 'anyauth', [CompletionResultType]::ParameterName, 'Pick any authentication method supported by the server')
+            [CompletionResult]::new('--aws-sigv4', 'aws-sigv4', [CompletionResultType]::ParameterName, 'Use AWS V4 signature authentication in the transfer')
             [CompletionResult]::new('--cacert', 'cacert', [CompletionResultType]::ParameterName, 'CA certificate to verify peer against (PEM format)')
+            [CompletionResult]::new('--cert-status', 'cert-status', [CompletionResultType]::ParameterName, 'Verify the status of the server certificate (OCSP stapling)')
             [CompletionResult]::new('--cert', 'cert', [CompletionResultType]::ParameterName, 'Client certificate file and password')
             [CompletionResult]::new('--key', 'key', [CompletionResultType]::ParameterName, 'Private key file name')
             [CompletionResult]::new('--color', 'color', [CompletionResultType]::ParameterName, 'Colorize output')
-            [CompletionResult]::new('--compressed', 'compressed', [CompletionResultType]::ParameterName, 'Request compressed response (using deflate or gzip)')
+            [CompletionResult]::new('--compress-body', 'compress-body', [CompletionResultType]::ParameterName, 'Compress request bodies with ENCODING')
+            [CompletionResult]::new('--compressed', 'compressed', [CompletionResultType]::ParameterName, 'Request compressed response (using deflate, gzip, br or zstd)')
             [CompletionResult]::new('--connect-timeout', 'connect-timeout', [CompletionResultType]::ParameterName, 'Maximum time allowed for connection')
             [CompletionResult]::new('--connect-to', 'connect-to', [CompletionResultType]::ParameterName, 'For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead')
+            [CompletionResult]::new('--content-digest', 'content-digest', [CompletionResultType]::ParameterName, 'Add a Content-Digest header to requests with a body')
             [CompletionResult]::new('--continue-on-error', 'continue-on-error', [CompletionResultType]::ParameterName, 'Continue executing requests even if an error occurs')
             [CompletionResult]::new('--cookie', 'cookie', [CompletionResultType]::ParameterName, 'Read cookies from FILE')
             [CompletionResult]::new('--cookie-jar', 'cookie-jar', [CompletionResultType]::ParameterName, 'Write cookies to FILE after running the session')
+            [CompletionResult]::new('--crlfile', 'crlfile', [CompletionResultType]::ParameterName, 'Use this CRL list (PEM format)')
             [CompletionResult]::new('--curl', 'curl', [CompletionResultType]::ParameterName, 'Export each request to a list of curl commands')
             [CompletionResult]::new('--delay', 'delay', [CompletionResultType]::ParameterName, 'Sets delay before each request (aka sleep)')
+            [CompletionResult]::new('--digest', 'digest', [CompletionResultType]::ParameterName, 'Use HTTP Digest authentication')
             [CompletionResult]::new('--error-format', 'error-format', [CompletionResultType]::ParameterName, 'Control the format of error messages')
             [CompletionResult]::new('--file-root', 'file-root', [CompletionResultType]::ParameterName, 'Set root directory to import files [default: input file directory]')
             [CompletionResult]::new('--location', 'location', [CompletionResultType]::ParameterName, 'Follow redirects')
@@ -44,6 +50,7 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
             [CompletionResult]::new('--http1.1', 'http1.1', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 1.1')
             [CompletionResult]::new('--http2', 'http2', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 2')
             [CompletionResult]::new('--http3', 'http3', [CompletionResultType]::ParameterName, 'Tell Hurl to use HTTP version 3')
+            [CompletionResult]::new('--http-signature', 'http-signature', [CompletionResultType]::ParameterName, 'Sign requests with an HTTP message signature')
             [CompletionResult]::new('--ignore-asserts', 'ignore-asserts', [CompletionResultType]::ParameterName, 'Ignore asserts defined in the Hurl file')
             [CompletionResult]::new('--include', 'include', [CompletionResultType]::ParameterName, 'Include the HTTP headers in the output')
             [CompletionResult]::new('--insecure', 'insecure', [CompletionResultType]::ParameterName, 'Allow insecure SSL connections')
@@ -56,28 +63,53 @@ Register-ArgumentCompleter -Native -CommandName 'hurl' -ScriptBlock {
             [CompletionResult]::new('--max-filesize', 'max-filesize', [CompletionResultType]::ParameterName, 'Specify the maximum size in bytes of a file to download')
             [CompletionResult]::new('--max-redirs', 'max-redirs', [CompletionResultType]::ParameterName, 'Maximum number of redirects allowed, -1 for unlimited redirects')
             [CompletionResult]::new('--max-time', 'max-time', [CompletionResultType]::ParameterName, 'Maximum time allowed for the transfer')
+            [CompletionResult]::new('--negotiate', 'negotiate', [CompletionResultType]::ParameterName, 'Use HTTP Negotiate (SPNEGO) authentication')
             [CompletionResult]::new('--netrc', 'netrc', [CompletionResultType]::ParameterName, 'Must read .netrc for username and password')
             [CompletionResult]::new('--netrc-file', 'netrc-file', [CompletionResultType]::ParameterName, 'Specify FILE for .netrc')
             [CompletionResult]::new('--netrc-optional', 'netrc-optional', [CompletionResultType]::ParameterName, 'Use either .netrc or the URL')
             [CompletionResult]::new('--no-color', 'no-color', [CompletionResultType]::ParameterName, 'Do not colorize output')
             [CompletionResult]::new('--no-output', 'no-output', [CompletionResultType]::ParameterName, 'Suppress output. By default, Hurl outputs the body of the last response')
             [CompletionResult]::new('--noproxy', 'noproxy', [CompletionResultType]::ParameterName, 'List of hosts which do not use proxy')
+            [CompletionResult]::new('--ntlm', 'ntlm', [CompletionResultType]::ParameterName, 'Use HTTP NTLM authentication')
+            [CompletionResult]::new('--oauth2', 'oauth2', [CompletionResultType]::ParameterName, 'Use OAuth2 bearer token authentication')
             [CompletionResult]::new('--output', 'output', [CompletionResultType]::ParameterName, 'Write to FILE instead of stdout')
             [CompletionResult]::new('--parallel', 'parallel', [CompletionResultType]::ParameterName, 'Run files in parallel (default in test mode)')
             [CompletionResult]::new('--path-as-is', 'path-as-is', [CompletionResultType]::ParameterName, 'Tell Hurl to not handle sequences of /../ or /./ in the given URL path')
+            [CompletionResult]::new('--pinnedpubkey', 'pinnedpubkey', [CompletionResultType]::ParameterName, 'Public key to verify peer against')
             [CompletionResult]::new('--proxy', 'proxy', [CompletionResultType]::ParameterName, 'Use proxy on given PROTOCOL/HOST/PORT')
+            [CompletionResult]::new('--proxy-anyauth', 'proxy-anyauth', [CompletionResultType]::ParameterName, 'Pick any proxy authentication method supported by the proxy')
+            [CompletionResult]::new('--proxy-cacert', 'proxy-cacert', [CompletionResultType]::ParameterName, 'CA certificate to verify an HTTPS proxy against (PEM format)')
+            [CompletionResult]::new('--proxy-digest', 'proxy-digest', [CompletionResultType]::ParameterName, 'Use Digest authentication on the proxy')
+            [CompletionResult]::new('--proxy-header', 'proxy-header', [CompletionResultType]::ParameterName, 'Pass custom header(s) to proxy')
+            [CompletionResult]::new('--proxy-insecure', 'proxy-insecure', [CompletionResultType]::ParameterName, 'Allow insecure SSL connections to an HTTPS proxy')
+            [CompletionResult]::new('--proxy-negotiate', 'proxy-negotiate', [CompletionResultType]::ParameterName, 'Use HTTP Negotiate (SPNEGO) authentication on the proxy')
+            [CompletionResult]::new('--proxy-ntlm', 'proxy-ntlm', [CompletionResultType]::ParameterName, 'Use NTLM authentication on the proxy')
+            [CompletionResult]::new('--proxy-user', 'proxy-user', [CompletionResultType]::ParameterName, 'Specify the user name and password for proxy authentication')
             [CompletionResult]::new('--repeat', 'repeat', [CompletionResultType]::ParameterName, 'Repeat the input files sequence NUM times, -1 for infinite loop')
+            [CompletionResult]::new('--report-compare', 'report-compare', [CompletionResultType]::ParameterName, 'Compare two JSON reports FILE (baseline first) into an HTML report')
+            [CompletionResult]::new('--report-compare-threshold', 'report-compare-threshold', [CompletionResultType]::ParameterName, 'Duration increase in percent above which an entry is reported as a regression')
+            [CompletionResult]::new('--report-har', 'report-har', [CompletionResultType]::ParameterName, 'Write a HAR 1.2 archive of all HTTP calls to FILE')
             [CompletionResult]::new('--report-html', 'report-html', [CompletionResultType]::ParameterName, 'Generate HTML report to DIR')
+            [CompletionResult]::new('--report-html-max-body-size', 'report-html-max-body-size', [CompletionResultType]::ParameterName, 'Limit the size in bytes of the HTTP bodies stored in the HTML report')
             [CompletionResult]::new('--report-json', 'report-json', [CompletionResultType]::ParameterName, 'Generate JSON report to DIR')
             [CompletionResult]::new('--report-junit', 'report-junit', [CompletionResultType]::ParameterName, 'Write a JUnit XML report to FILE')
+            [CompletionResult]::new('--report-junit-granularity', 'report-junit-granularity', [CompletionResultType]::ParameterName, 'Generate one JUnit testcase per file or per entry')
+            [CompletionResult]::new('--report-markdown', 'report-markdown', [CompletionResultType]::ParameterName, 'Write a Markdown summary report to FILE')
+            [CompletionResult]::new('--report-metrics', 'report-metrics', [CompletionResultType]::ParameterName, 'Write metrics of the run in OpenMetrics text format to FILE')
+            [CompletionResult]::new('--report-otlp', 'report-otlp', [CompletionResultType]::ParameterName, 'Write an OpenTelemetry trace (OTLP-JSON) of the run to FILE')
+            [CompletionResult]::new('--report-sarif', 'report-sarif', [CompletionResultType]::ParameterName, 'Write a SARIF 2.1 report to FILE')
             [CompletionResult]::new('--report-tap', 'report-tap', [CompletionResultType]::ParameterName, 'Write a TAP report to FILE')
             [CompletionResult]::new('--resolve', 'resolve', [CompletionResultType]::ParameterName, 'Provide a custom address for a specific HOST and PORT pair')
             [CompletionResult]::new('--retry', 'retry', [CompletionResultType]::ParameterName, 'Maximum number of retries, 0 for no retries, -1 for unlimited retries')
             [CompletionResult]::new('--retry-interval', 'retry-interval', [CompletionResultType]::ParameterName, 'Interval in milliseconds before a retry')
             [CompletionResult]::new('--secret', 'secret', [CompletionResultType]::ParameterName, 'Define a variable which value is secret')
+            [CompletionResult]::new('--socks5-hostname', 'socks5-hostname', [CompletionResultType]::ParameterName, 'Use SOCKS5 proxy on given HOST/PORT, resolving host names with the proxy')
             [CompletionResult]::new('--ssl-no-revoke', 'ssl-no-revoke', [CompletionResultType]::ParameterName, '(Windows) Tell Hurl to disable certificate revocation checks')
             [CompletionResult]::new('--test', 'test', [CompletionResultType]::ParameterName, 'Activate test mode (use parallel execution)')
+            [CompletionResult]::new('--tls-max', 'tls-max', [CompletionResultType]::ParameterName, 'Set maximum allowed TLS version')
+            [CompletionResult]::new('--tls-min', 'tls-min', [CompletionResultType]::ParameterName, 'Set minimum allowed TLS version')
             [CompletionResult]::new('--to-entry', 'to-entry', [CompletionResultType]::ParameterName, 'Execute Hurl file to ENTRY_NUMBER (starting at 1)')
+            [CompletionResult]::new('--traceparent', 'traceparent', [CompletionResultType]::ParameterName, 'Add a W3C traceparent header to each request')
             [CompletionResult]::new('--unix-socket', 'unix-socket', [CompletionResultType]::ParameterName, '(HTTP) Connect through this Unix domain socket, instead of using the network')
             [CompletionResult]::new('--user', 'user', [CompletionResultType]::ParameterName, 'Add basic Authentication header to each request')
             [CompletionResult]::new('--user-agent', 'user-agent', [CompletionResultType]::ParameterName, 'Specify the User-Agent string to send to the HTTP server')
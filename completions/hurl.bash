@@ -4,7 +4,7 @@ _hurl()
     cur="${COMP_WORDS[COMP_CWORD]}"
 
     if [[ $cur == -* ]]; then
-        COMPREPLY=($(compgen -W '--aws-sigv4 --cacert --cert --key --color --compressed --connect-timeout --connect-to --continue-on-error --cookie --cookie-jar --curl --delay --error-format --file-root --location --location-trusted --from-entry --glob --header --http1.0 --http1.1 --http2 --http3 --ignore-asserts --include --insecure --interactive --ipv4 --ipv6 --jobs --json --limit-rate --max-filesize --max-redirs --max-time --netrc --netrc-file --netrc-optional --no-color --no-output --noproxy --output --parallel --path-as-is --proxy --repeat --report-html --report-json --report-junit --report-tap --resolve --retry --retry-interval --secret --ssl-no-revoke --test --to-entry --unix-socket --user --user-agent --variable --variables-file --verbose --very-verbose --help --version' -- "$cur"))
+        COMPREPLY=($(compgen -W '--anyauth --aws-sigv4 --cacert --cert-status --cert --key --color --compress-body --compressed --connect-timeout --connect-to --content-digest --continue-on-error --cookie --cookie-jar --crlfile --curl --delay --digest --error-format --file-root --location --location-trusted --from-entry --glob --header --http1.0 --http1.1 --http2 --http3 --http-signature --ignore-asserts --include --insecure --interactive --ipv4 --ipv6 --jobs --json --limit-rate --max-filesize --max-redirs --max-time --negotiate --netrc --netrc-file --netrc-optional --no-color --no-output --noproxy --ntlm --oauth2 --output --parallel --path-as-is --pinnedpubkey --proxy --proxy-anyauth --proxy-cacert --proxy-digest --proxy-header --proxy-insecure --proxy-negotiate --proxy-ntlm --proxy-user --repeat --report-compare --report-compare-threshold --report-har --report-html --report-html-max-body-size --report-json --report-junit --report-junit-granularity --report-markdown --report-metrics --report-otlp --report-sarif --report-tap --resolve --retry --retry-interval --secret --socks5-hostname --ssl-no-revoke --test --tls-max --tls-min --to-entry --traceparent --unix-socket --user --user-agent --variable --variables-file --verbose --very-verbose --help --version' -- "$cur"))
         return
     fi
     # Generate filenames by default
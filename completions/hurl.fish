@@ -1,16 +1,22 @@
+complete -c hurl -l anyauth -d 'Pick any authentication method supported by the server'
 complete -c hurl -l aws-sigv4 -d 'Use AWS V4 signature authentication in the transfer'
 complete -c hurl -l cacert -d 'CA certificate to verify peer against (PEM format)'
+complete -c hurl -l cert-status -d 'Verify the status of the server certificate (OCSP stapling)'
 complete -c hurl -l cert -d 'Client certificate file and password'
 complete -c hurl -l key -d 'Private key file name'
 complete -c hurl -l color -d 'Colorize output'
-complete -c hurl -l compressed -d 'Request compressed response (using deflate or gzip)'
+complete -c hurl -l compress-body -d 'Compress request bodies with ENCODING'
+complete -c hurl -l compressed -d 'Request compressed response (using deflate, gzip, br or zstd)'
 complete -c hurl -l connect-timeout -d 'Maximum time allowed for connection'
 complete -c hurl -l connect-to -d 'For a request to the given HOST1:PORT1 pair, connect to HOST2:PORT2 instead'
+complete -c hurl -l content-digest -d 'Add a Content-Digest header to requests with a body'
 complete -c hurl -l continue-on-error -d 'Continue executing requests even if an error occurs'
 complete -c hurl -l cookie -d 'Read cookies from FILE'
 complete -c hurl -l cookie-jar -d 'Write cookies to FILE after running the session'
+complete -c hurl -l crlfile -d 'Use this CRL list (PEM format)'
 complete -c hurl -l curl -d 'Export each request to a list of curl commands'
 complete -c hurl -l delay -d 'Sets delay before each request (aka sleep)'
+complete -c hurl -l digest -d 'Use HTTP Digest authentication'
 complete -c hurl -l error-format -d 'Control the format of error messages'
 complete -c hurl -l file-root -d 'Set root directory to import files [default: input file directory]'
 complete -c hurl -l location -d 'Follow redirects'
@@ -22,6 +28,7 @@ complete -c hurl -l http1.0 -d 'Tell Hurl to use HTTP version 1.0'
 complete -c hurl -l http1.1 -d 'Tell Hurl to use HTTP version 1.1'
 complete -c hurl -l http2 -d 'Tell Hurl to use HTTP version 2'
 complete -c hurl -l http3 -d 'Tell Hurl to use HTTP version 3'
+complete -c hurl -l http-signature -d 'Sign requests with an HTTP message signature'
 complete -c hurl -l ignore-asserts -d 'Ignore asserts defined in the Hurl file'
 complete -c hurl -l include -d 'Include the HTTP headers in the output'
 complete -c hurl -l insecure -d 'Allow insecure SSL connections'
@@ -34,28 +41,53 @@ complete -c hurl -l limit-rate -d 'Specify the maximum transfer rate in bytes/se
 complete -c hurl -l max-filesize -d 'Specify the maximum size in bytes of a file to download'
 complete -c hurl -l max-redirs -d 'Maximum number of redirects allowed, -1 for unlimited redirects'
 complete -c hurl -l max-time -d 'Maximum time allowed for the transfer'
+complete -c hurl -l negotiate -d 'Use HTTP Negotiate (SPNEGO) authentication'
 complete -c hurl -l netrc -d 'Must read .netrc for username and password'
 complete -c hurl -l netrc-file -d 'Specify FILE for .netrc'
 complete -c hurl -l netrc-optional -d 'Use either .netrc or the URL'
 complete -c hurl -l no-color -d 'Do not colorize output'
 complete -c hurl -l no-output -d 'Suppress output. By default, Hurl outputs the body of the last response'
 complete -c hurl -l noproxy -d 'List of hosts which do not use proxy'
+complete -c hurl -l ntlm -d 'Use HTTP NTLM authentication'
+complete -c hurl -l oauth2 -d 'Use OAuth2 bearer token authentication'
 complete -c hurl -l output -d 'Write to FILE instead of stdout'
 complete -c hurl -l parallel -d 'Run files in parallel (default in test mode)'
 complete -c hurl -l path-as-is -d 'Tell Hurl to not handle sequences of /../ or /./ in the given URL path'
+complete -c hurl -l pinnedpubkey -d 'Public key to verify peer against'
 complete -c hurl -l proxy -d 'Use proxy on given PROTOCOL/HOST/PORT'
+complete -c hurl -l proxy-anyauth -d 'Pick any proxy authentication method supported by the proxy'
+complete -c hurl -l proxy-cacert -d 'CA certificate to verify an HTTPS proxy against (PEM format)'
+complete -c hurl -l proxy-digest -d 'Use Digest authentication on the proxy'
+complete -c hurl -l proxy-header -d 'Pass custom header(s) to proxy'
+complete -c hurl -l proxy-insecure -d 'Allow insecure SSL connections to an HTTPS proxy'
+complete -c hurl -l proxy-negotiate -d 'Use HTTP Negotiate (SPNEGO) authentication on the proxy'
+complete -c hurl -l proxy-ntlm -d 'Use NTLM authentication on the proxy'
+complete -c hurl -l proxy-user -d 'Specify the user name and password for proxy authentication'
 complete -c hurl -l repeat -d 'Repeat the input files sequence NUM times, -1 for infinite loop'
+complete -c hurl -l report-compare -d 'Compare two JSON reports FILE (baseline first) into an HTML report'
+complete -c hurl -l report-compare-threshold -d 'Duration increase in percent above which an entry is reported as a regression'
+complete -c hurl -l report-har -d 'Write a HAR 1.2 archive of all HTTP calls to FILE'
 complete -c hurl -l report-html -d 'Generate HTML report to DIR'
+complete -c hurl -l report-html-max-body-size -d 'Limit the size in bytes of the HTTP bodies stored in the HTML report'
 complete -c hurl -l report-json -d 'Generate JSON report to DIR'
 complete -c hurl -l report-junit -d 'Write a JUnit XML report to FILE'
+complete -c hurl -l report-junit-granularity -d 'Generate one JUnit testcase per file or per entry'
+complete -c hurl -l report-markdown -d 'Write a Markdown summary report to FILE'
+complete -c hurl -l report-metrics -d 'Write metrics of the run in OpenMetrics text format to FILE'
+complete -c hurl -l report-otlp -d 'Write an OpenTelemetry trace (OTLP-JSON) of the run to FILE'
+complete -c hurl -l report-sarif -d 'Write a SARIF 2.1 report to FILE'
 complete -c hurl -l report-tap -d 'Write a TAP report to FILE'
 complete -c hurl -l resolve -d 'Provide a custom address for a specific HOST and PORT pair'
 complete -c hurl -l retry -d 'Maximum number of retries, 0 for no retries, -1 for unlimited retries'
 complete -c hurl -l retry-interval -d 'Interval in milliseconds before a retry'
 complete -c hurl -l secret -d 'Define a variable which value is secret'
+complete -c hurl -l socks5-hostname -d 'Use SOCKS5 proxy on given HOST/PORT, resolving host names with the proxy'
 complete -c hurl -l ssl-no-revoke -d '(Windows) Tell Hurl to disable certificate revocation checks'
 complete -c hurl -l test -d 'Activate test mode (use parallel execution)'
+complete -c hurl -l tls-max -d 'Set maximum allowed TLS version'
+complete -c hurl -l tls-min -d 'Set minimum allowed TLS version'
 complete -c hurl -l to-entry -d 'Execute Hurl file to ENTRY_NUMBER (starting at 1)'
+complete -c hurl -l traceparent -d 'Add a W3C traceparent header to each request'
 complete -c hurl -l unix-socket -d '(HTTP) Connect through this Unix domain socket, instead of using the network'
 complete -c hurl -l user -d 'Add basic Authentication header to each request'
 complete -c hurl -l user-agent -d 'Specify the User-Agent string to send to the HTTP server'
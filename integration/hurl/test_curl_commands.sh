@@ -17,6 +17,10 @@ if ! curl --version | grep brotli >/dev/null 2>&1 ; then
     exclude_lines="^#|brotli"
     echo "${color_yellow}! Brotli tests excluded because curl does not contain this functionality in this system${color_reset}"
 fi
+if ! curl --version | grep zstd >/dev/null 2>&1 ; then
+    exclude_lines="${exclude_lines}|zstd"
+    echo "${color_yellow}! Zstandard tests excluded because curl does not contain this functionality in this system${color_reset}"
+fi
 
 while read -r test_file ; do
     echo "** ${test_file}"
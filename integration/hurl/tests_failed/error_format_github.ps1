@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --error-format github --continue-on-error tests_failed/error_format_github.hurl
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --error-format github --continue-on-error tests_failed/error_format_github.hurl
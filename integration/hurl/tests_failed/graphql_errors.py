@@ -0,0 +1,10 @@
+from app import app
+from flask import make_response
+
+
+@app.route("/graphql-errors", methods=["POST"])
+def graphql_errors():
+    data = r'{"data":{"human":null},"errors":[{"message":"Human 1000 not found"},{"message":"Unauthorized"}]}'
+    resp = make_response(data)
+    resp.headers["Content-Type"] = "application/json"
+    return resp
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --continue-on-error tests_failed/max_time_option.hurl
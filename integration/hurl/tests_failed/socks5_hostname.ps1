@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --verbose --socks5-hostname localhost:1111 tests_failed/socks5_hostname.hurl
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --verbose --socks5-hostname localhost:1111 tests_failed/socks5_hostname.hurl
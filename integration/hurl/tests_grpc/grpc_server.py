@@ -0,0 +1,54 @@
+#!/usr/bin/env python3
+# A minimal gRPC server implementing the `helloworld.Greeter` service of `greeter.proto`.
+#
+# Protobuf messages are encoded and decoded by hand so that the server doesn't depend on
+# generated code.
+from concurrent import futures
+
+import grpc
+
+
+def decode_name(data: bytes) -> str:
+    """Decodes the field `name` (1) of a `HelloRequest` message."""
+    if len(data) < 2 or data[0] != 0x0A:
+        return ""
+    length = data[1]
+    return data[2 : 2 + length].decode()
+
+
+def encode_reply(message: str) -> bytes:
+    """Encodes a `HelloReply` message."""
+    value = message.encode()
+    return bytes([0x0A, len(value)]) + value
+
+
+def say_hello(request: bytes, context: grpc.ServicerContext) -> bytes:
+    name = decode_name(request)
+    if not name:
+        context.abort(grpc.StatusCode.INVALID_ARGUMENT, "name is required")
+    return encode_reply(f"Hello {name}")
+
+
+def lots_of_replies(request: bytes, context: grpc.ServicerContext):
+    name = decode_name(request)
+    for i in range(1, 4):
+        yield encode_reply(f"Hello {name} #{i}")
+
+
+def main():
+    handler = grpc.method_handlers_generic_handler(
+        "helloworld.Greeter",
+        {
+            "SayHello": grpc.unary_unary_rpc_method_handler(say_hello),
+            "LotsOfReplies": grpc.unary_stream_rpc_method_handler(lots_of_replies),
+        },
+    )
+    server = grpc.server(futures.ThreadPoolExecutor(max_workers=4))
+    server.add_generic_rpc_handlers((handler,))
+    server.add_insecure_port("127.0.0.1:50051")
+    server.start()
+    server.wait_for_termination()
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,31 @@
+import gzip
+import zlib
+
+from app import app
+from flask import make_response, request
+
+
+@app.route("/compress-body/echo", methods=["POST"])
+def compress_body_echo():
+    # The body is sent back as received, with the same content encoding.
+    encoding = request.headers["Content-Encoding"]
+    data = request.get_data()
+    if encoding == "gzip":
+        text = gzip.decompress(data)
+    elif encoding == "deflate":
+        text = zlib.decompress(data)
+    else:
+        text = None
+    length = request.headers.get("X-Uncompressed-Length")
+    if text is not None and length is not None:
+        assert int(length) == len(text)
+    resp = make_response(data)
+    resp.headers["Content-Encoding"] = encoding
+    resp.headers["Content-Type"] = request.headers.get("Content-Type", "text/plain")
+    return resp
+
+
+@app.route("/compress-body/no-body", methods=["GET"])
+def compress_body_no_body():
+    assert "Content-Encoding" not in request.headers
+    return ""
@@ -68,6 +68,53 @@ def compressed_brotli_identity():
     return resp
 
 
+@app.route("/compressed/zstd")
+def compressed_zstd():
+    assert "zstd" in request.headers["Accept-Encoding"]
+    result = BytesIO()
+    result.write(
+        b"\x28\xb5\x2f\xfd\x00\x68\x61\x00\x00\x48\x65\x6c\x6c\x6f\x20\x57\x6f"
+        b"\x72\x6c\x64\x21"
+    )
+    data = result.getvalue()
+    resp = make_response(data)
+    resp.headers["Content-Encoding"] = "zstd"
+    return resp
+
+
+@app.route("/compressed/zstd_json")
+def compressed_zstd_json():
+    assert "zstd" in request.headers["Accept-Encoding"]
+    result = BytesIO()
+    result.write(
+        b"\x28\xb5\x2f\xfd\x60\x4f\x00\x4d\x01\x00\x14\x02\x7b\x22\x67\x72\x65"
+        b"\x65\x74\x69\x6e\x67\x73\x22\x3a\x20\x5b\x22\x48\x65\x6c\x6c\x6f\x20"
+        b"\x57\x6f\x72\x6c\x64\x21\x22\x2c\x20\x5d\x7d\x01\x00\xaf\x8c\x3a\x9d"
+    )
+    data = result.getvalue()
+    resp = make_response(data)
+    resp.headers["Content-Encoding"] = "zstd"
+    resp.headers["Content-Type"] = "application/json"
+    return resp
+
+
+@app.route("/compressed/zstd_xml")
+def compressed_zstd_xml():
+    assert "zstd" in request.headers["Accept-Encoding"]
+    result = BytesIO()
+    result.write(
+        b"\x28\xb5\x2f\xfd\x60\xab\x01\x55\x01\x00\xd8\x3c\x67\x72\x65\x65\x74"
+        b"\x69\x6e\x67\x73\x3e\x3e\x48\x65\x6c\x6c\x6f\x20\x57\x6f\x72\x6c\x64"
+        b"\x21\x3c\x2f\x2f\x04\x00\xa3\xc4\x26\x72\x88\xaa\x80\x4e\x52\x5d\x94"
+        b"\x39"
+    )
+    data = result.getvalue()
+    resp = make_response(data)
+    resp.headers["Content-Encoding"] = "zstd"
+    resp.headers["Content-Type"] = "application/xml"
+    return resp
+
+
 @app.route("/compressed/none")
 def compressed_none():
     return "Hello World!"
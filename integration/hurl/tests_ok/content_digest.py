@@ -0,0 +1,59 @@
+import base64
+import gzip
+import hashlib
+
+from app import app
+from flask import Response, request
+
+
+def digest(data, algorithm="sha-256"):
+    hash = hashlib.sha256 if algorithm == "sha-256" else hashlib.sha512
+    return f"{algorithm}=:{base64.b64encode(hash(data).digest()).decode()}:"
+
+
+@app.route("/content-digest/request", methods=["POST"])
+def content_digest_request():
+    # The digest is computed on the body actually sent.
+    data = request.get_data()
+    algorithm = request.args.get("algorithm", "sha-256")
+    assert request.headers["Content-Digest"] == digest(data, algorithm)
+    if request.args.get("type") == "form":
+        assert request.form["name"] == "Bob"
+    if request.args.get("type") == "multipart":
+        assert request.form["key1"] == "value1"
+        assert request.files["upload1"].read() == b"Hello World!"
+    return ""
+
+
+@app.route("/content-digest/no-body", methods=["GET"])
+def content_digest_no_body():
+    assert "Content-Digest" not in request.headers
+    return ""
+
+
+@app.route("/content-digest/response")
+def content_digest_response():
+    data = b'{"hello": "world"}'
+    headers = {
+        "Content-Type": "application/json",
+        "Content-Digest": digest(data),
+        "Repr-Digest": f"{digest(data, 'sha-512')}, unixsum=:AAA=:",
+    }
+    return Response(data, headers=headers)
+
+
+@app.route("/content-digest/response-gzip")
+def content_digest_response_gzip():
+    data = gzip.compress(b'{"hello": "world"}')
+    headers = {
+        "Content-Type": "application/json",
+        "Content-Encoding": "gzip",
+        "Content-Digest": digest(data),
+    }
+    return Response(data, headers=headers)
+
+
+@app.route("/content-digest/response-invalid")
+def content_digest_response_invalid():
+    headers = {"Content-Digest": digest(b"Hello")}
+    return Response("Bye", headers=headers)
@@ -0,0 +1,8 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path build/cookies.json) {
+    Remove-Item build/cookies.json
+}
+hurl --cookie tests_ok/cookie_jar_json.input.json --cookie-jar build/cookies.json --no-output tests_ok/cookie_jar_json.hurl
+Write-Host (Get-Content build/cookies.json -Raw) -NoNewLine
@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -f build/cookies.json
+hurl --cookie tests_ok/cookie_jar_json.input.json --cookie-jar build/cookies.json --no-output tests_ok/cookie_jar_json.hurl
+cat build/cookies.json
@@ -0,0 +1,28 @@
+from app import app
+from flask import make_response, request
+
+
+@app.route("/cookiejar-query/set")
+def cookiejar_query_set():
+    resp = make_response()
+    resp.set_cookie("session", "12345", httponly=True, samesite="Strict")
+    resp.set_cookie(
+        "theme",
+        "dark",
+        expires="Thu, 13 Jan 2078 22:23:01 GMT",
+        samesite="Lax",
+    )
+    return resp
+
+
+@app.route("/cookiejar-query/set-other")
+def cookiejar_query_set_other():
+    resp = make_response(cookiejar_query_echo())
+    resp.set_cookie("other", "abc")
+    return resp
+
+
+@app.route("/cookiejar-query/echo")
+def cookiejar_query_echo():
+    cookies = sorted(request.cookies.items())
+    return "; ".join(f"{name}={value}" for name, value in cookies)
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --digest --user bob:secret tests_ok/digest_authentication.hurl
@@ -0,0 +1,52 @@
+import hashlib
+import re
+
+from app import app
+from flask import Response, request
+
+REALM = "hurl"
+NONCE = "dcd98b7102dd2f0e8b11d0f600bfb0c093"
+USERS = {"bob": "secret"}
+
+
+def md5(text):
+    return hashlib.md5(text.encode()).hexdigest()
+
+
+def digest_challenge():
+    return Response(
+        "Unauthorized",
+        status=401,
+        headers={
+            "WWW-Authenticate": f'Digest realm="{REALM}", qop="auth", nonce="{NONCE}", algorithm=MD5'
+        },
+    )
+
+
+def digest_authenticated():
+    authorization = request.headers.get("Authorization", "")
+    if not authorization.startswith("Digest "):
+        return False
+    params = {
+        k: v
+        for k, v in (
+            (m[0], m[1] or m[2])
+            for m in re.findall(r'(\w+)=(?:"([^"]*)"|([^,\s]*))', authorization[7:])
+        )
+    }
+    username = params.get("username")
+    if username not in USERS or params.get("nonce") != NONCE:
+        return False
+    ha1 = md5(f"{username}:{REALM}:{USERS[username]}")
+    ha2 = md5(f"{request.method}:{params['uri']}")
+    expected = md5(
+        f"{ha1}:{NONCE}:{params['nc']}:{params['cnonce']}:{params['qop']}:{ha2}"
+    )
+    return params.get("response") == expected
+
+
+@app.route("/digest-authentication")
+def digest_authentication():
+    if not digest_authenticated():
+        return digest_challenge()
+    return "You are authenticated"
@@ -0,0 +1,15 @@
+from app import app
+from flask import make_response, request
+
+
+@app.route("/graphql-asserts", methods=["POST"])
+def graphql_asserts():
+    assert request.headers["Content-Type"] == "application/json"
+    query = request.json["query"]
+    if "hero" in query:
+        data = r'{"data":{"hero":{"name":"Luke Skywalker","height":1.72}},"extensions":{"cost":3}}'
+    else:
+        data = r'{"data":{"human":null},"errors":[{"message":"Human 1000 not found","path":["human"]}]}'
+    resp = make_response(data)
+    resp.headers["Content-Type"] = "application/json"
+    return resp
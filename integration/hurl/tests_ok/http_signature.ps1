@@ -0,0 +1,8 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl tests_ok/http_signature.hurl
+if ($LASTEXITCODE -ne 0) { exit $LASTEXITCODE }
+
+hurl --http-signature "keyid=client-key alg=hmac-sha256 key=tests_ok/http_signature.key components=(request-target),(created),host format=cavage" `
+    tests_ok/http_signature_option.hurl
@@ -0,0 +1,113 @@
+import base64
+import hashlib
+import hmac
+import re
+
+from app import app
+from flask import Response, request
+
+KEY = b"s3cr3t-signature-key"
+
+
+def component(name):
+    if name == "@method":
+        return request.method
+    if name == "@authority":
+        return request.host
+    if name == "@path":
+        return request.path
+    if name == "@query":
+        return "?" + request.query_string.decode()
+    if name == "host":
+        return request.host
+    if name == "(request-target)":
+        path = request.full_path.rstrip("?")
+        return f"{request.method.lower()} {path}"
+    return request.headers[name]
+
+
+def sign(data):
+    digest = hmac.new(KEY, data.encode(), hashlib.sha256).digest()
+    return base64.b64encode(digest).decode()
+
+
+@app.route("/http-signature/rfc9421", methods=["POST"])
+def http_signature_rfc9421():
+    signature_input = request.headers["Signature-Input"]
+    label, params = signature_input.split("=", 1)
+    assert label == "sig1"
+    components = re.findall(r'"([^"]+)"', params[: params.index(")")])
+    assert components == [
+        "@method",
+        "@path",
+        "@query",
+        "@authority",
+        "content-type",
+        "user-agent",
+    ]
+    assert 'keyid="client-key"' in params
+    assert 'alg="hmac-sha256"' in params
+    base = "".join(f'"{c}": {component(c)}\n' for c in components)
+    base += f'"@signature-params": {params}'
+    assert request.headers["Signature"] == f"sig1=:{sign(base)}:"
+
+    # The response is signed back.
+    params = (
+        '("@status" "content-type");created=1618884473;keyid="server-key";'
+        'alg="hmac-sha256"'
+    )
+    base = f'"@status": 200\n"content-type": text/plain\n"@signature-params": {params}'
+    headers = {
+        "Content-Type": "text/plain",
+        "Signature-Input": f"sig1={params}",
+        "Signature": f"sig1=:{sign(base)}:",
+    }
+    return Response("Signed", headers=headers)
+
+
+@app.route("/http-signature/rfc9421/libcurl-headers", methods=["POST"])
+def http_signature_rfc9421_libcurl_headers():
+    assert request.headers["Content-Length"] == "6"
+    assert request.headers["Cookie"] == "session=abc; lang=fr"
+    params = request.headers["Signature-Input"].split("=", 1)[1]
+    components = re.findall(r'"([^"]+)"', params[: params.index(")")])
+    assert components == ["@method", "content-length", "cookie"]
+    base = "".join(f'"{c}": {component(c)}\n' for c in components)
+    base += f'"@signature-params": {params}'
+    assert request.headers["Signature"] == f"sig1=:{sign(base)}:"
+    return Response("Signed")
+
+
+@app.route("/http-signature/cavage", methods=["GET"])
+def http_signature_cavage():
+    params = dict(re.findall(r'(\w+)="?([^",]*)"?', request.headers["Signature"]))
+    assert params["keyId"] == "client-key"
+    assert params["algorithm"] == "hmac-sha256"
+    assert params["headers"] == "(request-target) (created) host"
+    lines = []
+    for c in params["headers"].split(" "):
+        value = params["created"] if c == "(created)" else component(c)
+        lines.append(f"{c}: {value}")
+    assert params["signature"] == sign("\n".join(lines))
+
+    # The response is signed back.
+    signature = sign("content-type: text/plain")
+    headers = {
+        "Content-Type": "text/plain",
+        "Signature": (
+            'keyId="server-key",algorithm="hmac-sha256",headers="content-type",'
+            f'signature="{signature}"'
+        ),
+    }
+    return Response("Signed", headers=headers)
+
+
+@app.route("/http-signature/tampered", methods=["GET"])
+def http_signature_tampered():
+    params = '("@status");created=1618884473;keyid="server-key";alg="hmac-sha256"'
+    base = f'"@status": 201\n"@signature-params": {params}'
+    headers = {
+        "Signature-Input": f"sig1={params}",
+        "Signature": f"sig1=:{sign(base)}:",
+    }
+    return Response("Tampered", headers=headers)
@@ -0,0 +1,7 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl tests_ok/http_signature.hurl
+
+hurl --http-signature "keyid=client-key alg=hmac-sha256 key=tests_ok/http_signature.key components=(request-target),(created),host format=cavage" \
+    tests_ok/http_signature_option.hurl
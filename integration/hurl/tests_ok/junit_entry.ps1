@@ -0,0 +1,15 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/junit_entry/result.xml) {
+    Remove-Item build/junit_entry/result.xml
+}
+
+# The second entry is KO but we want the script to continue until the end
+try {
+    hurl --test --report-junit build/junit_entry/result.xml --report-junit-granularity entry tests_ok/junit_entry.hurl
+} finally {
+    $global:LASTEXITCODE = 0
+    $ErrorActionPreference = 'Continue'
+}
+
+Write-Host (Get-Content build/junit_entry/result.xml -Raw) -NoNewLine
@@ -0,0 +1,10 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/junit_entry/result.xml
+
+# The second entry is KO but we want the script to continue until the end
+set +eo pipefail
+hurl --test --report-junit build/junit_entry/result.xml --report-junit-granularity entry tests_ok/junit_entry.hurl
+set -Eeuo pipefail
+
+cat build/junit_entry/result.xml
@@ -0,0 +1,16 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/markdown/report.md) {
+    Remove-Item build/markdown/report.md
+}
+
+# test2 is KO but we want the script to continue until the end
+try {
+    # We use --jobs 1 to force the standard error order to be test1 then test2.
+    hurl --test --jobs 1 --report-markdown build/markdown/report.md tests_ok/test.1.hurl tests_ok/test.2.hurl
+} finally {
+    $global:LASTEXITCODE = 0
+    $ErrorActionPreference = 'Continue'
+}
+
+Write-Host (Get-Content build/markdown/report.md -Raw) -NoNewLine
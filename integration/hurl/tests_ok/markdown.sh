@@ -0,0 +1,11 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/markdown/report.md
+
+# test2 is KO but we want the script to continue until the end
+set +eo pipefail
+# We use --jobs 1 to force the standard error order to be test1 then test2.
+hurl --test --jobs 1 --report-markdown build/markdown/report.md tests_ok/test.1.hurl tests_ok/test.2.hurl
+set -Eeuo pipefail
+
+cat build/markdown/report.md
@@ -0,0 +1,51 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path -Path build/oauth2) {
+    Remove-Item -Recurse build/oauth2
+}
+New-Item -ItemType Directory -Force -Path build/oauth2 | Out-Null
+
+hurl --very-verbose `
+    --secret client_secret=s3cr3t-client `
+    --curl build/oauth2/curl.txt `
+    --report-html build/oauth2/report-html `
+    --report-json build/oauth2/report-json `
+    --report-har build/oauth2/report.har `
+    --report-metrics build/oauth2/report-metrics.txt `
+    --report-otlp build/oauth2/report-otlp.json `
+    tests_ok/oauth2.hurl 2> build/oauth2/stderr.txt
+if ($LASTEXITCODE -ne 0) { exit $LASTEXITCODE }
+
+hurl --very-verbose `
+    --oauth2 "token_url=http://localhost:8000/oauth2/token client_id=hurl client_secret=s3cr3t-client scope=read,write" `
+    --parallel --jobs 4 `
+    tests_ok/oauth2_parallel.hurl tests_ok/oauth2_parallel.hurl tests_ok/oauth2_parallel.hurl tests_ok/oauth2_parallel.hurl `
+    2> build/oauth2/stderr-parallel.txt
+if ($LASTEXITCODE -ne 0) { exit $LASTEXITCODE }
+
+# Client secret, client credentials and tokens are redacted.
+$secrets = @("s3cr3t-client", "aHVybDpzM2NyM3QtY2xpZW50", "oauth2-access-token", "oauth2-refresh-token")
+
+$files = @(Get-ChildItem -Filter *.html -Recurse build/oauth2/report-html)
+$files += @(Get-ChildItem -Filter *.json build/oauth2/report-json)
+$files += @(Get-ChildItem build/oauth2/curl.txt)
+$files += @(Get-ChildItem build/oauth2/report.har)
+$files += @(Get-ChildItem build/oauth2/report-metrics.txt)
+$files += @(Get-ChildItem build/oauth2/report-otlp.json)
+$files += @(Get-ChildItem build/oauth2/stderr.txt)
+$files += @(Get-ChildItem build/oauth2/stderr-parallel.txt)
+
+foreach ($secret in $secrets) {
+    foreach ($file in $files) {
+        # Don't search leaks in sources
+        if ($file.name.EndsWith("source.html")) {
+            continue
+        }
+        if (Get-Content $file | Select-String -CaseSensitive $secret) {
+            echo "Secret <$secret> have leaked in $file"
+            Get-Content $file
+            exit 1
+        }
+    }
+}
@@ -0,0 +1,73 @@
+import time
+
+from app import app
+from flask import jsonify, request
+
+# Access tokens lifetime in seconds.
+EXPIRES_IN = 2
+
+oauth2_state = {"token_requests": 0, "grants": [], "tokens": {}, "refresh_tokens": set()}
+
+
+@app.route("/oauth2/reset")
+def oauth2_reset():
+    oauth2_state["token_requests"] = 0
+    oauth2_state["grants"] = []
+    oauth2_state["tokens"] = {}
+    oauth2_state["refresh_tokens"] = set()
+    return ""
+
+
+@app.route("/oauth2/token", methods=["POST"])
+def oauth2_token():
+    # Client is authenticated with Basic auth: hurl:s3cr3t-client
+    if request.headers.get("Authorization") != "Basic aHVybDpzM2NyM3QtY2xpZW50":
+        return jsonify({"error": "invalid_client"}), 401
+
+    grant_type = request.form.get("grant_type")
+    if grant_type == "client_credentials":
+        assert request.form.get("scope") == "read write"
+    elif grant_type == "refresh_token":
+        refresh_token = request.form.get("refresh_token")
+        if refresh_token not in oauth2_state["refresh_tokens"]:
+            return jsonify({"error": "invalid_grant"}), 400
+        oauth2_state["refresh_tokens"].remove(refresh_token)
+    else:
+        return jsonify({"error": "unsupported_grant_type"}), 400
+
+    oauth2_state["token_requests"] += 1
+    oauth2_state["grants"].append(grant_type)
+    count = oauth2_state["token_requests"]
+    access_token = f"oauth2-access-token-{count}"
+    refresh_token = f"oauth2-refresh-token-{count}"
+    oauth2_state["tokens"][access_token] = time.time() + EXPIRES_IN
+    oauth2_state["refresh_tokens"].add(refresh_token)
+    return jsonify(
+        {
+            "access_token": access_token,
+            "token_type": "Bearer",
+            "expires_in": EXPIRES_IN,
+            "refresh_token": refresh_token,
+        }
+    )
+
+
+@app.route("/oauth2/protected")
+def oauth2_protected():
+    auth = request.headers.get("Authorization", "")
+    if not auth.startswith("Bearer "):
+        return "", 401
+    expires_at = oauth2_state["tokens"].get(auth[len("Bearer ") :])
+    if expires_at is None or expires_at < time.time():
+        return "", 401
+    return jsonify(
+        {
+            "token_requests": oauth2_state["token_requests"],
+            "grants": oauth2_state["grants"],
+        }
+    )
+
+
+@app.route("/oauth2/authorization")
+def oauth2_authorization():
+    return request.headers.get("Authorization", "")
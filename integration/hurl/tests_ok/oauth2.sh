@@ -0,0 +1,49 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -rf build/oauth2
+mkdir -p build/oauth2
+
+hurl --very-verbose \
+    --secret client_secret=s3cr3t-client \
+    --curl build/oauth2/curl.txt \
+    --report-html build/oauth2/report-html \
+    --report-json build/oauth2/report-json \
+    --report-har build/oauth2/report.har \
+    --report-metrics build/oauth2/report-metrics.txt \
+    --report-otlp build/oauth2/report-otlp.json \
+    tests_ok/oauth2.hurl 2> build/oauth2/stderr.txt
+
+hurl --very-verbose \
+    --oauth2 "token_url=http://localhost:8000/oauth2/token client_id=hurl client_secret=s3cr3t-client scope=read,write" \
+    --parallel --jobs 4 \
+    tests_ok/oauth2_parallel.hurl tests_ok/oauth2_parallel.hurl tests_ok/oauth2_parallel.hurl tests_ok/oauth2_parallel.hurl \
+    2> build/oauth2/stderr-parallel.txt
+
+# Client secret, client credentials and tokens are redacted.
+secrets=("s3cr3t-client" "aHVybDpzM2NyM3QtY2xpZW50" "oauth2-access-token" "oauth2-refresh-token")
+
+files=$(find build/oauth2/report-html/*.html \
+  build/oauth2/report-html/**/*.html \
+  build/oauth2/report-json/*.json \
+  build/oauth2/curl.txt \
+  build/oauth2/report.har \
+  build/oauth2/report-metrics.txt \
+  build/oauth2/report-otlp.json \
+  build/oauth2/stderr.txt \
+  build/oauth2/stderr-parallel.txt
+)
+
+for secret in "${secrets[@]}"; do
+  for file in $files; do
+    # Don't search leaks in sources
+    if [[ "$file" == *source.html ]]; then
+      continue
+    fi
+    if grep -q "$secret" "$file"; then
+        echo "Secret <$secret> have leaked in $file"
+        cat "$file"
+        exit 1
+    fi
+  done
+done
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+hurl --secret proxy_password=secret --verbose tests_ok/proxy_credentials.hurl
@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --secret proxy_password=secret --verbose tests_ok/proxy_credentials.hurl
@@ -0,0 +1,11 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path build/report/compare/compare.html) {
+    Remove-Item build/report/compare/compare.html
+}
+
+hurl --report-compare tests_ok/report_compare.baseline.json --report-compare tests_ok/report_compare.current.json --report-html build/report/compare
+if ($LASTEXITCODE -ne 0) { exit $LASTEXITCODE }
+
+Write-Host (Get-Content build/report/compare/compare.html -Raw) -NoNewLine
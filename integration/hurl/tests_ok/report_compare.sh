@@ -0,0 +1,8 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -f build/report/compare/compare.html
+
+hurl --report-compare tests_ok/report_compare.baseline.json --report-compare tests_ok/report_compare.current.json --report-html build/report/compare
+
+cat build/report/compare/compare.html
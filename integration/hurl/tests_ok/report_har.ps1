@@ -0,0 +1,13 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path build/report/har/report.har) {
+    Remove-Item build/report/har/report.har
+}
+
+# test.2.hurl is KO but we want the script to continue until the end
+$ErrorActionPreference = 'Continue'
+hurl --test --jobs 1 --report-har build/report/har/report.har tests_ok/test.1.hurl tests_ok/test.2.hurl
+$ErrorActionPreference = 'Stop'
+
+Write-Host (Get-Content build/report/har/report.har -Raw) -NoNewLine
@@ -0,0 +1,11 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -f build/report/har/report.har
+
+# test.2.hurl is KO but we want the script to continue until the end
+set +eo pipefail
+hurl --test --jobs 1 --report-har build/report/har/report.har tests_ok/test.1.hurl tests_ok/test.2.hurl
+set -Eeuo pipefail
+
+cat build/report/har/report.har
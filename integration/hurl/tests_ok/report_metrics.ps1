@@ -0,0 +1,13 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path build/report/metrics/report.txt) {
+    Remove-Item build/report/metrics/report.txt
+}
+
+# test.2.hurl is KO but we want the script to continue until the end
+$ErrorActionPreference = 'Continue'
+hurl --test --jobs 1 --report-metrics build/report/metrics/report.txt tests_ok/test.1.hurl tests_ok/test.2.hurl
+$ErrorActionPreference = 'Stop'
+
+Write-Host (Get-Content build/report/metrics/report.txt -Raw) -NoNewLine
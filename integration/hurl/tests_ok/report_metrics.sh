@@ -0,0 +1,11 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -f build/report/metrics/report.txt
+
+# test.2.hurl is KO but we want the script to continue until the end
+set +eo pipefail
+hurl --test --jobs 1 --report-metrics build/report/metrics/report.txt tests_ok/test.1.hurl tests_ok/test.2.hurl
+set -Eeuo pipefail
+
+cat build/report/metrics/report.txt
@@ -0,0 +1,11 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -f build/report/otlp/report.json
+
+# test.2.hurl is KO but we want the script to continue until the end
+set +eo pipefail
+hurl --test --jobs 1 --report-otlp build/report/otlp/report.json tests_ok/test.1.hurl tests_ok/test.2.hurl
+set -Eeuo pipefail
+
+cat build/report/otlp/report.json
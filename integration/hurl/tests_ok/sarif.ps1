@@ -0,0 +1,28 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/sarif/report.sarif) {
+    Remove-Item build/sarif/report.sarif
+}
+if (Test-Path build/sarif/parsing.sarif) {
+    Remove-Item build/sarif/parsing.sarif
+}
+
+# test2 is KO but we want the script to continue until the end
+try {
+    # We use --jobs 1 to force the standard error order to be test1 then test2.
+    hurl --test --jobs 1 --report-sarif build/sarif/report.sarif tests_ok/test.1.hurl tests_ok/test.2.hurl
+} finally {
+    $global:LASTEXITCODE = 0
+    $ErrorActionPreference = 'Continue'
+}
+
+# Parsing errors are also reported
+try {
+    hurl --test --report-sarif build/sarif/parsing.sarif tests_error_parser/base64.hurl
+} finally {
+    $global:LASTEXITCODE = 0
+    $ErrorActionPreference = 'Continue'
+}
+
+Write-Host (Get-Content build/sarif/report.sarif -Raw) -NoNewLine
+Write-Host (Get-Content build/sarif/parsing.sarif -Raw) -NoNewLine
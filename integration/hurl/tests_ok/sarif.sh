@@ -0,0 +1,15 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/sarif/report.sarif
+rm -f build/sarif/parsing.sarif
+
+# test2 is KO but we want the script to continue until the end
+set +eo pipefail
+# We use --jobs 1 to force the standard error order to be test1 then test2.
+hurl --test --jobs 1 --report-sarif build/sarif/report.sarif tests_ok/test.1.hurl tests_ok/test.2.hurl
+# Parsing errors are also reported
+hurl --test --report-sarif build/sarif/parsing.sarif tests_error_parser/base64.hurl
+set -Eeuo pipefail
+
+cat build/sarif/report.sarif
+cat build/sarif/parsing.sarif
@@ -13,6 +13,9 @@ hurl --very-verbose `
     --cookie-jar build/secret-cookies.txt `
     --report-html build/secret/report-html `
     --report-json build/secret/report-json `
+    --report-har build/secret/report.har `
+    --report-metrics build/secret/report-metrics.txt `
+    --report-otlp build/secret/report-otlp.json `
     tests_ok/secret.hurl
 
 $secrets = @("secret1", "secret2", "secret3", "12345678", "secret-dynamic-0", "secret-dynamic-1", "secret-dynamic-2")
@@ -20,6 +23,9 @@ $secrets = @("secret1", "secret2", "secret3", "12345678", "secret-dynamic-0", "s
 $files = @(Get-ChildItem -Filter *.html -Recurse build/secret/report-html)
 $files += @(Get-ChildItem -Filter *.json build/secret/report-json)
 $files += @(Get-ChildItem build/secret/curl.txt)
+$files += @(Get-ChildItem build/secret/report.har)
+$files += @(Get-ChildItem build/secret/report-metrics.txt)
+$files += @(Get-ChildItem build/secret/report-otlp.json)
 $files += @(Get-ChildItem build/secret-cookies.txt)
 $files += @(Get-ChildItem tests_ok/secret.err.pattern)
 
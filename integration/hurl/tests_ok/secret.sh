@@ -11,6 +11,9 @@ hurl --very-verbose \
     --cookie-jar build/secret-cookies.txt \
     --report-html build/secret/report-html \
     --report-json build/secret/report-json \
+    --report-har build/secret/report.har \
+    --report-metrics build/secret/report-metrics.txt \
+    --report-otlp build/secret/report-otlp.json \
     tests_ok/secret.hurl
 
 secrets=("secret1" "secret2" "secret3" "12345678" "secret-dynamic-0" "secret-dynamic-1" "secret-dynamic-2")
@@ -19,6 +22,9 @@ files=$(find build/secret/report-html/*.html \
   build/secret/report-html/**/*.html \
   build/secret/report-json/*.json \
   build/secret/curl.txt \
+  build/secret/report.har \
+  build/secret/report-metrics.txt \
+  build/secret/report-otlp.json \
   build/secret-cookies.txt \
   tests_ok/secret.err.pattern
 )
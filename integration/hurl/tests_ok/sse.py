@@ -0,0 +1,60 @@
+import gzip
+import time
+
+from app import app
+from flask import Response
+
+
+@app.route("/sse/events")
+def sse_events():
+    data = (
+        ": welcome\n"
+        "\n"
+        "id: 1\n"
+        "data: Hello\n"
+        "\n"
+        "id: 2\n"
+        "event: update\n"
+        'data: {"count": 2}\n'
+        "retry: 3000\n"
+        "\n"
+        "data: first line\n"
+        "data: second line\n"
+        "\n"
+    )
+    return Response(data, mimetype="text/event-stream")
+
+
+@app.route("/sse/infinite")
+def sse_infinite():
+    def generate():
+        count = 0
+        while True:
+            count += 1
+            yield f"id: {count}\ndata: tick {count}\n\n"
+            time.sleep(0.1)
+
+    return Response(generate(), mimetype="text/event-stream")
+
+
+@app.route("/sse/plain")
+def sse_plain():
+    return Response("data: a\n\ndata: b\n\n", mimetype="text/plain")
+
+
+@app.route("/sse/slow")
+def sse_slow():
+    def generate():
+        yield "a"
+        time.sleep(0.5)
+        yield "b"
+
+    return Response(generate(), mimetype="text/plain")
+
+
+@app.route("/sse/gzip")
+def sse_gzip():
+    data = gzip.compress(b"data: a\n\ndata: b\n\n")
+    return Response(
+        data, mimetype="text/event-stream", headers={"Content-Encoding": "gzip"}
+    )
@@ -0,0 +1,7 @@
+from app import app
+from flask import request
+
+
+@app.route("/traceparent")
+def traceparent():
+    return request.headers.get("traceparent", "")
@@ -0,0 +1,91 @@
+import base64
+import hashlib
+import struct
+
+from app import app
+from flask import Response, make_response, request
+
+WEBSOCKET_GUID = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11"
+
+
+def recv_exact(sock, n):
+    data = b""
+    while len(data) < n:
+        chunk = sock.recv(n - len(data))
+        if not chunk:
+            raise ConnectionError("connection closed")
+        data += chunk
+    return data
+
+
+def recv_frame(sock):
+    b0, b1 = recv_exact(sock, 2)
+    opcode = b0 & 0x0F
+    length = b1 & 0x7F
+    if length == 126:
+        (length,) = struct.unpack("!H", recv_exact(sock, 2))
+    elif length == 127:
+        (length,) = struct.unpack("!Q", recv_exact(sock, 8))
+    mask = recv_exact(sock, 4)
+    payload = recv_exact(sock, length)
+    payload = bytes(b ^ mask[i % 4] for i, b in enumerate(payload))
+    return opcode, payload
+
+
+def send_frame(sock, opcode, payload):
+    header = bytes([0x80 | opcode])
+    length = len(payload)
+    if length < 126:
+        header += bytes([length])
+    elif length < 65536:
+        header += bytes([126]) + struct.pack("!H", length)
+    else:
+        header += bytes([127]) + struct.pack("!Q", length)
+    sock.sendall(header + payload)
+
+
+def handshake():
+    sock = request.environ["werkzeug.socket"]
+    key = request.headers["Sec-WebSocket-Key"]
+    digest = hashlib.sha1((key + WEBSOCKET_GUID).encode()).digest()
+    accept = base64.b64encode(digest).decode()
+    sock.sendall(
+        (
+            "HTTP/1.1 101 Switching Protocols\r\n"
+            "Upgrade: websocket\r\n"
+            "Connection: Upgrade\r\n"
+            f"Sec-WebSocket-Accept: {accept}\r\n"
+            "\r\n"
+        ).encode()
+    )
+    return sock
+
+
+@app.route("/websocket/echo", websocket=True)
+def websocket_echo():
+    sock = handshake()
+    opcode, payload = recv_frame(sock)
+    send_frame(sock, opcode, payload)
+    send_frame(sock, 0x8, struct.pack("!H", 1000))
+    return ""
+
+
+@app.route("/websocket/greetings", websocket=True)
+def websocket_greetings():
+    sock = handshake()
+    for name in ["Alice", "Bob", "Charlie"]:
+        send_frame(sock, 0x1, f'{{"hello":"{name}"}}'.encode())
+    send_frame(sock, 0x8, struct.pack("!H", 1000))
+    return ""
+
+
+@app.route("/websocket/cookie")
+def websocket_cookie():
+    resp = make_response()
+    resp.set_cookie("token", "abc")
+    return resp
+
+
+@app.route("/websocket/refused", websocket=True)
+def websocket_refused():
+    return Response(f"Refused with token {request.cookies.get('token')}", status=403)
@@ -0,0 +1,12 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+try {
+    $cacert = Get-Content -Raw tests_ssl/certs/server/cert.selfsigned.pem
+    $client_cert = Get-Content -Raw tests_ssl/certs/client/cert.pem
+    $client_key = Get-Content -Raw tests_ssl/certs/client/key.pem
+    hurl tests_ssl/client_authentication_pem.hurl --variable "cacert=$cacert" --variable "client_cert=$client_cert" --secret "client_key=$client_key" --verbose
+    hurl tests_ssl/client_authentication.hurl --cacert="$cacert" --cert="$client_cert" --key="$client_key" --verbose
+} finally {
+    $global:LASTEXITCODE = 0
+    $ErrorActionPreference = 'Continue'
+}
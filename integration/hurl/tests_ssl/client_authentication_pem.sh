@@ -0,0 +1,12 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ssl/client_authentication_pem.hurl \
+    --variable cacert="$(cat tests_ssl/certs/server/cert.selfsigned.pem)" \
+    --variable client_cert="$(cat tests_ssl/certs/client/cert.pem)" \
+    --secret client_key="$(cat tests_ssl/certs/client/key.pem)" \
+    --verbose
+hurl tests_ssl/client_authentication.hurl \
+    --cacert="$(cat tests_ssl/certs/server/cert.selfsigned.pem)" \
+    --cert="$(cat tests_ssl/certs/client/cert.pem)" \
+    --key="$(cat tests_ssl/certs/client/key.pem)" \
+    --verbose
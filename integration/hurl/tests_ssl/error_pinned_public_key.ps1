@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ssl/error_pinned_public_key.hurl --cacert tests_ssl/certs/server/cert.selfsigned.pem
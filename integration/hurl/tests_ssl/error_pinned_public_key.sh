@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ssl/error_pinned_public_key.hurl --cacert tests_ssl/certs/server/cert.selfsigned.pem
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ssl/tls_verification.hurl --cacert tests_ssl/certs/server/cert.selfsigned.pem --verbose
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ssl/tls_verification.hurl --cacert tests_ssl/certs/server/cert.selfsigned.pem --verbose
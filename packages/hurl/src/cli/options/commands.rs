@@ -26,6 +26,17 @@ pub fn input_files() -> clap::Arg {
         .num_args(1..)
 }
 
+pub fn anyauth() -> clap::Arg {
+    clap::Arg::new("anyauth")
+        .long("anyauth")
+        .help("Pick any authentication method supported by the server")
+        .help_heading("HTTP options")
+        .conflicts_with("digest")
+        .conflicts_with("negotiate")
+        .conflicts_with("ntlm")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn aws_sigv4() -> clap::Arg {
     clap::Arg::new("aws_sigv4")
         .long("aws-sigv4")
@@ -44,6 +55,14 @@ pub fn cacert_file() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn cert_status() -> clap::Arg {
+    clap::Arg::new("cert_status")
+        .long("cert-status")
+        .help("Verify the status of the server certificate (OCSP stapling)")
+        .help_heading("HTTP options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn client_cert_file() -> clap::Arg {
     clap::Arg::new("client_cert_file")
         .long("cert")
@@ -72,10 +91,19 @@ pub fn color() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn compress_body() -> clap::Arg {
+    clap::Arg::new("compress_body")
+        .long("compress-body")
+        .value_name("ENCODING")
+        .help("Compress request bodies with ENCODING")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
 pub fn compressed() -> clap::Arg {
     clap::Arg::new("compressed")
         .long("compressed")
-        .help("Request compressed response (using deflate or gzip)")
+        .help("Request compressed response (using deflate, gzip, br or zstd)")
         .help_heading("HTTP options")
         .action(clap::ArgAction::SetTrue)
 }
@@ -100,6 +128,15 @@ pub fn connect_to() -> clap::Arg {
         .action(clap::ArgAction::Append)
 }
 
+pub fn content_digest() -> clap::Arg {
+    clap::Arg::new("content_digest")
+        .long("content-digest")
+        .value_name("ALGORITHM")
+        .help("Add a Content-Digest header to requests with a body")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
 pub fn continue_on_error() -> clap::Arg {
     clap::Arg::new("continue_on_error")
         .long("continue-on-error")
@@ -128,6 +165,15 @@ pub fn cookies_output_file() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn crl_file() -> clap::Arg {
+    clap::Arg::new("crl_file")
+        .long("crlfile")
+        .value_name("FILE")
+        .help("Use this CRL list (PEM format)")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
 pub fn curl() -> clap::Arg {
     clap::Arg::new("curl")
         .long("curl")
@@ -147,12 +193,23 @@ pub fn delay() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn digest() -> clap::Arg {
+    clap::Arg::new("digest")
+        .long("digest")
+        .help("Use HTTP Digest authentication")
+        .help_heading("HTTP options")
+        .conflicts_with("anyauth")
+        .conflicts_with("negotiate")
+        .conflicts_with("ntlm")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn error_format() -> clap::Arg {
     clap::Arg::new("error_format")
         .long("error-format")
         .value_name("FORMAT")
         .default_value("short")
-        .value_parser(["short", "long"])
+        .value_parser(["short", "long", "github"])
         .help("Control the format of error messages")
         .help_heading("Output options")
         .num_args(1)
@@ -249,6 +306,15 @@ pub fn http3() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn http_signature() -> clap::Arg {
+    clap::Arg::new("http_signature")
+        .long("http-signature")
+        .value_name("PARAMS")
+        .help("Sign requests with an HTTP message signature")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
 pub fn ignore_asserts() -> clap::Arg {
     clap::Arg::new("ignore_asserts")
         .long("ignore-asserts")
@@ -364,6 +430,17 @@ pub fn max_time() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn negotiate() -> clap::Arg {
+    clap::Arg::new("negotiate")
+        .long("negotiate")
+        .help("Use HTTP Negotiate (SPNEGO) authentication")
+        .help_heading("HTTP options")
+        .conflicts_with("anyauth")
+        .conflicts_with("digest")
+        .conflicts_with("ntlm")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn netrc() -> clap::Arg {
     clap::Arg::new("netrc")
         .long("netrc")
@@ -421,6 +498,26 @@ pub fn noproxy() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn ntlm() -> clap::Arg {
+    clap::Arg::new("ntlm")
+        .long("ntlm")
+        .help("Use HTTP NTLM authentication")
+        .help_heading("HTTP options")
+        .conflicts_with("anyauth")
+        .conflicts_with("digest")
+        .conflicts_with("negotiate")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn oauth2() -> clap::Arg {
+    clap::Arg::new("oauth2")
+        .long("oauth2")
+        .value_name("PARAMS")
+        .help("Use OAuth2 bearer token authentication")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
 pub fn output() -> clap::Arg {
     clap::Arg::new("output")
         .long("output")
@@ -447,6 +544,15 @@ pub fn path_as_is() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn pinned_pubkey() -> clap::Arg {
+    clap::Arg::new("pinned_pubkey")
+        .long("pinnedpubkey")
+        .value_name("HASHES")
+        .help("Public key to verify peer against")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
 pub fn proxy() -> clap::Arg {
     clap::Arg::new("proxy")
         .long("proxy")
@@ -457,6 +563,87 @@ pub fn proxy() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn proxy_anyauth() -> clap::Arg {
+    clap::Arg::new("proxy_anyauth")
+        .long("proxy-anyauth")
+        .help("Pick any proxy authentication method supported by the proxy")
+        .help_heading("HTTP options")
+        .conflicts_with("proxy_digest")
+        .conflicts_with("proxy_negotiate")
+        .conflicts_with("proxy_ntlm")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn proxy_cacert_file() -> clap::Arg {
+    clap::Arg::new("proxy_cacert_file")
+        .long("proxy-cacert")
+        .value_name("FILE")
+        .help("CA certificate to verify an HTTPS proxy against (PEM format)")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
+pub fn proxy_digest() -> clap::Arg {
+    clap::Arg::new("proxy_digest")
+        .long("proxy-digest")
+        .help("Use Digest authentication on the proxy")
+        .help_heading("HTTP options")
+        .conflicts_with("proxy_anyauth")
+        .conflicts_with("proxy_negotiate")
+        .conflicts_with("proxy_ntlm")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn proxy_header() -> clap::Arg {
+    clap::Arg::new("proxy_header")
+        .long("proxy-header")
+        .value_name("HEADER")
+        .help("Pass custom header(s) to proxy")
+        .help_heading("HTTP options")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
+pub fn proxy_insecure() -> clap::Arg {
+    clap::Arg::new("proxy_insecure")
+        .long("proxy-insecure")
+        .help("Allow insecure SSL connections to an HTTPS proxy")
+        .help_heading("HTTP options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn proxy_negotiate() -> clap::Arg {
+    clap::Arg::new("proxy_negotiate")
+        .long("proxy-negotiate")
+        .help("Use HTTP Negotiate (SPNEGO) authentication on the proxy")
+        .help_heading("HTTP options")
+        .conflicts_with("proxy_anyauth")
+        .conflicts_with("proxy_digest")
+        .conflicts_with("proxy_ntlm")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn proxy_ntlm() -> clap::Arg {
+    clap::Arg::new("proxy_ntlm")
+        .long("proxy-ntlm")
+        .help("Use NTLM authentication on the proxy")
+        .help_heading("HTTP options")
+        .conflicts_with("proxy_anyauth")
+        .conflicts_with("proxy_digest")
+        .conflicts_with("proxy_negotiate")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn proxy_user() -> clap::Arg {
+    clap::Arg::new("proxy_user")
+        .long("proxy-user")
+        .short('U')
+        .value_name("USER:PASSWORD")
+        .help("Specify the user name and password for proxy authentication")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
 pub fn repeat() -> clap::Arg {
     clap::Arg::new("repeat")
         .long("repeat")
@@ -468,6 +655,36 @@ pub fn repeat() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn report_compare() -> clap::Arg {
+    clap::Arg::new("report_compare")
+        .long("report-compare")
+        .value_name("FILE")
+        .help("Compare two JSON reports FILE (baseline first) into an HTML report")
+        .help_heading("Report options")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
+pub fn report_compare_threshold() -> clap::Arg {
+    clap::Arg::new("report_compare_threshold")
+        .long("report-compare-threshold")
+        .value_name("PERCENT")
+        .default_value("20")
+        .value_parser(clap::value_parser!(u64))
+        .help("Duration increase in percent above which an entry is reported as a regression")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
+pub fn report_har() -> clap::Arg {
+    clap::Arg::new("report_har")
+        .long("report-har")
+        .value_name("FILE")
+        .help("Write a HAR 1.2 archive of all HTTP calls to FILE")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
 pub fn report_html() -> clap::Arg {
     clap::Arg::new("report_html")
         .long("report-html")
@@ -477,6 +694,16 @@ pub fn report_html() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn report_html_max_body_size() -> clap::Arg {
+    clap::Arg::new("report_html_max_body_size")
+        .long("report-html-max-body-size")
+        .value_name("BYTES")
+        .value_parser(clap::value_parser!(u64))
+        .help("Limit the size in bytes of the HTTP bodies stored in the HTML report")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
 pub fn report_json() -> clap::Arg {
     clap::Arg::new("report_json")
         .long("report-json")
@@ -495,6 +722,53 @@ pub fn report_junit() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn report_junit_granularity() -> clap::Arg {
+    clap::Arg::new("report_junit_granularity")
+        .long("report-junit-granularity")
+        .value_name("GRANULARITY")
+        .default_value("file")
+        .value_parser(["file", "entry"])
+        .help("Generate one JUnit testcase per file or per entry")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
+pub fn report_markdown() -> clap::Arg {
+    clap::Arg::new("report_markdown")
+        .long("report-markdown")
+        .value_name("FILE")
+        .help("Write a Markdown summary report to FILE")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
+pub fn report_metrics() -> clap::Arg {
+    clap::Arg::new("report_metrics")
+        .long("report-metrics")
+        .value_name("FILE")
+        .help("Write metrics of the run in OpenMetrics text format to FILE")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
+pub fn report_otlp() -> clap::Arg {
+    clap::Arg::new("report_otlp")
+        .long("report-otlp")
+        .value_name("FILE")
+        .help("Write an OpenTelemetry trace (OTLP-JSON) of the run to FILE")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
+pub fn report_sarif() -> clap::Arg {
+    clap::Arg::new("report_sarif")
+        .long("report-sarif")
+        .value_name("FILE")
+        .help("Write a SARIF 2.1 report to FILE")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
 pub fn report_tap() -> clap::Arg {
     clap::Arg::new("report_tap")
         .long("report-tap")
@@ -545,6 +819,16 @@ pub fn secret() -> clap::Arg {
         .action(clap::ArgAction::Append)
 }
 
+pub fn socks5_hostname() -> clap::Arg {
+    clap::Arg::new("socks5_hostname")
+        .long("socks5-hostname")
+        .value_name("HOST[:PORT]")
+        .help("Use SOCKS5 proxy on given HOST/PORT, resolving host names with the proxy")
+        .help_heading("HTTP options")
+        .conflicts_with("proxy")
+        .num_args(1)
+}
+
 pub fn ssl_no_revoke() -> clap::Arg {
     clap::Arg::new("ssl_no_revoke")
         .long("ssl-no-revoke")
@@ -561,6 +845,24 @@ pub fn test() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn tls_max() -> clap::Arg {
+    clap::Arg::new("tls_max")
+        .long("tls-max")
+        .value_name("VERSION")
+        .help("Set maximum allowed TLS version")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
+pub fn tls_min() -> clap::Arg {
+    clap::Arg::new("tls_min")
+        .long("tls-min")
+        .value_name("VERSION")
+        .help("Set minimum allowed TLS version")
+        .help_heading("HTTP options")
+        .num_args(1)
+}
+
 pub fn to_entry() -> clap::Arg {
     clap::Arg::new("to_entry")
         .long("to-entry")
@@ -572,6 +874,14 @@ pub fn to_entry() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn traceparent() -> clap::Arg {
+    clap::Arg::new("traceparent")
+        .long("traceparent")
+        .help("Add a W3C traceparent header to each request")
+        .help_heading("HTTP options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn unix_socket() -> clap::Arg {
     clap::Arg::new("unix_socket")
         .long("unix-socket")
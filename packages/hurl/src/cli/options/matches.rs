@@ -19,22 +19,27 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader, IsTerminal};
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::time::Duration;
 use std::{env, fs, io};
 
 use clap::ArgMatches;
+use hurl::http::{is_pem, ContentEncoding, DigestAlgorithm, HttpSignature, OAuth2, TlsVersion};
+use hurl::report::junit;
 use hurl::runner::Value;
 use hurl_core::input::Input;
 use hurl_core::typing::{BytesPerSec, Count, DurationUnit};
 
 use crate::cli::options::{
-    duration, variables, CliOptionsError, ErrorFormat, HttpVersion, IpResolve, Output,
+    duration, variables, AuthMethod, CliOptionsError, ErrorFormat, HttpVersion, IpResolve, Output,
 };
 use crate::cli::OutputType;
 
 pub fn cacert_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
     match get_string(arg_matches, "cacert_file") {
         None => Ok(None),
+        // A PEM content can be used instead of a file.
+        Some(pem) if is_pem(&pem) => Ok(Some(pem)),
         Some(filename) => {
             let path = Path::new(&filename);
             if path.exists() {
@@ -49,6 +54,20 @@ pub fn cacert_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOption
     }
 }
 
+pub fn auth_method(arg_matches: &ArgMatches) -> Option<AuthMethod> {
+    if has_flag(arg_matches, "anyauth") {
+        Some(AuthMethod::Any)
+    } else if has_flag(arg_matches, "digest") {
+        Some(AuthMethod::Digest)
+    } else if has_flag(arg_matches, "negotiate") {
+        Some(AuthMethod::Negotiate)
+    } else if has_flag(arg_matches, "ntlm") {
+        Some(AuthMethod::Ntlm)
+    } else {
+        None
+    }
+}
+
 pub fn aws_sigv4(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "aws_sigv4")
 }
@@ -56,6 +75,8 @@ pub fn aws_sigv4(arg_matches: &ArgMatches) -> Option<String> {
 pub fn client_cert_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
     match get::<String>(arg_matches, "client_cert_file") {
         None => Ok(None),
+        // A PEM content can be used instead of a file.
+        Some(pem) if is_pem(&pem) => Ok(Some(pem)),
         Some(filename) => {
             if !Path::new(&filename).is_file() {
                 let message = format!("File {filename} does not exist");
@@ -70,6 +91,8 @@ pub fn client_cert_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliO
 pub fn client_key_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
     match get::<String>(arg_matches, "client_key_file") {
         None => Ok(None),
+        // A PEM content can be used instead of a file.
+        Some(pem) if is_pem(&pem) => Ok(Some(pem)),
         Some(filename) => {
             if !Path::new(&filename).is_file() {
                 let message = format!("File {filename} does not exist");
@@ -95,6 +118,50 @@ pub fn color(arg_matches: &ArgMatches, allow_color_from_env: bool) -> bool {
     allow_color_from_env
 }
 
+/// Returns the two JSON reports to compare: the baseline report, then the report to compare.
+pub fn compare_files(arg_matches: &ArgMatches) -> Result<Vec<PathBuf>, CliOptionsError> {
+    let Some(filenames) = get_strings(arg_matches, "report_compare") else {
+        return Ok(vec![]);
+    };
+    if filenames.len() != 2 {
+        return Err(CliOptionsError::Error(
+            "--report-compare must be used twice: the baseline JSON report and the JSON report to compare".to_string(),
+        ));
+    }
+    let mut files = vec![];
+    for filename in &filenames {
+        let path = Path::new(filename);
+        if !path.is_file() {
+            return Err(CliOptionsError::Error(format!(
+                "JSON report {} does not exist",
+                path.display()
+            )));
+        }
+        files.push(path.to_path_buf());
+    }
+    Ok(files)
+}
+
+pub fn compare_threshold(arg_matches: &ArgMatches) -> u64 {
+    get::<u64>(arg_matches, "report_compare_threshold").unwrap()
+}
+
+pub fn compress_body(arg_matches: &ArgMatches) -> Result<Option<ContentEncoding>, CliOptionsError> {
+    match get::<String>(arg_matches, "compress_body") {
+        None => Ok(None),
+        Some(encoding) => match ContentEncoding::from_str(&encoding) {
+            Ok(encoding) => Ok(Some(encoding)),
+            Err(message) => Err(CliOptionsError::Error(format!(
+                "Invalid --compress-body option: {message}"
+            ))),
+        },
+    }
+}
+
+pub fn cert_status(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "cert_status")
+}
+
 pub fn compressed(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "compressed")
 }
@@ -108,6 +175,20 @@ pub fn connects_to(arg_matches: &ArgMatches) -> Vec<String> {
     get_strings(arg_matches, "connect_to").unwrap_or_default()
 }
 
+pub fn content_digest(
+    arg_matches: &ArgMatches,
+) -> Result<Option<DigestAlgorithm>, CliOptionsError> {
+    match get::<String>(arg_matches, "content_digest") {
+        None => Ok(None),
+        Some(algorithm) => match DigestAlgorithm::from_str(&algorithm) {
+            Ok(algorithm) => Ok(Some(algorithm)),
+            Err(message) => Err(CliOptionsError::Error(format!(
+                "Invalid --content-digest option: {message}"
+            ))),
+        },
+    }
+}
+
 pub fn continue_on_error(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "continue_on_error")
 }
@@ -132,6 +213,7 @@ pub fn delay(arg_matches: &ArgMatches) -> Result<Duration, CliOptionsError> {
 pub fn error_format(arg_matches: &ArgMatches) -> ErrorFormat {
     let error_format = get::<String>(arg_matches, "error_format");
     match error_format.as_deref() {
+        Some("github") => ErrorFormat::Github,
         Some("long") => ErrorFormat::Long,
         Some("short") => ErrorFormat::Short,
         _ => ErrorFormat::Short,
@@ -153,6 +235,24 @@ pub fn from_entry(arg_matches: &ArgMatches) -> Option<usize> {
     get::<u32>(arg_matches, "from_entry").map(|x| x as usize)
 }
 
+pub fn crl_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
+    match get::<String>(arg_matches, "crl_file") {
+        None => Ok(None),
+        Some(filename) => {
+            if !Path::new(&filename).is_file() {
+                let message = format!("File {filename} does not exist");
+                Err(CliOptionsError::Error(message))
+            } else {
+                Ok(Some(filename))
+            }
+        }
+    }
+}
+
+pub fn har_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get::<String>(arg_matches, "report_har").map(PathBuf::from)
+}
+
 pub fn headers(arg_matches: &ArgMatches) -> Vec<String> {
     get_strings(arg_matches, "header").unwrap_or_default()
 }
@@ -181,6 +281,22 @@ pub fn html_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, CliOptionsE
     }
 }
 
+pub fn html_max_body_size(arg_matches: &ArgMatches) -> Option<u64> {
+    get::<u64>(arg_matches, "report_html_max_body_size")
+}
+
+pub fn http_signature(arg_matches: &ArgMatches) -> Result<Option<HttpSignature>, CliOptionsError> {
+    match get::<String>(arg_matches, "http_signature") {
+        None => Ok(None),
+        Some(params) => match HttpSignature::from_str(&params) {
+            Ok(http_signature) => Ok(Some(http_signature)),
+            Err(message) => Err(CliOptionsError::Error(format!(
+                "Invalid --http-signature option: {message}"
+            ))),
+        },
+    }
+}
+
 pub fn http_version(arg_matches: &ArgMatches) -> Option<HttpVersion> {
     if has_flag(arg_matches, "http3") {
         Some(HttpVersion::V3)
@@ -211,6 +327,10 @@ pub fn has_input_files(arg_matches: &ArgMatches) -> bool {
         || !io::stdin().is_terminal()
 }
 
+pub fn has_compare_files(arg_matches: &ArgMatches) -> bool {
+    get_strings(arg_matches, "report_compare").is_some()
+}
+
 /// Returns the input files from the positional arguments and the glob options
 pub fn input_files(arg_matches: &ArgMatches) -> Result<Vec<Input>, CliOptionsError> {
     let mut files = vec![];
@@ -282,10 +402,30 @@ pub fn junit_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
     get::<String>(arg_matches, "report_junit").map(PathBuf::from)
 }
 
+pub fn junit_granularity(arg_matches: &ArgMatches) -> junit::Granularity {
+    let granularity = get::<String>(arg_matches, "report_junit_granularity");
+    match granularity.as_deref() {
+        Some("entry") => junit::Granularity::Entry,
+        _ => junit::Granularity::File,
+    }
+}
+
 pub fn limit_rate(arg_matches: &ArgMatches) -> Option<BytesPerSec> {
     get::<u64>(arg_matches, "limit_rate").map(BytesPerSec)
 }
 
+pub fn markdown_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get::<String>(arg_matches, "report_markdown").map(PathBuf::from)
+}
+
+pub fn metrics_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get::<String>(arg_matches, "report_metrics").map(PathBuf::from)
+}
+
+pub fn otlp_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get::<String>(arg_matches, "report_otlp").map(PathBuf::from)
+}
+
 pub fn max_filesize(arg_matches: &ArgMatches) -> Option<u64> {
     get::<u64>(arg_matches, "max_filesize")
 }
@@ -351,6 +491,18 @@ pub fn no_proxy(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "noproxy")
 }
 
+pub fn oauth2(arg_matches: &ArgMatches) -> Result<Option<OAuth2>, CliOptionsError> {
+    match get::<String>(arg_matches, "oauth2") {
+        None => Ok(None),
+        Some(params) => match OAuth2::from_str(&params) {
+            Ok(oauth2) => Ok(Some(oauth2)),
+            Err(message) => Err(CliOptionsError::Error(format!(
+                "Invalid --oauth2 option: {message}"
+            ))),
+        },
+    }
+}
+
 pub fn output(arg_matches: &ArgMatches) -> Option<Output> {
     get::<String>(arg_matches, "output").map(|filename| Output::new(&filename))
 }
@@ -377,10 +529,61 @@ pub fn progress_bar(arg_matches: &ArgMatches) -> bool {
     test(arg_matches) && !interactive(arg_matches) && !is_ci() && io::stderr().is_terminal()
 }
 
+pub fn pinned_pubkey(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "pinned_pubkey")
+}
+
 pub fn proxy(arg_matches: &ArgMatches) -> Option<String> {
+    // With `--socks5-hostname`, host names are resolved by the SOCKS5 proxy.
+    if let Some(host) = get::<String>(arg_matches, "socks5_hostname") {
+        return Some(format!("socks5h://{host}"));
+    }
     get::<String>(arg_matches, "proxy")
 }
 
+pub fn proxy_auth_method(arg_matches: &ArgMatches) -> Option<AuthMethod> {
+    if has_flag(arg_matches, "proxy_anyauth") {
+        Some(AuthMethod::Any)
+    } else if has_flag(arg_matches, "proxy_digest") {
+        Some(AuthMethod::Digest)
+    } else if has_flag(arg_matches, "proxy_negotiate") {
+        Some(AuthMethod::Negotiate)
+    } else if has_flag(arg_matches, "proxy_ntlm") {
+        Some(AuthMethod::Ntlm)
+    } else {
+        None
+    }
+}
+
+pub fn proxy_cacert_file(arg_matches: &ArgMatches) -> Result<Option<String>, CliOptionsError> {
+    match get_string(arg_matches, "proxy_cacert_file") {
+        None => Ok(None),
+        Some(filename) => {
+            let path = Path::new(&filename);
+            if path.exists() {
+                Ok(Some(filename))
+            } else {
+                Err(CliOptionsError::Error(format!(
+                    "Input file {} does not exist",
+                    path.display()
+                )))
+            }
+        }
+    }
+}
+
+pub fn proxy_headers(arg_matches: &ArgMatches) -> Vec<String> {
+    get_strings(arg_matches, "proxy_header").unwrap_or_default()
+}
+
+pub fn proxy_insecure(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "proxy_insecure")
+}
+
+pub fn proxy_user(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "proxy_user")
+}
+
 pub fn repeat(arg_matches: &ArgMatches) -> Option<Count> {
     match get::<i32>(arg_matches, "repeat") {
         Some(-1) => Some(Count::Infinite),
@@ -406,6 +609,10 @@ pub fn retry_interval(arg_matches: &ArgMatches) -> Result<Duration, CliOptionsEr
     get_duration(&s, DurationUnit::MilliSecond)
 }
 
+pub fn sarif_file(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    get::<String>(arg_matches, "report_sarif").map(PathBuf::from)
+}
+
 pub fn secret(matches: &ArgMatches) -> Result<HashMap<String, String>, CliOptionsError> {
     let mut secrets = HashMap::new();
     if let Some(secret) = get_strings(matches, "secret") {
@@ -449,10 +656,38 @@ pub fn to_entry(arg_matches: &ArgMatches) -> Option<usize> {
     get::<u32>(arg_matches, "to_entry").map(|x| x as usize)
 }
 
+pub fn traceparent(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "traceparent")
+}
+
 pub fn unix_socket(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "unix_socket")
 }
 
+pub fn tls_max_version(arg_matches: &ArgMatches) -> Result<Option<TlsVersion>, CliOptionsError> {
+    tls_version(arg_matches, "tls_max")
+}
+
+pub fn tls_min_version(arg_matches: &ArgMatches) -> Result<Option<TlsVersion>, CliOptionsError> {
+    tls_version(arg_matches, "tls_min")
+}
+
+fn tls_version(
+    arg_matches: &ArgMatches,
+    name: &str,
+) -> Result<Option<TlsVersion>, CliOptionsError> {
+    match get::<String>(arg_matches, name) {
+        None => Ok(None),
+        Some(version) => match TlsVersion::from_str(&version) {
+            Ok(version) => Ok(Some(version)),
+            Err(message) => Err(CliOptionsError::Error(format!(
+                "Invalid --{} option: {message}",
+                name.replace('_', "-")
+            ))),
+        },
+    }
+}
+
 pub fn user(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "user")
 }
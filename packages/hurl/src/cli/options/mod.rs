@@ -31,7 +31,10 @@ use clap::builder::Styles;
 use clap::ArgMatches;
 pub use error::CliOptionsError;
 use hurl::http;
-use hurl::http::RequestedHttpVersion;
+use hurl::http::{
+    ContentEncoding, DigestAlgorithm, HttpSignature, OAuth2, RequestedHttpVersion, TlsVersion,
+};
+use hurl::report::junit;
 use hurl::runner::Output;
 use hurl::util::logger::{LoggerOptions, LoggerOptionsBuilder, Verbosity};
 use hurl::util::path::ContextDir;
@@ -45,16 +48,23 @@ use crate::runner::{RunnerOptions, RunnerOptionsBuilder, Value};
 /// Represents the list of all options that can be used in Hurl command line.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CliOptions {
+    pub auth_method: Option<AuthMethod>,
     pub aws_sigv4: Option<String>,
     pub cacert_file: Option<String>,
+    pub cert_status: bool,
     pub client_cert_file: Option<String>,
     pub client_key_file: Option<String>,
     pub color: bool,
+    pub compare_files: Vec<PathBuf>,
+    pub compare_threshold: u64,
+    pub compress_body: Option<ContentEncoding>,
     pub compressed: bool,
     pub connect_timeout: Duration,
     pub connects_to: Vec<String>,
+    pub content_digest: Option<DigestAlgorithm>,
     pub continue_on_error: bool,
     pub cookie_input_file: Option<String>,
+    pub crl_file: Option<String>,
     pub cookie_output_file: Option<PathBuf>,
     pub curl_file: Option<PathBuf>,
     pub delay: Duration,
@@ -63,8 +73,11 @@ pub struct CliOptions {
     pub follow_location: bool,
     pub follow_location_trusted: bool,
     pub from_entry: Option<usize>,
+    pub har_file: Option<PathBuf>,
     pub headers: Vec<String>,
+    pub http_signature: Option<HttpSignature>,
     pub html_dir: Option<PathBuf>,
+    pub html_max_body_size: Option<u64>,
     pub http_version: Option<HttpVersion>,
     pub ignore_asserts: bool,
     pub include: bool,
@@ -75,29 +88,44 @@ pub struct CliOptions {
     pub jobs: Option<usize>,
     pub json_report_dir: Option<PathBuf>,
     pub junit_file: Option<PathBuf>,
+    pub junit_granularity: junit::Granularity,
     pub limit_rate: Option<BytesPerSec>,
+    pub markdown_file: Option<PathBuf>,
     pub max_filesize: Option<u64>,
+    pub metrics_file: Option<PathBuf>,
     pub max_redirect: Count,
     pub netrc: bool,
     pub netrc_file: Option<String>,
     pub netrc_optional: bool,
     pub no_proxy: Option<String>,
+    pub oauth2: Option<OAuth2>,
+    pub otlp_file: Option<PathBuf>,
     pub output: Option<Output>,
     pub output_type: OutputType,
     pub parallel: bool,
     pub path_as_is: bool,
+    pub pinned_pubkey: Option<String>,
     pub progress_bar: bool,
     pub proxy: Option<String>,
+    pub proxy_auth_method: Option<AuthMethod>,
+    pub proxy_cacert_file: Option<String>,
+    pub proxy_headers: Vec<String>,
+    pub proxy_insecure: bool,
+    pub proxy_user: Option<String>,
     pub repeat: Option<Count>,
     pub resolves: Vec<String>,
     pub retry: Option<Count>,
     pub retry_interval: Duration,
+    pub sarif_file: Option<PathBuf>,
     pub secrets: HashMap<String, String>,
     pub ssl_no_revoke: bool,
     pub tap_file: Option<PathBuf>,
     pub test: bool,
     pub timeout: Duration,
+    pub tls_max_version: Option<TlsVersion>,
+    pub tls_min_version: Option<TlsVersion>,
     pub to_entry: Option<usize>,
+    pub traceparent: bool,
     pub unix_socket: Option<String>,
     pub user: Option<String>,
     pub user_agent: Option<String>,
@@ -106,11 +134,32 @@ pub struct CliOptions {
     pub very_verbose: bool,
 }
 
-/// Error format: long or rich.
+/// HTTP authentication method used with user credentials.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AuthMethod {
+    Any,
+    Digest,
+    Negotiate,
+    Ntlm,
+}
+
+impl From<AuthMethod> for http::AuthMethod {
+    fn from(value: AuthMethod) -> Self {
+        match value {
+            AuthMethod::Any => http::AuthMethod::Any,
+            AuthMethod::Digest => http::AuthMethod::Digest,
+            AuthMethod::Negotiate => http::AuthMethod::Negotiate,
+            AuthMethod::Ntlm => http::AuthMethod::Ntlm,
+        }
+    }
+}
+
+/// Error format: long or rich, or GitHub Actions annotations.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ErrorFormat {
     Short,
     Long,
+    Github,
 }
 
 impl From<ErrorFormat> for hurl::util::logger::ErrorFormat {
@@ -118,6 +167,7 @@ impl From<ErrorFormat> for hurl::util::logger::ErrorFormat {
         match value {
             ErrorFormat::Short => hurl::util::logger::ErrorFormat::Short,
             ErrorFormat::Long => hurl::util::logger::ErrorFormat::Long,
+            ErrorFormat::Github => hurl::util::logger::ErrorFormat::Github,
         }
     }
 }
@@ -187,17 +237,24 @@ pub fn parse(allow_color: bool) -> Result<CliOptions, CliOptionsError> {
         .styles(styles)
         .about("Hurl, run and test HTTP requests with plain text")
         // HTTP options
+        .arg(commands::anyauth())
         .arg(commands::aws_sigv4())
         .arg(commands::cacert_file())
+        .arg(commands::cert_status())
         .arg(commands::client_cert_file())
+        .arg(commands::compress_body())
         .arg(commands::compressed())
         .arg(commands::connect_timeout())
         .arg(commands::connect_to())
+        .arg(commands::content_digest())
+        .arg(commands::crl_file())
+        .arg(commands::digest())
         .arg(commands::header())
         .arg(commands::http10())
         .arg(commands::http11())
         .arg(commands::http2())
         .arg(commands::http3())
+        .arg(commands::http_signature())
         .arg(commands::input_files())
         .arg(commands::insecure())
         .arg(commands::ipv4())
@@ -209,11 +266,27 @@ pub fn parse(allow_color: bool) -> Result<CliOptions, CliOptionsError> {
         .arg(commands::max_filesize())
         .arg(commands::max_redirects())
         .arg(commands::max_time())
+        .arg(commands::negotiate())
         .arg(commands::noproxy())
+        .arg(commands::ntlm())
+        .arg(commands::oauth2())
         .arg(commands::path_as_is())
+        .arg(commands::pinned_pubkey())
         .arg(commands::proxy())
+        .arg(commands::proxy_anyauth())
+        .arg(commands::proxy_cacert_file())
+        .arg(commands::proxy_digest())
+        .arg(commands::proxy_header())
+        .arg(commands::proxy_insecure())
+        .arg(commands::proxy_negotiate())
+        .arg(commands::proxy_ntlm())
+        .arg(commands::proxy_user())
         .arg(commands::resolve())
+        .arg(commands::socks5_hostname())
         .arg(commands::ssl_no_revoke())
+        .arg(commands::tls_max())
+        .arg(commands::tls_min())
+        .arg(commands::traceparent())
         .arg(commands::unix_socket())
         .arg(commands::user())
         .arg(commands::user_agent())
@@ -245,9 +318,18 @@ pub fn parse(allow_color: bool) -> Result<CliOptions, CliOptionsError> {
         .arg(commands::variable())
         .arg(commands::variables_file())
         // Report options
+        .arg(commands::report_har())
+        .arg(commands::report_compare())
+        .arg(commands::report_compare_threshold())
         .arg(commands::report_html())
+        .arg(commands::report_html_max_body_size())
         .arg(commands::report_json())
         .arg(commands::report_junit())
+        .arg(commands::report_junit_granularity())
+        .arg(commands::report_markdown())
+        .arg(commands::report_metrics())
+        .arg(commands::report_otlp())
+        .arg(commands::report_sarif())
         .arg(commands::report_tap())
         // Other options
         .arg(commands::cookies_input_file())
@@ -265,8 +347,9 @@ pub fn parse(allow_color: bool) -> Result<CliOptions, CliOptionsError> {
     };
 
     // If we've no file input (either from the standard input or from the command line arguments),
-    // we just print help and exit.
-    if !matches::has_input_files(&arg_matches) {
+    // we just print help and exit. When comparing JSON reports, no Hurl file is run.
+    let compare = matches::has_compare_files(&arg_matches);
+    if !compare && !matches::has_input_files(&arg_matches) {
         let help = if allow_color {
             command.render_help().ansi().to_string()
         } else {
@@ -276,6 +359,15 @@ pub fn parse(allow_color: bool) -> Result<CliOptions, CliOptionsError> {
     }
 
     let opts = parse_matches(&arg_matches, allow_color)?;
+    if compare {
+        if opts.html_dir.is_none() {
+            return Err(CliOptionsError::Error(
+                "--report-compare requires an HTML report directory (--report-html DIR)"
+                    .to_string(),
+            ));
+        }
+        return Ok(opts);
+    }
     if opts.input_files.is_empty() {
         return Err(CliOptionsError::Error(
             "No input files provided".to_string(),
@@ -289,16 +381,23 @@ fn parse_matches(
     arg_matches: &ArgMatches,
     allow_color: bool,
 ) -> Result<CliOptions, CliOptionsError> {
+    let auth_method = matches::auth_method(arg_matches);
     let aws_sigv4 = matches::aws_sigv4(arg_matches);
     let cacert_file = matches::cacert_file(arg_matches)?;
+    let cert_status = matches::cert_status(arg_matches);
     let client_cert_file = matches::client_cert_file(arg_matches)?;
     let client_key_file = matches::client_key_file(arg_matches)?;
     let color = matches::color(arg_matches, allow_color);
+    let compare_files = matches::compare_files(arg_matches)?;
+    let compare_threshold = matches::compare_threshold(arg_matches);
+    let compress_body = matches::compress_body(arg_matches)?;
     let compressed = matches::compressed(arg_matches);
     let connect_timeout = matches::connect_timeout(arg_matches)?;
     let connects_to = matches::connects_to(arg_matches);
+    let content_digest = matches::content_digest(arg_matches)?;
     let continue_on_error = matches::continue_on_error(arg_matches);
     let cookie_input_file = matches::cookie_input_file(arg_matches);
+    let crl_file = matches::crl_file(arg_matches)?;
     let cookie_output_file = matches::cookie_output_file(arg_matches);
     let curl_file = matches::curl_file(arg_matches);
     let delay = matches::delay(arg_matches)?;
@@ -306,8 +405,11 @@ fn parse_matches(
     let file_root = matches::file_root(arg_matches);
     let (follow_location, follow_location_trusted) = matches::follow_location(arg_matches);
     let from_entry = matches::from_entry(arg_matches);
+    let har_file = matches::har_file(arg_matches);
     let headers = matches::headers(arg_matches);
+    let http_signature = matches::http_signature(arg_matches)?;
     let html_dir = matches::html_dir(arg_matches)?;
+    let html_max_body_size = matches::html_max_body_size(arg_matches);
     let http_version = matches::http_version(arg_matches);
     let ignore_asserts = matches::ignore_asserts(arg_matches);
     let include = matches::include(arg_matches);
@@ -318,29 +420,44 @@ fn parse_matches(
     let jobs = matches::jobs(arg_matches);
     let json_report_dir = matches::json_report_dir(arg_matches)?;
     let junit_file = matches::junit_file(arg_matches);
+    let junit_granularity = matches::junit_granularity(arg_matches);
     let limit_rate = matches::limit_rate(arg_matches);
+    let markdown_file = matches::markdown_file(arg_matches);
     let max_filesize = matches::max_filesize(arg_matches);
     let max_redirect = matches::max_redirect(arg_matches);
+    let metrics_file = matches::metrics_file(arg_matches);
     let netrc = matches::netrc(arg_matches);
     let netrc_file = matches::netrc_file(arg_matches)?;
     let netrc_optional = matches::netrc_optional(arg_matches);
     let no_proxy = matches::no_proxy(arg_matches);
+    let oauth2 = matches::oauth2(arg_matches)?;
+    let otlp_file = matches::otlp_file(arg_matches);
     let parallel = matches::parallel(arg_matches);
     let path_as_is = matches::path_as_is(arg_matches);
+    let pinned_pubkey = matches::pinned_pubkey(arg_matches);
     let progress_bar = matches::progress_bar(arg_matches);
     let proxy = matches::proxy(arg_matches);
+    let proxy_auth_method = matches::proxy_auth_method(arg_matches);
+    let proxy_cacert_file = matches::proxy_cacert_file(arg_matches)?;
+    let proxy_headers = matches::proxy_headers(arg_matches);
+    let proxy_insecure = matches::proxy_insecure(arg_matches);
+    let proxy_user = matches::proxy_user(arg_matches);
     let output = matches::output(arg_matches);
     let output_type = matches::output_type(arg_matches);
     let repeat = matches::repeat(arg_matches);
     let resolves = matches::resolves(arg_matches);
     let retry = matches::retry(arg_matches);
     let retry_interval = matches::retry_interval(arg_matches)?;
+    let sarif_file = matches::sarif_file(arg_matches);
     let secrets = matches::secret(arg_matches)?;
     let ssl_no_revoke = matches::ssl_no_revoke(arg_matches);
     let tap_file = matches::tap_file(arg_matches);
     let test = matches::test(arg_matches);
     let timeout = matches::timeout(arg_matches)?;
+    let tls_max_version = matches::tls_max_version(arg_matches)?;
+    let tls_min_version = matches::tls_min_version(arg_matches)?;
     let to_entry = matches::to_entry(arg_matches);
+    let traceparent = matches::traceparent(arg_matches);
     let unix_socket = matches::unix_socket(arg_matches);
     let user = matches::user(arg_matches);
     let user_agent = matches::user_agent(arg_matches);
@@ -348,16 +465,23 @@ fn parse_matches(
     let verbose = matches::verbose(arg_matches);
     let very_verbose = matches::very_verbose(arg_matches);
     Ok(CliOptions {
+        auth_method,
         aws_sigv4,
         cacert_file,
+        cert_status,
         client_cert_file,
         client_key_file,
         color,
+        compare_files,
+        compare_threshold,
+        compress_body,
         compressed,
         connect_timeout,
         connects_to,
+        content_digest,
         continue_on_error,
         cookie_input_file,
+        crl_file,
         cookie_output_file,
         curl_file,
         delay,
@@ -366,8 +490,11 @@ fn parse_matches(
         follow_location,
         follow_location_trusted,
         from_entry,
+        har_file,
         headers,
+        http_signature,
         html_dir,
+        html_max_body_size,
         http_version,
         ignore_asserts,
         include,
@@ -377,29 +504,44 @@ fn parse_matches(
         ip_resolve,
         json_report_dir,
         junit_file,
+        junit_granularity,
         limit_rate,
+        markdown_file,
         max_filesize,
         max_redirect,
+        metrics_file,
         netrc,
         netrc_file,
         netrc_optional,
         no_proxy,
+        oauth2,
+        otlp_file,
         path_as_is,
+        pinned_pubkey,
         parallel,
         progress_bar,
         proxy,
+        proxy_auth_method,
+        proxy_cacert_file,
+        proxy_headers,
+        proxy_insecure,
+        proxy_user,
         output,
         output_type,
         repeat,
         resolves,
         retry,
         retry_interval,
+        sarif_file,
         secrets,
         ssl_no_revoke,
         tap_file,
         test,
         timeout,
+        tls_max_version,
+        tls_min_version,
         to_entry,
+        traceparent,
         unix_socket,
         user,
         user_agent,
@@ -423,13 +565,20 @@ pub enum OutputType {
 impl CliOptions {
     /// Converts this instance of [`CliOptions`] to an instance of [`RunnerOptions`]
     pub fn to_runner_options(&self, filename: &Input, current_dir: &Path) -> RunnerOptions {
+        let auth_method = match self.auth_method {
+            Some(auth_method) => auth_method.into(),
+            None => http::AuthMethod::default(),
+        };
         let aws_sigv4 = self.aws_sigv4.clone();
         let cacert_file = self.cacert_file.clone();
+        let cert_status = self.cert_status;
         let client_cert_file = self.client_cert_file.clone();
         let client_key_file = self.client_key_file.clone();
+        let compress_body = self.compress_body;
         let compressed = self.compressed;
         let connect_timeout = self.connect_timeout;
         let connects_to = self.connects_to.clone();
+        let content_digest = self.content_digest;
         let file_root = match &self.file_root {
             Some(f) => Path::new(f),
             None => match filename.kind() {
@@ -440,11 +589,13 @@ impl CliOptions {
         let context_dir = ContextDir::new(current_dir, file_root);
         let continue_on_error = self.continue_on_error;
         let cookie_input_file = self.cookie_input_file.clone();
+        let crl_file = self.crl_file.clone();
         let delay = self.delay;
         let follow_location = self.follow_location;
         let follow_location_trusted = self.follow_location_trusted;
         let from_entry = self.from_entry;
         let headers = &self.headers;
+        let http_signature = self.http_signature.clone();
         let http_version = match self.http_version {
             Some(version) => version.into(),
             None => RequestedHttpVersion::default(),
@@ -465,8 +616,10 @@ impl CliOptions {
         let netrc_file = self.netrc_file.clone();
         let netrc_optional = self.netrc_optional;
         let no_proxy = self.no_proxy.clone();
+        let oauth2 = self.oauth2.clone();
         let output = self.output.clone();
         let path_as_is = self.path_as_is;
+        let pinned_pubkey = self.pinned_pubkey.clone();
         let post_entry = if self.interactive {
             Some(cli::interactive::post_entry as fn() -> bool)
         } else {
@@ -478,32 +631,49 @@ impl CliOptions {
             None
         };
         let proxy = self.proxy.clone();
+        let proxy_auth_method = match self.proxy_auth_method {
+            Some(auth_method) => auth_method.into(),
+            None => http::AuthMethod::default(),
+        };
+        let proxy_cacert_file = self.proxy_cacert_file.clone();
+        let proxy_headers = self.proxy_headers.clone();
+        let proxy_insecure = self.proxy_insecure;
+        let proxy_user = self.proxy_user.clone();
         let resolves = self.resolves.clone();
         let retry = self.retry;
         let retry_interval = self.retry_interval;
         let ssl_no_revoke = self.ssl_no_revoke;
         let timeout = self.timeout;
+        let tls_max_version = self.tls_max_version;
+        let tls_min_version = self.tls_min_version;
         let to_entry = self.to_entry;
+        let traceparent = self.traceparent;
         let unix_socket = self.unix_socket.clone();
         let user = self.user.clone();
         let user_agent = self.user_agent.clone();
 
         RunnerOptionsBuilder::new()
+            .auth_method(auth_method)
             .aws_sigv4(aws_sigv4)
             .cacert_file(cacert_file)
+            .cert_status(cert_status)
             .client_cert_file(client_cert_file)
             .client_key_file(client_key_file)
             .delay(delay)
+            .compress_body(compress_body)
             .compressed(compressed)
             .connect_timeout(connect_timeout)
             .connects_to(&connects_to)
+            .content_digest(content_digest)
             .continue_on_error(continue_on_error)
             .context_dir(&context_dir)
             .cookie_input_file(cookie_input_file)
+            .crl_file(crl_file)
             .follow_location(follow_location)
             .follow_location_trusted(follow_location_trusted)
             .from_entry(from_entry)
             .headers(headers)
+            .http_signature(http_signature)
             .http_version(http_version)
             .ignore_asserts(ignore_asserts)
             .insecure(insecure)
@@ -516,17 +686,27 @@ impl CliOptions {
             .netrc_file(netrc_file)
             .netrc_optional(netrc_optional)
             .no_proxy(no_proxy)
+            .oauth2(oauth2)
             .output(output)
             .path_as_is(path_as_is)
+            .pinned_pubkey(pinned_pubkey)
             .post_entry(post_entry)
             .pre_entry(pre_entry)
             .proxy(proxy)
+            .proxy_auth_method(proxy_auth_method)
+            .proxy_cacert_file(proxy_cacert_file)
+            .proxy_headers(&proxy_headers)
+            .proxy_insecure(proxy_insecure)
+            .proxy_user(proxy_user)
             .resolves(&resolves)
             .retry(retry)
             .retry_interval(retry_interval)
             .ssl_no_revoke(ssl_no_revoke)
             .timeout(timeout)
+            .tls_max_version(tls_max_version)
+            .tls_min_version(tls_min_version)
             .to_entry(to_entry)
+            .traceparent(traceparent)
             .unix_socket(unix_socket)
             .user(user)
             .user_agent(user_agent)
@@ -15,7 +15,9 @@
  * limitations under the License.
  *
  */
+use std::cell::Cell;
 use std::collections::HashMap;
+use std::path::Path;
 use std::str;
 use std::str::FromStr;
 use std::time::Instant;
@@ -23,26 +25,34 @@ use std::time::Instant;
 use base64::engine::general_purpose;
 use base64::Engine;
 use chrono::Utc;
-use curl::easy::{List, NetRc, SslOpt};
+use curl::easy::{Auth, List, NetRc, SslOpt, SslVersion};
 use curl::{easy, Version};
 use encoding::all::ISO_8859_1;
 use encoding::{DecoderTrap, Encoding};
 use hurl_core::typing::Count;
+use rand_core::{OsRng, RngCore};
 
 use crate::http::certificate::Certificate;
 use crate::http::curl_cmd::CurlCmd;
 use crate::http::debug::log_body;
+use crate::http::easy_ext::HeaderList;
+use crate::http::grpc::GrpcMethod;
 use crate::http::header::{
-    HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, EXPECT, LOCATION, USER_AGENT,
+    HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_DIGEST, CONTENT_ENCODING, CONTENT_LENGTH,
+    CONTENT_TYPE, COOKIE, EXPECT, HOST, LOCATION, SEC_WEBSOCKET_KEY, TE, TRACEPARENT, USER_AGENT,
 };
 use crate::http::ip::IpAddr;
+use crate::http::mimetype;
 use crate::http::options::ClientOptions;
+use crate::http::sse::EventStreamParser;
 use crate::http::timings::Timings;
+use crate::http::traceparent::random_trace_id;
 use crate::http::url::Url;
+use crate::http::websocket::{self, WebSocket};
 use crate::http::{
-    easy_ext, Call, Cookie, FileParam, Header, HttpError, HttpVersion, IpResolve, Method,
-    MultipartParam, Param, Request, RequestCookie, RequestSpec, RequestedHttpVersion, Response,
-    Verbosity,
+    easy_ext, is_pem, AuthMethod, Body, Call, Cookie, FileParam, Header, HttpError, HttpSignature,
+    HttpVersion, IpResolve, Method, MultipartParam, Param, Request, RequestCookie, RequestSpec,
+    RequestedHttpVersion, Response, TlsVersion, TraceParent, Verbosity, WebSocketMessage,
 };
 use crate::runner::Output;
 use crate::util::logger::Logger;
@@ -61,6 +71,14 @@ pub struct Client {
     http3: bool,
     /// Certificates cache to get SSL certificates on reused libcurl connections.
     certificates: HashMap<i64, Certificate>,
+    /// Headers sent to the proxy, kept alive while the libcurl handle uses them.
+    proxy_headers: HeaderList,
+    /// `SameSite` attributes of the stored cookies, indexed by cookie domain and name: libcurl
+    /// cookie storage doesn't keep this attribute.
+    same_sites: HashMap<(String, String), String>,
+    /// Trace id of the `traceparent` headers sent by this client: all the requests of a client
+    /// belong to the same trace.
+    trace_id: String,
 }
 
 impl Client {
@@ -73,6 +91,9 @@ impl Client {
             http2: version.feature_http2(),
             http3: version.feature_http3(),
             certificates: HashMap::new(),
+            proxy_headers: HeaderList::default(),
+            same_sites: HashMap::new(),
+            trace_id: random_trace_id(),
         }
     }
 
@@ -149,15 +170,46 @@ impl Client {
         // prior to everything.
         self.handle.reset();
 
+        if request_spec.url.is_websocket() {
+            return self.execute_websocket(request_spec, options, logger);
+        }
+        if let Some(grpc_proto) = &options.grpc_proto {
+            return self.execute_grpc(request_spec, grpc_proto, options, logger);
+        }
+
         let (url, method) = self.configure(request_spec, options, logger)?;
 
+        // Event streams can be read for a given duration: the transfer is stopped from the write
+        // and progress callbacks, the progress callback being called even if no data is received.
+        let sse_duration = options.sse_duration;
+        if sse_duration.is_some() {
+            self.handle.progress(true)?;
+        }
+        let max_events = match options.sse_events {
+            Count::Finite(n) => Some(n),
+            Count::Infinite => None,
+        };
+        let mut event_stream = EventStreamParser::new();
+        let stream_stopped = Cell::new(false);
+        // Only a non compressed `text/event-stream` response can be parsed while it's received.
+        let is_event_stream = Cell::new(false);
+        let is_encoded = Cell::new(false);
+
         let start = Instant::now();
+        // Returns true if an event stream has been read for the expected duration.
+        let is_stream_elapsed = || {
+            sse_duration.is_some_and(|d| start.elapsed() >= d)
+                && is_event_stream.get()
+                && !is_encoded.get()
+        };
         let start_dt = Utc::now();
         let verbose = options.verbosity.is_some();
         let very_verbose = options.verbosity == Some(Verbosity::VeryVerbose);
         let mut request_headers = HeaderVec::new();
         let mut status_lines = vec![];
         let mut response_headers = vec![];
+        let auth_round_trip = options.auth_method != AuthMethod::Basic
+            || options.proxy_auth_method != AuthMethod::Basic;
         let has_body_data = !request_spec.body.bytes().is_empty()
             || !request_spec.form.is_empty()
             || !request_spec.multipart.is_empty();
@@ -168,6 +220,7 @@ impl Client {
         // of key-value.
         let mut request_body = Vec::<u8>::new();
         let mut response_body = Vec::<u8>::new();
+        let transfer_result;
 
         {
             let mut transfer = self.handle.transfer();
@@ -176,6 +229,10 @@ impl Client {
                 // Return all request headers (not one by one)
                 easy::InfoType::HeaderOut => {
                     let lines = split_lines(data);
+                    // Some authentication methods (Digest, NTLM etc...) need several requests:
+                    // we only keep the headers and body of the last request.
+                    request_headers = HeaderVec::new();
+                    request_body.clear();
                     // Extracts request headers from libcurl debug info.
                     // First line is method/path/version line, last line is empty
                     for line in &lines[1..lines.len() - 1] {
@@ -227,8 +284,22 @@ impl Client {
             transfer.header_function(|h| {
                 if let Some(s) = decode_header(h) {
                     if s.starts_with("HTTP/") {
+                        // With authentication methods needing several requests, the headers of
+                        // the previous responses (authentication challenges) are discarded.
+                        if auth_round_trip {
+                            response_headers.clear();
+                        }
+                        is_event_stream.set(false);
+                        is_encoded.set(false);
                         status_lines.push(s);
                     } else {
+                        if let Some(header) = Header::parse(&s) {
+                            if header.name_eq(CONTENT_TYPE) {
+                                is_event_stream.set(mimetype::is_event_stream(&header.value));
+                            } else if header.name_eq(CONTENT_ENCODING) {
+                                is_encoded.set(!header.value.eq_ignore_ascii_case("identity"));
+                            }
+                        }
                         response_headers.push(s);
                     }
                 }
@@ -236,17 +307,50 @@ impl Client {
             })?;
 
             transfer.write_function(|data| {
-                response_body.extend(data);
+                if is_stream_elapsed() {
+                    stream_stopped.set(true);
+                    return Ok(0);
+                }
+                let Some(max_events) =
+                    max_events.filter(|_| is_event_stream.get() && !is_encoded.get())
+                else {
+                    response_body.extend(data);
+                    return Ok(data.len());
+                };
+                // We keep the body bytes up to the last expected event, and abort the transfer
+                // (by consuming less bytes than provided) once all the events have been received.
+                let len = event_stream.parse(data, Some(max_events));
+                response_body.extend(&data[..len]);
+                if event_stream.count() >= max_events {
+                    stream_stopped.set(true);
+                    return Ok(0);
+                }
                 Ok(data.len())
             })?;
 
-            if let Err(e) = transfer.perform() {
-                let code = e.code() as i32; // due to windows build
-                let description = match e.extra_description() {
-                    None => e.description().to_string(),
-                    Some(s) => s.to_string(),
-                };
-                return Err(HttpError::Libcurl { code, description });
+            if sse_duration.is_some() {
+                transfer.progress_function(|_, _, _, _| {
+                    if is_stream_elapsed() {
+                        stream_stopped.set(true);
+                        return false;
+                    }
+                    true
+                })?;
+            }
+
+            transfer_result = transfer.perform();
+        }
+
+        if let Err(e) = transfer_result {
+            // An event stream stopped after the expected number of events, or after the expected
+            // duration, is not an error.
+            let stopped =
+                stream_stopped.get() && (e.is_write_error() || e.is_aborted_by_callback());
+            if !stopped || status_lines.is_empty() {
+                let stop_dt = start_dt + start.elapsed();
+                let timings = Timings::new(&mut self.handle, start_dt, stop_dt);
+                let tls = url.starts_with("https://");
+                return Err(transfer_error(&e, options, &timings, tls));
             }
         }
 
@@ -266,10 +370,10 @@ impl Client {
         let status = self.handle.response_code()?;
         // TODO: explain why status_lines is Vec ?
         let version = match status_lines.last() {
-            Some(status_line) => self.parse_response_version(status_line)?,
+            Some(status_line) => parse_response_version(status_line)?,
             None => return Err(HttpError::CouldNotParseResponse),
         };
-        let headers = self.parse_response_headers(&response_headers);
+        let headers = parse_response_headers(&response_headers);
         let length = response_body.len();
 
         let certificate = self.cert_info(logger)?;
@@ -294,7 +398,9 @@ impl Client {
             url,
             certificate,
             ip_addr,
+            vec![],
         );
+        self.update_same_sites(&response);
 
         if verbose {
             // FIXME: the cast to u64 seems not necessary.
@@ -335,6 +441,299 @@ impl Client {
         })
     }
 
+    /// Executes a gRPC call described by `request_spec`, the gRPC method being described by the
+    /// `.proto` file (or descriptor set) `grpc_proto`.
+    ///
+    /// The JSON request body is encoded to a protobuf message, and sent over HTTP/2. The protobuf
+    /// response messages are decoded to JSON, while the gRPC status is available in the response
+    /// trailers (exposed as standard response headers).
+    fn execute_grpc(
+        &mut self,
+        request_spec: &RequestSpec,
+        grpc_proto: &str,
+        options: &ClientOptions,
+        logger: &mut Logger,
+    ) -> Result<Call, HttpError> {
+        let method = GrpcMethod::load(Path::new(grpc_proto), &request_spec.url.path())?;
+        let body = method.encode_request(&request_spec.body.bytes())?;
+
+        let mut headers = request_spec.headers.clone();
+        if !headers.contains_key(TE) {
+            headers.push(Header::new(TE, "trailers"));
+        }
+        let request_spec = RequestSpec {
+            headers,
+            body: Body::Binary(body),
+            implicit_content_type: Some("application/grpc".to_string()),
+            ..request_spec.clone()
+        };
+        // gRPC requires HTTP/2, even without TLS.
+        let http_version = match options.http_version {
+            RequestedHttpVersion::Default | RequestedHttpVersion::Http2 => {
+                RequestedHttpVersion::Http2PriorKnowledge
+            }
+            version => version,
+        };
+        let options = ClientOptions {
+            grpc_proto: None,
+            http_version,
+            ..options.clone()
+        };
+        let mut call = self.execute(&request_spec, &options, logger)?;
+
+        let is_grpc = call
+            .response
+            .headers
+            .content_type()
+            .is_some_and(mimetype::is_grpc);
+        if is_grpc {
+            call.response.body = method.decode_response(&call.response.body)?;
+            if options.verbosity == Some(Verbosity::VeryVerbose) {
+                logger.debug_important("gRPC response messages:");
+                logger.debug(&String::from_utf8_lossy(&call.response.body));
+            }
+        }
+        Ok(call)
+    }
+
+    /// Executes a WebSocket exchange described by `request_spec`: opens the connection with an
+    /// HTTP/1.1 upgrade handshake, sends the request body as a single data message and waits for
+    /// the messages sent by the server.
+    ///
+    /// libcurl is used in "connect only" mode so the connection benefits from the same TLS, proxy,
+    /// resolve etc... configuration as standard HTTP requests.
+    fn execute_websocket(
+        &mut self,
+        request_spec: &RequestSpec,
+        options: &ClientOptions,
+        logger: &mut Logger,
+    ) -> Result<Call, HttpError> {
+        let (url, method) = self.configure(request_spec, options, logger)?;
+        let url = Url::from_str(&url)?;
+
+        // libcurl only establishes the connection, and we take the lead just after.
+        self.handle.url(&websocket::http_url(&url.raw()))?;
+        self.handle.connect_only(true)?;
+        if options.proxy.is_some() {
+            self.handle.http_proxy_tunnel(true)?;
+        }
+
+        let start = Instant::now();
+        let start_dt = Utc::now();
+        let verbose = options.verbosity.is_some();
+        let very_verbose = options.verbosity == Some(Verbosity::VeryVerbose);
+
+        let transfer_result = {
+            let mut transfer = self.handle.transfer();
+            transfer.debug_function(|info_type, data| {
+                // Curl debug logs
+                if let easy::InfoType::Text = info_type {
+                    let len = data.len();
+                    if very_verbose && len > 0 {
+                        let text = str::from_utf8(&data[..len - 1]);
+                        if let Ok(text) = text {
+                            logger.debug_curl(text);
+                        }
+                    }
+                }
+            })?;
+            transfer.perform()
+        };
+        if let Err(e) = transfer_result {
+            let stop_dt = start_dt + start.elapsed();
+            let timings = Timings::new(&mut self.handle, start_dt, stop_dt);
+            let tls = url.scheme() == "wss";
+            return Err(transfer_error(&e, options, &timings, tls));
+        }
+
+        // Opening handshake, see <https://datatracker.ietf.org/doc/html/rfc6455#section-4.1>
+        let key = websocket::generate_key();
+        let request_headers =
+            self.websocket_handshake_headers(request_spec, options, &url, &key, logger);
+        let request_line = format!("{method} {} HTTP/1.1", url.path_and_query());
+        let mut handshake = format!("{request_line}\r\n");
+        for header in &request_headers {
+            handshake.push_str(&format!("{}: {}\r\n", header.name, header.value));
+        }
+        handshake.push_str("\r\n");
+        if verbose {
+            logger.debug_method_version_out(&request_line);
+            let headers = request_headers
+                .iter()
+                .map(|h| (h.name.as_str(), h.value.as_str()))
+                .collect::<Vec<_>>();
+            logger.debug_headers_out(&headers);
+        }
+
+        let mut ws = WebSocket::new(&mut self.handle, options.websocket_timeout);
+        ws.send_bytes(handshake.as_bytes())?;
+        let head = ws.read_handshake_response()?;
+        let mut lines = head.lines().filter(|l| !l.is_empty());
+        let Some(status_line) = lines.next() else {
+            return Err(HttpError::CouldNotParseResponse);
+        };
+        let version = parse_response_version(status_line)?;
+        let status = status_line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|s| s.parse::<u32>().ok())
+            .ok_or(HttpError::CouldNotParseResponse)?;
+        let response_lines = lines.map(|l| l.to_string()).collect::<Vec<_>>();
+        let headers = parse_response_headers(&response_lines);
+        if verbose {
+            logger.debug_status_version_in(status_line);
+            let headers = headers
+                .iter()
+                .map(|h| (h.name.as_str(), h.value.as_str()))
+                .collect::<Vec<_>>();
+            logger.debug_headers_in(&headers);
+        }
+
+        let request_message = match &request_spec.body {
+            Body::Text(text) => WebSocketMessage::Text(text.clone()),
+            Body::Binary(bytes) | Body::File(bytes, _) => WebSocketMessage::Binary(bytes.clone()),
+        };
+        let request_body = request_spec.body.bytes();
+
+        let mut response_body = vec![];
+        let mut messages = vec![];
+        if status == 101 {
+            // The server must prove that it has received our handshake.
+            websocket::check_accept(&request_headers, &headers)?;
+
+            if !request_body.is_empty() {
+                if very_verbose {
+                    logger.debug_important("WebSocket message sent:");
+                    logger.debug(&request_message.to_string());
+                }
+                ws.send_message(&request_message)?;
+            }
+
+            loop {
+                if let Count::Finite(max) = options.websocket_messages {
+                    if messages.len() >= max {
+                        break;
+                    }
+                }
+                let Some(message) = ws.recv_message()? else {
+                    logger.debug("WebSocket connection closed by server");
+                    break;
+                };
+                if very_verbose {
+                    logger.debug_important("WebSocket message received:");
+                    logger.debug(&message.to_string());
+                }
+                messages.push(message);
+            }
+            // Closing is best effort: the server may have already closed the connection.
+            let _ = ws.send_close();
+        } else {
+            // The server has refused to switch protocol: we return its HTTP response so it can be
+            // asserted like any other response, without waiting for the connection to be closed.
+            let len = headers
+                .get(CONTENT_LENGTH)
+                .and_then(|h| h.value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            response_body = ws.read_exact(len)?;
+        }
+
+        let certificate = self.cert_info(logger)?;
+        let duration = start.elapsed();
+        let stop_dt = start_dt + duration;
+        let timings = Timings::new(&mut self.handle, start_dt, stop_dt);
+        let ip_addr = self.primary_ip()?;
+
+        if verbose {
+            let duration = duration.as_millis() as u64;
+            let count = messages.len();
+            logger.debug_important(&format!(
+                "Response: (received {count} WebSocket messages in {duration} ms)"
+            ));
+            logger.debug("");
+            if very_verbose {
+                timings.log(logger);
+            }
+        }
+
+        let request = Request::new(
+            &method.to_string(),
+            url.clone(),
+            request_headers,
+            request_body,
+        );
+        let response = Response::new(
+            version,
+            status,
+            headers,
+            response_body,
+            duration,
+            url,
+            certificate,
+            ip_addr,
+            messages,
+        );
+        Ok(Call {
+            request,
+            response,
+            timings,
+        })
+    }
+
+    /// Returns the headers of a WebSocket opening handshake to `url`, with this handshake `key`.
+    fn websocket_handshake_headers(
+        &mut self,
+        request_spec: &RequestSpec,
+        options: &ClientOptions,
+        url: &Url,
+        key: &str,
+        logger: &mut Logger,
+    ) -> HeaderVec {
+        let options_headers = options
+            .headers
+            .iter()
+            .map(|h| h.as_str())
+            .collect::<Vec<&str>>();
+        let user_headers = request_spec.headers.aggregate_raw_headers(&options_headers);
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new(HOST, &url.authority()));
+        let user_agent = match &options.user_agent {
+            Some(u) => u.clone(),
+            None => format!("hurl/{}", env!("CARGO_PKG_VERSION")),
+        };
+        headers.push(Header::new(USER_AGENT, &user_agent));
+        if let Some(user) = &options.user {
+            let authorization = general_purpose::STANDARD.encode(user.as_bytes());
+            headers.push(Header::new(
+                AUTHORIZATION,
+                &format!("Basic {authorization}"),
+            ));
+        }
+        // libcurl doesn't send the request, so we add the cookies of the cookie store ourselves.
+        let cookie_storage = self.cookie_storage(logger);
+        let cookies = all_cookies(&cookie_storage, request_spec)
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<String>>()
+            .join("; ");
+        if !cookies.is_empty() {
+            headers.push(Header::new(COOKIE, &cookies));
+        }
+        headers.push(Header::new("Upgrade", "websocket"));
+        headers.push(Header::new("Connection", "Upgrade"));
+        headers.push(Header::new(SEC_WEBSOCKET_KEY, key));
+        headers.push(Header::new("Sec-WebSocket-Version", "13"));
+
+        // User headers override the default ones.
+        for header in user_headers.iter() {
+            headers.retain(|h| !h.name_eq(&header.name));
+        }
+        for header in user_headers.iter() {
+            headers.push(header.clone());
+        }
+        headers
+    }
+
     /// Configure libcurl handle to send a `request_spec`, using `options`.
     /// If configuration is successful, returns a tuple of the concrete requested URL and method.
     fn configure(
@@ -360,9 +759,11 @@ impl Client {
 
         // We check libcurl HTTP version support.
         let http_version = options.http_version;
-        if (http_version == RequestedHttpVersion::Http2 && !self.http2)
-            || (http_version == RequestedHttpVersion::Http3 && !self.http3)
-        {
+        let http2 = matches!(
+            http_version,
+            RequestedHttpVersion::Http2 | RequestedHttpVersion::Http2PriorKnowledge
+        );
+        if (http2 && !self.http2) || (http_version == RequestedHttpVersion::Http3 && !self.http3) {
             return Err(HttpError::UnsupportedHttpVersion(http_version));
         }
 
@@ -374,6 +775,9 @@ impl Client {
         self.handle.http_version(options.http_version.into())?;
 
         self.handle.ip_resolve(options.ip_resolve.into())?;
+        if options.auth_method != AuthMethod::Basic {
+            self.handle.http_auth(&options.auth_method.into())?;
+        }
 
         // Activates the access of certificates info chain after a transfer has been executed.
         self.handle.certinfo(true)?;
@@ -389,29 +793,80 @@ impl Client {
         self.handle.ssl_verify_host(!options.insecure)?;
         self.handle.ssl_verify_peer(!options.insecure)?;
         if let Some(cacert_file) = &options.cacert_file {
-            self.handle.cainfo(cacert_file)?;
+            if is_pem(cacert_file) {
+                self.handle.ssl_cainfo_blob(cacert_file.as_bytes())?;
+            } else {
+                self.handle.cainfo(cacert_file)?;
+            }
             self.handle.ssl_cert_type("PEM")?;
         }
         if let Some(client_cert_file) = &options.client_cert_file {
-            match parse_cert_password(client_cert_file) {
-                (cert, Some(password)) => {
-                    self.handle.ssl_cert(cert)?;
-                    self.handle.key_password(&password)?;
-                }
-                (cert, None) => {
-                    self.handle.ssl_cert(cert)?;
+            if is_pem(client_cert_file) {
+                // A PEM content is used as is, without password.
+                self.handle.ssl_cert_blob(client_cert_file.as_bytes())?;
+            } else {
+                match parse_cert_password(client_cert_file) {
+                    (cert, Some(password)) => {
+                        self.handle.ssl_cert(cert)?;
+                        self.handle.key_password(&password)?;
+                    }
+                    (cert, None) => {
+                        self.handle.ssl_cert(cert)?;
+                    }
                 }
             }
             self.handle.ssl_cert_type("PEM")?;
         }
         if let Some(client_key_file) = &options.client_key_file {
-            self.handle.ssl_key(client_key_file)?;
+            if is_pem(client_key_file) {
+                self.handle.ssl_key_blob(client_key_file.as_bytes())?;
+            } else {
+                self.handle.ssl_key(client_key_file)?;
+            }
             self.handle.ssl_cert_type("PEM")?;
         }
+        if let Some(crl_file) = &options.crl_file {
+            self.handle.crlfile(crl_file)?;
+        }
+        if let Some(pinned_pubkey) = &options.pinned_pubkey {
+            self.handle.pinned_public_key(pinned_pubkey)?;
+        }
+        if options.cert_status {
+            easy_ext::ssl_verify_status(&mut self.handle, true)?;
+        }
+        if options.tls_min_version.is_some() || options.tls_max_version.is_some() {
+            let min_version = options
+                .tls_min_version
+                .map_or(SslVersion::Default, |v| v.into());
+            let max_version = options
+                .tls_max_version
+                .map_or(SslVersion::Default, |v| v.into());
+            self.handle.ssl_min_max_version(min_version, max_version)?;
+        }
         self.handle.path_as_is(options.path_as_is)?;
         if let Some(proxy) = &options.proxy {
             self.handle.proxy(proxy)?;
         }
+        if let Some(proxy_user) = &options.proxy_user {
+            match proxy_user.split_once(':') {
+                Some((username, password)) => {
+                    self.handle.proxy_username(username)?;
+                    self.handle.proxy_password(password)?;
+                }
+                None => self.handle.proxy_username(proxy_user)?,
+            }
+        }
+        if options.proxy_auth_method != AuthMethod::Basic {
+            self.handle.proxy_auth(&options.proxy_auth_method.into())?;
+        }
+        if let Some(proxy_cacert_file) = &options.proxy_cacert_file {
+            self.handle.proxy_cainfo(proxy_cacert_file)?;
+        }
+        self.handle.proxy_ssl_verify_host(!options.proxy_insecure)?;
+        self.handle.proxy_ssl_verify_peer(!options.proxy_insecure)?;
+        let proxy_headers = HeaderList::new(&options.proxy_headers)?;
+        easy_ext::proxy_headers(&mut self.handle, &proxy_headers)?;
+        self.proxy_headers = proxy_headers;
         if let Some(no_proxy) = &options.no_proxy {
             self.handle.noproxy(no_proxy)?;
         }
@@ -449,10 +904,39 @@ impl Client {
         let method = &request_spec.method;
         self.set_method(method)?;
         self.set_cookies(&request_spec.cookies)?;
-        self.set_form(&request_spec.form)?;
-        self.set_multipart(&request_spec.multipart)?;
         let request_spec_body = &request_spec.body.bytes();
-        self.set_body(request_spec_body)?;
+        let mut implicit_content_type = request_spec.implicit_content_type.clone();
+        // Headers describing the request body, computed from the bytes actually sent.
+        let mut body_headers = vec![];
+        // Length of the body sent by libcurl, `None` when libcurl computes it (multipart).
+        let content_length;
+        if let Some(body) = self.encode_body(request_spec, options, logger)? {
+            if body.implicit_content_type.is_some() {
+                implicit_content_type = body.implicit_content_type;
+            }
+            body_headers = body.headers;
+            content_length = Some(body.bytes.len());
+            self.set_body(&body.bytes)?;
+        } else {
+            self.set_form(&request_spec.form)?;
+            self.set_multipart(&request_spec.multipart)?;
+            self.set_body(request_spec_body)?;
+            content_length = if !request_spec.multipart.is_empty() {
+                None
+            } else if !request_spec.form.is_empty() {
+                Some(self.url_encode_params(&request_spec.form).len())
+            } else {
+                Some(request_spec_body.len())
+            };
+        }
+        // libcurl adds `Content-Length` and `Cookie` headers itself: we compute them so they can
+        // be covered by an HTTP message signature.
+        let curl_headers = match &options.http_signature {
+            Some(http_signature) => {
+                self.curl_headers(request_spec, content_length, http_signature, logger)?
+            }
+            None => vec![],
+        };
         // TODO: do we want to manage the headers with no content? There are two type of no-content
         // headers: `foo:` and `foo;`. The first one can be used to remove libcurl headers (`Host:`)
         // while the second one is used to send an empty header.
@@ -464,8 +948,12 @@ impl Client {
             .collect::<Vec<&str>>();
         let headers = &request_spec.headers.aggregate_raw_headers(&options_headers);
         self.set_headers(
+            method,
+            &url,
             headers,
-            request_spec.implicit_content_type.as_deref(),
+            implicit_content_type.as_deref(),
+            &body_headers,
+            &curl_headers,
             options,
         )?;
         if let Some(aws_sigv4) = &options.aws_sigv4 {
@@ -510,19 +998,30 @@ impl Client {
     }
 
     /// Sets HTTP headers.
+    ///
+    /// `curl_headers` are the headers added by libcurl itself, they are only used to compute an
+    /// HTTP message signature.
+    #[allow(clippy::too_many_arguments)]
     fn set_headers(
         &mut self,
+        method: &Method,
+        url: &str,
         headers: &HeaderVec,
         implicit_content_type: Option<&str>,
+        body_headers: &[Header],
+        curl_headers: &[Header],
         options: &ClientOptions,
     ) -> Result<(), HttpError> {
         let mut list = headers.to_curl_headers()?;
+        // Headers sent with this request, used to compute an HTTP message signature.
+        let mut sent_headers = headers.clone();
 
         // If request has no `Content-Type` header, we set it if the content type has been set
         // implicitly on this request.
         if !headers.contains_key(CONTENT_TYPE) {
             if let Some(s) = implicit_content_type {
                 list.append(&format!("{}: {s}", CONTENT_TYPE))?;
+                sent_headers.push(Header::new(CONTENT_TYPE, s));
             } else {
                 // We remove default `Content-Type` headers added by curl because we want to
                 // explicitly manage this header.
@@ -553,13 +1052,16 @@ impl Client {
                 }
             };
             list.append(&format!("{}: {user_agent}", USER_AGENT))?;
+            sent_headers.push(Header::new(USER_AGENT, &user_agent));
         }
 
         if let Some(user) = &options.user {
-            if options.aws_sigv4.is_some() {
+            if options.aws_sigv4.is_some() || options.auth_method != AuthMethod::Basic {
                 // curl's aws_sigv4 support needs to know the username and password for the
                 // request, as it uses those values to calculate the Authorization header for the
-                // AWS V4 signature.
+                // AWS V4 signature. Other authentication methods (Digest, NTLM etc...) need
+                // exchanges with the server to compute the Authorization header, so we let
+                // libcurl do it.
                 if let Some((username, password)) = user.split_once(':') {
                     self.handle.username(username)?;
                     self.handle.password(password)?;
@@ -568,18 +1070,94 @@ impl Client {
                 let user = user.as_bytes();
                 let authorization = general_purpose::STANDARD.encode(user);
                 if !headers.contains_key(AUTHORIZATION) {
-                    list.append(&format!("{}: Basic {authorization}", AUTHORIZATION))?;
+                    let authorization = format!("Basic {authorization}");
+                    list.append(&format!("{}: {authorization}", AUTHORIZATION))?;
+                    sent_headers.push(Header::new(AUTHORIZATION, &authorization));
                 }
             }
         }
         if options.compressed && !headers.contains_key(ACCEPT_ENCODING) {
-            list.append(&format!("{}: gzip, deflate, br", ACCEPT_ENCODING))?;
+            list.append(&format!("{}: gzip, deflate, br, zstd", ACCEPT_ENCODING))?;
+            sent_headers.push(Header::new(ACCEPT_ENCODING, "gzip, deflate, br, zstd"));
+        }
+
+        for header in body_headers {
+            if !headers.contains_key(&header.name) {
+                list.append(&header.to_string())?;
+                sent_headers.push(header.clone());
+            }
+        }
+
+        // Each call gets a new span id, so the server spans can be linked to this call.
+        if options.traceparent && !headers.contains_key(TRACEPARENT) {
+            let traceparent = TraceParent::new(&self.trace_id).to_string();
+            list.append(&format!("{TRACEPARENT}: {traceparent}"))?;
+            sent_headers.push(Header::new(TRACEPARENT, &traceparent));
+        }
+
+        // The signature is computed last, over the final request headers.
+        if let Some(http_signature) = &options.http_signature {
+            for header in curl_headers {
+                if !sent_headers.contains_key(&header.name) {
+                    sent_headers.push(header.clone());
+                }
+            }
+            let url = Url::from_str(url)?;
+            let created = Utc::now().timestamp();
+            let signature_headers = http_signature.sign(&method.0, &url, &sent_headers, created)?;
+            for header in signature_headers {
+                list.append(&header.to_string())?;
+            }
         }
 
         self.handle.http_headers(list)?;
         Ok(())
     }
 
+    /// Returns the headers added by libcurl to the request of `request_spec` and covered by
+    /// `http_signature`: `Content-Length`, given the `content_length` of the body, and `Cookie`.
+    ///
+    /// Returns an error if `Content-Length` is covered but its value is computed by libcurl.
+    fn curl_headers(
+        &mut self,
+        request_spec: &RequestSpec,
+        content_length: Option<usize>,
+        http_signature: &HttpSignature,
+        logger: &mut Logger,
+    ) -> Result<Vec<Header>, HttpError> {
+        let is_covered = |name: &str| {
+            http_signature
+                .components
+                .iter()
+                .any(|c| c.eq_ignore_ascii_case(name))
+        };
+        let mut headers = vec![];
+        if is_covered(CONTENT_LENGTH) {
+            match content_length {
+                Some(0) => {}
+                Some(length) => headers.push(Header::new(CONTENT_LENGTH, &length.to_string())),
+                None => {
+                    return Err(HttpError::HttpSignature {
+                        description: "content-length can not be signed with a multipart body"
+                            .to_string(),
+                    });
+                }
+            }
+        }
+        if is_covered(COOKIE) {
+            let cookie_storage = self.cookie_storage(logger);
+            let cookies = sent_cookies(&cookie_storage, request_spec)
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+                .join("; ");
+            if !cookies.is_empty() {
+                headers.push(Header::new(COOKIE, &cookies));
+            }
+        }
+        Ok(headers)
+    }
+
     /// Sets request cookies.
     fn set_cookies(&mut self, cookies: &[RequestCookie]) -> Result<(), HttpError> {
         let s = cookies
@@ -648,6 +1226,50 @@ impl Client {
         Ok(())
     }
 
+    /// Encodes the body of `request_spec` when it has to be compressed or digested with `options`.
+    ///
+    /// Returns `None` if the body can be sent as is by libcurl.
+    fn encode_body(
+        &mut self,
+        request_spec: &RequestSpec,
+        options: &ClientOptions,
+        logger: &mut Logger,
+    ) -> Result<Option<EncodedBody>, HttpError> {
+        if options.compress_body.is_none() && options.content_digest.is_none() {
+            return Ok(None);
+        }
+        let mut implicit_content_type = None;
+        let mut headers = vec![];
+        let mut bytes = if !request_spec.form.is_empty() {
+            self.url_encode_params(&request_spec.form).into_bytes()
+        } else if !request_spec.multipart.is_empty() {
+            // libcurl encodes multipart form data with a random boundary that we can't get
+            // back, so we encode multipart form data ourselves and send it as a raw body.
+            let boundary = multipart_boundary();
+            implicit_content_type = Some(format!("multipart/form-data; boundary={boundary}"));
+            multipart_body(&request_spec.multipart, &boundary)
+        } else {
+            request_spec.body.bytes()
+        };
+        if let (Some(encoding), false) = (options.compress_body, bytes.is_empty()) {
+            let size = bytes.len();
+            bytes = encoding.encode(&bytes)?;
+            logger.debug(&format!(
+                "Request body compressed with {encoding}: {size} bytes -> {} bytes",
+                bytes.len()
+            ));
+            headers.push(Header::new(CONTENT_ENCODING, &encoding.to_string()));
+        }
+        if let (Some(algorithm), false) = (options.content_digest, bytes.is_empty()) {
+            headers.push(Header::new(CONTENT_DIGEST, &algorithm.field_value(&bytes)));
+        }
+        Ok(Some(EncodedBody {
+            bytes,
+            implicit_content_type,
+            headers,
+        }))
+    }
+
     /// URL encodes parameters.
     fn url_encode_params(&mut self, params: &[Param]) -> String {
         params
@@ -660,32 +1282,6 @@ impl Client {
             .join("&")
     }
 
-    /// Parses HTTP response version.
-    fn parse_response_version(&mut self, line: &str) -> Result<HttpVersion, HttpError> {
-        if line.starts_with("HTTP/1.0") {
-            Ok(HttpVersion::Http10)
-        } else if line.starts_with("HTTP/1.1") {
-            Ok(HttpVersion::Http11)
-        } else if line.starts_with("HTTP/2") {
-            Ok(HttpVersion::Http2)
-        } else if line.starts_with("HTTP/3") {
-            Ok(HttpVersion::Http3)
-        } else {
-            Err(HttpError::CouldNotParseResponse)
-        }
-    }
-
-    /// Parse headers from libcurl responses.
-    fn parse_response_headers(&mut self, lines: &[String]) -> HeaderVec {
-        let mut headers = HeaderVec::new();
-        for line in lines {
-            if let Some(header) = Header::parse(line) {
-                headers.push(header);
-            }
-        }
-        headers
-    }
-
     /// Get the IP address of the last connection from libcurl
     fn primary_ip(&mut self) -> Result<IpAddr, HttpError> {
         match self.handle.primary_ip()? {
@@ -723,7 +1319,12 @@ impl Client {
         for cookie in list.iter() {
             let line = str::from_utf8(cookie).unwrap();
             if let Ok(cookie) = Cookie::from_str(line) {
-                cookies.push(cookie);
+                let key = same_site_key(&cookie.domain, &cookie.name);
+                let same_site = self.same_sites.get(&key).cloned();
+                cookies.push(Cookie {
+                    same_site,
+                    ..cookie
+                });
             } else {
                 logger.warning(&format!("Line <{line}> can not be parsed as cookie"));
             }
@@ -732,17 +1333,37 @@ impl Client {
     }
 
     /// Adds a cookie to the cookie jar.
-    pub fn add_cookie(&mut self, cookie: &Cookie, logger: &mut Logger) {
+    pub fn add_cookie(&mut self, cookie: &Cookie, logger: &mut Logger) -> Result<(), HttpError> {
         logger.debug(&format!("Add to cookie store <{cookie}> (experimental)"));
-        self.handle
-            .cookie_list(cookie.to_string().as_str())
-            .unwrap();
+        // Outside a transfer, libcurl verbose logs are written to stderr. Verbose mode is set
+        // again on each request to capture request headers.
+        self.handle.verbose(false)?;
+        self.handle.cookie_list(cookie.to_string().as_str())?;
+        let key = same_site_key(&cookie.domain, &cookie.name);
+        match &cookie.same_site {
+            Some(same_site) => self.same_sites.insert(key, same_site.clone()),
+            None => self.same_sites.remove(&key),
+        };
+        Ok(())
+    }
+
+    /// Keeps track of the `SameSite` attribute of the cookies set by this `response`.
+    fn update_same_sites(&mut self, response: &Response) {
+        for cookie in response.cookies() {
+            let domain = cookie.domain().unwrap_or_else(|| response.url.host());
+            let key = same_site_key(&domain, &cookie.name);
+            match cookie.samesite() {
+                Some(same_site) => self.same_sites.insert(key, same_site),
+                None => self.same_sites.remove(&key),
+            };
+        }
     }
 
     /// Clears cookie storage.
     pub fn clear_cookie_storage(&mut self, logger: &mut Logger) {
         logger.debug("Clear cookie storage (experimental)");
         self.handle.cookie_list("ALL").unwrap();
+        self.same_sites.clear();
     }
 
     /// Returns curl command-line for the HTTP `request_spec` run by this client.
@@ -755,7 +1376,26 @@ impl Client {
         logger: &mut Logger,
     ) -> CurlCmd {
         let cookies = self.cookie_storage(logger);
-        CurlCmd::new(request_spec, &cookies, context_dir, output, options)
+        // The body is encoded the same way as for the request, so the command sends the same
+        // bytes (except for a multipart body, encoded with a new random boundary).
+        let body = match self.encode_body(request_spec, options, logger) {
+            Ok(body) => body,
+            Err(error) => {
+                logger.warning(&format!(
+                    "Can not encode request body - {}",
+                    error.description()
+                ));
+                None
+            }
+        };
+        CurlCmd::new(
+            request_spec,
+            &cookies,
+            context_dir,
+            output,
+            options,
+            body.as_ref(),
+        )
     }
 
     /// Returns the SSL certificates information associated to this call.
@@ -792,6 +1432,42 @@ impl Client {
     }
 }
 
+/// Parses HTTP response version.
+fn parse_response_version(line: &str) -> Result<HttpVersion, HttpError> {
+    if line.starts_with("HTTP/1.0") {
+        Ok(HttpVersion::Http10)
+    } else if line.starts_with("HTTP/1.1") {
+        Ok(HttpVersion::Http11)
+    } else if line.starts_with("HTTP/2") {
+        Ok(HttpVersion::Http2)
+    } else if line.starts_with("HTTP/3") {
+        Ok(HttpVersion::Http3)
+    } else {
+        Err(HttpError::CouldNotParseResponse)
+    }
+}
+
+/// Parse headers from libcurl responses.
+fn parse_response_headers(lines: &[String]) -> HeaderVec {
+    let mut headers = HeaderVec::new();
+    for line in lines {
+        if let Some(header) = Header::parse(line) {
+            headers.push(header);
+        }
+    }
+    headers
+}
+
+/// A request body encoded by Hurl instead of libcurl, because it's compressed or digested.
+pub struct EncodedBody {
+    /// The bytes sent over the wire.
+    pub bytes: Vec<u8>,
+    /// The content type of the body, if it differs from the request implicit content type.
+    pub implicit_content_type: Option<String>,
+    /// The headers describing the body (`Content-Encoding`, `Content-Digest`).
+    pub headers: Vec<Header>,
+}
+
 /// Returns the method used for redirecting a request/response with `response_status`.
 fn redirect_method(response_status: u32, original_method: Method) -> Method {
     // This replicates curl's behavior
@@ -803,6 +1479,12 @@ fn redirect_method(response_status: u32, original_method: Method) -> Method {
     }
 }
 
+/// Returns the key used to index a cookie `SameSite` attribute, given its `domain` and `name`.
+fn same_site_key(domain: &str, name: &str) -> (String, String) {
+    let domain = domain.trim_start_matches('.').to_lowercase();
+    (domain, name.to_string())
+}
+
 /// Returns cookies from both cookies from the cookie storage and the request.
 pub fn all_cookies(cookie_storage: &[Cookie], request_spec: &RequestSpec) -> Vec<RequestCookie> {
     let mut cookies = request_spec.cookies.clone();
@@ -820,6 +1502,26 @@ pub fn all_cookies(cookie_storage: &[Cookie], request_spec: &RequestSpec) -> Vec
     cookies
 }
 
+/// Returns the cookies sent by libcurl for `request_spec`, in the order of the `Cookie` header:
+/// cookies of the `cookie_storage` first (longest paths first), then the request cookies.
+fn sent_cookies(cookie_storage: &[Cookie], request_spec: &RequestSpec) -> Vec<RequestCookie> {
+    let mut storage_cookies = cookie_storage
+        .iter()
+        .filter(|c| c.expires != "1")
+        .filter(|c| match_cookie(c, &request_spec.url))
+        .collect::<Vec<_>>();
+    storage_cookies.sort_by_key(|c| std::cmp::Reverse(c.path.len()));
+    let mut cookies = storage_cookies
+        .iter()
+        .map(|c| RequestCookie {
+            name: c.name.clone(),
+            value: c.value.clone(),
+        })
+        .collect::<Vec<_>>();
+    cookies.extend(request_spec.cookies.iter().cloned());
+    cookies
+}
+
 /// Matches cookie for a given URL.
 pub fn match_cookie(cookie: &Cookie, url: &Url) -> bool {
     if let Some(domain) = url.domain() {
@@ -863,6 +1565,57 @@ impl HeaderVec {
     }
 }
 
+/// Returns a random boundary to encode multipart form data.
+fn multipart_boundary() -> String {
+    let mut bytes = [0u8; 12];
+    OsRng.fill_bytes(&mut bytes);
+    let random = bytes.iter().map(|b| format!("{b:02x}")).collect::<String>();
+    format!("------------------------{random}")
+}
+
+/// Encodes multipart form data `params` with a `boundary` (see <https://www.rfc-editor.org/rfc/rfc7578>).
+fn multipart_body(params: &[MultipartParam], boundary: &str) -> Vec<u8> {
+    // Like libcurl, we escape quotes and line breaks in names and filenames.
+    let escape = |s: &str| {
+        s.replace('"', "%22")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    };
+    let mut body = vec![];
+    for param in params {
+        body.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+        match param {
+            MultipartParam::Param(Param { name, value }) => {
+                let name = escape(name);
+                body.extend_from_slice(
+                    format!("Content-Disposition: form-data; name=\"{name}\"\r\n\r\n").as_bytes(),
+                );
+                body.extend_from_slice(value.as_bytes());
+            }
+            MultipartParam::FileParam(FileParam {
+                name,
+                filename,
+                data,
+                content_type,
+            }) => {
+                let name = escape(name);
+                let filename = escape(filename);
+                body.extend_from_slice(
+                    format!(
+                        "Content-Disposition: form-data; name=\"{name}\"; filename=\"{filename}\"\r\n\
+                         Content-Type: {content_type}\r\n\r\n"
+                    )
+                    .as_bytes(),
+                );
+                body.extend_from_slice(data);
+            }
+        }
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+    body
+}
+
 /// Splits an array of bytes into HTTP lines (\r\n separator).
 fn split_lines(data: &[u8]) -> Vec<String> {
     let mut lines = vec![];
@@ -956,11 +1709,102 @@ impl From<RequestedHttpVersion> for easy::HttpVersion {
             RequestedHttpVersion::Http10 => easy::HttpVersion::V10,
             RequestedHttpVersion::Http11 => easy::HttpVersion::V11,
             RequestedHttpVersion::Http2 => easy::HttpVersion::V2,
+            RequestedHttpVersion::Http2PriorKnowledge => easy::HttpVersion::V2PriorKnowledge,
             RequestedHttpVersion::Http3 => easy::HttpVersion::V3,
         }
     }
 }
 
+impl From<AuthMethod> for Auth {
+    fn from(value: AuthMethod) -> Self {
+        let mut auth = Auth::new();
+        match value {
+            AuthMethod::Basic => auth.basic(true),
+            AuthMethod::Digest => auth.digest(true),
+            AuthMethod::Ntlm => auth.ntlm(true),
+            AuthMethod::Negotiate => auth.gssnegotiate(true),
+            AuthMethod::Any => auth.auto(true),
+        };
+        auth
+    }
+}
+
+impl From<TlsVersion> for SslVersion {
+    fn from(value: TlsVersion) -> Self {
+        match value {
+            TlsVersion::Tls10 => SslVersion::Tlsv10,
+            TlsVersion::Tls11 => SslVersion::Tlsv11,
+            TlsVersion::Tls12 => SslVersion::Tlsv12,
+            TlsVersion::Tls13 => SslVersion::Tlsv13,
+        }
+    }
+}
+
+/// Converts a libcurl transfer error `e` to an [`HttpError`].
+///
+/// TLS verification failures, related to the `options` used for the transfer (pinned public key,
+/// certificate status, certificate revocation list and TLS versions), have their own errors. A timeout
+/// is reported with the phase of the transfer reached, given by the transfer `timings`.
+fn transfer_error(
+    e: &curl::Error,
+    options: &ClientOptions,
+    timings: &Timings,
+    tls: bool,
+) -> HttpError {
+    let code = e.code() as i32; // due to windows build
+    let description = match e.extra_description() {
+        None => e.description().to_string(),
+        Some(s) => s.to_string(),
+    };
+    let tls_versions = options.tls_min_version.is_some() || options.tls_max_version.is_some();
+    match e.code() {
+        curl_sys::CURLE_SSL_PINNEDPUBKEYNOTMATCH => {
+            HttpError::PinnedPublicKeyMismatch { description }
+        }
+        curl_sys::CURLE_SSL_INVALIDCERTSTATUS => {
+            HttpError::InvalidCertificateStatus { description }
+        }
+        curl_sys::CURLE_SSL_CRL_BADFILE => HttpError::InvalidCrlFile { description },
+        // libcurl has no dedicated error code for a revoked certificate: we rely on the TLS
+        // backend error message.
+        curl_sys::CURLE_PEER_FAILED_VERIFICATION
+            if options.crl_file.is_some() && is_revoked_error(&description) =>
+        {
+            HttpError::CertificateRevoked { description }
+        }
+        curl_sys::CURLE_OPERATION_TIMEDOUT => HttpError::Timeout {
+            phase: timings.phase(tls),
+            description,
+        },
+        curl_sys::CURLE_SSL_CONNECT_ERROR if tls_versions && is_tls_version_error(&description) => {
+            HttpError::TlsVersionMismatch { description }
+        }
+        _ => HttpError::Libcurl { code, description },
+    }
+}
+
+/// Returns true if this TLS backend error `description` reports a revoked certificate.
+fn is_revoked_error(description: &str) -> bool {
+    let description = description.to_lowercase();
+    // OpenSSL, GnuTLS, Secure Transport: "certificate revoked", Schannel: "CRYPT_E_REVOKED"
+    description.contains("revoked")
+}
+
+/// Returns true if this TLS backend error `description` reports a protocol version failure.
+fn is_tls_version_error(description: &str) -> bool {
+    let description = description.to_lowercase();
+    [
+        "protocol version",
+        "unsupported protocol",
+        "no protocols available",
+        "wrong version number",
+        "version too low",
+        "sec_e_unsupported_function",
+    ]
+    .iter()
+    .any(|s| description.contains(s))
+}
+
 impl From<IpResolve> for easy::IpResolve {
     fn from(value: IpResolve) -> Self {
         match value {
@@ -993,6 +1837,33 @@ mod tests {
         assert!(Header::parse("Foo").is_none());
     }
 
+    #[test]
+    fn test_multipart_body() {
+        let params = vec![
+            MultipartParam::Param(Param {
+                name: "key1".to_string(),
+                value: "value1".to_string(),
+            }),
+            MultipartParam::FileParam(FileParam {
+                name: "upload1".to_string(),
+                filename: "data.txt".to_string(),
+                data: b"Hello World!".to_vec(),
+                content_type: "text/plain".to_string(),
+            }),
+        ];
+        assert_eq!(
+            String::from_utf8(multipart_body(&params, "boundary")).unwrap(),
+            "--boundary\r\n\
+             Content-Disposition: form-data; name=\"key1\"\r\n\r\n\
+             value1\r\n\
+             --boundary\r\n\
+             Content-Disposition: form-data; name=\"upload1\"; filename=\"data.txt\"\r\n\
+             Content-Type: text/plain\r\n\r\n\
+             Hello World!\r\n\
+             --boundary--\r\n"
+        );
+    }
+
     #[test]
     fn test_split_lines_header() {
         let data = b"GET /hello HTTP/1.1\r\nHost: localhost:8000\r\n\r\n";
@@ -1014,6 +1885,7 @@ mod tests {
             name: String::new(),
             value: String::new(),
             http_only: false,
+            same_site: None,
         };
         assert!(match_cookie(
             &cookie,
@@ -1037,6 +1909,7 @@ mod tests {
             name: String::new(),
             value: String::new(),
             http_only: false,
+            same_site: None,
         };
         assert!(match_cookie(
             &cookie,
@@ -1052,6 +1925,54 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_sent_cookies() {
+        let cookie = |path: &str, name: &str| Cookie {
+            domain: "example.com".to_string(),
+            include_subdomain: "FALSE".to_string(),
+            path: path.to_string(),
+            https: String::new(),
+            expires: String::new(),
+            name: name.to_string(),
+            value: "1".to_string(),
+            http_only: false,
+            same_site: None,
+        };
+        let cookie_storage = vec![cookie("/", "a"), cookie("/toto", "b"), cookie("/tata", "c")];
+        let request_spec = RequestSpec {
+            url: Url::from_str("http://example.com/toto").unwrap(),
+            cookies: vec![RequestCookie {
+                name: "d".to_string(),
+                value: "1".to_string(),
+            }],
+            ..Default::default()
+        };
+        let cookies = sent_cookies(&cookie_storage, &request_spec)
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(cookies, vec!["b=1", "a=1", "d=1"]);
+    }
+
+    #[test]
+    fn test_tls_errors() {
+        assert!(is_tls_version_error(
+            "TLS connect error: error:0A00042E:SSL routines::tlsv1 alert protocol version"
+        ));
+        assert!(is_tls_version_error(
+            "OpenSSL/3.0.13: error:0A0000BF:SSL routines::no protocols available"
+        ));
+        assert!(!is_tls_version_error(
+            "TLS connect error: error:0A000410:SSL routines::sslv3 alert handshake failure"
+        ));
+        assert!(is_revoked_error(
+            "SSL certificate problem: certificate revoked"
+        ));
+        assert!(!is_revoked_error(
+            "SSL certificate problem: self-signed certificate"
+        ));
+    }
+
     #[test]
     fn test_redirect_method() {
         // Status of the response to be redirected | method of the original request | method of the new request
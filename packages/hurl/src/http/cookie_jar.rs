@@ -0,0 +1,246 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Cookie jar files in JSON format, and the modes used to handle the cookie storage on a request.
+//!
+//! Contrary to the Netscape cookie format, the JSON format keeps every attribute of the cookies
+//! (including `SameSite`), and the expiration dates are ISO 8601 dates.
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use chrono::{DateTime, SecondsFormat};
+use serde::{Deserialize, Serialize};
+
+use crate::http::Cookie;
+use crate::util::redacted::Redact;
+
+#[derive(Deserialize, Serialize)]
+struct CookieJson {
+    domain: String,
+    include_subdomains: bool,
+    path: String,
+    secure: bool,
+    #[serde(rename = "httponly")]
+    http_only: bool,
+    /// Expiration date of the cookie, `None` for a session cookie.
+    expires: Option<String>,
+    name: String,
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    same_site: Option<String>,
+}
+
+/// Returns `true` if this cookie jar `filename` uses the JSON format (i.e. has a `.json` extension),
+/// `false` if it uses the Netscape cookie format.
+pub fn is_json_cookie_jar(filename: &str) -> bool {
+    Path::new(filename)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+}
+
+/// Serializes a list of `cookies` to a JSON cookie jar, redacting `secrets` from cookie values.
+pub fn cookies_to_json(cookies: &[Cookie], secrets: &[&str]) -> String {
+    let cookies = cookies
+        .iter()
+        .map(|c| CookieJson {
+            domain: c.domain.clone(),
+            include_subdomains: c.include_subdomain == "TRUE",
+            path: c.path.clone(),
+            secure: c.https == "TRUE",
+            http_only: c.http_only,
+            expires: expires_to_iso(&c.expires),
+            name: c.name.clone(),
+            value: c.value.redact(secrets),
+            same_site: c.same_site.clone(),
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&cookies).unwrap()
+}
+
+/// Parses a JSON cookie jar `content` to a list of cookies.
+pub fn cookies_from_json(content: &str) -> Result<Vec<Cookie>, String> {
+    let cookies = serde_json::from_str::<Vec<CookieJson>>(content).map_err(|e| e.to_string())?;
+    cookies
+        .into_iter()
+        .map(|c| {
+            let expires = match &c.expires {
+                None => "0".to_string(),
+                Some(date) => match DateTime::parse_from_rfc3339(date) {
+                    Ok(date) => date.timestamp().to_string(),
+                    Err(_) => return Err(format!("invalid expiration date <{date}>")),
+                },
+            };
+            Ok(Cookie {
+                domain: c.domain,
+                include_subdomain: bool_to_netscape(c.include_subdomains),
+                path: c.path,
+                https: bool_to_netscape(c.secure),
+                expires,
+                name: c.name,
+                value: c.value,
+                http_only: c.http_only,
+                same_site: c.same_site,
+            })
+        })
+        .collect()
+}
+
+/// Converts a Netscape `expires` timestamp to an ISO 8601 date, `None` for a session cookie.
+fn expires_to_iso(expires: &str) -> Option<String> {
+    let timestamp = expires.parse::<i64>().ok().filter(|t| *t > 0)?;
+    let date = DateTime::from_timestamp(timestamp, 0)?;
+    Some(date.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+fn bool_to_netscape(value: bool) -> String {
+    if value { "TRUE" } else { "FALSE" }.to_string()
+}
+
+/// How the cookie storage of the HTTP client is handled for a request.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CookieStorageMode {
+    /// The cookie storage is cleared before running the request.
+    Clear,
+    /// The request is run with an empty cookie storage, and the previous cookie storage is
+    /// restored after the request.
+    Isolate,
+}
+
+impl FromStr for CookieStorageMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "clear" => Ok(CookieStorageMode::Clear),
+            "isolate" => Ok(CookieStorageMode::Isolate),
+            _ => Err(format!(
+                "unsupported cookie storage mode <{s}> (valid values are clear, isolate)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for CookieStorageMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            CookieStorageMode::Clear => "clear",
+            CookieStorageMode::Isolate => "isolate",
+        };
+        write!(f, "{value}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cookies() -> Vec<Cookie> {
+        vec![
+            Cookie {
+                domain: "localhost".to_string(),
+                include_subdomain: "FALSE".to_string(),
+                path: "/".to_string(),
+                https: "FALSE".to_string(),
+                expires: "0".to_string(),
+                name: "session".to_string(),
+                value: "12345".to_string(),
+                http_only: true,
+                same_site: Some("Lax".to_string()),
+            },
+            Cookie {
+                domain: ".example.org".to_string(),
+                include_subdomain: "TRUE".to_string(),
+                path: "/api".to_string(),
+                https: "TRUE".to_string(),
+                expires: "1893456000".to_string(),
+                name: "token".to_string(),
+                value: "abcdef".to_string(),
+                http_only: false,
+                same_site: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_cookies_to_json() {
+        assert_eq!(
+            cookies_to_json(&cookies(), &["abcdef"]),
+            r#"[
+  {
+    "domain": "localhost",
+    "include_subdomains": false,
+    "path": "/",
+    "secure": false,
+    "httponly": true,
+    "expires": null,
+    "name": "session",
+    "value": "12345",
+    "same_site": "Lax"
+  },
+  {
+    "domain": ".example.org",
+    "include_subdomains": true,
+    "path": "/api",
+    "secure": true,
+    "httponly": false,
+    "expires": "2030-01-01T00:00:00Z",
+    "name": "token",
+    "value": "***"
+  }
+]"#
+        );
+    }
+
+    #[test]
+    fn test_cookies_from_json() {
+        let json = cookies_to_json(&cookies(), &[]);
+        assert_eq!(cookies_from_json(&json).unwrap(), cookies());
+
+        assert!(cookies_from_json("{}").is_err());
+        assert_eq!(
+            cookies_from_json(
+                r#"[{"domain": "localhost", "include_subdomains": false, "path": "/", "secure": false,
+                "httponly": false, "expires": "tomorrow", "name": "a", "value": "b"}]"#
+            )
+            .unwrap_err(),
+            "invalid expiration date <tomorrow>"
+        );
+    }
+
+    #[test]
+    fn test_is_json_cookie_jar() {
+        assert!(is_json_cookie_jar("cookies.json"));
+        assert!(is_json_cookie_jar("build/cookies.JSON"));
+        assert!(!is_json_cookie_jar("cookies.txt"));
+        assert!(!is_json_cookie_jar("cookies"));
+    }
+
+    #[test]
+    fn test_cookie_storage_mode() {
+        assert_eq!(
+            CookieStorageMode::from_str("clear").unwrap(),
+            CookieStorageMode::Clear
+        );
+        assert_eq!(
+            CookieStorageMode::from_str("isolate").unwrap(),
+            CookieStorageMode::Isolate
+        );
+        assert!(CookieStorageMode::from_str("reset").is_err());
+        assert_eq!(CookieStorageMode::Isolate.to_string(), "isolate");
+    }
+}
@@ -38,6 +38,10 @@ pub struct Cookie {
     pub value: String,
     /// Forbids JavaScript from accessing the cookie.
     pub http_only: bool,
+    /// Controls whether the cookie is sent with cross-site requests (`Strict`, `Lax` or `None`).
+    ///
+    /// This attribute is not part of the Netscape cookie format.
+    pub same_site: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -187,6 +191,7 @@ impl FromStr for Cookie {
             name,
             value,
             http_only,
+            same_site: None,
         })
     }
 }
@@ -208,6 +213,7 @@ mod tests {
                 name: "cookie1".to_string(),
                 value: "valueA".to_string(),
                 http_only: false,
+                same_site: None,
             }
         );
         assert_eq!(
@@ -221,6 +227,7 @@ mod tests {
                 name: "cookie2".to_string(),
                 value: String::new(),
                 http_only: false,
+                same_site: None,
             }
         );
 
@@ -18,13 +18,14 @@
 use core::fmt;
 use std::collections::HashMap;
 use std::path::Path;
+use std::time::Duration;
 
 use hurl_core::typing::Count;
 
-use crate::http::client::all_cookies;
+use crate::http::client::{all_cookies, EncodedBody};
 use crate::http::{
-    Body, ClientOptions, Cookie, FileParam, Header, HeaderVec, IpResolve, Method, MultipartParam,
-    Param, RequestSpec, RequestedHttpVersion, CONTENT_TYPE,
+    is_pem, AuthMethod, Body, ClientOptions, Cookie, FileParam, Header, HeaderVec, IpResolve,
+    Method, MultipartParam, Param, RequestSpec, RequestedHttpVersion, CONTENT_TYPE,
 };
 use crate::runner::Output;
 use crate::util::path::ContextDir;
@@ -53,12 +54,15 @@ impl Default for CurlCmd {
 impl CurlCmd {
     /// Creates a new curl command, based on an HTTP request, cookies, a context directory, output
     /// and runner options.
+    ///
+    /// An `encoded_body` (compressed or digested body) is sent as is, instead of the request body.
     pub fn new(
         request_spec: &RequestSpec,
         cookies: &[Cookie],
         context_dir: &ContextDir,
         output: Option<&Output>,
         options: &ClientOptions,
+        encoded_body: Option<&EncodedBody>,
     ) -> Self {
         let mut args = vec!["curl".to_string()];
 
@@ -71,14 +75,16 @@ impl CurlCmd {
             .map(|h| h.as_str())
             .collect::<Vec<&str>>();
         let headers = &request_spec.headers.aggregate_raw_headers(&options_headers);
-        let mut params = headers_params(
-            headers,
-            request_spec.implicit_content_type.as_deref(),
-            &request_spec.body,
-        );
+        let implicit_content_type = encoded_body
+            .and_then(|b| b.implicit_content_type.as_deref())
+            .or(request_spec.implicit_content_type.as_deref());
+        let mut params = headers_params(headers, implicit_content_type, &request_spec.body);
         args.append(&mut params);
 
-        let mut params = body_params(request_spec, context_dir);
+        let mut params = match encoded_body {
+            Some(body) => encoded_body_params(body),
+            None => body_params(request_spec, context_dir),
+        };
         args.append(&mut params);
 
         let mut params = cookies_params(request_spec, cookies);
@@ -190,6 +196,21 @@ fn body_params(request_spec: &RequestSpec, context_dir: &ContextDir) -> Vec<Stri
     args
 }
 
+/// Returns the curl args corresponding to a request body encoded by Hurl, and its headers.
+fn encoded_body_params(body: &EncodedBody) -> Vec<String> {
+    let mut args = vec![];
+    for header in &body.headers {
+        args.append(&mut header.curl_args());
+    }
+    if !body.bytes.is_empty() {
+        // Encoded bodies usually contain NUL bytes, that can't be passed in a shell string: we
+        // use a process substitution to read the body from `printf` output.
+        args.push("--data-binary".to_string());
+        args.push(format!("@<(printf '{}')", encode_bytes(&body.bytes)));
+    }
+    args
+}
+
 /// Returns the curl args corresponding to a list of cookies.
 fn cookies_params(request_spec: &RequestSpec, cookies: &[Cookie]) -> Vec<String> {
     let mut args = vec![];
@@ -373,22 +394,25 @@ impl ClientOptions {
         }
         if let Some(ref cacert_file) = self.cacert_file {
             arguments.push("--cacert".to_string());
-            arguments.push(cacert_file.clone());
+            arguments.push(encode_cert_arg(cacert_file));
         }
         if let Some(ref client_cert_file) = self.client_cert_file {
             arguments.push("--cert".to_string());
-            arguments.push(client_cert_file.clone());
+            arguments.push(encode_cert_arg(client_cert_file));
         }
         if let Some(ref client_key_file) = self.client_key_file {
             arguments.push("--key".to_string());
-            arguments.push(client_key_file.clone());
+            arguments.push(encode_cert_arg(client_key_file));
+        }
+        if self.cert_status {
+            arguments.push("--cert-status".to_string());
         }
         if self.compressed {
             arguments.push("--compressed".to_string());
         }
         if self.connect_timeout != ClientOptions::default().connect_timeout {
             arguments.push("--connect-timeout".to_string());
-            arguments.push(self.connect_timeout.as_secs().to_string());
+            arguments.push(encode_duration(self.connect_timeout));
         }
         for connect in self.connects_to.iter() {
             arguments.push("--connect-to".to_string());
@@ -398,11 +422,25 @@ impl ClientOptions {
             arguments.push("--cookie".to_string());
             arguments.push(cookie_file.clone());
         }
+        if let Some(ref crl_file) = self.crl_file {
+            arguments.push("--crlfile".to_string());
+            arguments.push(crl_file.clone());
+        }
+        match self.auth_method {
+            AuthMethod::Basic => {}
+            AuthMethod::Digest => arguments.push("--digest".to_string()),
+            AuthMethod::Ntlm => arguments.push("--ntlm".to_string()),
+            AuthMethod::Negotiate => arguments.push("--negotiate".to_string()),
+            AuthMethod::Any => arguments.push("--anyauth".to_string()),
+        }
         match self.http_version {
             RequestedHttpVersion::Default => {}
             RequestedHttpVersion::Http10 => arguments.push("--http1.0".to_string()),
             RequestedHttpVersion::Http11 => arguments.push("--http1.1".to_string()),
             RequestedHttpVersion::Http2 => arguments.push("--http2".to_string()),
+            RequestedHttpVersion::Http2PriorKnowledge => {
+                arguments.push("--http2-prior-knowledge".to_string());
+            }
             RequestedHttpVersion::Http3 => arguments.push("--http3".to_string()),
         }
         if self.insecure {
@@ -436,6 +474,10 @@ impl ClientOptions {
             arguments.push("--max-redirs".to_string());
             arguments.push(max_redirect.to_string());
         }
+        if self.timeout != ClientOptions::default().timeout {
+            arguments.push("--max-time".to_string());
+            arguments.push(encode_duration(self.timeout));
+        }
         if let Some(filename) = &self.netrc_file {
             arguments.push("--netrc-file".to_string());
             arguments.push(format!("'{filename}'"));
@@ -449,10 +491,41 @@ impl ClientOptions {
         if self.path_as_is {
             arguments.push("--path-as-is".to_string());
         }
+        if let Some(ref pinned_pubkey) = self.pinned_pubkey {
+            arguments.push("--pinnedpubkey".to_string());
+            arguments.push(format!("'{pinned_pubkey}'"));
+        }
         if let Some(ref proxy) = self.proxy {
             arguments.push("--proxy".to_string());
             arguments.push(format!("'{proxy}'"));
         }
+        match self.proxy_auth_method {
+            AuthMethod::Basic => {}
+            AuthMethod::Digest => arguments.push("--proxy-digest".to_string()),
+            AuthMethod::Ntlm => arguments.push("--proxy-ntlm".to_string()),
+            AuthMethod::Negotiate => arguments.push("--proxy-negotiate".to_string()),
+            AuthMethod::Any => arguments.push("--proxy-anyauth".to_string()),
+        }
+        if let Some(ref proxy_cacert_file) = self.proxy_cacert_file {
+            arguments.push("--proxy-cacert".to_string());
+            arguments.push(proxy_cacert_file.clone());
+        }
+        for header in self.proxy_headers.iter() {
+            arguments.push("--proxy-header".to_string());
+            arguments.push(encode_shell_string(header));
+        }
+        if self.proxy_insecure {
+            arguments.push("--proxy-insecure".to_string());
+        }
+        if let Some(ref proxy_user) = self.proxy_user {
+            // The proxy password is never exported, even if it's not declared as a secret.
+            let proxy_user = match proxy_user.split_once(':') {
+                Some((user, password)) if !password.is_empty() => format!("{user}:***"),
+                _ => proxy_user.clone(),
+            };
+            arguments.push("--proxy-user".to_string());
+            arguments.push(format!("'{proxy_user}'"));
+        }
         for resolve in self.resolves.iter() {
             arguments.push("--resolve".to_string());
             arguments.push(resolve.clone());
@@ -460,9 +533,12 @@ impl ClientOptions {
         if self.ssl_no_revoke {
             arguments.push("--ssl-no-revoke".to_string());
         }
-        if self.timeout != ClientOptions::default().timeout {
-            arguments.push("--timeout".to_string());
-            arguments.push(self.timeout.as_secs().to_string());
+        if let Some(tls_min_version) = self.tls_min_version {
+            arguments.push(format!("--tlsv{tls_min_version}"));
+        }
+        if let Some(tls_max_version) = self.tls_max_version {
+            arguments.push("--tls-max".to_string());
+            arguments.push(tls_max_version.to_string());
         }
         if let Some(ref unix_socket) = self.unix_socket {
             arguments.push("--unix-socket".to_string());
@@ -480,6 +556,15 @@ impl ClientOptions {
     }
 }
 
+/// Encodes a `duration` in seconds, with a decimal part if needed (ex: `20`, `0.5`).
+fn encode_duration(duration: Duration) -> String {
+    if duration.subsec_millis() == 0 {
+        duration.as_secs().to_string()
+    } else {
+        format!("{}", duration.as_secs_f64())
+    }
+}
+
 fn escape_url(s: &str) -> String {
     percent_encoding::percent_encode(s.as_bytes(), percent_encoding::NON_ALPHANUMERIC).to_string()
 }
@@ -494,6 +579,16 @@ fn encode_shell_string(s: &str) -> String {
     }
 }
 
+/// Encodes a certificate or key argument: a file path is used as is, while a PEM content is given
+/// to curl through a process substitution (curl doesn't support PEM content in command line).
+fn encode_cert_arg(value: &str) -> String {
+    if is_pem(value) {
+        format!("<(printf '%s' {})", encode_shell_string(value))
+    } else {
+        value.to_string()
+    }
+}
+
 // the shell string must be in escaped mode ($'...')
 // if it contains \n, \t or '
 fn escape_mode(s: &str) -> bool {
@@ -526,12 +621,11 @@ fn escape_string(s: &str) -> String {
 mod tests {
     use std::path::Path;
     use std::str::FromStr;
-    use std::time::Duration;
 
     use hurl_core::typing::BytesPerSec;
 
     use super::*;
-    use crate::http::{HeaderVec, Url};
+    use crate::http::{HeaderVec, TlsVersion, Url};
 
     #[test]
     fn hello_request_with_default_options() {
@@ -546,12 +640,26 @@ mod tests {
         let options = ClientOptions::default();
         let output = None;
 
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(cmd.to_string(), "curl 'http://localhost:8000/hello'");
 
         // Same requests with some output:
         let output = Some(Output::new("foo.out"));
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(
             cmd.to_string(),
             "curl \
@@ -564,7 +672,14 @@ mod tests {
         headers.push(Header::new("User-Agent", "iPhone"));
         headers.push(Header::new("Foo", "Bar"));
         request.headers = headers;
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(
             cmd.to_string(),
             "curl \
@@ -585,6 +700,7 @@ mod tests {
                 name: "cookie1".to_string(),
                 value: "valueA".to_string(),
                 http_only: false,
+                same_site: None,
             },
             Cookie {
                 domain: "localhost".to_string(),
@@ -595,9 +711,17 @@ mod tests {
                 name: "cookie2".to_string(),
                 value: String::new(),
                 http_only: true,
+                same_site: None,
             },
         ];
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(
             cmd.to_string(),
             "curl \
@@ -621,21 +745,28 @@ mod tests {
         let cookies = vec![];
         let options = ClientOptions {
             allow_reuse: true,
+            auth_method: AuthMethod::Digest,
             aws_sigv4: None,
             cacert_file: None,
+            cert_status: true,
             client_cert_file: None,
             client_key_file: None,
+            compress_body: None,
             compressed: true,
             connect_timeout: Duration::from_secs(20),
             connects_to: vec!["example.com:443:host-47.example.com:443".to_string()],
+            content_digest: None,
             cookie_input_file: Some("cookie_file".to_string()),
+            crl_file: Some("crl.pem".to_string()),
             follow_location: true,
             follow_location_trusted: false,
+            grpc_proto: None,
             headers: vec![
                 "Test-Header-1: content-1".to_string(),
                 "Test-Header-2: content-2".to_string(),
                 "Test-Header-Empty:".to_string(),
             ],
+            http_signature: None,
             http_version: RequestedHttpVersion::Http10,
             insecure: true,
             ip_resolve: IpResolve::IpV6,
@@ -647,44 +778,68 @@ mod tests {
             netrc_file: Some("/var/run/netrc".to_string()),
             netrc_optional: true,
             path_as_is: true,
+            pinned_pubkey: Some("sha256//YhKJKSzoTt2b5FP18fvpHo7fJYqQCjAa3HWY3tvRMwE=".to_string()),
             proxy: Some("localhost:3128".to_string()),
+            proxy_auth_method: AuthMethod::Ntlm,
+            proxy_cacert_file: Some("proxy-ca.pem".to_string()),
+            proxy_headers: vec!["X-Proxy-Id: 1234".to_string()],
+            proxy_insecure: true,
+            proxy_user: Some("bob:secret".to_string()),
             no_proxy: None,
             resolves: vec![
                 "foo.com:80:192.168.0.1".to_string(),
                 "bar.com:443:127.0.0.1".to_string(),
             ],
+            sse_duration: None,
+            sse_events: Count::Infinite,
             ssl_no_revoke: false,
-            timeout: Duration::from_secs(10),
+            timeout: Duration::from_millis(10500),
+            tls_max_version: Some(TlsVersion::Tls13),
+            tls_min_version: Some(TlsVersion::Tls12),
+            traceparent: false,
             unix_socket: Some("/var/run/example.sock".to_string()),
             user: Some("user:password".to_string()),
             user_agent: Some("my-useragent".to_string()),
             verbosity: None,
+            websocket_messages: Count::Finite(1),
+            websocket_timeout: Duration::from_secs(10),
         };
 
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, None, &options);
+        let cmd = CurlCmd::new(&request, &cookies, context_dir, None, &options, None);
         assert_eq!(
             cmd.to_string(),
             "curl \
         --header 'Test-Header-1: content-1' \
         --header 'Test-Header-2: content-2' \
         --header 'Test-Header-Empty;' \
+        --cert-status \
         --compressed \
         --connect-timeout 20 \
         --connect-to example.com:443:host-47.example.com:443 \
         --cookie cookie_file \
+        --crlfile crl.pem \
+        --digest \
         --http1.0 \
         --insecure \
         --ipv6 \
         --location \
         --limit-rate 8000 \
         --max-redirs 10 \
+        --max-time 10.5 \
         --netrc-file '/var/run/netrc' \
         --netrc-optional \
         --path-as-is \
+        --pinnedpubkey 'sha256//YhKJKSzoTt2b5FP18fvpHo7fJYqQCjAa3HWY3tvRMwE=' \
         --proxy 'localhost:3128' \
+        --proxy-ntlm \
+        --proxy-cacert proxy-ca.pem \
+        --proxy-header 'X-Proxy-Id: 1234' \
+        --proxy-insecure \
+        --proxy-user 'bob:***' \
         --resolve foo.com:80:192.168.0.1 \
         --resolve bar.com:443:127.0.0.1 \
-        --timeout 10 \
+        --tlsv1.2 \
+        --tls-max 1.3 \
         --unix-socket '/var/run/example.sock' \
         --user 'user:password' \
         --user-agent 'my-useragent' \
@@ -705,7 +860,14 @@ mod tests {
         let options = ClientOptions::default();
         let output = None;
 
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(
             cmd.to_string(),
             "curl 'https://example.org/hello/../to/../your/../file'"
@@ -725,7 +887,14 @@ mod tests {
         let options = ClientOptions::default();
         let output = None;
 
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(
             cmd.to_string(),
             "curl \
@@ -757,7 +926,14 @@ mod tests {
         let options = ClientOptions::default();
         let output = None;
 
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(
             cmd.to_string(),
             "curl 'http://localhost:8000/querystring-params?param1=value1&param2=a%20b'",
@@ -767,7 +943,14 @@ mod tests {
         request.url =
             Url::from_str("http://localhost:8000/querystring-params?param3=foo&param4=bar")
                 .unwrap();
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(
             cmd.to_string(),
             "curl 'http://localhost:8000/querystring-params?param3=foo&param4=bar&param1=value1&param2=a%20b'",
@@ -796,7 +979,14 @@ mod tests {
         let options = ClientOptions::default();
         let output = None;
 
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(
             cmd.to_string(),
             "curl \
@@ -825,7 +1015,14 @@ mod tests {
         let options = ClientOptions::default();
         let output = None;
 
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(
             cmd.to_string(),
             "curl \
@@ -836,7 +1033,14 @@ mod tests {
 
         // Add a non-empty body
         request.body = Body::Text("{\"foo\":\"bar\"}".to_string());
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(
             cmd.to_string(),
             "curl \
@@ -847,7 +1051,14 @@ mod tests {
 
         // Change method
         request.method = Method("PUT".to_string());
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(
             cmd.to_string(),
             "curl \
@@ -872,7 +1083,14 @@ mod tests {
         let options = ClientOptions::default();
         let output = None;
 
-        let cmd = CurlCmd::new(&request, &cookies, context_dir, output.as_ref(), &options);
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            context_dir,
+            output.as_ref(),
+            &options,
+            None,
+        );
         assert_eq!(
             cmd.to_string(),
             "curl \
@@ -882,6 +1100,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn post_encoded_body() {
+        let request = RequestSpec {
+            method: Method("POST".to_string()),
+            url: Url::from_str("http://localhost:8000/hello").unwrap(),
+            body: Body::Text("Hello".to_string()),
+            implicit_content_type: Some("text/plain".to_string()),
+            ..Default::default()
+        };
+        let body = EncodedBody {
+            bytes: vec![0x1f, 0x8b, 0x00],
+            implicit_content_type: None,
+            headers: vec![
+                Header::new("Content-Encoding", "gzip"),
+                Header::new("Content-Digest", "sha-256=:abc=:"),
+            ],
+        };
+
+        let context_dir = &ContextDir::default();
+        let cookies = vec![];
+        let options = ClientOptions::default();
+
+        let cmd = CurlCmd::new(&request, &cookies, context_dir, None, &options, Some(&body));
+        assert_eq!(
+            cmd.to_string(),
+            "curl \
+            --header 'Content-Type: text/plain' \
+            --header 'Content-Encoding: gzip' \
+            --header 'Content-Digest: sha-256=:abc=:' \
+            --data-binary @<(printf '\\x1f\\x8b\\x00') \
+            'http://localhost:8000/hello'"
+        );
+    }
+
     #[test]
     fn test_encode_byte() {
         assert_eq!(encode_byte(1), "\\x01".to_string());
@@ -971,6 +1223,15 @@ mod tests {
         assert_eq!(encode_shell_string("\n"), "$'\\n'");
     }
 
+    #[test]
+    fn test_encode_cert_arg() {
+        assert_eq!(encode_cert_arg("/etc/cert.pem"), "/etc/cert.pem");
+        assert_eq!(
+            encode_cert_arg("-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----\n"),
+            "<(printf '%s' $'-----BEGIN CERTIFICATE-----\\nMIIB\\n-----END CERTIFICATE-----\\n')"
+        );
+    }
+
     #[test]
     fn test_escape_string() {
         assert_eq!(escape_string("hello"), "hello");
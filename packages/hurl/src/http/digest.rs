@@ -0,0 +1,200 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Digest fields `Content-Digest` and `Repr-Digest`, see <https://www.rfc-editor.org/rfc/rfc9530>.
+use std::fmt;
+use std::str::FromStr;
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use sha2::{Digest, Sha256, Sha512};
+
+use crate::http::Response;
+
+/// Hashing algorithm of a digest field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DigestAlgorithm {
+    Sha256,
+    Sha512,
+}
+
+impl DigestAlgorithm {
+    /// Returns the identifier of this algorithm in a digest field.
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            DigestAlgorithm::Sha256 => "sha-256",
+            DigestAlgorithm::Sha512 => "sha-512",
+        }
+    }
+
+    /// Returns the digest field value of `data`, for instance `sha-256=:X48E9qOokqqrvdts8nOJRJN3OWDUoyWxBf7kbu9DBPE=:`.
+    pub fn field_value(&self, data: &[u8]) -> String {
+        let digest = general_purpose::STANDARD.encode(self.digest(data));
+        format!("{}=:{digest}:", self.identifier())
+    }
+
+    fn digest(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            DigestAlgorithm::Sha256 => Sha256::digest(data).to_vec(),
+            DigestAlgorithm::Sha512 => Sha512::digest(data).to_vec(),
+        }
+    }
+}
+
+impl FromStr for DigestAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sha-256" => Ok(DigestAlgorithm::Sha256),
+            "sha-512" => Ok(DigestAlgorithm::Sha512),
+            _ => Err(format!(
+                "unsupported digest algorithm <{s}> (valid values are sha-256, sha-512)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for DigestAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.identifier())
+    }
+}
+
+/// Verifies a digest field `value` (from a `Content-Digest` or `Repr-Digest` header) against the
+/// body of a `response`.
+///
+/// Digests are computed on the body as received, with its content encoding. As some servers
+/// compute them on the decoded body, the decoded body is also checked for compressed responses.
+/// Digests with unsupported algorithms are ignored, and an error is returned if there is no
+/// supported digest in `value`.
+pub fn verify_digest(value: &str, response: &Response) -> Result<bool, String> {
+    let digests = parse_digests(value)?;
+    let matches = |body: &[u8]| {
+        digests
+            .iter()
+            .all(|(algorithm, digest)| algorithm.digest(body) == *digest)
+    };
+    if matches(&response.body) {
+        return Ok(true);
+    }
+    let content_encodings = response.headers.content_encoding().unwrap_or_default();
+    if content_encodings.is_empty() {
+        return Ok(false);
+    }
+    match response.uncompress_body() {
+        Ok(body) => Ok(matches(&body)),
+        Err(_) => Ok(false),
+    }
+}
+
+/// Parses a digest field `value`, and returns the list of digests with a supported algorithm.
+fn parse_digests(value: &str) -> Result<Vec<(DigestAlgorithm, Vec<u8>)>, String> {
+    let mut digests = vec![];
+    for member in value.split(',') {
+        let Some((algorithm, digest)) = member.trim().split_once('=') else {
+            return Err(format!("invalid digest <{}>", member.trim()));
+        };
+        let Ok(algorithm) = DigestAlgorithm::from_str(algorithm) else {
+            continue;
+        };
+        let digest = digest
+            .strip_prefix(':')
+            .and_then(|d| d.strip_suffix(':'))
+            .and_then(|d| general_purpose::STANDARD.decode(d).ok())
+            .ok_or(format!("digest <{algorithm}> is not a byte sequence"))?;
+        digests.push((algorithm, digest));
+    }
+    if digests.is_empty() {
+        return Err(format!("no supported digest algorithm in <{value}>"));
+    }
+    Ok(digests)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{Header, HeaderVec, HttpVersion, Url};
+
+    fn response(headers: HeaderVec, body: &[u8]) -> Response {
+        Response {
+            version: HttpVersion::Http11,
+            status: 200,
+            headers,
+            body: body.to_vec(),
+            duration: Default::default(),
+            url: Url::from_str("http://localhost").unwrap(),
+            certificate: None,
+            ip_addr: Default::default(),
+            messages: vec![],
+        }
+    }
+
+    #[test]
+    fn test_field_value() {
+        // Examples from <https://www.rfc-editor.org/rfc/rfc9530#appendix-B>
+        let data = b"{\"hello\": \"world\"}";
+        assert_eq!(
+            DigestAlgorithm::Sha256.field_value(data),
+            "sha-256=:X48E9qOokqqrvdts8nOJRJN3OWDUoyWxBf7kbu9DBPE=:"
+        );
+        assert_eq!(
+            DigestAlgorithm::Sha512.field_value(data),
+            "sha-512=:WZDPaVn/7XgHaAy8pmojAkGWoRx2UFChF41A2svX+TaPm+AbwAgBWnrIiYllu7BNNyealdVLvRwEmTHWXvJwew==:"
+        );
+    }
+
+    #[test]
+    fn test_verify_digest() {
+        let body = b"{\"hello\": \"world\"}";
+        let response = response(HeaderVec::new(), body);
+        let value = "sha-256=:X48E9qOokqqrvdts8nOJRJN3OWDUoyWxBf7kbu9DBPE=:";
+        assert!(verify_digest(value, &response).unwrap());
+        let value = "sha-256=:RK/0qy18MlBSVnWgjwz6lZEWjP/lF5HF9bvEF8FabDg=:";
+        assert!(!verify_digest(value, &response).unwrap());
+
+        // Unsupported algorithms are ignored.
+        let value = "unixsum=:AAA=:, sha-256=:X48E9qOokqqrvdts8nOJRJN3OWDUoyWxBf7kbu9DBPE=:";
+        assert!(verify_digest(value, &response).unwrap());
+        assert_eq!(
+            verify_digest("unixsum=:AAA=:", &response).unwrap_err(),
+            "no supported digest algorithm in <unixsum=:AAA=:>"
+        );
+        assert_eq!(
+            verify_digest("sha-256=abc", &response).unwrap_err(),
+            "digest <sha-256> is not a byte sequence"
+        );
+    }
+
+    #[test]
+    fn test_verify_digest_compressed_body() {
+        // Body is gzipped `{"hello": "world"}`.
+        let body = [
+            0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0xab, 0x56, 0xca, 0x48,
+            0xcd, 0xc9, 0xc9, 0x57, 0xb2, 0x52, 0x50, 0x2a, 0xcf, 0x2f, 0xca, 0x49, 0x51, 0xaa,
+            0x05, 0x00, 0x22, 0xae, 0xa3, 0x86, 0x12, 0x00, 0x00, 0x00,
+        ];
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Encoding", "gzip"));
+        let response = response(headers, &body);
+
+        let value = DigestAlgorithm::Sha256.field_value(&body);
+        assert!(verify_digest(&value, &response).unwrap());
+        let value = "sha-256=:X48E9qOokqqrvdts8nOJRJN3OWDUoyWxBf7kbu9DBPE=:";
+        assert!(verify_digest(value, &response).unwrap());
+    }
+}
@@ -15,13 +15,16 @@
  * limitations under the License.
  *
  */
-use std::ffi::{CStr, CString};
+use std::ffi::{c_long, CStr, CString};
 use std::ptr;
 use std::time::Duration;
 
 use curl::easy::Easy;
 use curl::Error;
-use curl_sys::{curl_certinfo, curl_off_t, curl_slist, CURLINFO, CURLOPT_NETRC_FILE};
+use curl_sys::{
+    curl_certinfo, curl_off_t, curl_slist, CURLoption, CURLINFO, CURLOPTTYPE_LONG,
+    CURLOPTTYPE_OBJECTPOINT, CURLOPT_NETRC_FILE,
+};
 
 /// Some definitions not present in curl-sys
 const CURLINFO_OFF_T: CURLINFO = 0x600000;
@@ -34,6 +37,9 @@ const CURLINFO_STARTTRANSFER_TIME_T: CURLINFO = CURLINFO_OFF_T + 54;
 const CURLINFO_APPCONNECT_TIME_T: CURLINFO = CURLINFO_OFF_T + 56;
 const CURLINFO_CONN_ID: CURLINFO = CURLINFO_OFF_T + 64;
 
+const CURLOPT_PROXYHEADER: CURLoption = CURLOPTTYPE_OBJECTPOINT + 228;
+const CURLOPT_SSL_VERIFYSTATUS: CURLoption = CURLOPTTYPE_LONG + 232;
+
 /// Represents certificate information.
 /// `data` has format "name:content";
 #[derive(Clone)]
@@ -191,6 +197,63 @@ pub fn netrc_file(easy: &mut Easy, filename: &str) -> Result<(), Error> {
     })
 }
 
+/// A list of headers, owned by Hurl, that must live as long as a libcurl handle uses it.
+#[derive(Debug)]
+pub struct HeaderList {
+    raw: *mut curl_slist,
+}
+
+// Like curl crate [`curl::easy::List`], the list is not shared and can be sent to another thread.
+unsafe impl Send for HeaderList {}
+
+impl Default for HeaderList {
+    fn default() -> Self {
+        HeaderList {
+            raw: ptr::null_mut(),
+        }
+    }
+}
+
+impl HeaderList {
+    /// Creates a new list of `headers`.
+    pub fn new(headers: &[String]) -> Result<Self, Error> {
+        let mut list = HeaderList::default();
+        for header in headers {
+            let header = CString::new(header.as_str())?;
+            let raw = unsafe { curl_sys::curl_slist_append(list.raw, header.as_ptr()) };
+            if raw.is_null() {
+                return Err(Error::new(curl_sys::CURLE_OUT_OF_MEMORY));
+            }
+            list.raw = raw;
+        }
+        Ok(list)
+    }
+}
+
+impl Drop for HeaderList {
+    fn drop(&mut self) {
+        unsafe { curl_sys::curl_slist_free_all(self.raw) }
+    }
+}
+
+/// Sets the headers sent to a proxy.
+///
+/// See <https://curl.se/libcurl/c/CURLOPT_PROXYHEADER.html>
+pub fn proxy_headers(easy: &mut Easy, headers: &HeaderList) -> Result<(), Error> {
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_PROXYHEADER, headers.raw)
+    })
+}
+
+/// Sets the verification of the server certificate's status, with OCSP stapling.
+///
+/// See <https://curl.se/libcurl/c/CURLOPT_SSL_VERIFYSTATUS.html>
+pub fn ssl_verify_status(easy: &mut Easy, verify: bool) -> Result<(), Error> {
+    cvt(easy, unsafe {
+        curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_SSL_VERIFYSTATUS, verify as c_long)
+    })
+}
+
 /// Converts an instance of libcurl linked list [`curl_slist`] to a vec of [`String`].
 fn to_list(slist: *mut curl_slist) -> Vec<String> {
     let mut data = vec![];
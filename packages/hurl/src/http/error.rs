@@ -15,18 +15,33 @@
 * limitations under the License.
 *
 */
-use crate::http::RequestedHttpVersion;
+use crate::http::{RequestedHttpVersion, TransferPhase};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HttpError {
+    /// The server certificate has been revoked, according to the certificate revocation list.
+    CertificateRevoked {
+        description: String,
+    },
     CouldNotParseCookieExpires(String),
     CouldNotParseResponse,
+    CouldNotCompressRequest {
+        description: String,
+    },
     CouldNotUncompressResponse {
         description: String,
     },
+    /// The server certificate status (OCSP stapling) can not be verified.
+    InvalidCertificateStatus {
+        description: String,
+    },
     InvalidCharset {
         charset: String,
     },
+    /// The certificate revocation list file can not be loaded.
+    InvalidCrlFile {
+        description: String,
+    },
     InvalidDecoding {
         charset: String,
     },
@@ -39,10 +54,33 @@ pub enum HttpError {
         minimum_version: String,
     },
     NoPrimaryIp,
+    /// An OAuth2 access token can not be obtained from the token endpoint.
+    OAuth2 {
+        description: String,
+    },
+    /// The public key of the server doesn't match the pinned public key.
+    PinnedPublicKeyMismatch {
+        description: String,
+    },
+    /// The TLS connection can not be established with the allowed TLS versions.
+    TlsVersionMismatch {
+        description: String,
+    },
+    /// The transfer has not been completed in the allowed time (`connect-timeout` or `max-time`),
+    /// `phase` being the phase of the transfer when the timeout has been reached.
+    Timeout {
+        phase: TransferPhase,
+        description: String,
+    },
     TooManyRedirect,
     UnsupportedContentEncoding {
         description: String,
     },
+    /// A dictionary-compressed content encoding (`dcb` or `dcz`), that can not be decoded without
+    /// its shared dictionary.
+    UnsupportedDictionaryContentEncoding {
+        description: String,
+    },
     UnsupportedHttpVersion(RequestedHttpVersion),
     /// Request URL is invalid (URL and reason)
     InvalidUrl(String, String),
@@ -50,6 +88,20 @@ pub enum HttpError {
     /// This error can be raised even if libcurl has been configured to respect a given maximum
     /// file size.
     AllowedResponseSizeExceeded(u64),
+    /// A WebSocket protocol error (invalid handshake, invalid frame etc...)
+    WebSocket {
+        description: String,
+    },
+    /// The WebSocket exchange has not been completed in the allowed time.
+    WebSocketTimeout,
+    /// A gRPC error (invalid descriptors, message not matching its descriptor etc...)
+    Grpc {
+        description: String,
+    },
+    /// A request can not be signed (unreadable key file, missing covered component etc...)
+    HttpSignature {
+        description: String,
+    },
 }
 
 impl From<curl::Error> for HttpError {
@@ -64,18 +116,33 @@ impl HttpError {
     pub fn description(&self) -> String {
         match self {
             HttpError::AllowedResponseSizeExceeded(_) => "HTTP connection".to_string(),
+            HttpError::CertificateRevoked { .. } => "Certificate revoked".to_string(),
             HttpError::CouldNotParseCookieExpires(_) => "HTTP connection".to_string(),
             HttpError::CouldNotParseResponse => "HTTP connection".to_string(),
+            HttpError::CouldNotCompressRequest { .. } => "Compression error".to_string(),
             HttpError::CouldNotUncompressResponse { .. } => "Decompression error".to_string(),
+            HttpError::Grpc { .. } => "gRPC".to_string(),
+            HttpError::HttpSignature { .. } => "HTTP signature".to_string(),
+            HttpError::InvalidCertificateStatus { .. } => "Invalid certificate status".to_string(),
             HttpError::InvalidCharset { .. } => "Invalid charset".to_string(),
+            HttpError::InvalidCrlFile { .. } => "Invalid CRL file".to_string(),
             HttpError::InvalidDecoding { .. } => "Invalid decoding".to_string(),
             HttpError::InvalidUrl(..) => "Invalid URL".to_string(),
             HttpError::Libcurl { .. } => "HTTP connection".to_string(),
             HttpError::LibcurlUnknownOption { .. } => "HTTP connection".to_string(),
             HttpError::NoPrimaryIp => "HTTP connection".to_string(),
+            HttpError::OAuth2 { .. } => "OAuth2".to_string(),
+            HttpError::PinnedPublicKeyMismatch { .. } => "Pinned public key".to_string(),
+            HttpError::TlsVersionMismatch { .. } => "TLS version".to_string(),
+            HttpError::Timeout { .. } => "Timeout".to_string(),
             HttpError::TooManyRedirect => "HTTP connection".to_string(),
             HttpError::UnsupportedContentEncoding { .. } => "Decompression error".to_string(),
+            HttpError::UnsupportedDictionaryContentEncoding { .. } => {
+                "Decompression error".to_string()
+            }
             HttpError::UnsupportedHttpVersion(_) => "Unsupported HTTP version".to_string(),
+            HttpError::WebSocket { .. } => "WebSocket".to_string(),
+            HttpError::WebSocketTimeout => "WebSocket".to_string(),
         }
     }
 
@@ -84,13 +151,27 @@ impl HttpError {
             HttpError::AllowedResponseSizeExceeded(max_size) => {
                 format!("exceeded the maximum allowed file size ({max_size} bytes)")
             }
+            HttpError::CertificateRevoked { description } => {
+                format!("the server certificate has been revoked ({description})")
+            }
             HttpError::CouldNotParseCookieExpires(value) => {
                 format!("could not parse Cookie Expires attribute value <{value}>")
             }
             HttpError::CouldNotParseResponse => "could not parse Response".to_string(),
+            HttpError::CouldNotCompressRequest { description } => {
+                format!("could not compress request with {description}")
+            }
             HttpError::CouldNotUncompressResponse { description } => {
                 format!("could not uncompress response with {description}")
             }
+            HttpError::Grpc { description } => description.clone(),
+            HttpError::HttpSignature { description } => description.clone(),
+            HttpError::InvalidCertificateStatus { description } => {
+                format!("the server certificate status can not be verified ({description})")
+            }
+            HttpError::InvalidCrlFile { description } => {
+                format!("the certificate revocation list file can not be loaded ({description})")
+            }
             HttpError::InvalidCharset { charset } => {
                 format!("the charset '{charset}' is not valid")
             }
@@ -106,6 +187,16 @@ impl HttpError {
                 minimum_version,
             } => format!("Option {option} requires libcurl version {minimum_version} or higher"),
             HttpError::NoPrimaryIp => "No primary IP found in response".to_string(),
+            HttpError::OAuth2 { description } => description.clone(),
+            HttpError::PinnedPublicKeyMismatch { description } => {
+                format!("the server public key doesn't match the pinned public key ({description})")
+            }
+            HttpError::TlsVersionMismatch { description } => {
+                format!("the TLS connection can not be established with the allowed TLS versions ({description})")
+            }
+            HttpError::Timeout { phase, description } => {
+                format!("timeout was reached during {phase} ({description})")
+            }
             HttpError::TooManyRedirect => "too many redirect".to_string(),
             HttpError::UnsupportedHttpVersion(version) => {
                 format!("{version} is not supported, check --version").to_string()
@@ -113,6 +204,13 @@ impl HttpError {
             HttpError::UnsupportedContentEncoding { description } => {
                 format!("compression {description} is not supported").to_string()
             }
+            HttpError::UnsupportedDictionaryContentEncoding { description } => {
+                format!("compression {description} requires a shared dictionary, which is not supported")
+            }
+            HttpError::WebSocket { description } => description.clone(),
+            HttpError::WebSocketTimeout => {
+                "timeout was reached while waiting for WebSocket messages".to_string()
+            }
         }
     }
 }
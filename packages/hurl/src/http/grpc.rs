@@ -0,0 +1,292 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! gRPC support, see <https://github.com/grpc/grpc/blob/master/doc/PROTOCOL-HTTP2.md>.
+//!
+//! Request and response messages are written and read as JSON: they're encoded to (and decoded
+//! from) protobuf with the descriptors of a local `.proto` file or descriptor set.
+use std::path::Path;
+
+use prost::Message;
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor, SerializeOptions};
+
+use crate::http::HttpError;
+
+/// Size of the length-prefixed message header (compressed flag and message length).
+const FRAME_HEADER_LEN: usize = 5;
+
+/// A gRPC method, with its request and response message descriptors.
+#[derive(Clone, Debug)]
+pub struct GrpcMethod {
+    input: MessageDescriptor,
+    output: MessageDescriptor,
+}
+
+impl GrpcMethod {
+    /// Loads the gRPC method of this URL `path` (`/package.Service/Method`) from a `.proto` file
+    /// or a descriptor set `file`.
+    pub fn load(file: &Path, path: &str) -> Result<GrpcMethod, HttpError> {
+        let pool = load_descriptors(file)?;
+        let Some((service, method)) = path.trim_start_matches('/').split_once('/') else {
+            return Err(grpc_error(format!(
+                "path <{path}> is not a valid gRPC path (/package.Service/Method)"
+            )));
+        };
+        let Some(service_desc) = pool.get_service_by_name(service) else {
+            return Err(grpc_error(format!(
+                "service <{service}> not found in <{}>",
+                file.display()
+            )));
+        };
+        let Some(method_desc) = service_desc.methods().find(|m| m.name() == method) else {
+            return Err(grpc_error(format!(
+                "method <{method}> not found in service <{service}>"
+            )));
+        };
+        Ok(GrpcMethod {
+            input: method_desc.input(),
+            output: method_desc.output(),
+        })
+    }
+
+    /// Encodes a JSON request message to a length-prefixed protobuf message.
+    ///
+    /// An empty `json` is encoded as an empty message.
+    pub fn encode_request(&self, json: &[u8]) -> Result<Vec<u8>, HttpError> {
+        let json = if json.iter().all(u8::is_ascii_whitespace) {
+            b"{}"
+        } else {
+            json
+        };
+        let mut deserializer = serde_json::Deserializer::from_slice(json);
+        let message = DynamicMessage::deserialize(self.input.clone(), &mut deserializer)
+            .and_then(|m| deserializer.end().map(|_| m))
+            .map_err(|e| {
+                grpc_error(format!(
+                    "request body can not be encoded to <{}>: {e}",
+                    self.input.full_name()
+                ))
+            })?;
+        Ok(encode_frame(&message.encode_to_vec()))
+    }
+
+    /// Decodes length-prefixed protobuf response messages to JSON.
+    ///
+    /// A single message is decoded to a JSON object, multiple messages (server streaming) are
+    /// decoded to a JSON array, and an empty body is kept empty.
+    pub fn decode_response(&self, body: &[u8]) -> Result<Vec<u8>, HttpError> {
+        let options = SerializeOptions::new()
+            .skip_default_fields(false)
+            .stringify_64_bit_integers(false);
+        let mut values = vec![];
+        for frame in decode_frames(body)? {
+            let message = DynamicMessage::decode(self.output.clone(), frame).map_err(|e| {
+                grpc_error(format!(
+                    "response can not be decoded to <{}>: {e}",
+                    self.output.full_name()
+                ))
+            })?;
+            let value = message
+                .serialize_with_options(serde_json::value::Serializer, &options)
+                .map_err(|e| grpc_error(e.to_string()))?;
+            values.push(value);
+        }
+        let json = match values.len() {
+            0 => return Ok(vec![]),
+            1 => values.remove(0),
+            _ => serde_json::Value::Array(values),
+        };
+        Ok(json.to_string().into_bytes())
+    }
+}
+
+/// Loads a pool of descriptors from a `.proto` file or a descriptor set `file`
+/// (as produced by `protoc --descriptor_set_out`).
+fn load_descriptors(file: &Path) -> Result<DescriptorPool, HttpError> {
+    if file.extension().is_some_and(|ext| ext == "proto") {
+        return parse_proto(file);
+    }
+    let bytes = std::fs::read(file)
+        .map_err(|e| grpc_error(format!("{} can not be read: {e}", file.display())))?;
+    DescriptorPool::decode(bytes.as_slice())
+        .map_err(|e| grpc_error(format!("invalid descriptor set {}: {e}", file.display())))
+}
+
+/// Parses a `.proto` `file` (and its imports, relative to the file directory).
+fn parse_proto(file: &Path) -> Result<DescriptorPool, HttpError> {
+    let include = file.parent().unwrap_or(Path::new("."));
+    let parsed = protobuf_parse::Parser::new()
+        .pure()
+        .include(include)
+        .input(file)
+        .parse_and_typecheck()
+        .map_err(|e| grpc_error(format!("{} can not be parsed: {e:#}", file.display())))?;
+
+    // Descriptors are converted from the `protobuf` model to the `prost` one through their
+    // binary representation, including all the imported files.
+    let mut set = protobuf::descriptor::FileDescriptorSet::new();
+    set.file = parsed.file_descriptors;
+    let bytes = protobuf::Message::write_to_bytes(&set).map_err(|e| grpc_error(e.to_string()))?;
+    DescriptorPool::decode(bytes.as_slice()).map_err(|e| grpc_error(e.to_string()))
+}
+
+/// Returns a length-prefixed message: a compressed flag (always uncompressed), the message
+/// length (4 bytes, big-endian), and the message bytes.
+fn encode_frame(message: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + message.len());
+    frame.push(0);
+    frame.extend((message.len() as u32).to_be_bytes());
+    frame.extend(message);
+    frame
+}
+
+/// Splits `data` in a list of length-prefixed messages.
+fn decode_frames(data: &[u8]) -> Result<Vec<&[u8]>, HttpError> {
+    let mut frames = vec![];
+    let mut data = data;
+    while !data.is_empty() {
+        if data.len() < FRAME_HEADER_LEN {
+            return Err(grpc_error("truncated message header".to_string()));
+        }
+        if data[0] != 0 {
+            return Err(grpc_error(
+                "compressed messages are not supported".to_string(),
+            ));
+        }
+        let len = u32::from_be_bytes([data[1], data[2], data[3], data[4]]) as usize;
+        let Some(frame) = data.get(FRAME_HEADER_LEN..FRAME_HEADER_LEN + len) else {
+            return Err(grpc_error("truncated message".to_string()));
+        };
+        frames.push(frame);
+        data = &data[FRAME_HEADER_LEN + len..];
+    }
+    Ok(frames)
+}
+
+fn grpc_error(description: String) -> HttpError {
+    HttpError::Grpc { description }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    /// A `.proto` file written in a temporary directory, deleted when the file is dropped.
+    struct ProtoFile {
+        dir: PathBuf,
+        file: PathBuf,
+    }
+
+    impl Drop for ProtoFile {
+        fn drop(&mut self) {
+            _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    fn proto_file() -> ProtoFile {
+        let dir = std::env::temp_dir().join(format!("hurl-grpc-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("greeter.proto");
+        std::fs::write(
+            &file,
+            r#"
+            syntax = "proto3";
+            package helloworld;
+
+            import "google/protobuf/timestamp.proto";
+
+            service Greeter {
+              rpc SayHello (HelloRequest) returns (HelloReply) {}
+            }
+
+            message HelloRequest {
+              string name = 1;
+              int64 count = 2;
+            }
+
+            message HelloReply {
+              string message = 1;
+              int64 count = 2;
+              bool done = 3;
+              google.protobuf.Timestamp date = 4;
+            }
+            "#,
+        )
+        .unwrap();
+        ProtoFile { dir, file }
+    }
+
+    #[test]
+    fn test_encode_decode_frames() {
+        assert_eq!(encode_frame(b"abc"), vec![0, 0, 0, 0, 3, b'a', b'b', b'c']);
+        assert_eq!(
+            decode_frames(&[0, 0, 0, 0, 1, b'a', 0, 0, 0, 0, 0]).unwrap(),
+            vec![b"a".as_slice(), b"".as_slice()]
+        );
+        assert!(decode_frames(&[0, 0, 0, 0, 2, b'a']).is_err());
+        assert!(decode_frames(&[1, 0, 0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_load_method() {
+        let proto = proto_file();
+        let file = &proto.file;
+        assert!(GrpcMethod::load(file, "/helloworld.Greeter/SayHello").is_ok());
+        assert_eq!(
+            GrpcMethod::load(file, "/helloworld.Greeter/SayGoodbye")
+                .unwrap_err()
+                .message(),
+            "method <SayGoodbye> not found in service <helloworld.Greeter>"
+        );
+        assert!(GrpcMethod::load(file, "/helloworld.Unknown/SayHello").is_err());
+        assert!(GrpcMethod::load(file, "/hello").is_err());
+    }
+
+    #[test]
+    fn test_encode_request() {
+        let proto = proto_file();
+        let method = GrpcMethod::load(&proto.file, "/helloworld.Greeter/SayHello").unwrap();
+        // name = "Bob" (field 1, length-delimited), count = 2 (field 2, varint)
+        assert_eq!(
+            method
+                .encode_request(br#"{"name": "Bob", "count": 2}"#)
+                .unwrap(),
+            vec![0, 0, 0, 0, 7, 0x0a, 3, b'B', b'o', b'b', 0x10, 2]
+        );
+        assert_eq!(method.encode_request(b"").unwrap(), vec![0, 0, 0, 0, 0]);
+        assert!(method.encode_request(br#"{"unknown": 1}"#).is_err());
+    }
+
+    #[test]
+    fn test_decode_response() {
+        let proto = proto_file();
+        let method = GrpcMethod::load(&proto.file, "/helloworld.Greeter/SayHello").unwrap();
+        let body = [0, 0, 0, 0, 7, 0x0a, 3, b'B', b'o', b'b', 0x10, 2];
+        assert_eq!(
+            String::from_utf8(method.decode_response(&body).unwrap()).unwrap(),
+            r#"{"count":2,"done":false,"message":"Bob"}"#
+        );
+        let body = [0, 0, 0, 0, 2, 0x18, 1, 0, 0, 0, 0, 0];
+        assert_eq!(
+            String::from_utf8(method.decode_response(&body).unwrap()).unwrap(),
+            r#"[{"count":0,"done":true,"message":""},{"count":0,"done":false,"message":""}]"#
+        );
+        assert!(method.decode_response(&[]).unwrap().is_empty());
+    }
+}
@@ -24,16 +24,32 @@ pub const ACCEPT_ENCODING: &str = "Accept-Encoding";
 pub const AUTHORIZATION: &str = "Authorization";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Cookie>
 pub const COOKIE: &str = "Cookie";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Digest>
+pub const CONTENT_DIGEST: &str = "Content-Digest";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Encoding>
 pub const CONTENT_ENCODING: &str = "Content-Encoding";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Length>
+pub const CONTENT_LENGTH: &str = "Content-Length";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Type>
 pub const CONTENT_TYPE: &str = "Content-Type";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Expect>
 pub const EXPECT: &str = "Expect";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Host>
+pub const HOST: &str = "Host";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Location>
 pub const LOCATION: &str = "Location";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Proxy-Authorization>
+pub const PROXY_AUTHORIZATION: &str = "Proxy-Authorization";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Sec-WebSocket-Accept>
+pub const SEC_WEBSOCKET_ACCEPT: &str = "Sec-WebSocket-Accept";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Sec-WebSocket-Key>
+pub const SEC_WEBSOCKET_KEY: &str = "Sec-WebSocket-Key";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Set-Cookie>
 pub const SET_COOKIE: &str = "Set-Cookie";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/TE>
+pub const TE: &str = "TE";
+/// See <https://www.w3.org/TR/trace-context/#traceparent-header>
+pub const TRACEPARENT: &str = "traceparent";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/User-Agent>
 pub const USER_AGENT: &str = "User-Agent";
 
@@ -55,6 +55,25 @@ pub fn is_json(content_type: &str) -> bool {
         .any(|p| Regex::new(p).unwrap().is_match(&content_type))
 }
 
+/// Returns true if this `content_type` is an event stream (Server-Sent Events).
+pub fn is_event_stream(content_type: &str) -> bool {
+    let content_type = content_type.trim().to_lowercase();
+    content_type.starts_with("text/event-stream")
+}
+
+/// Returns true if this `content_type` is an image (`image/png`, `image/svg+xml` etc...)
+pub fn is_image(content_type: &str) -> bool {
+    let content_type = content_type.trim().to_lowercase();
+    content_type.starts_with("image/")
+}
+
+/// Returns true if this `content_type` is a gRPC content type (`application/grpc`,
+/// `application/grpc+proto` etc...)
+pub fn is_grpc(content_type: &str) -> bool {
+    let content_type = content_type.trim().to_lowercase();
+    content_type.starts_with("application/grpc")
+}
+
 /// Extracts charset from mime-type String
 pub fn charset(mime_type: &str) -> Option<String> {
     let parts = mime_type.trim().split(';');
@@ -96,6 +115,15 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_is_image() {
+        assert!(is_image("image/png"));
+        assert!(is_image("image/svg+xml"));
+        assert!(is_image(" IMAGE/JPEG"));
+        assert!(!is_image("text/html; charset=utf-8"));
+        assert!(!is_image("application/octet-stream"));
+    }
+
     // Dataset for mimetypes issued from <https://www.iana.org/assignments/media-types/media-types.xhtml#application>
 
     #[test]
@@ -20,49 +20,71 @@
 //! The Hurl HTTP engine is not public. It's a wrapper around libcurl and only the models
 //! returned by an HTTP exchange are exposed.
 pub use self::call::Call;
-pub use self::certificate::Certificate;
-pub(crate) use self::client::Client;
+pub(crate) use self::certificate::pem_secrets;
+pub use self::certificate::{is_pem, Certificate};
+pub(crate) use self::client::{match_cookie, Client};
 pub use self::cookie::{CookieAttribute, ResponseCookie};
+pub use self::cookie_jar::{
+    cookies_from_json, cookies_to_json, is_json_cookie_jar, CookieStorageMode,
+};
 pub use self::core::Cookie;
 pub(crate) use self::core::{Param, RequestCookie};
 pub use self::curl_cmd::CurlCmd;
+pub use self::digest::{verify_digest, DigestAlgorithm};
 pub(crate) use self::error::HttpError;
 pub use self::header::{
-    Header, HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT, USER_AGENT,
+    Header, HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_DIGEST, CONTENT_ENCODING,
+    CONTENT_TYPE, COOKIE, EXPECT, TRACEPARENT, USER_AGENT,
 };
+pub use self::oauth2::{OAuth2, OAuth2Token, OAuth2TokenCache};
 pub(crate) use self::options::{ClientOptions, Verbosity};
-pub use self::request::{IpResolve, Request, RequestedHttpVersion};
+pub(crate) use self::proxy::{proxy_header_secrets, proxy_url_secrets, proxy_user_secrets};
+pub use self::request::{AuthMethod, IpResolve, Request, RequestedHttpVersion, TlsVersion};
 pub(crate) use self::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
 pub use self::response::{HttpVersion, Response};
+pub use self::response_decoding::ContentEncoding;
+pub use self::signature::{HttpSignature, SignatureAlgorithm, SignatureFormat, SignatureVerifier};
+pub use self::sse::ServerSentEvent;
 #[cfg(test)]
 pub use self::tests::*;
-pub use self::timings::Timings;
+pub use self::timings::{Timings, TransferPhase};
+pub use self::traceparent::{random_span_id, random_trace_id, TraceParent};
 pub use self::url::{Url, UrlError};
 pub use self::version::libcurl_version_info;
+pub use self::websocket::WebSocketMessage;
 
 mod call;
 mod certificate;
 mod client;
 mod cookie;
+mod cookie_jar;
 mod core;
 mod curl_cmd;
 mod debug;
+mod digest;
 mod easy_ext;
 mod error;
+mod grpc;
 mod header;
 mod headers_helper;
 mod ip;
-mod mimetype;
+pub(crate) mod mimetype;
+mod oauth2;
 mod options;
+mod proxy;
 mod request;
 mod request_spec;
 mod response;
 mod response_cookie;
 mod response_debug;
 mod response_decoding;
+mod signature;
+mod sse;
 #[cfg(test)]
 mod tests;
 mod timings;
 mod timings_debug;
+mod traceparent;
 mod url;
 mod version;
+mod websocket;
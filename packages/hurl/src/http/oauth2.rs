@@ -0,0 +1,452 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! OAuth2 support, see <https://datatracker.ietf.org/doc/html/rfc6749>.
+//!
+//! Access tokens are obtained with the client credentials grant (or the refresh token grant) and
+//! cached for a whole Hurl run, so that entries (and parallel workers) share the same token until
+//! it expires.
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use url::form_urlencoded;
+
+use crate::http::{
+    Client, ClientOptions, Header, HeaderVec, HttpError, Method, Param, RequestSpec, Url,
+    AUTHORIZATION,
+};
+use crate::util::logger::Logger;
+
+/// A token expiring in less than this margin is considered as expired, so it's not used for a
+/// request that may be received by the server after the expiration.
+const EXPIRATION_MARGIN: Duration = Duration::from_secs(1);
+
+/// OAuth2 parameters to obtain an access token from a token endpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OAuth2 {
+    /// URL of the token endpoint.
+    pub token_url: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    /// Scope of the access request, as a space-delimited list (`,` can also be used as delimiter).
+    pub scope: Option<String>,
+    /// Refresh token used to obtain the first access token (instead of the client credentials).
+    pub refresh_token: Option<String>,
+}
+
+impl FromStr for OAuth2 {
+    type Err = String;
+
+    /// Parses OAuth2 parameters, given as whitespace separated `key=value` pairs, for instance:
+    /// `token_url=https://example.org/token client_id=foo client_secret=bar scope=read,write`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut token_url = None;
+        let mut client_id = None;
+        let mut client_secret = None;
+        let mut scope = None;
+        let mut refresh_token = None;
+        for param in s.split_whitespace() {
+            let Some((key, value)) = param.split_once('=') else {
+                return Err(format!(
+                    "OAuth2 parameter <{param}> must be of the form key=value"
+                ));
+            };
+            let value = Some(value.to_string());
+            match key {
+                "token_url" => token_url = value,
+                "client_id" => client_id = value,
+                "client_secret" => client_secret = value,
+                "scope" => scope = value.map(|v| v.replace(',', " ")),
+                "refresh_token" => refresh_token = value,
+                _ => return Err(format!("unknown OAuth2 parameter <{key}>")),
+            }
+        }
+        let Some(token_url) = token_url else {
+            return Err("missing OAuth2 parameter <token_url>".to_string());
+        };
+        let Some(client_id) = client_id else {
+            return Err("missing OAuth2 parameter <client_id>".to_string());
+        };
+        Ok(OAuth2 {
+            token_url,
+            client_id,
+            client_secret,
+            scope,
+            refresh_token,
+        })
+    }
+}
+
+impl fmt::Display for OAuth2 {
+    /// Formats these parameters, the secrets being not displayed.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "token_url={} client_id={}",
+            self.token_url, self.client_id
+        )?;
+        if let Some(scope) = &self.scope {
+            write!(f, " scope={}", scope.replace(' ', ","))?;
+        }
+        Ok(())
+    }
+}
+
+impl OAuth2 {
+    /// Returns the values of these parameters that must never be logged.
+    pub fn secrets(&self) -> Vec<String> {
+        let mut secrets = vec![];
+        if let Some(client_secret) = &self.client_secret {
+            secrets.push(client_secret.clone());
+            secrets.push(self.basic_credentials());
+        }
+        if let Some(refresh_token) = &self.refresh_token {
+            secrets.push(refresh_token.clone());
+        }
+        secrets
+    }
+
+    /// Returns the Base64 encoded client credentials, used to authenticate to the token endpoint.
+    ///
+    /// Client id and secret are form-urlencoded before being joined, see
+    /// <https://datatracker.ietf.org/doc/html/rfc6749#section-2.3.1>.
+    fn basic_credentials(&self) -> String {
+        let encode = |s: &str| form_urlencoded::byte_serialize(s.as_bytes()).collect::<String>();
+        let id = encode(&self.client_id);
+        let secret = encode(self.client_secret.as_deref().unwrap_or_default());
+        general_purpose::STANDARD.encode(format!("{id}:{secret}"))
+    }
+
+    /// Returns the key of the tokens obtained with these parameters.
+    fn key(&self) -> TokenKey {
+        (
+            self.token_url.clone(),
+            self.client_id.clone(),
+            self.scope.clone(),
+        )
+    }
+}
+
+/// An access token returned by a token endpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OAuth2Token {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    /// Expiration instant, `None` if the token endpoint doesn't specify a lifetime.
+    expires_at: Option<Instant>,
+}
+
+impl OAuth2Token {
+    /// Returns the values of this token that must never be logged.
+    pub fn secrets(&self) -> Vec<String> {
+        let mut secrets = vec![self.access_token.clone()];
+        if let Some(refresh_token) = &self.refresh_token {
+            secrets.push(refresh_token.clone());
+        }
+        secrets
+    }
+
+    fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| Instant::now() + EXPIRATION_MARGIN >= expires_at)
+    }
+}
+
+/// Tokens are cached by token URL, client id and scope.
+type TokenKey = (String, String, Option<String>);
+
+/// A cache of OAuth2 tokens, shared by all the clones of this cache.
+///
+/// This cache is safe to use from multiple threads: while a token is requested, other users of
+/// the cache wait for this token instead of requesting a new one.
+#[derive(Clone, Debug, Default)]
+pub struct OAuth2TokenCache(Arc<Mutex<HashMap<TokenKey, OAuth2Token>>>);
+
+impl OAuth2TokenCache {
+    /// Creates a new empty cache.
+    pub fn new() -> Self {
+        OAuth2TokenCache::default()
+    }
+
+    /// Returns a valid access token for these `oauth2` parameters.
+    ///
+    /// A cached token is returned if it has not expired. Otherwise, a new token is requested with
+    /// `client`, using the refresh token of the expired token if any.
+    pub(crate) fn token(
+        &self,
+        oauth2: &OAuth2,
+        client: &mut Client,
+        options: &ClientOptions,
+        logger: &mut Logger,
+    ) -> Result<OAuth2Token, HttpError> {
+        let mut tokens = self.0.lock().unwrap();
+        let key = oauth2.key();
+        let cached = tokens.get(&key);
+        if let Some(token) = cached.filter(|t| !t.is_expired()) {
+            return Ok(token.clone());
+        }
+
+        let token = match cached.and_then(|t| t.refresh_token.clone()) {
+            // If the refresh token of an expired token is rejected, we fall back to the client
+            // credentials.
+            Some(refresh_token) => {
+                logger.debug(&format!(
+                    "Refreshing OAuth2 token from {}",
+                    oauth2.token_url
+                ));
+                let grant = [
+                    ("grant_type", "refresh_token"),
+                    ("refresh_token", &refresh_token),
+                ];
+                match request_token(oauth2, &grant, client, options, logger) {
+                    Ok(token) => token,
+                    Err(_) => request_first_token(oauth2, client, options, logger)?,
+                }
+            }
+            None => request_first_token(oauth2, client, options, logger)?,
+        };
+        tokens.insert(key, token.clone());
+        Ok(token)
+    }
+}
+
+/// Requests a first access token, with the configured refresh token or with the client credentials.
+fn request_first_token(
+    oauth2: &OAuth2,
+    client: &mut Client,
+    options: &ClientOptions,
+    logger: &mut Logger,
+) -> Result<OAuth2Token, HttpError> {
+    logger.debug(&format!(
+        "Requesting OAuth2 token from {}",
+        oauth2.token_url
+    ));
+    match &oauth2.refresh_token {
+        Some(refresh_token) => {
+            let grant = [
+                ("grant_type", "refresh_token"),
+                ("refresh_token", refresh_token),
+            ];
+            request_token(oauth2, &grant, client, options, logger)
+        }
+        None => {
+            let grant = [("grant_type", "client_credentials")];
+            request_token(oauth2, &grant, client, options, logger)
+        }
+    }
+}
+
+/// Requests an access token to the token endpoint, with the `grant` form parameters.
+///
+/// The exchange with the token endpoint is never logged, as it contains the client credentials
+/// and the tokens.
+fn request_token(
+    oauth2: &OAuth2,
+    grant: &[(&str, &str)],
+    client: &mut Client,
+    options: &ClientOptions,
+    logger: &mut Logger,
+) -> Result<OAuth2Token, HttpError> {
+    let url = Url::from_str(&oauth2.token_url)
+        .map_err(|e| oauth2_error(format!("invalid token URL <{}>: {}", e.url, e.reason)))?;
+    let mut form = grant
+        .iter()
+        .map(|(name, value)| Param::new(name, value))
+        .collect::<Vec<_>>();
+    if let Some(scope) = &oauth2.scope {
+        form.push(Param::new("scope", scope));
+    }
+    let mut headers = HeaderVec::new();
+    headers.push(Header::new("Accept", "application/json"));
+    match &oauth2.client_secret {
+        Some(_) => {
+            let value = format!("Basic {}", oauth2.basic_credentials());
+            headers.push(Header::new(AUTHORIZATION, &value));
+        }
+        // A public client is only identified by its id.
+        None => form.push(Param::new("client_id", &oauth2.client_id)),
+    }
+    let request_spec = RequestSpec {
+        method: Method("POST".to_string()),
+        url,
+        headers,
+        form,
+        implicit_content_type: Some("application/x-www-form-urlencoded".to_string()),
+        ..Default::default()
+    };
+    let options = ClientOptions {
+        aws_sigv4: None,
+        compress_body: None,
+        content_digest: None,
+        follow_location: false,
+        grpc_proto: None,
+        headers: vec![],
+        http_signature: None,
+        sse_duration: None,
+        user: None,
+        verbosity: None,
+        ..options.clone()
+    };
+    let verbosity = logger.verbosity.take();
+    let call = client.execute(&request_spec, &options, logger);
+    logger.verbosity = verbosity;
+    let call = call?;
+
+    let response = call.response;
+    if !(200..300).contains(&response.status) {
+        // Error responses may describe the error with an `error` code.
+        let error = serde_json::from_slice::<serde_json::Value>(&response.body)
+            .ok()
+            .and_then(|json| json.get("error")?.as_str().map(|e| format!(" ({e})")))
+            .unwrap_or_default();
+        return Err(oauth2_error(format!(
+            "token endpoint <{}> returned status {}{error}",
+            oauth2.token_url, response.status
+        )));
+    }
+    parse_token(&response.body)
+}
+
+/// Parses the JSON `body` of a successful token response.
+fn parse_token(body: &[u8]) -> Result<OAuth2Token, HttpError> {
+    let json = serde_json::from_slice::<serde_json::Value>(body)
+        .map_err(|_| oauth2_error("token response is not a valid JSON".to_string()))?;
+    let Some(access_token) = json.get("access_token").and_then(|v| v.as_str()) else {
+        return Err(oauth2_error(
+            "token response has no <access_token>".to_string(),
+        ));
+    };
+    let refresh_token = json
+        .get("refresh_token")
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string());
+    let expires_at = json
+        .get("expires_in")
+        .and_then(|v| v.as_u64())
+        .map(|secs| Instant::now() + Duration::from_secs(secs));
+    Ok(OAuth2Token {
+        access_token: access_token.to_string(),
+        refresh_token,
+        expires_at,
+    })
+}
+
+fn oauth2_error(description: String) -> HttpError {
+    HttpError::OAuth2 { description }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_oauth2() {
+        assert_eq!(
+            OAuth2::from_str(
+                "token_url=http://localhost:8000/token client_id=hurl client_secret=s3cr3t scope=read,write"
+            )
+            .unwrap(),
+            OAuth2 {
+                token_url: "http://localhost:8000/token".to_string(),
+                client_id: "hurl".to_string(),
+                client_secret: Some("s3cr3t".to_string()),
+                scope: Some("read write".to_string()),
+                refresh_token: None,
+            }
+        );
+        assert_eq!(
+            OAuth2::from_str("client_id=hurl").unwrap_err(),
+            "missing OAuth2 parameter <token_url>"
+        );
+        assert_eq!(
+            OAuth2::from_str("token_url=http://localhost:8000/token client_id=hurl foo=bar")
+                .unwrap_err(),
+            "unknown OAuth2 parameter <foo>"
+        );
+        assert_eq!(
+            OAuth2::from_str("token_url client_id=hurl").unwrap_err(),
+            "OAuth2 parameter <token_url> must be of the form key=value"
+        );
+    }
+
+    #[test]
+    fn test_oauth2_secrets() {
+        let oauth2 = OAuth2::from_str(
+            "token_url=http://localhost:8000/token client_id=hurl client_secret=s3cr3t",
+        )
+        .unwrap();
+        assert_eq!(
+            oauth2.secrets(),
+            vec!["s3cr3t".to_string(), "aHVybDpzM2NyM3Q=".to_string()]
+        );
+        assert_eq!(
+            oauth2.to_string(),
+            "token_url=http://localhost:8000/token client_id=hurl"
+        );
+    }
+
+    #[test]
+    fn test_basic_credentials() {
+        let oauth2 = OAuth2::from_str(
+            "token_url=http://localhost:8000/token client_id=my:app client_secret=a+b%c",
+        )
+        .unwrap();
+        // "my%3Aapp:a%2Bb%25c"
+        assert_eq!(oauth2.basic_credentials(), "bXklM0FhcHA6YSUyQmIlMjVj");
+    }
+
+    #[test]
+    fn test_parse_token() {
+        let token = parse_token(
+            br#"{"access_token": "abc", "token_type": "Bearer", "expires_in": 3600, "refresh_token": "def"}"#,
+        )
+        .unwrap();
+        assert_eq!(token.access_token, "abc");
+        assert_eq!(token.refresh_token, Some("def".to_string()));
+        assert!(!token.is_expired());
+
+        let token = parse_token(br#"{"access_token": "abc", "expires_in": 0}"#).unwrap();
+        assert!(token.is_expired());
+        let token = parse_token(br#"{"access_token": "abc"}"#).unwrap();
+        assert!(!token.is_expired());
+
+        assert!(parse_token(br#"{"error": "invalid_client"}"#).is_err());
+        assert!(parse_token(b"<html>").is_err());
+    }
+
+    #[test]
+    fn test_cache_is_shared_by_clones() {
+        let cache = OAuth2TokenCache::new();
+        let other = cache.clone();
+        let token = parse_token(br#"{"access_token": "abc"}"#).unwrap();
+        other.0.lock().unwrap().insert(
+            (
+                "http://localhost:8000/token".to_string(),
+                "hurl".to_string(),
+                None,
+            ),
+            token,
+        );
+        assert_eq!(cache.0.lock().unwrap().len(), 1);
+        assert!(OAuth2TokenCache::new().0.lock().unwrap().is_empty());
+    }
+}
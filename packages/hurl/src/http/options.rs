@@ -20,7 +20,9 @@ use std::time::Duration;
 use hurl_core::typing::{BytesPerSec, Count};
 
 use crate::http::request::RequestedHttpVersion;
-use crate::http::IpResolve;
+use crate::http::{
+    AuthMethod, ContentEncoding, DigestAlgorithm, HttpSignature, IpResolve, TlsVersion,
+};
 
 #[derive(Debug, Clone)]
 pub struct ClientOptions {
@@ -29,17 +31,31 @@ pub struct ClientOptions {
     /// Under the hood, this activates libcurl [`CURLOPT_FRESH_CONNECT`](https://curl.se/libcurl/c/CURLOPT_FRESH_CONNECT.html)
     /// and [`CURLOPT_FORBID_REUSE`](https://curl.se/libcurl/c/CURLOPT_FORBID_REUSE.html).
     pub allow_reuse: bool,
+    /// Authentication method used with the user credentials.
+    pub auth_method: AuthMethod,
     pub aws_sigv4: Option<String>,
     pub cacert_file: Option<String>,
+    /// Requires the server certificate status (OCSP stapling) to be verified.
+    pub cert_status: bool,
     pub client_cert_file: Option<String>,
     pub client_key_file: Option<String>,
+    /// Content encoding used to compress request bodies.
+    pub compress_body: Option<ContentEncoding>,
     pub compressed: bool,
     pub connect_timeout: Duration,
     pub connects_to: Vec<String>,
+    /// Algorithm of the `Content-Digest` header added to requests with a body.
+    pub content_digest: Option<DigestAlgorithm>,
     pub cookie_input_file: Option<String>,
+    /// Certificate Revocation List file (PEM format) used to verify the server certificate.
+    pub crl_file: Option<String>,
     pub follow_location: bool,
     pub follow_location_trusted: bool,
+    /// Path of the `.proto` file (or the binary descriptor set) describing a gRPC request.
+    pub grpc_proto: Option<String>,
     pub headers: Vec<String>,
+    /// Parameters to sign requests with an HTTP message signature.
+    pub http_signature: Option<HttpSignature>,
     pub http_version: RequestedHttpVersion,
     pub insecure: bool,
     pub ip_resolve: IpResolve,
@@ -52,14 +68,41 @@ pub struct ClientOptions {
     pub netrc_optional: bool,
     pub no_proxy: Option<String>,
     pub path_as_is: bool,
+    /// Public key (sha256 hashes or PEM/DER file) the server certificate must match.
+    pub pinned_pubkey: Option<String>,
     pub proxy: Option<String>,
+    /// Authentication method used with the proxy credentials.
+    pub proxy_auth_method: AuthMethod,
+    /// Certificate file used to verify an HTTPS proxy.
+    pub proxy_cacert_file: Option<String>,
+    /// Headers sent only to the proxy.
+    pub proxy_headers: Vec<String>,
+    /// Skips the verification of an HTTPS proxy certificate.
+    pub proxy_insecure: bool,
+    /// Proxy credentials (`user:password`).
+    pub proxy_user: Option<String>,
     pub resolves: Vec<String>,
+    /// Maximum duration to read a `text/event-stream` response, the transfer being stopped
+    /// without error once elapsed.
+    pub sse_duration: Option<Duration>,
+    /// Number of events to read from a `text/event-stream` response.
+    pub sse_events: Count,
     pub ssl_no_revoke: bool,
     pub timeout: Duration,
+    /// Maximum TLS version allowed.
+    pub tls_max_version: Option<TlsVersion>,
+    /// Minimum TLS version allowed.
+    pub tls_min_version: Option<TlsVersion>,
+    /// Adds a W3C `traceparent` header to each request.
+    pub traceparent: bool,
     pub unix_socket: Option<String>,
     pub user: Option<String>,
     pub user_agent: Option<String>,
     pub verbosity: Option<Verbosity>,
+    /// Number of messages to wait for on a WebSocket connection.
+    pub websocket_messages: Count,
+    /// Maximum time allowed for a WebSocket exchange.
+    pub websocket_timeout: Duration,
 }
 
 // FIXME/ we could implement copy here
@@ -73,17 +116,24 @@ impl Default for ClientOptions {
     fn default() -> Self {
         ClientOptions {
             allow_reuse: true,
+            auth_method: AuthMethod::default(),
             aws_sigv4: None,
             cacert_file: None,
+            cert_status: false,
             client_cert_file: None,
             client_key_file: None,
+            compress_body: None,
             compressed: false,
             connect_timeout: Duration::from_secs(300),
             connects_to: vec![],
+            content_digest: None,
             cookie_input_file: None,
+            crl_file: None,
             follow_location: false,
             follow_location_trusted: false,
+            grpc_proto: None,
             headers: vec![],
+            http_signature: None,
             http_version: RequestedHttpVersion::default(),
             insecure: false,
             ip_resolve: IpResolve::default(),
@@ -96,14 +146,27 @@ impl Default for ClientOptions {
             netrc_optional: false,
             no_proxy: None,
             path_as_is: false,
+            pinned_pubkey: None,
             proxy: None,
+            proxy_auth_method: AuthMethod::default(),
+            proxy_cacert_file: None,
+            proxy_headers: vec![],
+            proxy_insecure: false,
+            proxy_user: None,
             resolves: vec![],
+            sse_duration: None,
+            sse_events: Count::Infinite,
             ssl_no_revoke: false,
             timeout: Duration::from_secs(300),
+            tls_max_version: None,
+            tls_min_version: None,
+            traceparent: false,
             unix_socket: None,
             user: None,
             user_agent: None,
             verbosity: None,
+            websocket_messages: Count::Finite(1),
+            websocket_timeout: Duration::from_secs(10),
         }
     }
 }
@@ -16,6 +16,7 @@
  *
  */
 use std::fmt;
+use std::str::FromStr;
 
 use crate::http::header::{HeaderVec, COOKIE};
 use crate::http::url::Url;
@@ -46,6 +47,8 @@ pub enum RequestedHttpVersion {
     Http10,
     Http11,
     Http2,
+    /// HTTP/2 without HTTP/1.1 upgrade for non-TLS requests (as required by gRPC)
+    Http2PriorKnowledge,
     Http3,
 }
 
@@ -56,6 +59,7 @@ impl fmt::Display for RequestedHttpVersion {
             RequestedHttpVersion::Http10 => "HTTP/1.0",
             RequestedHttpVersion::Http11 => "HTTP/1.1",
             RequestedHttpVersion::Http2 => "HTTP/2",
+            RequestedHttpVersion::Http2PriorKnowledge => "HTTP/2",
             RequestedHttpVersion::Http3 => "HTTP/3",
         };
         write!(f, "{value}")
@@ -71,6 +75,57 @@ pub enum IpResolve {
     IpV6,
 }
 
+/// HTTP authentication method used with user credentials (see `--user` option).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum AuthMethod {
+    /// Default, credentials are sent in a Basic `Authorization` header.
+    #[default]
+    Basic,
+    Digest,
+    Ntlm,
+    /// SPNEGO authentication (Kerberos).
+    Negotiate,
+    /// The most secure method supported by the server is picked.
+    Any,
+}
+
+/// TLS protocol version, used to set the minimum and maximum versions allowed for a connection.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TlsVersion {
+    Tls10,
+    Tls11,
+    Tls12,
+    Tls13,
+}
+
+impl FromStr for TlsVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1.0" => Ok(TlsVersion::Tls10),
+            "1.1" => Ok(TlsVersion::Tls11),
+            "1.2" => Ok(TlsVersion::Tls12),
+            "1.3" => Ok(TlsVersion::Tls13),
+            _ => Err(format!(
+                "unsupported TLS version <{s}> (valid values are 1.0, 1.1, 1.2, 1.3)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for TlsVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            TlsVersion::Tls10 => "1.0",
+            TlsVersion::Tls11 => "1.1",
+            TlsVersion::Tls12 => "1.2",
+            TlsVersion::Tls13 => "1.3",
+        };
+        write!(f, "{value}")
+    }
+}
+
 impl Request {
     /// Creates a new request.
     pub fn new(method: &str, url: Url, headers: HeaderVec, body: Vec<u8>) -> Self {
@@ -140,6 +195,17 @@ mod tests {
         Request::new("GET", url, headers, vec![])
     }
 
+    #[test]
+    fn test_tls_version() {
+        assert_eq!(TlsVersion::from_str("1.2").unwrap(), TlsVersion::Tls12);
+        assert_eq!(TlsVersion::Tls13.to_string(), "1.3");
+        assert!(TlsVersion::Tls12 < TlsVersion::Tls13);
+        assert_eq!(
+            TlsVersion::from_str("1.4").unwrap_err(),
+            "unsupported TLS version <1.4> (valid values are 1.0, 1.1, 1.2, 1.3)"
+        );
+    }
+
     #[test]
     fn test_content_type() {
         assert_eq!(
@@ -20,7 +20,7 @@ use std::time::Duration;
 
 use crate::http::certificate::Certificate;
 use crate::http::ip::IpAddr;
-use crate::http::{HeaderVec, Url};
+use crate::http::{HeaderVec, Url, WebSocketMessage};
 
 /// Represents a runtime HTTP response.
 /// This is a real response, that has been executed by our HTTP client.
@@ -35,6 +35,8 @@ pub struct Response {
     /// The end-user certificate, in the response certificate chain
     pub certificate: Option<Certificate>,
     pub ip_addr: IpAddr,
+    /// The data messages received on a WebSocket connection (empty for plain HTTP exchanges)
+    pub messages: Vec<WebSocketMessage>,
 }
 
 impl Response {
@@ -49,6 +51,7 @@ impl Response {
         url: Url,
         certificate: Option<Certificate>,
         ip_addr: IpAddr,
+        messages: Vec<WebSocketMessage>,
     ) -> Self {
         Response {
             version,
@@ -59,6 +62,7 @@ impl Response {
             url,
             certificate,
             ip_addr,
+            messages,
         }
     }
 }
@@ -103,6 +107,7 @@ mod tests {
             url: "http://localhost".parse().unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            messages: vec![],
         };
         assert_eq!(response.headers.values("Content-Length"), vec!["12"]);
         assert!(response.headers.values("Unknown").is_empty());
@@ -21,10 +21,13 @@
 /// using the Content-Encoding response header
 ///
 /// See https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Encoding
+use std::fmt;
 use std::io::prelude::*;
+use std::str::FromStr;
 
 use encoding::DecoderTrap;
 
+use crate::http::sse::{self, ServerSentEvent};
 use crate::http::{mimetype, HttpError, Response};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -37,6 +40,8 @@ pub enum ContentEncoding {
     Deflate,
     /// No encoding.
     Identity,
+    /// A format using the Zstandard algorithm (defined in RFC 8878).
+    Zstd,
 }
 
 impl ContentEncoding {
@@ -47,6 +52,11 @@ impl ContentEncoding {
             "gzip" => Ok(ContentEncoding::Gzip),
             "deflate" => Ok(ContentEncoding::Deflate),
             "identity" => Ok(ContentEncoding::Identity),
+            "zstd" => Ok(ContentEncoding::Zstd),
+            // Dictionary-compressed Brotli and Zstandard, see <https://www.rfc-editor.org/rfc/rfc9842>
+            "dcb" | "dcz" => Err(HttpError::UnsupportedDictionaryContentEncoding {
+                description: s.to_string(),
+            }),
             v => Err(HttpError::UnsupportedContentEncoding {
                 description: v.to_string(),
             }),
@@ -60,8 +70,46 @@ impl ContentEncoding {
             ContentEncoding::Gzip => uncompress_gzip(data),
             ContentEncoding::Deflate => uncompress_zlib(data),
             ContentEncoding::Brotli => uncompress_brotli(data),
+            ContentEncoding::Zstd => uncompress_zstd(data),
         }
     }
+
+    /// Compresses `data` bytes.
+    pub fn encode(&self, data: &[u8]) -> Result<Vec<u8>, HttpError> {
+        let result = match self {
+            ContentEncoding::Identity => Ok(data.to_vec()),
+            ContentEncoding::Gzip => compress_gzip(data),
+            ContentEncoding::Deflate => compress_zlib(data),
+            ContentEncoding::Brotli => compress_brotli(data),
+            ContentEncoding::Zstd => zstd::encode_all(data, 0),
+        };
+        result.map_err(|_| HttpError::CouldNotCompressRequest {
+            description: self.to_string(),
+        })
+    }
+}
+
+impl FromStr for ContentEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ContentEncoding::parse(s).map_err(|_| {
+            format!("unsupported content encoding <{s}> (valid values are gzip, deflate, br, zstd)")
+        })
+    }
+}
+
+impl fmt::Display for ContentEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            ContentEncoding::Brotli => "br",
+            ContentEncoding::Gzip => "gzip",
+            ContentEncoding::Deflate => "deflate",
+            ContentEncoding::Identity => "identity",
+            ContentEncoding::Zstd => "zstd",
+        };
+        write!(f, "{value}")
+    }
 }
 
 impl Response {
@@ -82,6 +130,13 @@ impl Response {
         }
     }
 
+    /// Returns true if response body can be decoded as text.
+    pub fn is_kind_of_text(&self) -> bool {
+        self.headers
+            .content_type()
+            .is_some_and(mimetype::is_kind_of_text)
+    }
+
     /// Returns true if response is an HTML response.
     pub fn is_html(&self) -> bool {
         self.headers.content_type().is_some_and(mimetype::is_html)
@@ -97,6 +152,19 @@ impl Response {
         self.headers.content_type().is_some_and(mimetype::is_xml)
     }
 
+    /// Returns true if response is an event stream (Server-Sent Events) response.
+    pub fn is_event_stream(&self) -> bool {
+        self.headers
+            .content_type()
+            .is_some_and(mimetype::is_event_stream)
+    }
+
+    /// Returns the Server-Sent Events of this response body.
+    pub fn server_sent_events(&self) -> Result<Vec<ServerSentEvent>, HttpError> {
+        let body = self.uncompress_body()?;
+        Ok(sse::parse(&body))
+    }
+
     /// Decompresses HTTP body response.
     pub fn uncompress_body(&self) -> Result<Vec<u8>, HttpError> {
         let encodings = self.headers.content_encoding()?;
@@ -159,6 +227,37 @@ fn uncompress_zlib(data: &[u8]) -> Result<Vec<u8>, HttpError> {
     }
 }
 
+/// Decompresses Zstandard compressed `data`.
+fn uncompress_zstd(data: &[u8]) -> Result<Vec<u8>, HttpError> {
+    zstd::decode_all(data).map_err(|_| HttpError::CouldNotUncompressResponse {
+        description: "zstd".to_string(),
+    })
+}
+
+/// Compresses `data` with Brotli.
+fn compress_brotli(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    {
+        let mut writer = brotli::CompressorWriter::new(&mut buf, 4096, 11, 22);
+        writer.write_all(data)?;
+    }
+    Ok(buf)
+}
+
+/// Compresses `data` with GZip.
+fn compress_gzip(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut encoder = libflate::gzip::Encoder::new(Vec::new())?;
+    encoder.write_all(data)?;
+    encoder.finish().into_result()
+}
+
+/// Compresses `data` with Zlib.
+fn compress_zlib(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut encoder = libflate::zlib::Encoder::new(Vec::new())?;
+    encoder.write_all(data)?;
+    encoder.finish().into_result()
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -174,6 +273,7 @@ pub mod tests {
             url: "http://localhost".parse().unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            messages: vec![],
         }
     }
 
@@ -183,12 +283,22 @@ pub mod tests {
             ContentEncoding::parse("br").unwrap(),
             ContentEncoding::Brotli
         );
+        assert_eq!(
+            ContentEncoding::parse("zstd").unwrap(),
+            ContentEncoding::Zstd
+        );
         assert_eq!(
             ContentEncoding::parse("xx").err().unwrap(),
             HttpError::UnsupportedContentEncoding {
                 description: "xx".to_string()
             }
         );
+        assert_eq!(
+            ContentEncoding::parse("dcz").err().unwrap(),
+            HttpError::UnsupportedDictionaryContentEncoding {
+                description: "dcz".to_string()
+            }
+        );
     }
 
     #[test]
@@ -299,6 +409,47 @@ pub mod tests {
         assert_eq!(uncompress_zlib(&data[..]).unwrap(), b"Hello World!");
     }
 
+    #[test]
+    fn test_uncompress_zstd() {
+        let data = [
+            0x28, 0xb5, 0x2f, 0xfd, 0x00, 0x68, 0x61, 0x00, 0x00, 0x48, 0x65, 0x6c, 0x6c, 0x6f,
+            0x20, 0x57, 0x6f, 0x72, 0x6c, 0x64, 0x21,
+        ];
+        assert_eq!(uncompress_zstd(&data[..]).unwrap(), b"Hello World!");
+    }
+
+    #[test]
+    fn test_encode() {
+        let data = b"Hello World! Hello World! Hello World!";
+        for encoding in [
+            ContentEncoding::Brotli,
+            ContentEncoding::Gzip,
+            ContentEncoding::Deflate,
+            ContentEncoding::Identity,
+            ContentEncoding::Zstd,
+        ] {
+            let encoded = encoding.encode(data).unwrap();
+            assert_eq!(encoding.decode(&encoded).unwrap(), data);
+        }
+        assert_eq!(
+            ContentEncoding::Identity.encode(data).unwrap(),
+            data.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_content_encoding_from_str() {
+        assert_eq!(
+            ContentEncoding::from_str("zstd").unwrap(),
+            ContentEncoding::Zstd
+        );
+        assert_eq!(ContentEncoding::Brotli.to_string(), "br");
+        assert_eq!(
+            ContentEncoding::from_str("xx").unwrap_err(),
+            "unsupported content encoding <xx> (valid values are gzip, deflate, br, zstd)"
+        );
+    }
+
     #[test]
     fn test_uncompress_error() {
         let data = [0x21];
@@ -314,6 +465,12 @@ pub mod tests {
                 description: "gzip".to_string()
             }
         );
+        assert_eq!(
+            uncompress_zstd(&data[..]).err().unwrap(),
+            HttpError::CouldNotUncompressResponse {
+                description: "zstd".to_string()
+            }
+        );
     }
 
     fn hello_response() -> Response {
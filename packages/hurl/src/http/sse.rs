@@ -0,0 +1,254 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Server-Sent Events support, see <https://html.spec.whatwg.org/multipage/server-sent-events.html>.
+//!
+//! The event stream is parsed incrementally so the HTTP client can stop a transfer after a given
+//! number of events (for instance, to test endpoints that never close the stream).
+
+/// UTF-8 byte order mark, ignored at the start of a stream.
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// An event dispatched by a `text/event-stream` response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServerSentEvent {
+    /// The last event ID of the stream (persisted across events, as a browser would do).
+    pub id: Option<String>,
+    /// The event type (`message` if the event has no `event` field).
+    pub event: String,
+    /// The event data, multiple `data` fields being joined with a newline.
+    pub data: String,
+    /// The reconnection time (in milliseconds) if specified by this event.
+    pub retry: Option<u64>,
+}
+
+/// Parses all the events of an event stream `data`.
+///
+/// A last event not terminated by an empty line is discarded.
+pub fn parse(data: &[u8]) -> Vec<ServerSentEvent> {
+    let mut parser = EventStreamParser::new();
+    parser.parse(data, None);
+    parser.events
+}
+
+/// An incremental parser for an event stream.
+#[derive(Default)]
+pub struct EventStreamParser {
+    /// Number of bytes of the BOM matched at the start of the stream.
+    bom_len: usize,
+    /// True once the optional BOM has been processed.
+    started: bool,
+    /// Bytes of the current line, not yet terminated.
+    line: Vec<u8>,
+    /// True if the last processed byte was a CR, so that a following LF doesn't end a new line.
+    last_was_cr: bool,
+    /// The event type of the event being built.
+    event: Option<String>,
+    /// The data buffer of the event being built.
+    data: String,
+    /// The reconnection time of the event being built.
+    retry: Option<u64>,
+    /// The last event ID, persisted across events.
+    last_id: Option<String>,
+    /// Dispatched events.
+    events: Vec<ServerSentEvent>,
+}
+
+impl EventStreamParser {
+    /// Creates a new empty parser.
+    pub fn new() -> Self {
+        EventStreamParser::default()
+    }
+
+    /// Returns the number of events dispatched so far.
+    pub fn count(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Parses a new chunk of `data` and returns the number of bytes consumed.
+    ///
+    /// If `max_events` events have been dispatched, the parsing stops right after the last byte of
+    /// the last event, and the remaining bytes of `data` are not consumed.
+    pub fn parse(&mut self, data: &[u8], max_events: Option<usize>) -> usize {
+        for (i, byte) in data.iter().enumerate() {
+            if max_events.is_some_and(|max| self.events.len() >= max) {
+                return i;
+            }
+            self.parse_byte(*byte);
+        }
+        data.len()
+    }
+
+    /// Parses a `byte`, skipping the BOM at the start of the stream.
+    fn parse_byte(&mut self, byte: u8) {
+        if self.started {
+            self.parse_line_byte(byte);
+            return;
+        }
+        if byte == BOM[self.bom_len] {
+            self.bom_len += 1;
+            self.started = self.bom_len == BOM.len();
+            return;
+        }
+        // These bytes weren't a BOM: we parse them as regular bytes.
+        self.started = true;
+        for b in &BOM[..self.bom_len] {
+            self.parse_line_byte(*b);
+        }
+        self.parse_line_byte(byte);
+    }
+
+    /// Parses a `byte`, lines being terminated by CRLF, LF or CR.
+    fn parse_line_byte(&mut self, byte: u8) {
+        match byte {
+            b'\n' if self.last_was_cr => self.last_was_cr = false,
+            b'\n' | b'\r' => {
+                self.last_was_cr = byte == b'\r';
+                let line = std::mem::take(&mut self.line);
+                self.parse_line(&String::from_utf8_lossy(&line));
+            }
+            _ => {
+                self.last_was_cr = false;
+                self.line.push(byte);
+            }
+        }
+    }
+
+    /// Parses a complete `line`, an empty line dispatching the current event.
+    fn parse_line(&mut self, line: &str) {
+        if line.is_empty() {
+            self.dispatch();
+            return;
+        }
+        // Comment line
+        if line.starts_with(':') {
+            return;
+        }
+        let (name, value) = match line.split_once(':') {
+            Some((name, value)) => (name, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match name {
+            "event" => self.event = Some(value.to_string()),
+            "data" => {
+                self.data.push_str(value);
+                self.data.push('\n');
+            }
+            "id" if !value.contains('\0') => {
+                self.last_id = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                };
+            }
+            "retry" if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) => {
+                self.retry = value.parse().ok();
+            }
+            _ => {}
+        }
+    }
+
+    /// Dispatches the current event. An event without data is not dispatched.
+    fn dispatch(&mut self) {
+        let event = self.event.take();
+        let retry = self.retry.take();
+        let mut data = std::mem::take(&mut self.data);
+        if data.is_empty() {
+            return;
+        }
+        data.pop();
+        self.events.push(ServerSentEvent {
+            id: self.last_id.clone(),
+            event: event.unwrap_or_else(|| "message".to_string()),
+            data,
+            retry,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(id: Option<&str>, event: &str, data: &str, retry: Option<u64>) -> ServerSentEvent {
+        ServerSentEvent {
+            id: id.map(|s| s.to_string()),
+            event: event.to_string(),
+            data: data.to_string(),
+            retry,
+        }
+    }
+
+    #[test]
+    fn test_parse_events() {
+        let data = b"data: hello\n\n\
+                     : this is a comment\n\
+                     id: 1\n\
+                     event: update\n\
+                     data: {\"count\": 1}\n\
+                     retry: 3000\n\n\
+                     data:first line\n\
+                     data: second line\n\n";
+        assert_eq!(
+            parse(data),
+            vec![
+                event(None, "message", "hello", None),
+                event(Some("1"), "update", "{\"count\": 1}", Some(3000)),
+                event(Some("1"), "message", "first line\nsecond line", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_line_endings() {
+        let data = b"\xEF\xBB\xBFdata: a\r\n\r\ndata: b\r\rdata: c\n\n";
+        assert_eq!(
+            parse(data),
+            vec![
+                event(None, "message", "a", None),
+                event(None, "message", "b", None),
+                event(None, "message", "c", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_ignored_events() {
+        // Events without data are not dispatched, unterminated events are discarded.
+        let data = b"event: ping\n\nretry: abc\ndata\n\ndata: unterminated\n";
+        assert_eq!(parse(data), vec![event(None, "message", "", None)]);
+    }
+
+    #[test]
+    fn test_parse_chunks() {
+        let mut parser = EventStreamParser::new();
+        assert_eq!(parser.parse(b"\xEF\xBB", Some(2)), 2);
+        assert_eq!(parser.count(), 0);
+        assert_eq!(
+            parser.parse(b"\xBFdata: a\n\ndata: b\n\ndata: c\n\n", Some(2)),
+            19
+        );
+        assert_eq!(parser.count(), 2);
+        assert_eq!(
+            parser.events,
+            vec![
+                event(None, "message", "a", None),
+                event(None, "message", "b", None),
+            ]
+        );
+    }
+}
@@ -32,6 +32,7 @@ fn default_response() -> Response {
         url: Url::from_str("http://localhost").unwrap(),
         certificate: None,
         ip_addr: Default::default(),
+        messages: vec![],
     }
 }
 
@@ -15,6 +15,7 @@
  * limitations under the License.
  *
  */
+use std::fmt;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
@@ -72,4 +73,83 @@ impl Timings {
             total,
         }
     }
+
+    /// Returns the phase of the transfer that was running when these timings have been taken,
+    /// `tls` being true for a transfer over TLS.
+    ///
+    /// For an interrupted transfer (a timeout for instance), the timings of the phases that have
+    /// not been completed are zero.
+    pub fn phase(&self, tls: bool) -> TransferPhase {
+        if !self.start_transfer.is_zero() {
+            TransferPhase::ResponseTransfer
+        } else if !self.pre_transfer.is_zero() || (!tls && !self.connect.is_zero()) {
+            // Without TLS, there is no handshake between the connection and the request.
+            TransferPhase::ServerProcessing
+        } else if !self.connect.is_zero() {
+            TransferPhase::TlsHandshake
+        } else if !self.name_lookup.is_zero() {
+            TransferPhase::Connect
+        } else {
+            TransferPhase::NameLookup
+        }
+    }
+}
+
+/// A phase of an HTTP transfer, from the name lookup to the response transfer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TransferPhase {
+    NameLookup,
+    Connect,
+    TlsHandshake,
+    ServerProcessing,
+    ResponseTransfer,
+}
+
+impl fmt::Display for TransferPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            TransferPhase::NameLookup => "name lookup",
+            TransferPhase::Connect => "connection",
+            TransferPhase::TlsHandshake => "TLS handshake",
+            TransferPhase::ServerProcessing => "server processing",
+            TransferPhase::ResponseTransfer => "response transfer",
+        };
+        write!(f, "{value}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase() {
+        let mut timings = Timings::default();
+        assert_eq!(timings.phase(true), TransferPhase::NameLookup);
+
+        timings.name_lookup = Duration::from_micros(500);
+        assert_eq!(timings.phase(true), TransferPhase::Connect);
+
+        timings.connect = Duration::from_micros(800);
+        assert_eq!(timings.phase(true), TransferPhase::TlsHandshake);
+
+        timings.app_connect = Duration::from_micros(2000);
+        timings.pre_transfer = Duration::from_micros(2100);
+        assert_eq!(timings.phase(true), TransferPhase::ServerProcessing);
+
+        timings.start_transfer = Duration::from_micros(10000);
+        assert_eq!(timings.phase(true), TransferPhase::ResponseTransfer);
+    }
+
+    #[test]
+    fn test_phase_without_tls() {
+        let mut timings = Timings {
+            name_lookup: Duration::from_micros(500),
+            ..Default::default()
+        };
+        assert_eq!(timings.phase(false), TransferPhase::Connect);
+
+        timings.connect = Duration::from_micros(800);
+        assert_eq!(timings.phase(false), TransferPhase::ServerProcessing);
+    }
 }
@@ -0,0 +1,129 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! W3C trace context `traceparent` header, see <https://www.w3.org/TR/trace-context/#traceparent-header>.
+use std::fmt;
+
+use rand_core::{OsRng, RngCore};
+
+/// The trace context of an HTTP request, propagated to the server with a `traceparent` header.
+///
+/// The trace id identifies the whole trace (a Hurl file run), and the parent id identifies the
+/// span of the HTTP call in this trace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceParent {
+    /// Trace id, 16 bytes encoded as 32 lowercase hex characters.
+    pub trace_id: String,
+    /// Parent span id, 8 bytes encoded as 16 lowercase hex characters.
+    pub parent_id: String,
+}
+
+impl TraceParent {
+    /// Creates a new trace context in the trace `trace_id`, with a random parent id.
+    pub fn new(trace_id: &str) -> TraceParent {
+        TraceParent {
+            trace_id: trace_id.to_string(),
+            parent_id: random_span_id(),
+        }
+    }
+
+    /// Parses a `traceparent` header `value`, returning `None` if this value is not a valid
+    /// version `00` trace context.
+    pub fn parse(value: &str) -> Option<TraceParent> {
+        let parts = value.trim().split('-').collect::<Vec<_>>();
+        let [version, trace_id, parent_id, flags] = parts[..] else {
+            return None;
+        };
+        let is_id = |s: &str, len: usize| {
+            s.len() == len
+                && s.bytes()
+                    .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+                && s.bytes().any(|b| b != b'0')
+        };
+        if version != "00" || !is_id(trace_id, 32) || !is_id(parent_id, 16) || flags.len() != 2 {
+            return None;
+        }
+        Some(TraceParent {
+            trace_id: trace_id.to_string(),
+            parent_id: parent_id.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for TraceParent {
+    /// Formats this trace context as a `traceparent` header value, with the sampled flag set.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "00-{}-{}-01", self.trace_id, self.parent_id)
+    }
+}
+
+/// Returns a random trace id.
+pub fn random_trace_id() -> String {
+    random_hex::<16>()
+}
+
+/// Returns a random span id.
+pub fn random_span_id() -> String {
+    random_hex::<8>()
+}
+
+/// Returns `N` random bytes encoded in lowercase hex.
+fn random_hex<const N: usize>() -> String {
+    let mut bytes = [0u8; N];
+    OsRng.fill_bytes(&mut bytes);
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_traceparent() {
+        let traceparent = TraceParent::new("4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(traceparent.parent_id.len(), 16);
+        let value = traceparent.to_string();
+        assert!(value.starts_with("00-4bf92f3577b34da6a3ce929d0e0e4736-"));
+        assert!(value.ends_with("-01"));
+        assert_eq!(TraceParent::parse(&value), Some(traceparent));
+        assert_eq!(random_trace_id().len(), 32);
+    }
+
+    #[test]
+    fn test_parse_traceparent() {
+        assert_eq!(
+            TraceParent::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00"),
+            Some(TraceParent {
+                trace_id: "4bf92f3577b34da6a3ce929d0e0e4736".to_string(),
+                parent_id: "00f067aa0ba902b7".to_string(),
+            })
+        );
+        assert!(TraceParent::parse("").is_none());
+        assert!(
+            TraceParent::parse("01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").is_none()
+        );
+        assert!(
+            TraceParent::parse("00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01").is_none()
+        );
+        assert!(
+            TraceParent::parse("00-00000000000000000000000000000000-00f067aa0ba902b7-01").is_none()
+        );
+        assert!(
+            TraceParent::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7").is_none()
+        );
+    }
+}
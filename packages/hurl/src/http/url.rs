@@ -83,6 +83,37 @@ impl Url {
         self.inner.path().to_string()
     }
 
+    /// Returns the scheme of this URL (`http`, `https` etc...).
+    pub fn scheme(&self) -> String {
+        self.inner.scheme().to_string()
+    }
+
+    /// Returns the query of this URL, without the leading `?`.
+    pub fn query(&self) -> Option<String> {
+        self.inner.query().map(|s| s.to_string())
+    }
+
+    /// Returns the path and the optional query of this URL, as used in an HTTP request line.
+    pub fn path_and_query(&self) -> String {
+        match self.inner.query() {
+            Some(query) => format!("{}?{query}", self.inner.path()),
+            None => self.inner.path().to_string(),
+        }
+    }
+
+    /// Returns the host and the optional port of this URL, as used in an HTTP `Host` header.
+    pub fn authority(&self) -> String {
+        match self.inner.port() {
+            Some(port) => format!("{}:{port}", self.host()),
+            None => self.host(),
+        }
+    }
+
+    /// Returns `true` if this URL uses a WebSocket scheme (`ws://` or `wss://`).
+    pub fn is_websocket(&self) -> bool {
+        matches!(self.inner.scheme(), "ws" | "wss")
+    }
+
     /// Parse a string `input` as an URL, with this URL as the base URL.
     pub fn join(&self, input: &str) -> Result<Url, UrlError> {
         let new_inner = self.inner.join(input);
@@ -128,10 +159,10 @@ impl FromStr for Url {
                 ));
             }
             Some(scheme) => {
-                if scheme != "http://" && scheme != "https://" {
+                if !["http://", "https://", "ws://", "wss://"].contains(&scheme.as_str()) {
                     return Err(UrlError::new(
                         value,
-                        "Only <http://> and <https://> schemes are supported",
+                        "Only <http://>, <https://>, <ws://> and <wss://> schemes are supported",
                     ));
                 }
             }
@@ -171,7 +202,9 @@ mod tests {
             "http://localhost:8000/cookies",
             "http://localhost",
             "https://localhost:8000",
-            "http://localhost:8000/path-as-is/../resource"
+            "http://localhost:8000/path-as-is/../resource",
+            "ws://localhost:8000/echo",
+            "wss://localhost:8000/echo",
         ];
         for url in urls {
             assert!(Url::from_str(url).is_ok());
@@ -195,6 +228,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_websocket() {
+        let url: Url = "ws://localhost:8000/echo?foo=bar".parse().unwrap();
+        assert!(url.is_websocket());
+        assert_eq!(url.authority(), "localhost:8000");
+        assert_eq!(url.path_and_query(), "/echo?foo=bar");
+
+        let url: Url = "https://localhost/hello".parse().unwrap();
+        assert!(!url.is_websocket());
+        assert_eq!(url.authority(), "localhost");
+        assert_eq!(url.path_and_query(), "/hello");
+    }
+
     #[test]
     fn test_join() {
         let base: Url = "http://example.net/foo/index.html".parse().unwrap();
@@ -233,7 +279,7 @@ mod tests {
             Url::from_str("file://localhost:8000").err().unwrap(),
             UrlError::new(
                 "file://localhost:8000",
-                "Only <http://> and <https://> schemes are supported"
+                "Only <http://>, <https://>, <ws://> and <wss://> schemes are supported"
             )
         );
     }
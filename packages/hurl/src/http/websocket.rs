@@ -0,0 +1,580 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! WebSocket protocol support, see <https://datatracker.ietf.org/doc/html/rfc6455>.
+//!
+//! libcurl is only used to establish the connection (TCP, TLS, proxy tunnel, etc...) in
+//! "connect only" mode. The opening handshake and the data framing are implemented here, on top
+//! of the raw libcurl send/recv functions.
+use std::fmt;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use curl::easy;
+use sha1::{Digest, Sha1};
+
+use crate::http::header::{SEC_WEBSOCKET_ACCEPT, SEC_WEBSOCKET_KEY};
+use crate::http::{HeaderVec, HttpError};
+
+/// GUID used to compute the `Sec-WebSocket-Accept` header value.
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Polling interval when no data is available on the socket.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Maximum size in bytes of a received frame payload, or of a reassembled fragmented message.
+const MAX_PAYLOAD_SIZE: usize = 64 * 1024 * 1024;
+
+/// A WebSocket data message, sent or received on a WebSocket connection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebSocketMessage {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+impl fmt::Display for WebSocketMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WebSocketMessage::Text(text) => write!(f, "{text}"),
+            WebSocketMessage::Binary(bytes) => write!(f, "<{} bytes>", bytes.len()),
+        }
+    }
+}
+
+/// Frame opcodes, see <https://datatracker.ietf.org/doc/html/rfc6455#section-5.2>.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Opcode {
+    Continuation,
+    Text,
+    Binary,
+    Close,
+    Ping,
+    Pong,
+}
+
+impl Opcode {
+    fn from_u8(value: u8) -> Option<Opcode> {
+        match value {
+            0x0 => Some(Opcode::Continuation),
+            0x1 => Some(Opcode::Text),
+            0x2 => Some(Opcode::Binary),
+            0x8 => Some(Opcode::Close),
+            0x9 => Some(Opcode::Ping),
+            0xA => Some(Opcode::Pong),
+            _ => None,
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            Opcode::Continuation => 0x0,
+            Opcode::Text => 0x1,
+            Opcode::Binary => 0x2,
+            Opcode::Close => 0x8,
+            Opcode::Ping => 0x9,
+            Opcode::Pong => 0xA,
+        }
+    }
+}
+
+/// A WebSocket frame.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub fin: bool,
+    pub opcode: Opcode,
+    pub payload: Vec<u8>,
+}
+
+impl Frame {
+    /// Creates a new final frame with this `opcode` and `payload`.
+    pub fn new(opcode: Opcode, payload: &[u8]) -> Self {
+        Frame {
+            fin: true,
+            opcode,
+            payload: payload.to_vec(),
+        }
+    }
+
+    /// Encodes this frame to bytes, masking the payload with `mask`.
+    ///
+    /// As required by the RFC, all frames sent from a client are masked.
+    pub fn encode(&self, mask: [u8; 4]) -> Vec<u8> {
+        let mut bytes = vec![];
+        let fin = if self.fin { 0x80 } else { 0x00 };
+        bytes.push(fin | self.opcode.to_u8());
+
+        let len = self.payload.len();
+        if len < 126 {
+            bytes.push(0x80 | len as u8);
+        } else if len <= u16::MAX as usize {
+            bytes.push(0x80 | 126);
+            bytes.extend((len as u16).to_be_bytes());
+        } else {
+            bytes.push(0x80 | 127);
+            bytes.extend((len as u64).to_be_bytes());
+        }
+        bytes.extend(mask);
+        bytes.extend(
+            self.payload
+                .iter()
+                .enumerate()
+                .map(|(i, b)| b ^ mask[i % 4]),
+        );
+        bytes
+    }
+
+    /// Decodes a frame from the start of `bytes`.
+    ///
+    /// Returns the frame and the number of bytes read, or `None` if `bytes` doesn't contain a
+    /// complete frame yet.
+    pub fn decode(bytes: &[u8]) -> Result<Option<(Frame, usize)>, HttpError> {
+        if bytes.len() < 2 {
+            return Ok(None);
+        }
+        let fin = bytes[0] & 0x80 != 0;
+        let Some(opcode) = Opcode::from_u8(bytes[0] & 0x0F) else {
+            let description = format!("invalid frame opcode <{}>", bytes[0] & 0x0F);
+            return Err(HttpError::WebSocket { description });
+        };
+        let masked = bytes[1] & 0x80 != 0;
+        let mut offset = 2;
+        let len = match bytes[1] & 0x7F {
+            126 => {
+                if bytes.len() < offset + 2 {
+                    return Ok(None);
+                }
+                let len = u16::from_be_bytes([bytes[2], bytes[3]]) as usize;
+                offset += 2;
+                len
+            }
+            127 => {
+                if bytes.len() < offset + 8 {
+                    return Ok(None);
+                }
+                let mut len = [0; 8];
+                len.copy_from_slice(&bytes[2..10]);
+                offset += 8;
+                usize::try_from(u64::from_be_bytes(len)).unwrap_or(usize::MAX)
+            }
+            len => len as usize,
+        };
+        if len > MAX_PAYLOAD_SIZE {
+            return Err(payload_too_large(len));
+        }
+        let mask = if masked {
+            if bytes.len() < offset + 4 {
+                return Ok(None);
+            }
+            let mask = [
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+            ];
+            offset += 4;
+            Some(mask)
+        } else {
+            None
+        };
+        let Some(end) = offset.checked_add(len) else {
+            return Err(payload_too_large(len));
+        };
+        if bytes.len() < end {
+            return Ok(None);
+        }
+        let payload = &bytes[offset..end];
+        let payload = match mask {
+            Some(mask) => payload
+                .iter()
+                .enumerate()
+                .map(|(i, b)| b ^ mask[i % 4])
+                .collect(),
+            None => payload.to_vec(),
+        };
+        let frame = Frame {
+            fin,
+            opcode,
+            payload,
+        };
+        Ok(Some((frame, end)))
+    }
+}
+
+/// Returns a new random `Sec-WebSocket-Key` header value.
+pub fn generate_key() -> String {
+    let nonce = uuid::Uuid::new_v4();
+    general_purpose::STANDARD.encode(nonce.as_bytes())
+}
+
+/// Returns the expected `Sec-WebSocket-Accept` header value for this handshake `key`.
+pub fn accept_key(key: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(key.as_bytes());
+    hasher.update(WEBSOCKET_GUID.as_bytes());
+    general_purpose::STANDARD.encode(hasher.finalize())
+}
+
+/// Checks that the `Sec-WebSocket-Accept` header of the handshake `response_headers` matches the
+/// `Sec-WebSocket-Key` header sent in the handshake `request_headers` (the key can have been set
+/// by the user).
+pub fn check_accept(
+    request_headers: &HeaderVec,
+    response_headers: &HeaderVec,
+) -> Result<(), HttpError> {
+    let key = request_headers
+        .get(SEC_WEBSOCKET_KEY)
+        .map(|h| h.value.as_str());
+    let accept = response_headers
+        .get(SEC_WEBSOCKET_ACCEPT)
+        .map(|h| h.value.as_str());
+    match (key, accept) {
+        (Some(key), Some(accept)) if accept == accept_key(key) => Ok(()),
+        _ => {
+            let description = format!("invalid or missing {SEC_WEBSOCKET_ACCEPT} header");
+            Err(HttpError::WebSocket { description })
+        }
+    }
+}
+
+/// Returns a new random masking key.
+fn generate_mask() -> [u8; 4] {
+    let nonce = uuid::Uuid::new_v4();
+    let bytes = nonce.as_bytes();
+    [bytes[0], bytes[1], bytes[2], bytes[3]]
+}
+
+/// Returns the HTTP URL used to open the connection of this WebSocket `url`.
+///
+/// The opening handshake of a `ws://` (resp. `wss://`) URL is a plain HTTP (resp. HTTPS) request.
+pub fn http_url(url: &str) -> String {
+    if let Some(url) = url.strip_prefix("ws://") {
+        format!("http://{url}")
+    } else if let Some(url) = url.strip_prefix("wss://") {
+        format!("https://{url}")
+    } else {
+        url.to_string()
+    }
+}
+
+/// A WebSocket connection, on top of a libcurl handle in "connect only" mode.
+pub struct WebSocket<'a> {
+    handle: &'a mut easy::Easy,
+    /// Bytes received and not yet consumed.
+    buffer: Vec<u8>,
+    /// Deadline for every blocking operation on this connection.
+    deadline: Instant,
+}
+
+impl<'a> WebSocket<'a> {
+    /// Creates a new WebSocket on an already connected libcurl `handle`, with a `timeout` applied
+    /// to the whole exchange.
+    pub fn new(handle: &'a mut easy::Easy, timeout: Duration) -> Self {
+        WebSocket {
+            handle,
+            buffer: vec![],
+            deadline: Instant::now() + timeout,
+        }
+    }
+
+    /// Sends raw `data` on the connection.
+    pub fn send_bytes(&mut self, data: &[u8]) -> Result<(), HttpError> {
+        let mut sent = 0;
+        while sent < data.len() {
+            match self.handle.send(&data[sent..]) {
+                Ok(n) => sent += n,
+                Err(e) if e.is_again() => self.wait()?,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the HTTP response of the opening handshake, and returns the response head (status
+    /// line and headers).
+    pub fn read_handshake_response(&mut self) -> Result<String, HttpError> {
+        loop {
+            if let Some(index) = self.buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+                let head = self.buffer.drain(..index + 4).collect::<Vec<_>>();
+                return match String::from_utf8(head) {
+                    Ok(head) => Ok(head),
+                    Err(_) => Err(HttpError::CouldNotParseResponse),
+                };
+            }
+            if !self.fill_buffer()? {
+                return Err(HttpError::CouldNotParseResponse);
+            }
+        }
+    }
+
+    /// Returns the next `len` bytes received after the handshake response, or less if the
+    /// connection is closed before.
+    ///
+    /// When the server doesn't switch protocol, these bytes are the HTTP response body.
+    pub fn read_exact(&mut self, len: usize) -> Result<Vec<u8>, HttpError> {
+        while self.buffer.len() < len {
+            if !self.fill_buffer()? {
+                break;
+            }
+        }
+        let len = len.min(self.buffer.len());
+        Ok(self.buffer.drain(..len).collect())
+    }
+
+    /// Sends a data `message` in a single frame.
+    pub fn send_message(&mut self, message: &WebSocketMessage) -> Result<(), HttpError> {
+        let frame = match message {
+            WebSocketMessage::Text(text) => Frame::new(Opcode::Text, text.as_bytes()),
+            WebSocketMessage::Binary(bytes) => Frame::new(Opcode::Binary, bytes),
+        };
+        self.send_frame(&frame)
+    }
+
+    /// Sends a close frame, with a normal closure status code.
+    pub fn send_close(&mut self) -> Result<(), HttpError> {
+        let frame = Frame::new(Opcode::Close, &1000_u16.to_be_bytes());
+        self.send_frame(&frame)
+    }
+
+    fn send_frame(&mut self, frame: &Frame) -> Result<(), HttpError> {
+        let data = frame.encode(generate_mask());
+        self.send_bytes(&data)
+    }
+
+    /// Receives the next data message, answering to ping frames and reassembling fragmented
+    /// messages.
+    ///
+    /// Returns `None` if the connection has been closed by the server.
+    pub fn recv_message(&mut self) -> Result<Option<WebSocketMessage>, HttpError> {
+        let mut fragments: Option<(Opcode, Vec<u8>)> = None;
+        loop {
+            let frame = match Frame::decode(&self.buffer)? {
+                Some((frame, len)) => {
+                    self.buffer.drain(..len);
+                    frame
+                }
+                None => {
+                    if !self.fill_buffer()? {
+                        return Ok(None);
+                    }
+                    continue;
+                }
+            };
+            match frame.opcode {
+                Opcode::Close => return Ok(None),
+                Opcode::Ping => self.send_frame(&Frame::new(Opcode::Pong, &frame.payload))?,
+                Opcode::Pong => {}
+                Opcode::Text | Opcode::Binary => {
+                    if frame.fin {
+                        return message(frame.opcode, frame.payload).map(Some);
+                    }
+                    fragments = Some((frame.opcode, frame.payload));
+                }
+                Opcode::Continuation => {
+                    let Some((opcode, mut payload)) = fragments.take() else {
+                        let description = "unexpected continuation frame".to_string();
+                        return Err(HttpError::WebSocket { description });
+                    };
+                    if payload.len() + frame.payload.len() > MAX_PAYLOAD_SIZE {
+                        return Err(payload_too_large(payload.len() + frame.payload.len()));
+                    }
+                    payload.extend(frame.payload);
+                    if frame.fin {
+                        return message(opcode, payload).map(Some);
+                    }
+                    fragments = Some((opcode, payload));
+                }
+            }
+        }
+    }
+
+    /// Reads available bytes from the connection to the internal buffer.
+    ///
+    /// Returns `false` if the connection has been closed.
+    fn fill_buffer(&mut self) -> Result<bool, HttpError> {
+        let mut data = [0; 16 * 1024];
+        loop {
+            match self.handle.recv(&mut data) {
+                Ok(0) => return Ok(false),
+                Ok(n) => {
+                    self.buffer.extend(&data[..n]);
+                    return Ok(true);
+                }
+                Err(e) if e.is_again() => self.wait()?,
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Waits for the socket to be ready, or returns an error if the deadline is exceeded.
+    fn wait(&self) -> Result<(), HttpError> {
+        if Instant::now() >= self.deadline {
+            return Err(HttpError::WebSocketTimeout);
+        }
+        thread::sleep(POLL_INTERVAL);
+        Ok(())
+    }
+}
+
+/// Returns the error of a received frame or message whose payload of `len` bytes exceeds the
+/// maximum size.
+fn payload_too_large(len: usize) -> HttpError {
+    let description =
+        format!("payload of {len} bytes exceeds the maximum size of {MAX_PAYLOAD_SIZE} bytes");
+    HttpError::WebSocket { description }
+}
+
+/// Creates a data message of this `opcode` from a `payload`.
+fn message(opcode: Opcode, payload: Vec<u8>) -> Result<WebSocketMessage, HttpError> {
+    if opcode == Opcode::Binary {
+        return Ok(WebSocketMessage::Binary(payload));
+    }
+    match String::from_utf8(payload) {
+        Ok(text) => Ok(WebSocketMessage::Text(text)),
+        Err(_) => {
+            let description = "text message is not valid UTF-8".to_string();
+            Err(HttpError::WebSocket { description })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::Header;
+
+    #[test]
+    fn test_accept_key() {
+        // Example from <https://datatracker.ietf.org/doc/html/rfc6455#section-1.3>
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+
+    #[test]
+    fn test_check_accept() {
+        let mut request_headers = HeaderVec::new();
+        request_headers.push(Header::new("Sec-WebSocket-Key", "dGhlIHNhbXBsZSBub25jZQ=="));
+        let mut response_headers = HeaderVec::new();
+        response_headers.push(Header::new(
+            "Sec-WebSocket-Accept",
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=",
+        ));
+        assert!(check_accept(&request_headers, &response_headers).is_ok());
+
+        // The accept value doesn't match the key actually sent.
+        let mut request_headers = HeaderVec::new();
+        request_headers.push(Header::new("Sec-WebSocket-Key", "x3JJHMbDL1EzLkh9GBhXDw=="));
+        assert!(check_accept(&request_headers, &response_headers).is_err());
+
+        assert!(check_accept(&request_headers, &HeaderVec::new()).is_err());
+    }
+
+    #[test]
+    fn test_http_url() {
+        assert_eq!(
+            http_url("ws://localhost:8000/echo"),
+            "http://localhost:8000/echo"
+        );
+        assert_eq!(http_url("wss://example.org/"), "https://example.org/");
+    }
+
+    #[test]
+    fn test_encode_frame() {
+        // Examples from <https://datatracker.ietf.org/doc/html/rfc6455#section-5.7>
+        let frame = Frame::new(Opcode::Text, b"Hello");
+        assert_eq!(
+            frame.encode([0x37, 0xfa, 0x21, 0x3d]),
+            vec![0x81, 0x85, 0x37, 0xfa, 0x21, 0x3d, 0x7f, 0x9f, 0x4d, 0x51, 0x58]
+        );
+
+        let frame = Frame::new(Opcode::Binary, &[0; 256]);
+        let bytes = frame.encode([0, 0, 0, 0]);
+        assert_eq!(bytes[..4], [0x82, 0xFE, 0x01, 0x00]);
+        assert_eq!(bytes.len(), 4 + 4 + 256);
+    }
+
+    #[test]
+    fn test_decode_frame() {
+        let bytes = [0x81, 0x05, 0x48, 0x65, 0x6c, 0x6c, 0x6f];
+        assert_eq!(
+            Frame::decode(&bytes).unwrap(),
+            Some((Frame::new(Opcode::Text, b"Hello"), 7))
+        );
+
+        // Masked frame
+        let bytes = [
+            0x81, 0x85, 0x37, 0xfa, 0x21, 0x3d, 0x7f, 0x9f, 0x4d, 0x51, 0x58,
+        ];
+        assert_eq!(
+            Frame::decode(&bytes).unwrap(),
+            Some((Frame::new(Opcode::Text, b"Hello"), 11))
+        );
+
+        // Fragmented frame
+        let bytes = [0x01, 0x03, 0x48, 0x65, 0x6c];
+        assert_eq!(
+            Frame::decode(&bytes).unwrap(),
+            Some((
+                Frame {
+                    fin: false,
+                    opcode: Opcode::Text,
+                    payload: b"Hel".to_vec()
+                },
+                5
+            ))
+        );
+
+        // Incomplete frames
+        assert_eq!(Frame::decode(&[0x81]).unwrap(), None);
+        assert_eq!(Frame::decode(&[0x81, 0x05, 0x48]).unwrap(), None);
+        assert_eq!(Frame::decode(&[0x82, 0x7E, 0x01]).unwrap(), None);
+
+        // Invalid opcode
+        assert!(Frame::decode(&[0x83, 0x00]).is_err());
+    }
+
+    #[test]
+    fn test_decode_frame_too_large() {
+        // Declared length of 2^64 - 1 bytes
+        let bytes = [
+            0x82, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00,
+        ];
+        assert!(matches!(
+            Frame::decode(&bytes),
+            Err(HttpError::WebSocket { .. })
+        ));
+
+        // Masked frame, with a declared length just above the maximum size
+        let mut bytes = vec![0x82, 0xFF];
+        bytes.extend((MAX_PAYLOAD_SIZE as u64 + 1).to_be_bytes());
+        bytes.extend([0x00; 4]);
+        assert!(matches!(
+            Frame::decode(&bytes),
+            Err(HttpError::WebSocket { .. })
+        ));
+    }
+
+    #[test]
+    fn test_encode_decode_frame() {
+        let frame = Frame::new(Opcode::Binary, &[0xAB; 70_000]);
+        let bytes = frame.encode(generate_mask());
+        assert_eq!(Frame::decode(&bytes).unwrap(), Some((frame, bytes.len())));
+    }
+}
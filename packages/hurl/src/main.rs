@@ -25,11 +25,13 @@ use std::path::Path;
 use std::time::Instant;
 use std::{env, io, process, thread};
 
-use hurl::report::{curl, html, json, junit, tap};
+use hurl::http;
+use hurl::report::{curl, har, html, json, junit, markdown, metrics, otlp, sarif, tap};
 use hurl::runner;
 use hurl::runner::HurlResult;
 use hurl::util::redacted::Redact;
 use hurl_core::input::Input;
+use hurl_core::parser;
 use hurl_core::text;
 
 use crate::cli::options::{CliOptions, CliOptionsError};
@@ -79,6 +81,19 @@ fn main() {
     let current_dir = env::current_dir();
     let current_dir = unwrap_or_exit(current_dir, EXIT_ERROR_UNDEFINED, &base_logger);
     let current_dir = current_dir.as_path();
+
+    // When comparing two JSON reports, no Hurl file is run: we only write the comparison report.
+    if let (Some(dir), [baseline, current]) = (&opts.html_dir, opts.compare_files.as_slice()) {
+        let file = dir.join("compare.html");
+        base_logger.debug(&format!(
+            "Writing HTML comparison report to {}",
+            file.display()
+        ));
+        let ret = create_html_compare_report(baseline, current, dir, opts.compare_threshold);
+        unwrap_or_exit(ret, EXIT_ERROR_UNDEFINED, &base_logger);
+        process::exit(EXIT_OK);
+    }
+
     let start = Instant::now();
 
     let runs = if opts.parallel {
@@ -99,7 +114,16 @@ fn main() {
         Err(CliError::IO(msg)) => exit_with_error(&msg, EXIT_ERROR_PARSING, &base_logger),
         // In case of parsing error, there is no error because the display of parsing error has been
         // done in the execution of the Hurl files, inside the crates (and not in the main).
-        Err(CliError::Parsing) => exit_with_error("", EXIT_ERROR_PARSING, &base_logger),
+        Err(CliError::Parsing) => {
+            // Parsing errors are also reported in the SARIF report, so they can be located in the
+            // Hurl files by code scanning tools.
+            if let Some(file) = &opts.sarif_file {
+                base_logger.debug(&format!("Writing SARIF report to {}", file.display()));
+                let ret = create_sarif_parsing_report(&opts, file);
+                unwrap_or_exit(ret, EXIT_ERROR_UNDEFINED, &base_logger);
+            }
+            exit_with_error("", EXIT_ERROR_PARSING, &base_logger)
+        }
         Err(CliError::Runtime(msg)) => exit_with_error(&msg, EXIT_ERROR_RUNTIME, &base_logger),
     };
 
@@ -142,7 +166,12 @@ fn exit_with_error(message: &str, code: i32, logger: &BaseLogger) -> ! {
 /// Returns `true` if any kind of report should be created, `false` otherwise.
 fn has_report(opts: &CliOptions) -> bool {
     opts.curl_file.is_some()
+        || opts.har_file.is_some()
         || opts.junit_file.is_some()
+        || opts.markdown_file.is_some()
+        || opts.metrics_file.is_some()
+        || opts.otlp_file.is_some()
+        || opts.sarif_file.is_some()
         || opts.tap_file.is_some()
         || opts.html_dir.is_some()
         || opts.json_report_dir.is_some()
@@ -180,18 +209,40 @@ fn export_results(
     if let Some(file) = &opts.curl_file {
         create_curl_export(runs, file, &secrets)?;
     }
+    if let Some(file) = &opts.har_file {
+        logger.debug(&format!("Writing HAR report to {}", file.display()));
+        create_har_report(runs, file, &secrets)?;
+    }
     if let Some(file) = &opts.junit_file {
         logger.debug(&format!("Writing JUnit report to {}", file.display()));
-        create_junit_report(runs, file, &secrets)?;
+        create_junit_report(runs, file, opts.junit_granularity, &secrets)?;
+    }
+    if let Some(file) = &opts.markdown_file {
+        logger.debug(&format!("Writing Markdown report to {}", file.display()));
+        create_markdown_report(runs, file, &secrets)?;
+    }
+    if let Some(file) = &opts.metrics_file {
+        logger.debug(&format!("Writing metrics report to {}", file.display()));
+        create_metrics_report(runs, file, &secrets)?;
+    }
+    if let Some(file) = &opts.otlp_file {
+        logger.debug(&format!(
+            "Writing OpenTelemetry report to {}",
+            file.display()
+        ));
+        create_otlp_report(runs, file, &secrets)?;
+    }
+    if let Some(file) = &opts.sarif_file {
+        logger.debug(&format!("Writing SARIF report to {}", file.display()));
+        create_sarif_report(runs, file, &secrets)?;
     }
     if let Some(file) = &opts.tap_file {
-        // TAP files doesn't need to be redacted, they don't expose any logs apart from files names.
         logger.debug(&format!("Writing TAP report to {}", file.display()));
-        create_tap_report(runs, file)?;
+        create_tap_report(runs, file, &secrets)?;
     }
     if let Some(dir) = &opts.html_dir {
         logger.debug(&format!("Writing HTML report to {}", dir.display()));
-        create_html_report(runs, dir, &secrets)?;
+        create_html_report(runs, dir, opts.html_max_body_size, &secrets)?;
     }
     if let Some(dir) = &opts.json_report_dir {
         logger.debug(&format!("Writing JSON report to {}", dir.display()));
@@ -211,32 +262,142 @@ fn create_curl_export(runs: &[HurlRun], filename: &Path, secrets: &[&str]) -> Re
     Ok(())
 }
 
+/// Creates a HAR archive of all the HTTP calls of this run.
+fn create_har_report(runs: &[HurlRun], filename: &Path, secrets: &[&str]) -> Result<(), CliError> {
+    let testcases = runs
+        .iter()
+        .map(|r| har::Testcase::from(&r.hurl_result, &r.filename))
+        .collect::<Vec<_>>();
+    har::write_report(filename, &testcases, secrets)?;
+    Ok(())
+}
+
+/// Creates a metrics report (OpenMetrics) for this run.
+fn create_metrics_report(
+    runs: &[HurlRun],
+    filename: &Path,
+    secrets: &[&str],
+) -> Result<(), CliError> {
+    let testcases = runs
+        .iter()
+        .map(|r| metrics::Testcase::from(&r.hurl_result, &r.filename))
+        .collect::<Vec<_>>();
+    metrics::write_report(filename, &testcases, secrets)?;
+    Ok(())
+}
+
+/// Creates an OpenTelemetry trace report (OTLP-JSON) for this run.
+fn create_otlp_report(runs: &[HurlRun], filename: &Path, secrets: &[&str]) -> Result<(), CliError> {
+    let testcases = runs
+        .iter()
+        .map(|r| otlp::Testcase::from(&r.hurl_result, &r.content, &r.filename, secrets))
+        .collect::<Vec<_>>();
+    otlp::write_report(filename, &testcases, secrets)?;
+    Ok(())
+}
+
 /// Creates a JUnit report for this run.
 fn create_junit_report(
+    runs: &[HurlRun],
+    filename: &Path,
+    granularity: junit::Granularity,
+    secrets: &[&str],
+) -> Result<(), CliError> {
+    let testcases = match granularity {
+        junit::Granularity::File => runs
+            .iter()
+            .map(|r| junit::Testcase::from(&r.hurl_result, &r.content, &r.filename))
+            .collect::<Vec<_>>(),
+        junit::Granularity::Entry => runs
+            .iter()
+            .flat_map(|r| junit::Testcase::from_entries(&r.hurl_result, &r.content, &r.filename))
+            .collect::<Vec<_>>(),
+    };
+    junit::write_report(filename, &testcases, secrets)?;
+    Ok(())
+}
+
+/// Creates a Markdown report for this run.
+fn create_markdown_report(
     runs: &[HurlRun],
     filename: &Path,
     secrets: &[&str],
 ) -> Result<(), CliError> {
     let testcases = runs
         .iter()
-        .map(|r| junit::Testcase::from(&r.hurl_result, &r.content, &r.filename))
+        .map(|r| markdown::Testcase::from(&r.hurl_result, &r.content, &r.filename))
         .collect::<Vec<_>>();
-    junit::write_report(filename, &testcases, secrets)?;
+    markdown::write_report(filename, &testcases, secrets)?;
+    Ok(())
+}
+
+/// Creates a SARIF report for this run.
+fn create_sarif_report(
+    runs: &[HurlRun],
+    filename: &Path,
+    secrets: &[&str],
+) -> Result<(), CliError> {
+    let testcases = runs
+        .iter()
+        .map(|r| sarif::Testcase::from(&r.hurl_result, &r.content, &r.filename))
+        .collect::<Vec<_>>();
+    sarif::write_report(filename, &testcases, secrets)?;
+    Ok(())
+}
+
+/// Creates a SARIF report with the parsing errors of the input files, when a run has been stopped
+/// by a parsing error.
+fn create_sarif_parsing_report(opts: &CliOptions, filename: &Path) -> Result<(), CliError> {
+    let mut testcases = vec![];
+    for input in &opts.input_files {
+        // Unreadable files have already been reported, we only report parsing errors.
+        let Ok(content) = input.read_to_string() else {
+            continue;
+        };
+        if let Err(error) = parser::parse_hurl_file(&content) {
+            testcases.push(sarif::Testcase::from_parsing_error(&error, &content, input));
+        }
+    }
+    let secrets = opts
+        .secrets
+        .values()
+        .map(|s| s.as_str())
+        .collect::<Vec<_>>();
+    sarif::write_report(filename, &testcases, &secrets)?;
     Ok(())
 }
 
 /// Creates a TAP report for this run.
-fn create_tap_report(runs: &[HurlRun], filename: &Path) -> Result<(), CliError> {
+fn create_tap_report(runs: &[HurlRun], filename: &Path, secrets: &[&str]) -> Result<(), CliError> {
     let testcases = runs
         .iter()
-        .map(|r| tap::Testcase::from(&r.hurl_result, &r.filename))
+        .map(|r| tap::Testcase::from(&r.hurl_result, &r.content, &r.filename, secrets))
         .collect::<Vec<_>>();
     tap::write_report(filename, &testcases)?;
     Ok(())
 }
 
-/// Creates an HTML report for this run.
-fn create_html_report(runs: &[HurlRun], dir_path: &Path, secrets: &[&str]) -> Result<(), CliError> {
+/// Creates an HTML report in `dir_path` comparing the `current` JSON report with the `baseline`
+/// JSON report.
+fn create_html_compare_report(
+    baseline: &Path,
+    current: &Path,
+    dir_path: &Path,
+    threshold: u64,
+) -> Result<(), CliError> {
+    let baseline = json::deserialize::parse_json_report(baseline)?;
+    let current = json::deserialize::parse_json_report(current)?;
+    html::write_compare_report(dir_path, &baseline, &current, threshold)?;
+    Ok(())
+}
+
+/// Creates an HTML report for this run, HTTP bodies being truncated to `max_body_size` bytes.
+fn create_html_report(
+    runs: &[HurlRun],
+    dir_path: &Path,
+    max_body_size: Option<u64>,
+    secrets: &[&str],
+) -> Result<(), CliError> {
     // We ensure that the containing folder exists.
     let store_path = dir_path.join("store");
     std::fs::create_dir_all(&store_path)?;
@@ -245,7 +406,13 @@ fn create_html_report(runs: &[HurlRun], dir_path: &Path, secrets: &[&str]) -> Re
     for run in runs.iter() {
         let result = &run.hurl_result;
         let testcase = html::Testcase::from(result, &run.filename);
-        testcase.write_html(&run.content, &result.entries, &store_path, secrets)?;
+        testcase.write_html(
+            &run.content,
+            &result.entries,
+            &store_path,
+            max_body_size,
+            secrets,
+        )?;
         testcases.push(testcase);
     }
     html::write_report(dir_path, &testcases)?;
@@ -314,23 +481,33 @@ fn create_cookies_file(
         }
         Ok(file) => file,
     };
-    let mut s = r#"# Netscape HTTP Cookie File
-# This file was generated by Hurl
-
-"#
-    .to_string();
-
     if runs.is_empty() {
         return Err(CliError::IO("Issue fetching results".to_string()));
     }
-    for run in runs.iter() {
-        s.push_str(&format!("# Cookies for file <{}>", run.filename));
-        s.push('\n');
-        for cookie in run.hurl_result.cookies.iter() {
-            s.push_str(&cookie.redact(secrets));
+
+    // A JSON cookie jar is a flat list of the cookies of all the runs.
+    let s = if http::is_json_cookie_jar(&filename.to_string_lossy()) {
+        let cookies = runs
+            .iter()
+            .flat_map(|r| r.hurl_result.cookies.clone())
+            .collect::<Vec<_>>();
+        http::cookies_to_json(&cookies, secrets)
+    } else {
+        let mut s = r#"# Netscape HTTP Cookie File
+# This file was generated by Hurl
+
+"#
+        .to_string();
+        for run in runs.iter() {
+            s.push_str(&format!("# Cookies for file <{}>", run.filename));
             s.push('\n');
+            for cookie in run.hurl_result.cookies.iter() {
+                s.push_str(&cookie.redact(secrets));
+                s.push('\n');
+            }
         }
-    }
+        s
+    };
 
     if let Err(why) = file.write_all(s.as_bytes()) {
         return Err(CliError::IO(format!(
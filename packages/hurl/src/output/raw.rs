@@ -138,6 +138,7 @@ mod tests {
             url: Url::from_str("http://localhost").unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            messages: vec![],
         }
     }
 
@@ -200,6 +201,7 @@ mod tests {
                             url: Url::from_str("https://baz.com").unwrap(),
                             certificate: None,
                             ip_addr: Default::default(),
+                            messages: vec![],
                         },
                         timings: Default::default(),
                     }],
@@ -326,6 +326,7 @@ mod tests {
     use hurl_core::input::Input;
     use hurl_core::text::Format;
 
+    use crate::http::OAuth2TokenCache;
     use crate::parallel::job::Job;
     use crate::parallel::progress::{build_progress, progress_bar};
     use crate::parallel::runner::WorkerState;
@@ -337,12 +338,13 @@ mod tests {
         let (tx_out, _) = mpsc::channel();
         let (_, rx_in) = mpsc::channel();
         let rx_in = Arc::new(Mutex::new(rx_in));
+        let oauth2_tokens = OAuth2TokenCache::new();
 
-        let w0 = Worker::new(WorkerId::from(0), &tx_out, &rx_in);
-        let w1 = Worker::new(WorkerId::from(1), &tx_out, &rx_in);
-        let w2 = Worker::new(WorkerId::from(2), &tx_out, &rx_in);
-        let w3 = Worker::new(WorkerId::from(3), &tx_out, &rx_in);
-        let w4 = Worker::new(WorkerId::from(4), &tx_out, &rx_in);
+        let w0 = Worker::new(WorkerId::from(0), &tx_out, &rx_in, &oauth2_tokens);
+        let w1 = Worker::new(WorkerId::from(1), &tx_out, &rx_in, &oauth2_tokens);
+        let w2 = Worker::new(WorkerId::from(2), &tx_out, &rx_in, &oauth2_tokens);
+        let w3 = Worker::new(WorkerId::from(3), &tx_out, &rx_in, &oauth2_tokens);
+        let w4 = Worker::new(WorkerId::from(4), &tx_out, &rx_in, &oauth2_tokens);
 
         (w0, w1, w2, w3, w4)
     }
@@ -21,6 +21,7 @@ use std::sync::{mpsc, Arc, Mutex};
 use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::typing::Count;
 
+use crate::http::OAuth2TokenCache;
 use crate::output;
 use crate::parallel::error::JobError;
 use crate::parallel::job::{Job, JobQueue, JobResult};
@@ -105,6 +106,9 @@ impl ParallelRunner {
     /// new lines at width `max_width`.
     ///
     /// `color` determines if color if used in standard error.
+    ///
+    /// OAuth2 access tokens are cached in `oauth2_tokens`, shared by all the workers.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         workers_count: usize,
         output_type: OutputType,
@@ -113,6 +117,7 @@ impl ParallelRunner {
         progress_bar: bool,
         color: bool,
         max_width: Option<usize>,
+        oauth2_tokens: &OAuth2TokenCache,
     ) -> Self {
         // Worker are running on theirs own thread, while parallel runner is running in the main
         // thread.
@@ -125,7 +130,7 @@ impl ParallelRunner {
         // Create the workers:
         let workers = (0..workers_count)
             .map(|i| {
-                let worker = Worker::new(WorkerId::from(i), &tx_in, &rx_out);
+                let worker = Worker::new(WorkerId::from(i), &tx_in, &rx_out, oauth2_tokens);
                 let state = WorkerState::Idle;
                 (worker, state)
             })
@@ -21,6 +21,7 @@ use std::{fmt, thread};
 
 use hurl_core::parser;
 
+use crate::http::OAuth2TokenCache;
 use crate::parallel::job::{Job, JobResult};
 use crate::parallel::message::{
     CompletedMsg, IOErrorMsg, ParsingErrorMsg, RunningMsg, WorkerMessage,
@@ -66,13 +67,16 @@ impl Worker {
     /// The worker spawns a new thread and process [`Job`] sent by the parallel runner through `rx`
     /// (the receiving part of the `runner -> worker` channel). Worker send message back to the
     /// runner to update the job progression thorough `tx` (the sending part of the `worker -> runner`.
+    /// OAuth2 access tokens are cached in `oauth2_tokens`, shared with the other workers.
     pub fn new(
         worker_id: WorkerId,
         tx: &Sender<WorkerMessage>,
         rx: &Arc<Mutex<Receiver<Job>>>,
+        oauth2_tokens: &OAuth2TokenCache,
     ) -> Self {
         let rx = Arc::clone(rx);
         let tx = tx.clone();
+        let oauth2_tokens = oauth2_tokens.clone();
 
         let thread = thread::spawn(move || loop {
             let Ok(job) = rx.lock().unwrap().recv() else {
@@ -119,6 +123,7 @@ impl Worker {
                 &content,
                 Some(&job.filename),
                 &job.runner_options,
+                &oauth2_tokens,
                 &job.variables,
                 &mut stdout,
                 Some(&progress),
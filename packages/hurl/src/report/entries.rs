@@ -0,0 +1,71 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Entries of a Hurl file run, as reported by the test reports (JUnit, TAP).
+
+use hurl_core::parser;
+
+use crate::runner::{EntryResult, HurlResult};
+
+/// An entry of a Hurl file, with its run results.
+pub(crate) struct ReportEntry<'a> {
+    /// 1-based index of the entry in the Hurl file.
+    pub(crate) index: usize,
+    /// Name of the entry: the method and URL of the request.
+    pub(crate) name: String,
+    /// Results of the entry runs (an entry can be run several times with retry or repeat), empty
+    /// if the entry has not been run.
+    pub(crate) results: Vec<&'a EntryResult>,
+}
+
+/// Returns the entries of the Hurl file `content`, with their results in `hurl_result`.
+///
+/// An entry that has been run is named after the method and URL actually called, an entry that
+/// has not been run is named after its method and URL in `content`. Returns `None` if `content`
+/// can't be parsed.
+pub(crate) fn report_entries<'a>(
+    hurl_result: &'a HurlResult,
+    content: &str,
+) -> Option<Vec<ReportEntry<'a>>> {
+    // The file has already been run, so it should have been successfully parsed. It's only
+    // used to list the entries that have not been run.
+    let hurl_file = parser::parse_hurl_file(content).ok()?;
+    let entries = hurl_file
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let index = index + 1;
+            let results = hurl_result
+                .entries
+                .iter()
+                .filter(|e| e.entry_index == index)
+                .collect::<Vec<_>>();
+            let call = results.iter().rev().find_map(|e| e.calls.first());
+            let name = match call {
+                Some(call) => format!("{} {}", call.request.method, call.request.url),
+                None => format!("{} {}", entry.request.method, entry.request.url),
+            };
+            ReportEntry {
+                index,
+                name,
+                results,
+            }
+        })
+        .collect();
+    Some(entries)
+}
@@ -0,0 +1,29 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! HAR 1.2 export of all the HTTP calls of a run (HTTP Archive format), that can be opened in
+//! browser devtools and other HAR viewers.
+//!
+//! Each Hurl file is a HAR page, and each HTTP call (including redirections and retries) is a HAR
+//! entry of this page.
+//!
+//! See <http://www.softwareishard.com/blog/har-12-spec/>.
+mod report;
+mod testcase;
+
+pub use self::report::write_report;
+pub use self::testcase::Testcase;
@@ -0,0 +1,577 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use chrono::SecondsFormat;
+use serde::Serialize;
+
+use super::Testcase;
+use crate::http::{Call, Header, Request, Response, Timings};
+use crate::report::ReportError;
+use crate::util::path::create_dir_all;
+use crate::util::redacted::{redact_bytes, Redact};
+
+/// Creates a HAR 1.2 archive from a list of `testcases`.
+///
+/// `secrets` strings are redacted from the URLs, headers, cookies and bodies (binary bodies are
+/// redacted byte-wise before being encoded in base64). An existing HAR file is overwritten.
+pub fn write_report(
+    filename: &Path,
+    testcases: &[Testcase],
+    secrets: &[&str],
+) -> Result<(), ReportError> {
+    if let Err(err) = create_dir_all(filename) {
+        return Err(ReportError::from_error(
+            err,
+            filename,
+            "Issue writing HAR report",
+        ));
+    }
+    let mut file = match File::create(filename) {
+        Ok(f) => f,
+        Err(e) => {
+            return Err(ReportError::from_error(
+                e,
+                filename,
+                "Issue writing HAR report",
+            ))
+        }
+    };
+    let har = Har::new(testcases, secrets);
+    let s = serde_json::to_string_pretty(&har)?;
+    let s = format!("{s}\n");
+    match file.write_all(s.as_bytes()) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(ReportError::from_error(
+            e,
+            filename,
+            "Issue writing HAR report",
+        )),
+    }
+}
+
+/// These structures represent the HAR 1.2 format, see <http://www.softwareishard.com/blog/har-12-spec/>.
+#[derive(Serialize)]
+struct Har {
+    log: Log,
+}
+
+#[derive(Serialize)]
+struct Log {
+    version: &'static str,
+    creator: Creator,
+    pages: Vec<Page>,
+    entries: Vec<Entry>,
+}
+
+#[derive(Serialize)]
+struct Creator {
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Page {
+    started_date_time: String,
+    id: String,
+    title: String,
+    page_timings: PageTimings,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PageTimings {
+    on_content_load: f64,
+    on_load: f64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Entry {
+    pageref: String,
+    started_date_time: String,
+    time: f64,
+    request: HarRequest,
+    response: HarResponse,
+    /// Cache information is not available, this is always an empty object.
+    cache: serde_json::Map<String, serde_json::Value>,
+    timings: HarTimings,
+    #[serde(rename = "serverIPAddress", skip_serializing_if = "String::is_empty")]
+    server_ip_address: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarRequest {
+    method: String,
+    url: String,
+    http_version: String,
+    cookies: Vec<HarCookie>,
+    headers: Vec<NameValue>,
+    query_string: Vec<NameValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post_data: Option<PostData>,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarResponse {
+    status: u32,
+    status_text: String,
+    http_version: String,
+    cookies: Vec<HarCookie>,
+    headers: Vec<NameValue>,
+    content: Content,
+    #[serde(rename = "redirectURL")]
+    redirect_url: String,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HarCookie {
+    name: String,
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    http_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secure: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct NameValue {
+    name: String,
+    value: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PostData {
+    mime_type: String,
+    text: String,
+    /// Custom field (custom fields start with an underscore in HAR), set to "base64" when the
+    /// request body is binary.
+    #[serde(rename = "_encoding", skip_serializing_if = "Option::is_none")]
+    encoding: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Content {
+    size: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compression: Option<i64>,
+    mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding: Option<&'static str>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct HarTimings {
+    blocked: f64,
+    dns: f64,
+    connect: f64,
+    send: f64,
+    wait: f64,
+    receive: f64,
+    ssl: f64,
+}
+
+impl Har {
+    /// Creates a HAR archive with a page for each testcase.
+    fn new(testcases: &[Testcase], secrets: &[&str]) -> Har {
+        let mut pages = vec![];
+        let mut entries = vec![];
+        for (index, testcase) in testcases.iter().enumerate() {
+            let Some(first_call) = testcase.calls.first() else {
+                continue;
+            };
+            let id = format!("page_{}", index + 1);
+            pages.push(Page {
+                started_date_time: date_time(&first_call.timings),
+                id: id.clone(),
+                title: testcase.filename.clone(),
+                page_timings: PageTimings {
+                    on_content_load: -1.0,
+                    on_load: -1.0,
+                },
+            });
+            entries.extend(testcase.calls.iter().map(|c| Entry::new(c, &id, secrets)));
+        }
+        Har {
+            log: Log {
+                version: "1.2",
+                creator: Creator {
+                    name: "hurl",
+                    version: env!("CARGO_PKG_VERSION"),
+                },
+                pages,
+                entries,
+            },
+        }
+    }
+}
+
+impl Entry {
+    fn new(call: &Call, pageref: &str, secrets: &[&str]) -> Entry {
+        let http_version = call.response.version.to_string();
+        Entry {
+            pageref: pageref.to_string(),
+            started_date_time: date_time(&call.timings),
+            time: millis(call.timings.total),
+            request: HarRequest::new(&call.request, &http_version, secrets),
+            response: HarResponse::new(&call.response, secrets),
+            cache: serde_json::Map::new(),
+            timings: HarTimings::new(&call.timings),
+            server_ip_address: call.response.ip_addr.to_string(),
+        }
+    }
+}
+
+impl HarRequest {
+    fn new(request: &Request, http_version: &str, secrets: &[&str]) -> HarRequest {
+        let cookies = request
+            .cookies()
+            .iter()
+            .map(|c| HarCookie {
+                name: c.name.clone(),
+                value: c.value.redact(secrets),
+                path: None,
+                domain: None,
+                expires: None,
+                http_only: None,
+                secure: None,
+            })
+            .collect();
+        let query_string = request
+            .url
+            .query_params()
+            .iter()
+            .map(|p| NameValue {
+                name: p.name.clone(),
+                value: p.value.redact(secrets),
+            })
+            .collect();
+        let post_data = if request.body.is_empty() {
+            None
+        } else {
+            let mime_type = request.headers.content_type().unwrap_or_default();
+            let (text, encoding) = match std::str::from_utf8(&request.body) {
+                Ok(text) => (text.redact(secrets), None),
+                Err(_) => (base64(&request.body, secrets), Some("base64")),
+            };
+            Some(PostData {
+                mime_type: mime_type.to_string(),
+                text,
+                encoding,
+            })
+        };
+        HarRequest {
+            method: request.method.clone(),
+            url: request.url.to_string().redact(secrets),
+            http_version: http_version.to_string(),
+            cookies,
+            headers: headers(request.headers.iter(), secrets),
+            query_string,
+            post_data,
+            headers_size: -1,
+            body_size: request.body.len() as i64,
+        }
+    }
+}
+
+impl HarResponse {
+    fn new(response: &Response, secrets: &[&str]) -> HarResponse {
+        let cookies = response
+            .cookies()
+            .iter()
+            .map(|c| HarCookie {
+                name: c.name.clone(),
+                value: c.value.redact(secrets),
+                path: c.path(),
+                domain: c.domain(),
+                expires: c.expires(),
+                http_only: c.has_httponly().then_some(true),
+                secure: c.has_secure().then_some(true),
+            })
+            .collect();
+        let redirect_url = response
+            .headers
+            .get("Location")
+            .map(|h| h.value.redact(secrets))
+            .unwrap_or_default();
+        HarResponse {
+            status: response.status,
+            status_text: String::new(),
+            http_version: response.version.to_string(),
+            cookies,
+            headers: headers(response.headers.iter(), secrets),
+            content: Content::new(response, secrets),
+            redirect_url,
+            headers_size: -1,
+            body_size: response.body.len() as i64,
+        }
+    }
+}
+
+impl Content {
+    /// Creates the content of a response: the body is decoded (uncompressed) and saved as text
+    /// if it's a kind of text, or encoded in base64 otherwise.
+    fn new(response: &Response, secrets: &[&str]) -> Content {
+        let mime_type = response.headers.content_type().unwrap_or_default();
+        let body = response
+            .uncompress_body()
+            .unwrap_or_else(|_| response.body.clone());
+        let size = body.len() as i64;
+        let compression = size - response.body.len() as i64;
+        let compression = if compression > 0 {
+            Some(compression)
+        } else {
+            None
+        };
+        let (text, encoding) = if body.is_empty() {
+            (None, None)
+        } else if response.is_kind_of_text() {
+            match response.text() {
+                Ok(text) => (Some(text.redact(secrets)), None),
+                Err(_) => (Some(base64(&body, secrets)), Some("base64")),
+            }
+        } else {
+            (Some(base64(&body, secrets)), Some("base64"))
+        };
+        Content {
+            size,
+            compression,
+            mime_type: mime_type.to_string(),
+            text,
+            encoding,
+        }
+    }
+}
+
+impl HarTimings {
+    /// Maps libcurl cumulative timings to HAR timings.
+    ///
+    /// libcurl timings are durations from the start of the transfer, whereas HAR timings are the
+    /// duration of each phase (note that the HAR `connect` phase includes the `ssl` phase).
+    /// See <https://curl.se/libcurl/c/curl_easy_getinfo.html#TIMES>.
+    fn new(timings: &Timings) -> HarTimings {
+        let connected = timings.app_connect.max(timings.connect);
+        let ssl = if timings.app_connect.is_zero() {
+            -1.0
+        } else {
+            millis(timings.app_connect.saturating_sub(timings.connect))
+        };
+        HarTimings {
+            blocked: -1.0,
+            dns: millis(timings.name_lookup),
+            connect: millis(connected.saturating_sub(timings.name_lookup)),
+            send: millis(timings.pre_transfer.saturating_sub(connected)),
+            wait: millis(timings.start_transfer.saturating_sub(timings.pre_transfer)),
+            receive: millis(timings.total.saturating_sub(timings.start_transfer)),
+            ssl,
+        }
+    }
+}
+
+/// Returns the HAR representation of a list of headers.
+fn headers<'a>(headers: impl Iterator<Item = &'a Header>, secrets: &[&str]) -> Vec<NameValue> {
+    headers
+        .map(|h| NameValue {
+            name: h.name.clone(),
+            value: h.value.redact(secrets),
+        })
+        .collect()
+}
+
+/// Returns the base64 encoding of a binary `body`, with `secrets` redacted.
+fn base64(body: &[u8], secrets: &[&str]) -> String {
+    general_purpose::STANDARD.encode(redact_bytes(body, secrets))
+}
+
+/// Returns the start date of a call, in ISO 8601 format.
+fn date_time(timings: &Timings) -> String {
+    timings
+        .begin_call
+        .to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Returns a duration in milliseconds, as HAR uses milliseconds for all timings.
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::http::{HeaderVec, HttpVersion, Url};
+
+    #[test]
+    fn test_har_timings() {
+        let timings = Timings {
+            name_lookup: Duration::from_millis(2),
+            connect: Duration::from_millis(5),
+            app_connect: Duration::from_millis(15),
+            pre_transfer: Duration::from_millis(16),
+            start_transfer: Duration::from_millis(40),
+            total: Duration::from_millis(42),
+            ..Default::default()
+        };
+        assert_eq!(
+            HarTimings::new(&timings),
+            HarTimings {
+                blocked: -1.0,
+                dns: 2.0,
+                connect: 13.0,
+                send: 1.0,
+                wait: 24.0,
+                receive: 2.0,
+                ssl: 10.0,
+            }
+        );
+
+        // Reused connection without TLS.
+        let timings = Timings {
+            pre_transfer: Duration::from_millis(1),
+            start_transfer: Duration::from_millis(3),
+            total: Duration::from_millis(4),
+            ..Default::default()
+        };
+        assert_eq!(
+            HarTimings::new(&timings),
+            HarTimings {
+                blocked: -1.0,
+                dns: 0.0,
+                connect: 0.0,
+                send: 1.0,
+                wait: 2.0,
+                receive: 1.0,
+                ssl: -1.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_create_har() {
+        let mut request_headers = HeaderVec::new();
+        request_headers.push(Header::new("Authorization", "Bearer s3cr3t"));
+        request_headers.push(Header::new("Content-Type", "application/octet-stream"));
+        let mut response_headers = HeaderVec::new();
+        response_headers.push(Header::new("Content-Type", "text/plain"));
+        response_headers.push(Header::new("Set-Cookie", "session=s3cr3t; HttpOnly"));
+        let call = Call {
+            request: Request {
+                url: Url::from_str("http://localhost:8000/hello?token=s3cr3t").unwrap(),
+                method: "POST".to_string(),
+                headers: request_headers,
+                body: b"\xff\x00s3cr3t".to_vec(),
+            },
+            response: Response {
+                version: HttpVersion::Http11,
+                status: 200,
+                headers: response_headers,
+                body: b"Hello s3cr3t!".to_vec(),
+                duration: Duration::from_millis(12),
+                url: Url::from_str("http://localhost:8000/hello?token=s3cr3t").unwrap(),
+                certificate: None,
+                ip_addr: Default::default(),
+                messages: vec![],
+            },
+            timings: Timings {
+                total: Duration::from_millis(12),
+                ..Default::default()
+            },
+        };
+        let testcases = vec![Testcase {
+            filename: "tests/hello.hurl".to_string(),
+            calls: vec![call],
+        }];
+        let har = Har::new(&testcases, &["s3cr3t"]);
+        let value = serde_json::to_value(&har).unwrap();
+        let log = &value["log"];
+        assert_eq!(log["version"], "1.2");
+        assert_eq!(log["pages"][0]["id"], "page_1");
+        assert_eq!(log["pages"][0]["title"], "tests/hello.hurl");
+
+        let entry = &log["entries"][0];
+        assert_eq!(entry["pageref"], "page_1");
+        assert_eq!(entry["time"], 12.0);
+        assert!(entry.get("serverIPAddress").is_none());
+        assert_eq!(
+            entry["request"],
+            serde_json::json!({
+                "method": "POST",
+                "url": "http://localhost:8000/hello?token=***",
+                "httpVersion": "HTTP/1.1",
+                "cookies": [],
+                "headers": [
+                    { "name": "Authorization", "value": "Bearer ***" },
+                    { "name": "Content-Type", "value": "application/octet-stream" }
+                ],
+                "queryString": [ { "name": "token", "value": "***" } ],
+                "postData": {
+                    "mimeType": "application/octet-stream",
+                    "text": "/wAqKio=",
+                    "_encoding": "base64"
+                },
+                "headersSize": -1,
+                "bodySize": 8
+            })
+        );
+        assert_eq!(
+            entry["response"],
+            serde_json::json!({
+                "status": 200,
+                "statusText": "",
+                "httpVersion": "HTTP/1.1",
+                "cookies": [ { "name": "session", "value": "***", "httpOnly": true } ],
+                "headers": [
+                    { "name": "Content-Type", "value": "text/plain" },
+                    { "name": "Set-Cookie", "value": "session=***; HttpOnly" }
+                ],
+                "content": {
+                    "size": 13,
+                    "mimeType": "text/plain",
+                    "text": "Hello ***!"
+                },
+                "redirectURL": "",
+                "headersSize": -1,
+                "bodySize": 13
+            })
+        );
+    }
+}
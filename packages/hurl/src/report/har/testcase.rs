@@ -0,0 +1,44 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::input::Input;
+
+use crate::http::Call;
+use crate::runner::HurlResult;
+
+/// A Hurl file run, with all its HTTP calls, as exported in the HAR report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Testcase {
+    pub(crate) filename: String,
+    /// All the HTTP calls of the run, including redirections and retries.
+    pub(crate) calls: Vec<Call>,
+}
+
+impl Testcase {
+    /// Creates a HAR testcase from an [`HurlResult`].
+    pub fn from(hurl_result: &HurlResult, filename: &Input) -> Testcase {
+        let calls = hurl_result
+            .entries
+            .iter()
+            .flat_map(|e| e.calls.iter().cloned())
+            .collect();
+        Testcase {
+            filename: filename.to_string(),
+            calls,
+        }
+    }
+}
@@ -0,0 +1,559 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! HTML view of HTTP bodies: JSON and XML bodies are pretty-printed, images are previewed and
+//! lines targeted by failing asserts are highlighted.
+use base64::engine::general_purpose;
+use base64::Engine;
+
+use crate::html;
+use crate::http::mimetype;
+use crate::util::redacted::Redact;
+
+/// A segment of a JSON path (`$.books[0].title` has three segments).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// A failing assert to highlight in a body, at the JSON value targeted by `path`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Highlight {
+    pub path: Vec<PathSegment>,
+    pub message: String,
+}
+
+/// A line of a pretty-printed JSON body, with the path of the JSON value starting on this line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct JsonLine {
+    text: String,
+    path: Option<Vec<PathSegment>>,
+}
+
+/// Returns an HTML view of a body.
+///
+/// `bytes` are the raw (uncompressed) bytes of the body, and `text` the decoded body if the body
+/// is a kind of text. The body is truncated to `max_size` bytes if specified. `id` is used
+/// as the HTML identifier of the body for the copy button.
+pub fn get_body_html(
+    id: &str,
+    bytes: &[u8],
+    text: Option<&str>,
+    content_type: Option<&str>,
+    highlights: &[Highlight],
+    max_size: Option<u64>,
+    secrets: &[&str],
+) -> String {
+    if bytes.is_empty() {
+        return "<p class=\"body-info\">No body</p>".to_string();
+    }
+    let size = bytes.len();
+    let truncated = max_size.is_some_and(|max| size as u64 > max);
+    let content_type = content_type.unwrap_or_default();
+
+    if mimetype::is_image(content_type) {
+        if truncated {
+            return format!("<p class=\"body-info\">Image not stored ({size} bytes)</p>");
+        }
+        let data = general_purpose::STANDARD.encode(bytes);
+        let content_type = html::html_escape(content_type);
+        return format!("<img class=\"preview\" src=\"data:{content_type};base64,{data}\" alt=\"Image body ({size} bytes)\">");
+    }
+
+    let Some(text) = text else {
+        return format!("<p class=\"body-info\">Binary body ({size} bytes)</p>");
+    };
+
+    let mut html = String::new();
+    if truncated {
+        // Secrets are redacted before truncation so that a secret cut by the truncation is not
+        // partially displayed.
+        let max_size = max_size.unwrap_or_default() as usize;
+        let text = text.redact(secrets);
+        let mut end = max_size.min(text.len());
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        let text = html::html_escape(&text[..end]);
+        html.push_str(&format!(
+            "<p class=\"body-info\">Body truncated to {max_size} bytes ({size} bytes)</p>"
+        ));
+        html.push_str(&body_pre(id, &text));
+        return html;
+    }
+
+    if mimetype::is_json(content_type) {
+        if let Some(lines) = pretty_json(text) {
+            let lines = lines
+                .iter()
+                .map(|line| {
+                    let text = html::html_escape(&line.text.redact(secrets));
+                    let highlight = highlights
+                        .iter()
+                        .find(|h| line.path.as_ref() == Some(&h.path));
+                    match highlight {
+                        Some(h) => {
+                            let message = html::html_escape(&h.message.redact(secrets));
+                            format!(
+                                "<span class=\"line line-error\" title=\"{message}\">{text}</span>"
+                            )
+                        }
+                        None => format!("<span class=\"line\">{text}</span>"),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            html.push_str(&body_pre(id, &lines));
+            return html;
+        }
+    }
+    let text = if mimetype::is_xml(content_type) {
+        pretty_xml(text).unwrap_or_else(|| text.to_string())
+    } else {
+        text.to_string()
+    };
+    let text = html::html_escape(&text.redact(secrets));
+    html.push_str(&body_pre(id, &text));
+    html
+}
+
+/// Returns a `<pre>` HTML block with a copy button.
+fn body_pre(id: &str, code: &str) -> String {
+    format!(
+        "<div class=\"copyable\"><button class=\"copy\" data-target=\"{id}\">Copy</button><pre class=\"body\"><code id=\"{id}\">{code}</code></pre></div>"
+    )
+}
+
+/// Parses a simple JSONPath expression (like `$.books[0].title` or `$['books'][0]`), returning
+/// `None` for expressions that can target more than one value (wildcards, filters, recursive
+/// descent etc...)
+pub fn parse_simple_jsonpath(expr: &str) -> Option<Vec<PathSegment>> {
+    let mut chars = expr.trim().strip_prefix('$')?.chars().peekable();
+    let mut segments = vec![];
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => {
+                let mut key = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c == '.' || c == '[' {
+                        break;
+                    }
+                    key.push(c);
+                    chars.next();
+                }
+                if key.is_empty() || key == "*" {
+                    return None;
+                }
+                segments.push(PathSegment::Key(key));
+            }
+            '[' => {
+                let mut inner = String::new();
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                    inner.push(c);
+                }
+                let inner = inner.trim();
+                if let Ok(index) = inner.parse::<usize>() {
+                    segments.push(PathSegment::Index(index));
+                } else if inner.len() >= 2
+                    && ((inner.starts_with('\'') && inner.ends_with('\''))
+                        || (inner.starts_with('"') && inner.ends_with('"')))
+                {
+                    segments.push(PathSegment::Key(inner[1..inner.len() - 1].to_string()));
+                } else {
+                    return None;
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(segments)
+}
+
+/// A JSON container (object or array) being pretty-printed.
+struct Container {
+    is_object: bool,
+    key: String,
+    index: usize,
+}
+
+/// Pretty-prints a JSON `text`, keeping the original order of the object keys.
+///
+/// Each line is returned with the path of the JSON value starting on this line, if any. Returns
+/// `None` if `text` is not valid JSON.
+fn pretty_json(text: &str) -> Option<Vec<JsonLine>> {
+    if serde_json::from_str::<serde_json::Value>(text).is_err() {
+        return None;
+    }
+    let indent = |depth: usize| "  ".repeat(depth);
+    let path = |stack: &[Container]| {
+        stack
+            .iter()
+            .map(|c| {
+                if c.is_object {
+                    PathSegment::Key(c.key.clone())
+                } else {
+                    PathSegment::Index(c.index)
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut lines = vec![];
+    let mut line = JsonLine {
+        text: String::new(),
+        path: None,
+    };
+    let mut stack: Vec<Container> = vec![];
+    let mut expect_key = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '"' => {
+                let mut s = String::from('"');
+                let mut escaped = false;
+                for c in chars.by_ref() {
+                    s.push(c);
+                    if escaped {
+                        escaped = false;
+                    } else if c == '\\' {
+                        escaped = true;
+                    } else if c == '"' {
+                        break;
+                    }
+                }
+                if expect_key {
+                    if let Some(container) = stack.last_mut() {
+                        container.key = serde_json::from_str(&s).unwrap_or_default();
+                    }
+                } else if line.path.is_none() {
+                    line.path = Some(path(&stack));
+                }
+                line.text.push_str(&s);
+            }
+            ':' => {
+                expect_key = false;
+                line.text.push_str(": ");
+            }
+            ',' => {
+                line.text.push(',');
+                lines.push(line);
+                line = JsonLine {
+                    text: indent(stack.len()),
+                    path: None,
+                };
+                if let Some(container) = stack.last_mut() {
+                    container.index += 1;
+                    expect_key = container.is_object;
+                }
+            }
+            '{' | '[' => {
+                if line.path.is_none() {
+                    line.path = Some(path(&stack));
+                }
+                line.text.push(c);
+                let close = if c == '{' { '}' } else { ']' };
+                while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                    chars.next();
+                }
+                if chars.peek() == Some(&close) {
+                    chars.next();
+                    line.text.push(close);
+                    continue;
+                }
+                stack.push(Container {
+                    is_object: c == '{',
+                    key: String::new(),
+                    index: 0,
+                });
+                expect_key = c == '{';
+                lines.push(line);
+                line = JsonLine {
+                    text: indent(stack.len()),
+                    path: None,
+                };
+            }
+            '}' | ']' => {
+                stack.pop();
+                lines.push(line);
+                line = JsonLine {
+                    text: format!("{}{c}", indent(stack.len())),
+                    path: None,
+                };
+            }
+            _ => {
+                // Numbers, booleans and null.
+                if line.path.is_none() {
+                    line.path = Some(path(&stack));
+                }
+                line.text.push(c);
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == ',' || c == '}' || c == ']' {
+                        break;
+                    }
+                    line.text.push(c);
+                    chars.next();
+                }
+            }
+        }
+    }
+    lines.push(line);
+    Some(lines)
+}
+
+/// A token of an XML document.
+enum XmlToken<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    /// Self-closing tags, comments, processing instructions, doctypes and CDATA sections.
+    Single(&'a str),
+    Text(&'a str),
+}
+
+/// Pretty-prints an XML `text`, returning `None` if the document is not well-formed.
+fn pretty_xml(text: &str) -> Option<String> {
+    let tokens = xml_tokens(text)?;
+    let mut lines = vec![];
+    let mut depth = 0_usize;
+    let mut i = 0;
+    while i < tokens.len() {
+        let indent = "  ".repeat(depth);
+        match tokens[i] {
+            XmlToken::Open(tag) => {
+                // Elements with only text are kept on a single line.
+                match (tokens.get(i + 1), tokens.get(i + 2)) {
+                    (Some(XmlToken::Close(close)), _) => {
+                        lines.push(format!("{indent}{tag}{close}"));
+                        i += 2;
+                        continue;
+                    }
+                    (Some(XmlToken::Text(text)), Some(XmlToken::Close(close))) => {
+                        lines.push(format!("{indent}{tag}{}{close}", text.trim()));
+                        i += 3;
+                        continue;
+                    }
+                    _ => {
+                        lines.push(format!("{indent}{tag}"));
+                        depth += 1;
+                    }
+                }
+            }
+            XmlToken::Close(tag) => {
+                depth = depth.checked_sub(1)?;
+                lines.push(format!("{}{tag}", "  ".repeat(depth)));
+            }
+            XmlToken::Single(tag) => lines.push(format!("{indent}{tag}")),
+            XmlToken::Text(text) => {
+                let text = text.trim();
+                if !text.is_empty() {
+                    lines.push(format!("{indent}{text}"));
+                }
+            }
+        }
+        i += 1;
+    }
+    if depth != 0 {
+        return None;
+    }
+    Some(lines.join("\n"))
+}
+
+/// Splits an XML `text` into tokens, returning `None` if a tag is not closed.
+fn xml_tokens(text: &str) -> Option<Vec<XmlToken<'_>>> {
+    let mut tokens = vec![];
+    let mut rest = text;
+    while !rest.is_empty() {
+        if !rest.starts_with('<') {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(XmlToken::Text(&rest[..end]));
+            rest = &rest[end..];
+            continue;
+        }
+        let end = if rest.starts_with("<!--") {
+            rest.find("-->")? + 3
+        } else if rest.starts_with("<![CDATA[") {
+            rest.find("]]>")? + 3
+        } else {
+            // Attribute values can contain `>`.
+            let mut quote = None;
+            let mut end = None;
+            for (index, c) in rest.char_indices() {
+                match (quote, c) {
+                    (None, '"' | '\'') => quote = Some(c),
+                    (Some(q), c) if c == q => quote = None,
+                    (None, '>') => {
+                        end = Some(index + 1);
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+            end?
+        };
+        let tag = &rest[..end];
+        let token = if tag.starts_with("</") {
+            XmlToken::Close(tag)
+        } else if tag.starts_with("<!") || tag.starts_with("<?") || tag.ends_with("/>") {
+            XmlToken::Single(tag)
+        } else {
+            XmlToken::Open(tag)
+        };
+        tokens.push(token);
+        rest = &rest[end..];
+    }
+    Some(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_simple_jsonpath() {
+        assert_eq!(
+            parse_simple_jsonpath("$.books[0].title"),
+            Some(vec![
+                PathSegment::Key("books".to_string()),
+                PathSegment::Index(0),
+                PathSegment::Key("title".to_string()),
+            ])
+        );
+        assert_eq!(
+            parse_simple_jsonpath("$['first name']"),
+            Some(vec![PathSegment::Key("first name".to_string())])
+        );
+        assert_eq!(parse_simple_jsonpath("$"), Some(vec![]));
+        assert_eq!(parse_simple_jsonpath("$.books[*].title"), None);
+        assert_eq!(parse_simple_jsonpath("$..title"), None);
+        assert_eq!(parse_simple_jsonpath("$.books[?(@.id==1)]"), None);
+    }
+
+    #[test]
+    fn test_pretty_json() {
+        let text = r#"{"name":"Bob","tags":["a", {"id":1}],"empty":{},"ok":true}"#;
+        let lines = pretty_json(text).unwrap();
+        let texts = lines.iter().map(|l| l.text.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            texts.join("\n"),
+            r#"{
+  "name": "Bob",
+  "tags": [
+    "a",
+    {
+      "id": 1
+    }
+  ],
+  "empty": {},
+  "ok": true
+}"#
+        );
+        let key = |s: &str| PathSegment::Key(s.to_string());
+        assert_eq!(lines[0].path, Some(vec![]));
+        assert_eq!(lines[1].path, Some(vec![key("name")]));
+        assert_eq!(lines[2].path, Some(vec![key("tags")]));
+        assert_eq!(
+            lines[3].path,
+            Some(vec![key("tags"), PathSegment::Index(0)])
+        );
+        assert_eq!(
+            lines[4].path,
+            Some(vec![key("tags"), PathSegment::Index(1)])
+        );
+        assert_eq!(
+            lines[5].path,
+            Some(vec![key("tags"), PathSegment::Index(1), key("id")])
+        );
+        assert_eq!(lines[6].path, None);
+        assert_eq!(lines[8].path, Some(vec![key("empty")]));
+        assert_eq!(lines[9].path, Some(vec![key("ok")]));
+
+        assert!(pretty_json("{\"name\":").is_none());
+    }
+
+    #[test]
+    fn test_pretty_xml() {
+        let text = r#"<?xml version="1.0"?><books><book id="1" note="a>b"><title>Dune</title><tags/></book><!-- end --></books>"#;
+        assert_eq!(
+            pretty_xml(text).unwrap(),
+            r#"<?xml version="1.0"?>
+<books>
+  <book id="1" note="a>b">
+    <title>Dune</title>
+    <tags/>
+  </book>
+  <!-- end -->
+</books>"#
+        );
+        assert!(pretty_xml("<books><book></books>").is_none());
+        assert!(pretty_xml("<books").is_none());
+    }
+
+    #[test]
+    fn test_get_body_html() {
+        let highlights = vec![Highlight {
+            path: vec![PathSegment::Key("age".to_string())],
+            message: "actual: integer <27>".to_string(),
+        }];
+        let text = r#"{"name":"s3cr3t","age":27}"#;
+        assert_eq!(
+            get_body_html(
+                "b1",
+                text.as_bytes(),
+                Some(text),
+                Some("application/json"),
+                &highlights,
+                None,
+                &["s3cr3t"],
+            ),
+            "<div class=\"copyable\"><button class=\"copy\" data-target=\"b1\">Copy</button><pre class=\"body\"><code id=\"b1\">\
+            <span class=\"line\">{</span>\n\
+            <span class=\"line\">  &quot;name&quot;: &quot;***&quot;,</span>\n\
+            <span class=\"line line-error\" title=\"actual: integer &lt;27&gt;\">  &quot;age&quot;: 27</span>\n\
+            <span class=\"line\">}</span></code></pre></div>"
+        );
+
+        assert_eq!(
+            get_body_html("b2", b"Hello World!", Some("Hello World!"), Some("text/plain"), &[], Some(5), &[]),
+            "<p class=\"body-info\">Body truncated to 5 bytes (12 bytes)</p><div class=\"copyable\"><button class=\"copy\" data-target=\"b2\">Copy</button><pre class=\"body\"><code id=\"b2\">Hello</code></pre></div>"
+        );
+        assert_eq!(
+            get_body_html("b2", b"Hello s3cr3t!", Some("Hello s3cr3t!"), Some("text/plain"), &[], Some(8), &["s3cr3t"]),
+            "<p class=\"body-info\">Body truncated to 8 bytes (13 bytes)</p><div class=\"copyable\"><button class=\"copy\" data-target=\"b2\">Copy</button><pre class=\"body\"><code id=\"b2\">Hello **</code></pre></div>"
+        );
+        assert_eq!(
+            get_body_html("b3", &[0x89, 0x50], None, Some("image/png"), &[], None, &[]),
+            "<img class=\"preview\" src=\"data:image/png;base64,iVA=\" alt=\"Image body (2 bytes)\">"
+        );
+        assert_eq!(
+            get_body_html(
+                "b4",
+                &[0xff],
+                None,
+                Some("application/octet-stream"),
+                &[],
+                None,
+                &[]
+            ),
+            "<p class=\"body-info\">Binary body (1 bytes)</p>"
+        );
+    }
+}
@@ -0,0 +1,606 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::io::Write;
+use std::path::Path;
+
+use chrono::Local;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::html;
+use crate::report::ReportError;
+
+/// A Hurl file result, as read from a JSON report.
+///
+/// Only the fields needed for the comparison are deserialized.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+struct FileJson {
+    filename: String,
+    entries: Vec<EntryJson>,
+    success: bool,
+    time: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+struct EntryJson {
+    index: usize,
+    line: usize,
+    asserts: Vec<AssertJson>,
+    time: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+struct AssertJson {
+    success: bool,
+    message: Option<String>,
+}
+
+impl FileJson {
+    /// Returns the entry of this file at `index`. If the entry has been retried, its last run
+    /// is returned.
+    fn entry(&self, index: usize) -> Option<&EntryJson> {
+        self.entries.iter().rfind(|e| e.index == index)
+    }
+
+    /// Returns `true` if the `entry` of this file succeeds.
+    ///
+    /// The JSON report only has the asserts of an entry, not its runtime errors (HTTP connection,
+    /// failed capture etc...). As a runtime error stops the run of a file, a failed file without
+    /// failing asserts has failed on the last entry run.
+    fn entry_success(&self, entry: &EntryJson) -> bool {
+        if !entry.asserts_success() {
+            return false;
+        }
+        if self.success {
+            return true;
+        }
+        let has_failed_asserts = self
+            .entries
+            .iter()
+            .any(|e| self.entry(e.index) == Some(e) && !e.asserts_success());
+        has_failed_asserts || self.entries.last() != Some(entry)
+    }
+}
+
+impl EntryJson {
+    fn asserts_success(&self) -> bool {
+        self.asserts.iter().all(|a| a.success)
+    }
+}
+
+/// Status of a file or an entry, between the baseline run and the current run.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Status {
+    /// Succeeded in the baseline run, fails in the current run.
+    NewlyFailing,
+    /// Failed in the baseline run, succeeds in the current run.
+    Fixed,
+    /// Fails in both runs.
+    StillFailing,
+    /// Succeeds in both runs.
+    Success,
+    /// Only present in the current run, and succeeds.
+    Added,
+    /// Only present in the baseline run.
+    Removed,
+}
+
+impl Status {
+    /// Returns the status from the success of the `baseline` and `current` runs (`None` if absent
+    /// of a run). A failure that is absent of the baseline run is reported as newly failing.
+    fn new(baseline: Option<bool>, current: Option<bool>) -> Self {
+        match (baseline, current) {
+            (Some(true) | None, Some(false)) => Status::NewlyFailing,
+            (Some(false), Some(true)) => Status::Fixed,
+            (Some(false), Some(false)) => Status::StillFailing,
+            (Some(true), Some(true)) => Status::Success,
+            (None, Some(true)) => Status::Added,
+            (Some(_), None) | (None, None) => Status::Removed,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Status::NewlyFailing => "newly failing",
+            Status::Fixed => "fixed",
+            Status::StillFailing => "still failing",
+            Status::Success => "success",
+            Status::Added => "added",
+            Status::Removed => "removed",
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            Status::NewlyFailing => "newly-failing",
+            Status::Fixed => "fixed",
+            Status::StillFailing => "still-failing",
+            Status::Success => "success",
+            Status::Added => "added",
+            Status::Removed => "removed",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct FileDiff {
+    filename: String,
+    status: Status,
+    baseline_time: Option<u64>,
+    current_time: Option<u64>,
+    entries: Vec<EntryDiff>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct EntryDiff {
+    /// 1-based index of the entry in the file.
+    index: usize,
+    line: usize,
+    status: Status,
+    baseline_time: Option<u64>,
+    current_time: Option<u64>,
+    /// Duration of the entry has increased beyond the threshold.
+    regression: bool,
+    /// Failing asserts messages of the current run.
+    messages: Vec<String>,
+}
+
+/// Creates an HTML comparison report at `dir_path`/compare.html, between the `baseline` JSON report
+/// and the `current` JSON report.
+///
+/// Entries whose duration increases by more than `threshold` percent are reported as regressions.
+pub fn write_compare_report(
+    dir_path: &Path,
+    baseline: &[Value],
+    current: &[Value],
+    threshold: u64,
+) -> Result<(), ReportError> {
+    let baseline = parse_files(baseline)?;
+    let current = parse_files(current)?;
+    let files = compare_files(&baseline, &current, threshold);
+    let now = Local::now();
+    let s = create_html_compare(&now.to_rfc2822(), &files, threshold);
+
+    let file_path = dir_path.join("compare.html");
+    let mut file = match std::fs::File::create(&file_path) {
+        Err(err) => {
+            return Err(ReportError::from_error(
+                err,
+                &file_path,
+                "Issue writing HTML comparison report",
+            ))
+        }
+        Ok(file) => file,
+    };
+    if let Err(err) = file.write_all(s.as_bytes()) {
+        return Err(ReportError::from_error(
+            err,
+            &file_path,
+            "Issue writing HTML comparison report",
+        ));
+    }
+    Ok(())
+}
+
+fn parse_files(values: &[Value]) -> Result<Vec<FileJson>, ReportError> {
+    let files = values
+        .iter()
+        .map(|v| serde_json::from_value::<FileJson>(v.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(files)
+}
+
+/// Compares the `baseline` files with the `current` files.
+///
+/// Files are listed in the current run order, followed by the files removed since the baseline.
+/// If a file has been run multiple times in a report, its last run is used.
+fn compare_files(baseline: &[FileJson], current: &[FileJson], threshold: u64) -> Vec<FileDiff> {
+    let mut filenames: Vec<&str> = vec![];
+    for file in current.iter().chain(baseline.iter()) {
+        if !filenames.contains(&file.filename.as_str()) {
+            filenames.push(&file.filename);
+        }
+    }
+    filenames
+        .iter()
+        .map(|filename| {
+            let old = baseline.iter().rfind(|f| f.filename == *filename);
+            let new = current.iter().rfind(|f| f.filename == *filename);
+            compare_file(filename, old, new, threshold)
+        })
+        .collect()
+}
+
+fn compare_file(
+    filename: &str,
+    baseline: Option<&FileJson>,
+    current: Option<&FileJson>,
+    threshold: u64,
+) -> FileDiff {
+    let status = Status::new(baseline.map(|f| f.success), current.map(|f| f.success));
+    let old_entries = baseline.map_or(&[][..], |f| &f.entries);
+    let new_entries = current.map_or(&[][..], |f| &f.entries);
+    let count = old_entries
+        .iter()
+        .chain(new_entries.iter())
+        .map(|e| e.index)
+        .max()
+        .unwrap_or(0);
+    let entries = (1..=count)
+        .filter_map(|index| compare_entry(index, baseline, current, threshold))
+        .collect();
+    FileDiff {
+        filename: filename.to_string(),
+        status,
+        baseline_time: baseline.map(|f| f.time),
+        current_time: current.map(|f| f.time),
+        entries,
+    }
+}
+
+/// Compares the entry at `index` of the `baseline` file with the entry of the `current` file.
+fn compare_entry(
+    index: usize,
+    baseline: Option<&FileJson>,
+    current: Option<&FileJson>,
+    threshold: u64,
+) -> Option<EntryDiff> {
+    let old = baseline.and_then(|f| Some((f, f.entry(index)?)));
+    let new = current.and_then(|f| Some((f, f.entry(index)?)));
+    let line = new.or(old)?.1.line;
+    let status = Status::new(
+        old.map(|(f, e)| f.entry_success(e)),
+        new.map(|(f, e)| f.entry_success(e)),
+    );
+    let baseline = old.map(|(_, e)| e);
+    let current = new.map(|(_, e)| e);
+    let regression = match (baseline, current) {
+        (Some(old), Some(new)) => is_regression(old.time, new.time, threshold),
+        _ => false,
+    };
+    let messages = current.map_or(vec![], |e| {
+        e.asserts
+            .iter()
+            .filter(|a| !a.success)
+            .filter_map(|a| a.message.clone())
+            .collect()
+    });
+    Some(EntryDiff {
+        index,
+        line,
+        status,
+        baseline_time: baseline.map(|e| e.time),
+        current_time: current.map(|e| e.time),
+        regression,
+        messages,
+    })
+}
+
+/// Returns `true` if the duration `current` has increased by more than `threshold` percent
+/// from the duration `baseline`.
+fn is_regression(baseline: u64, current: u64, threshold: u64) -> bool {
+    current > baseline && (current - baseline) * 100 > baseline * threshold
+}
+
+/// Returns a standalone HTML comparison report from the list of `files`.
+fn create_html_compare(now: &str, files: &[FileDiff], threshold: u64) -> String {
+    let count = |status: Status| files.iter().filter(|f| f.status == status).count();
+    let count_newly_failing = count(Status::NewlyFailing);
+    let count_fixed = count(Status::Fixed);
+    let count_still_failing = count(Status::StillFailing);
+    let count_regression = files
+        .iter()
+        .flat_map(|f| f.entries.iter())
+        .filter(|e| e.regression)
+        .count();
+    let css = include_str!("resources/compare.css");
+    let rows = files
+        .iter()
+        .map(create_html_file_row)
+        .collect::<Vec<String>>()
+        .join("");
+    let details = files
+        .iter()
+        .map(create_html_file_details)
+        .collect::<Vec<String>>()
+        .join("");
+    format!(
+        include_str!("resources/compare.html"),
+        now = now,
+        css = css,
+        threshold = threshold,
+        count_newly_failing = count_newly_failing,
+        count_fixed = count_fixed,
+        count_still_failing = count_still_failing,
+        count_regression = count_regression,
+        rows = rows,
+        details = details,
+    )
+}
+
+fn create_html_file_row(file: &FileDiff) -> String {
+    let filename = html::html_escape(&file.filename);
+    let status = file.status;
+    let status_class = status.class();
+    let status_label = status.label();
+    let baseline = duration(file.baseline_time);
+    let current = duration(file.current_time);
+    let count_regression = file.entries.iter().filter(|e| e.regression).count();
+    format!(
+        r##"<tr>
+    <td><a href="#{filename}">{filename}</a></td>
+    <td class="{status_class}">{status_label}</td>
+    <td>{baseline}</td>
+    <td>{current}</td>
+    <td>{count_regression}</td>
+</tr>
+"##
+    )
+}
+
+/// Returns the entries table of a `file`, with a timeline comparing the entries durations.
+fn create_html_file_details(file: &FileDiff) -> String {
+    let filename = html::html_escape(&file.filename);
+    let max_time = file
+        .entries
+        .iter()
+        .flat_map(|e| [e.baseline_time, e.current_time])
+        .flatten()
+        .max()
+        .unwrap_or(0);
+    let rows = file
+        .entries
+        .iter()
+        .map(|e| create_html_entry_row(e, max_time))
+        .collect::<Vec<String>>()
+        .join("");
+    format!(
+        r#"<h3 id="{filename}">{filename}</h3>
+<table class="entries">
+    <thead>
+    <td>Entry</td>
+    <td>Line</td>
+    <td>Status</td>
+    <td>Baseline</td>
+    <td>Current</td>
+    <td class="timeline">Timeline</td>
+    </thead>
+    <tbody>
+    {rows}
+    </tbody>
+</table>
+"#
+    )
+}
+
+fn create_html_entry_row(entry: &EntryDiff, max_time: u64) -> String {
+    let index = entry.index;
+    let line = entry.line;
+    let status_class = entry.status.class();
+    let status_label = entry.status.label();
+    let baseline = duration(entry.baseline_time);
+    let current = duration(entry.current_time);
+    let current_class = if entry.regression {
+        "bar current regression"
+    } else {
+        "bar current"
+    };
+    let baseline_bar = bar("bar baseline", entry.baseline_time, max_time);
+    let current_bar = bar(current_class, entry.current_time, max_time);
+    let row_class = if entry.regression { "regression" } else { "" };
+    let mut html = format!(
+        r#"<tr class="{row_class}">
+    <td>{index}</td>
+    <td>{line}</td>
+    <td class="{status_class}">{status_label}</td>
+    <td>{baseline}</td>
+    <td>{current}</td>
+    <td class="timeline">{baseline_bar}{current_bar}</td>
+</tr>
+"#
+    );
+    if matches!(entry.status, Status::NewlyFailing | Status::StillFailing) {
+        let messages = entry
+            .messages
+            .iter()
+            .map(|m| html::html_escape(m))
+            .collect::<Vec<String>>()
+            .join("\n");
+        html.push_str(&format!(
+            r#"<tr class="messages"><td colspan="6"><pre><code>{messages}</code></pre></td></tr>
+"#
+        ));
+    }
+    html
+}
+
+/// Returns an HTML bar representing the duration `time`, relatively to `max_time`.
+fn bar(class: &str, time: Option<u64>, max_time: u64) -> String {
+    match time {
+        Some(time) => {
+            let width = if max_time == 0 {
+                0.0
+            } else {
+                time as f64 * 100.0 / max_time as f64
+            };
+            format!(r#"<div class="{class}" style="width: {width:.1}%"></div>"#)
+        }
+        None => format!(r#"<div class="{class}"></div>"#),
+    }
+}
+
+fn duration(time: Option<u64>) -> String {
+    match time {
+        Some(time) => format!("{time} ms"),
+        None => "-".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(filename: &str, entries: &[(bool, u64)]) -> FileJson {
+        let entries = entries
+            .iter()
+            .enumerate()
+            .map(|(i, (success, time))| EntryJson {
+                index: i + 1,
+                line: i * 4 + 1,
+                asserts: vec![AssertJson {
+                    success: *success,
+                    message: (!success).then(|| "Assert failure".to_string()),
+                }],
+                time: *time,
+            })
+            .collect::<Vec<_>>();
+        FileJson {
+            filename: filename.to_string(),
+            success: entries.iter().all(|e| e.asserts_success()),
+            time: entries.iter().map(|e| e.time).sum(),
+            entries,
+        }
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(Status::new(Some(true), Some(false)), Status::NewlyFailing);
+        assert_eq!(Status::new(Some(false), Some(true)), Status::Fixed);
+        assert_eq!(Status::new(Some(false), Some(false)), Status::StillFailing);
+        assert_eq!(Status::new(Some(true), Some(true)), Status::Success);
+        assert_eq!(Status::new(None, Some(true)), Status::Added);
+        assert_eq!(Status::new(None, Some(false)), Status::NewlyFailing);
+        assert_eq!(Status::new(Some(true), None), Status::Removed);
+    }
+
+    #[test]
+    fn test_is_regression() {
+        assert!(!is_regression(100, 120, 20));
+        assert!(is_regression(100, 121, 20));
+        assert!(!is_regression(100, 50, 20));
+        assert!(is_regression(0, 1, 20));
+        assert!(!is_regression(0, 0, 20));
+    }
+
+    #[test]
+    fn test_compare_files() {
+        let baseline = vec![
+            file("a.hurl", &[(true, 10), (true, 10)]),
+            file("b.hurl", &[(false, 10)]),
+            file("c.hurl", &[(true, 10)]),
+        ];
+        let current = vec![
+            file("a.hurl", &[(true, 10), (false, 50)]),
+            file("b.hurl", &[(true, 10)]),
+            file("d.hurl", &[(true, 10)]),
+        ];
+        let files = compare_files(&baseline, &current, 20);
+        assert_eq!(
+            files
+                .iter()
+                .map(|f| (f.filename.as_str(), f.status))
+                .collect::<Vec<_>>(),
+            vec![
+                ("a.hurl", Status::NewlyFailing),
+                ("b.hurl", Status::Fixed),
+                ("d.hurl", Status::Added),
+                ("c.hurl", Status::Removed),
+            ]
+        );
+        assert_eq!(
+            files[0].entries,
+            vec![
+                EntryDiff {
+                    index: 1,
+                    line: 1,
+                    status: Status::Success,
+                    baseline_time: Some(10),
+                    current_time: Some(10),
+                    regression: false,
+                    messages: vec![],
+                },
+                EntryDiff {
+                    index: 2,
+                    line: 5,
+                    status: Status::NewlyFailing,
+                    baseline_time: Some(10),
+                    current_time: Some(50),
+                    regression: true,
+                    messages: vec!["Assert failure".to_string()],
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_files_last_run() {
+        let baseline = vec![file("a.hurl", &[(false, 10)])];
+        let current = vec![
+            file("a.hurl", &[(false, 10)]),
+            file("a.hurl", &[(true, 10)]),
+        ];
+        let files = compare_files(&baseline, &current, 20);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].status, Status::Fixed);
+    }
+
+    #[test]
+    fn test_compare_files_runtime_error() {
+        // The second entry has no failing assert but the file has failed on a runtime error.
+        let baseline = vec![file("a.hurl", &[(true, 10), (true, 10)])];
+        let mut current = vec![file("a.hurl", &[(true, 10), (true, 10)])];
+        current[0].success = false;
+        let files = compare_files(&baseline, &current, 20);
+        assert_eq!(files[0].status, Status::NewlyFailing);
+        assert_eq!(
+            files[0]
+                .entries
+                .iter()
+                .map(|e| e.status)
+                .collect::<Vec<_>>(),
+            vec![Status::Success, Status::NewlyFailing]
+        );
+
+        // The runtime error is on the last entry run, not on the entries that have not been run.
+        let current = vec![FileJson {
+            entries: current[0].entries[..1].to_vec(),
+            ..current[0].clone()
+        }];
+        let files = compare_files(&baseline, &current, 20);
+        assert_eq!(
+            files[0]
+                .entries
+                .iter()
+                .map(|e| e.status)
+                .collect::<Vec<_>>(),
+            vec![Status::NewlyFailing, Status::Removed]
+        );
+    }
+
+    #[test]
+    fn test_bar() {
+        assert_eq!(
+            bar("bar baseline", Some(25), 100),
+            r#"<div class="bar baseline" style="width: 25.0%"></div>"#
+        );
+        assert_eq!(
+            bar("bar current", None, 100),
+            r#"<div class="bar current"></div>"#
+        );
+    }
+}
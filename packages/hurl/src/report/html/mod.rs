@@ -16,13 +16,17 @@
  *
  */
 //! HTML report.
+mod body;
+mod compare;
 mod nav;
 mod report;
 mod run;
 mod source;
 mod testcase;
 mod timeline;
+mod trends;
 
+pub use compare::write_compare_report;
 pub use report::write_report;
 pub use testcase::Testcase;
 
@@ -36,17 +40,21 @@ struct HTMLResult {
     time_in_ms: u128,
     success: bool,
     timestamp: i64,
+    /// The run number of this result, incremented each time the report is updated (`0` for
+    /// results of reports created before runs were recorded)
+    run: usize,
 }
 
 impl HTMLResult {
-    /// Creates a new HTMLResult from a [`Testcase`].
-    fn from(testcase: &Testcase) -> Self {
+    /// Creates a new HTMLResult from a [`Testcase`] executed in the run number `run`.
+    fn from(testcase: &Testcase, run: usize) -> Self {
         HTMLResult {
             filename: testcase.filename.clone(),
             id: testcase.id.clone(),
             time_in_ms: testcase.time_in_ms,
             success: testcase.success,
             timestamp: testcase.timestamp,
+            run,
         }
     }
 }
@@ -85,7 +85,7 @@ fn get_status_html(success: bool) -> &'static str {
 }
 
 /// Returns an HTML `<pre>` tag representing this `error`.
-fn error_to_html(
+pub fn error_to_html(
     error: &RunnerError,
     entry_src_info: SourceInfo,
     content: &str,
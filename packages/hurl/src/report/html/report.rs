@@ -20,7 +20,7 @@ use std::path::Path;
 
 use chrono::{DateTime, Local};
 
-use crate::report::html::{HTMLResult, Testcase};
+use crate::report::html::{trends, HTMLResult, Testcase};
 use crate::report::ReportError;
 
 /// Creates and HTML report for this list of [`Testcase`] at `dir_path`/index.html.
@@ -29,19 +29,26 @@ use crate::report::ReportError;
 pub fn write_report(dir_path: &Path, testcases: &[Testcase]) -> Result<(), ReportError> {
     let index_path = dir_path.join("index.html");
     let mut results = parse_html(&index_path)?;
+    let run = results.iter().map(|r| r.run).max().unwrap_or(0) + 1;
     for testcase in testcases.iter() {
-        let html_result = HTMLResult::from(testcase);
+        let html_result = HTMLResult::from(testcase, run);
         results.push(html_result);
     }
     let now = Local::now();
     let s = create_html_index(&now.to_rfc2822(), &results);
+    write_file(&index_path, &s)?;
 
-    let file_path = index_path;
-    let mut file = match std::fs::File::create(&file_path) {
+    // The trends page is generated from all the accumulated results.
+    let s = trends::create_html_trends(&now.to_rfc2822(), &results);
+    write_file(&dir_path.join("trends.html"), &s)
+}
+
+fn write_file(file_path: &Path, s: &str) -> Result<(), ReportError> {
+    let mut file = match std::fs::File::create(file_path) {
         Err(err) => {
             return Err(ReportError::from_error(
                 err,
-                &file_path,
+                file_path,
                 "Issue writing HTML report",
             ))
         }
@@ -50,7 +57,7 @@ pub fn write_report(dir_path: &Path, testcases: &[Testcase]) -> Result<(), Repor
     if let Err(err) = file.write_all(s.as_bytes()) {
         return Err(ReportError::from_error(
             err,
-            &file_path,
+            file_path,
             "Issue writing HTML report",
         ));
     }
@@ -95,14 +102,17 @@ fn parse_html(path: &Path) -> Result<Vec<HTMLResult>, ReportError> {
                 ))
             }
         };
-        Ok(parse_html_report(&s))
+        parse_html_report(&s)
     } else {
         Ok(vec![])
     }
 }
 
 /// Parses the HTML report `html` an returns a list of [`HTMLResult`].
-fn parse_html_report(html: &str) -> Vec<HTMLResult> {
+///
+/// Runs are not recorded in older reports: consecutive results without run are grouped in a single
+/// run, numbered after the previous run of the report.
+fn parse_html_report(html: &str) -> Result<Vec<HTMLResult>, ReportError> {
     let re = regex::Regex::new(
         r#"(?x)
         data-duration="(?P<time_in_ms>\d+)"
@@ -114,33 +124,56 @@ fn parse_html_report(html: &str) -> Vec<HTMLResult> {
         data-id="(?P<id>[a-f0-9]{8}-[a-f0-9]{4}-[a-f0-9]{4}-[a-f0-9]{4}-[a-f0-9]{12})"
         (\s+
         data-timestamp="(?P<timestamp>[0-9]{1,10})")?
+        (\s+
+        data-run="(?P<run>[0-9]+)")?
     "#,
     )
     .unwrap();
-    // TODO: if the existing HTML report is not valid, we consider that there is no
-    // existing report to append, without displaying any error or warning. Maybe a better option
-    // would be to raise an error here and ask the user to explicitly deal with this error.
+    // Rows that don't match the expected attributes are ignored, whereas rows with an invalid
+    // duration, timestamp or run return an error.
+    let mut last_run = 0;
+    let mut legacy_run = None;
     re.captures_iter(html)
         .map(|cap| {
             let filename = cap["filename"].to_string();
             let id = cap["id"].to_string();
-            let time_in_ms = cap["time_in_ms"].to_string().parse().unwrap();
+            let time_in_ms = parse_number(&cap["time_in_ms"], "duration")?;
             let success = &cap["status"] == "success";
 
             // Older reports won't have this so make it optional
-            let timestamp: i64 = cap
-                .name("timestamp")
-                .map_or(0, |m| m.as_str().parse().unwrap());
+            let timestamp: i64 = match cap.name("timestamp") {
+                Some(m) => parse_number(m.as_str(), "timestamp")?,
+                None => 0,
+            };
+
+            let run: usize = match cap.name("run") {
+                Some(m) => {
+                    legacy_run = None;
+                    parse_number(m.as_str(), "run")?
+                }
+                None => *legacy_run.get_or_insert(last_run + 1),
+            };
+            last_run = run;
 
-            HTMLResult {
+            Ok(HTMLResult {
                 filename,
                 id,
                 time_in_ms,
                 success,
                 timestamp,
-            }
+                run,
+            })
         })
-        .collect::<Vec<HTMLResult>>()
+        .collect()
+}
+
+/// Parses the `value` of an HTML report attribute `name` as a number.
+fn parse_number<T: std::str::FromStr>(value: &str, name: &str) -> Result<T, ReportError> {
+    value.parse().map_err(|_| {
+        ReportError::from_string(&format!(
+            "Issue reading HTML report (invalid {name} <{value}>)"
+        ))
+    })
 }
 
 fn create_html_table_row(result: &HTMLResult) -> String {
@@ -159,6 +192,7 @@ fn create_html_table_row(result: &HTMLResult) -> String {
     };
     let id = &result.id;
     let timestamp = result.timestamp;
+    let run = result.run;
     let displayed_time = if timestamp == 0 {
         "-".to_string()
     } else {
@@ -170,7 +204,7 @@ fn create_html_table_row(result: &HTMLResult) -> String {
             .to_rfc3339()
     };
     format!(
-        r#"<tr data-duration="{duration_in_ms}" data-status="{status}" data-filename="{filename}" data-id="{id}" data-timestamp="{timestamp}">
+        r#"<tr data-duration="{duration_in_ms}" data-status="{status}" data-filename="{filename}" data-id="{id}" data-timestamp="{timestamp}" data-run="{run}">
     <td><a href="store/{id}-source.html">{displayed_filename}</a></td>
     <td class="{status}"><a href="store/{id}-timeline.html">{status}</a></td>
     <td>{displayed_time}</td>
@@ -214,13 +248,19 @@ mod tests {
                   <td>2023-10-05T02:37:24Z</td>
                   <td>0.2s</td>
                 </tr>
+                <tr data-duration="120" data-status="success" data-filename="tests/hello.hurl" data-id="c0fc2a6a-7d4f-4d2b-a1a2-8d2c7ab5e9d1" data-timestamp="1696473500" data-run="3">
+                  <td><a href="tests/hello.hurl.html">tests/hello.hurl</a></td>
+                  <td>success</td>
+                  <td>2023-10-05T02:38:20Z</td>
+                  <td>0.12s</td>
+                </tr>
                 </tbody>
               <table>
            </body>
         </html>"#;
 
         assert_eq!(
-            parse_html_report(html),
+            parse_html_report(html).unwrap(),
             vec![
                 HTMLResult {
                     filename: "tests/hello.hurl".to_string(),
@@ -228,6 +268,7 @@ mod tests {
                     time_in_ms: 100,
                     success: true,
                     timestamp: 0,
+                    run: 1,
                 },
                 HTMLResult {
                     filename: "tests/failure.hurl".to_string(),
@@ -235,8 +276,26 @@ mod tests {
                     time_in_ms: 200,
                     success: false,
                     timestamp: 1696473444,
+                    run: 1,
+                },
+                HTMLResult {
+                    filename: "tests/hello.hurl".to_string(),
+                    id: "c0fc2a6a-7d4f-4d2b-a1a2-8d2c7ab5e9d1".to_string(),
+                    time_in_ms: 120,
+                    success: true,
+                    timestamp: 1696473500,
+                    run: 3,
                 }
             ]
         );
     }
+
+    #[test]
+    fn test_parse_html_report_invalid_run() {
+        let html = r#"<tr data-duration="100" data-status="success" data-filename="tests/hello.hurl" data-id="08aad14a-8d10-4ecc-892e-a72703c5b494" data-timestamp="1696473500" data-run="99999999999999999999999">"#;
+        assert_eq!(
+            parse_html_report(html).unwrap_err().to_string(),
+            "Issue reading HTML report (invalid run <99999999999999999999999>)"
+        );
+    }
 }
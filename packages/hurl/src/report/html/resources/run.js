@@ -0,0 +1,8 @@
+for (const button of document.querySelectorAll("button.copy")) {
+    button.addEventListener("click", () => {
+        const code = document.getElementById(button.dataset.target);
+        navigator.clipboard.writeText(code.textContent);
+        button.textContent = "Copied";
+        setTimeout(() => button.textContent = "Copy", 2000);
+    });
+}
@@ -15,48 +15,92 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::HurlFile;
+use hurl_core::ast::{Entry, HurlFile, QueryValue};
+use hurl_core::error::{DisplaySourceError, OutputFormat};
 
-use crate::http::Call;
-use crate::report::html::nav::Tab;
+use crate::html;
+use crate::http::{Call, ServerSentEvent};
+use crate::report::html::body::{get_body_html, parse_simple_jsonpath, Highlight};
+use crate::report::html::nav::{error_to_html, Tab};
 use crate::report::html::Testcase;
 use crate::runner::EntryResult;
 use crate::util::redacted::Redact;
 
+/// Common data of an entry, used to display each call of the entry.
+struct EntryView<'a> {
+    /// 1-based index of the entry in the run.
+    index: usize,
+    filename: &'a str,
+    /// Filename of the HTML source view.
+    source: &'a str,
+    /// Line of the entry in the Hurl source file.
+    line: usize,
+    curl_cmd: String,
+    /// HTML of the entry errors, displayed next to the last response body.
+    errors: String,
+    /// Failing asserts to highlight in the last response body.
+    highlights: Vec<Highlight>,
+    /// Maximum size of the stored bodies, bodies are not truncated if `None`.
+    max_body_size: Option<u64>,
+    secrets: &'a [&'a str],
+}
+
 impl Testcase {
-    /// Creates an HTML view of a run (HTTP status code, response header etc...)
+    /// Creates an HTML view of a run (HTTP status code, request and response headers and bodies etc...)
+    ///
+    /// Bodies are truncated to `max_body_size` bytes if specified.
     pub fn get_run_html(
         &self,
         hurl_file: &HurlFile,
         content: &str,
         entries: &[EntryResult],
+        max_body_size: Option<u64>,
         secrets: &[&str],
     ) -> String {
         let nav = self.get_nav_html(content, Tab::Run, secrets);
         let nav_css = include_str!("resources/nav.css");
         let run_css = include_str!("resources/run.css");
+        let source = self.source_filename();
 
         let mut run = String::new();
         for (entry_index, e) in entries.iter().enumerate() {
             let entry_src_index = e.entry_index - 1;
             let entry_src = hurl_file.entries.get(entry_src_index).unwrap();
-            let line = entry_src.source_info().start.line;
-            let source = self.source_filename();
+            let errors = e
+                .errors
+                .iter()
+                .map(|error| {
+                    let error = error_to_html(
+                        error,
+                        e.source_info,
+                        content,
+                        &self.filename,
+                        &source,
+                        secrets,
+                    );
+                    format!("<div class=\"error\">{error}</div>")
+                })
+                .collect::<Vec<_>>()
+                .join("");
+            let view = EntryView {
+                index: entry_index + 1,
+                filename: &self.filename,
+                source: &source,
+                line: entry_src.source_info().start.line,
+                curl_cmd: e.curl_cmd.to_string().redact(secrets),
+                errors,
+                highlights: get_highlights(e, entry_src, content, &self.filename),
+                max_body_size,
+                secrets,
+            };
 
             run.push_str("<details open>");
-            let info = get_entry_html(e, entry_index + 1, secrets);
+            let info = get_entry_html(e, view.index, secrets);
             run.push_str(&info);
 
             for (call_index, c) in e.calls.iter().enumerate() {
-                let info = get_call_html(
-                    c,
-                    entry_index + 1,
-                    call_index + 1,
-                    &self.filename,
-                    &source,
-                    line,
-                    secrets,
-                );
+                let last = call_index == e.calls.len() - 1;
+                let info = get_call_html(c, call_index + 1, last, &view);
                 run.push_str(&info);
             }
 
@@ -74,22 +118,50 @@ impl Testcase {
     }
 }
 
+/// Returns the failing JSONPath asserts of an `entry`, to be highlighted in the response body.
+///
+/// Only JSONPath expressions targeting a single value can be highlighted.
+fn get_highlights(
+    entry: &EntryResult,
+    entry_src: &Entry,
+    content: &str,
+    filename: &str,
+) -> Vec<Highlight> {
+    let Some(response) = &entry_src.response else {
+        return vec![];
+    };
+    entry
+        .errors
+        .iter()
+        .filter(|error| error.assert)
+        .filter_map(|error| {
+            let line = error.source_info.start.line;
+            let assert = response
+                .asserts()
+                .iter()
+                .find(|a| a.query.source_info.start.line == line)?;
+            let QueryValue::Jsonpath { expr, .. } = &assert.query.value else {
+                return None;
+            };
+            let path = parse_simple_jsonpath(&expr.to_string())?;
+            let message = error.to_string(filename, content, None, OutputFormat::Terminal(false));
+            Some(Highlight { path, message })
+        })
+        .collect()
+}
+
 /// Returns an HTML view of an `entry` information as HTML (title, `entry_index` and captures).
 fn get_entry_html(entry: &EntryResult, entry_index: usize, secrets: &[&str]) -> String {
     let mut text = String::new();
     text.push_str(&format!("<summary>Entry {entry_index}</summary>"));
 
-    let cmd = entry.curl_cmd.to_string().redact(secrets);
-    let table = new_table("Debug", &[("Command", &cmd)]);
-    text.push_str(&table);
-
     if !entry.captures.is_empty() {
         let mut values = entry
             .captures
             .iter()
             .map(|c| (&c.name, c.value.to_string().redact(secrets)))
             .collect::<Vec<(&String, String)>>();
-        values.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        values.sort_by_key(|v| v.0.to_lowercase());
         let table = new_table("Captures", &values);
         text.push_str(&table);
     }
@@ -97,26 +169,25 @@ fn get_entry_html(entry: &EntryResult, entry_index: usize, secrets: &[&str]) ->
     text
 }
 
-/// Returns an HTML view of a `call` (source file, request and response headers, certificate etc...)
-fn get_call_html(
-    call: &Call,
-    entry_index: usize,
-    call_index: usize,
-    filename: &str,
-    source: &str,
-    line: usize,
-    secrets: &[&str],
-) -> String {
+/// Returns an HTML view of a `call` (source file, curl command, request and response side by side
+/// etc...)
+///
+/// If this call is the `last` call of its entry, the entry errors are displayed next to the
+/// response body.
+fn get_call_html(call: &Call, call_index: usize, last: bool, entry: &EntryView) -> String {
+    let secrets = entry.secrets;
+    let entry_index = entry.index;
     let mut text = String::new();
     let id = format!("e{entry_index}:c{call_index}");
-    text.push_str(&format!("<h4 id=\"{id}\">Call {call_index}</h3>"));
+    text.push_str(&format!("<h4 id=\"{id}\">Call {call_index}</h4>"));
 
     // General
     let status = call.response.status.to_string();
     let version = call.response.version.to_string();
     let url = &call.request.url.to_string().redact(secrets);
     let url = format!("<a href=\"{url}\">{url}</a>");
-    let source = format!("<a href=\"{source}#l{line}\">{filename}:{line}</a>");
+    let (filename, line) = (entry.filename, entry.line);
+    let source = format!("<a href=\"{}#l{line}\">{filename}:{line}</a>", entry.source);
     let values = vec![
         ("Request URL", url.as_str()),
         ("Request Method", call.request.method.as_str()),
@@ -142,26 +213,118 @@ fn get_call_html(
         text.push_str(&table);
     }
 
+    // Curl command
+    let curl_id = format!("e{entry_index}-c{call_index}-curl");
+    let curl_cmd = html::html_escape(&entry.curl_cmd);
+    text.push_str(&format!(
+        "<div class=\"copyable\"><button class=\"copy\" data-target=\"{curl_id}\">Copy</button><pre class=\"curl\"><code id=\"{curl_id}\">{curl_cmd}</code></pre></div>"
+    ));
+
+    text.push_str("<div class=\"exchange\">");
+
+    // Request
+    text.push_str("<div class=\"request\"><h5>Request</h5>");
     let mut values = call
         .request
         .headers
         .iter()
         .map(|h| (h.name.as_str(), h.value.redact(secrets)))
         .collect::<Vec<(&str, String)>>();
-    values.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+    values.sort_by_key(|v| v.0.to_lowercase());
     let table = new_table("Request Headers", &values);
     text.push_str(&table);
+    let body = &call.request.body;
+    let body_text = std::str::from_utf8(body).ok();
+    let body = get_body_html(
+        &format!("e{entry_index}-c{call_index}-request"),
+        body,
+        body_text,
+        call.request.headers.content_type(),
+        &[],
+        entry.max_body_size,
+        secrets,
+    );
+    text.push_str(&body);
+    text.push_str("</div>");
 
+    // Response
+    text.push_str("<div class=\"response\"><h5>Response</h5>");
     let mut values = call
         .response
         .headers
         .iter()
         .map(|h| (h.name.as_str(), h.value.redact(secrets)))
         .collect::<Vec<(&str, String)>>();
-    values.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+    values.sort_by_key(|v| v.0.to_lowercase());
     let table = new_table("Response Headers", &values);
     text.push_str(&table);
 
+    if last {
+        text.push_str(&entry.errors);
+    }
+
+    // Server-Sent Events
+    if call.response.is_event_stream() {
+        if let Ok(events) = call.response.server_sent_events() {
+            let table = new_events_table(&events, secrets);
+            text.push_str(&table);
+        }
+    } else {
+        let response = &call.response;
+        let content_type = response.headers.content_type();
+        let body = response
+            .uncompress_body()
+            .unwrap_or_else(|_| response.body.clone());
+        let body_text = if response.is_kind_of_text() {
+            response.text().ok()
+        } else if content_type.is_none() {
+            String::from_utf8(body.clone()).ok()
+        } else {
+            None
+        };
+        let highlights = if last { &entry.highlights[..] } else { &[] };
+        let body = get_body_html(
+            &format!("e{entry_index}-c{call_index}-response"),
+            &body,
+            body_text.as_deref(),
+            content_type,
+            highlights,
+            entry.max_body_size,
+            secrets,
+        );
+        text.push_str(&body);
+    }
+    text.push_str("</div>");
+
+    text.push_str("</div>");
+    text
+}
+
+/// Returns an HTML table of Server-Sent `events`, one row per event. Values are redacted using
+/// `secrets`.
+fn new_events_table(events: &[ServerSentEvent], secrets: &[&str]) -> String {
+    let mut text = String::new();
+    text.push_str(
+        "<table class=\"events\"><thead><tr><th colspan=\"5\">Server-Sent Events</th></tr>",
+    );
+    text.push_str(
+        "<tr><th>#</th><th>Id</th><th>Event</th><th>Data</th><th>Retry</th></tr></thead><tbody>",
+    );
+    for (index, event) in events.iter().enumerate() {
+        let index = index + 1;
+        let id = event.id.as_deref().unwrap_or_default().redact(secrets);
+        let id = html::html_escape(&id);
+        let event_type = html::html_escape(&event.event.redact(secrets));
+        let data = html::html_escape(&event.data.redact(secrets));
+        let retry = event.retry.map(|r| r.to_string()).unwrap_or_default();
+        text.push_str(&format!(
+            "<tr><td class=\"name\">{index}</td><td>{id}</td><td>{event_type}</td>"
+        ));
+        text.push_str(&format!(
+            "<td class=\"value\"><pre>{data}</pre></td><td>{retry}</td></tr>"
+        ));
+    }
+    text.push_str("</tbody></table>");
     text
 }
 
@@ -62,14 +62,16 @@ impl Testcase {
     /// It will create three HTML files:
     /// - an HTML view of the Hurl source file (with potential errors and syntax colored),
     /// - an HTML timeline view of the executed entries (with potential errors, waterfall)
-    /// - an HTML view of the executed run (headers, cookies, etc...)
+    /// - an HTML view of the executed run (headers, bodies, etc...)
     ///
-    /// `secrets` strings are redacted from the produced HTML.
+    /// HTTP bodies are truncated to `max_body_size` bytes if specified. `secrets` strings are
+    /// redacted from the produced HTML.
     pub fn write_html(
         &self,
         content: &str,
         entries: &[EntryResult],
         dir: &Path,
+        max_body_size: Option<u64>,
         secrets: &[&str],
     ) -> Result<(), crate::report::ReportError> {
         // We parse the content as we'll reuse the AST to construct the HTML source file, and
@@ -84,9 +86,14 @@ impl Testcase {
 
         // Then create the run view.
         let output_file = dir.join(self.run_filename());
-        let html = self.get_run_html(&hurl_file, content, entries, secrets);
+        let html = self.get_run_html(&hurl_file, content, entries, max_body_size, secrets);
         fs::write(output_file, html.as_bytes())?;
 
+        // The run view copy buttons are handled by an external script, so the HTML files of the
+        // report don't have any inline script.
+        let output_file = dir.join("run.js");
+        fs::write(output_file, include_str!("resources/run.js"))?;
+
         // And create the source view.
         let output_file = dir.join(self.source_filename());
         let html = self.get_source_html(&hurl_file, content, secrets);
@@ -25,7 +25,7 @@ use crate::runner::EntryResult;
 
 mod calls;
 mod nice;
-mod svg;
+pub mod svg;
 mod unit;
 mod util;
 mod waterfall;
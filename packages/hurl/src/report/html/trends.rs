@@ -0,0 +1,468 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use chrono::{DateTime, Local};
+
+use crate::html;
+use crate::report::html::timeline::svg;
+use crate::report::html::timeline::svg::Attribute::{Class, Fill, Height, Stroke, ViewBox, Width};
+use crate::report::html::timeline::svg::Element;
+use crate::report::html::HTMLResult;
+
+/// Maximum number of files displayed in the slowest and flakiest files tables.
+const TOP_FILES_COUNT: usize = 10;
+
+/// The results of a report run.
+struct Run<'a> {
+    /// The run number, see [`HTMLResult::run`]
+    number: usize,
+    results: Vec<&'a HTMLResult>,
+}
+
+impl Run<'_> {
+    fn count_success(&self) -> usize {
+        self.results.iter().filter(|r| r.success).count()
+    }
+
+    fn count_failure(&self) -> usize {
+        self.results.iter().filter(|r| !r.success).count()
+    }
+
+    /// Returns a label of this run, using the start time of its first file.
+    fn label(&self) -> String {
+        let timestamp = self
+            .results
+            .iter()
+            .map(|r| r.timestamp)
+            .filter(|t| *t > 0)
+            .min();
+        match timestamp.and_then(|t| DateTime::from_timestamp(t, 0)) {
+            Some(date) => date.with_timezone(&Local).format("%F %R").to_string(),
+            None => format!("Run #{}", self.number),
+        }
+    }
+}
+
+/// The results of a file, across all the runs.
+struct FileTrend<'a> {
+    filename: &'a str,
+    /// Results, ordered by run.
+    results: Vec<&'a HTMLResult>,
+}
+
+impl FileTrend<'_> {
+    fn mean_time_in_ms(&self) -> u128 {
+        let total = self.results.iter().map(|r| r.time_in_ms).sum::<u128>();
+        total / self.results.len() as u128
+    }
+
+    fn last(&self) -> &HTMLResult {
+        self.results.last().unwrap()
+    }
+
+    /// Returns the number of status changes between two successive runs of this file.
+    fn flips(&self) -> usize {
+        self.results
+            .windows(2)
+            .filter(|w| w[0].success != w[1].success)
+            .count()
+    }
+
+    fn pass_rate(&self) -> String {
+        let count_success = self.results.iter().filter(|r| r.success).count();
+        let rate = count_success as f64 * 100.0 / self.results.len() as f64;
+        format!("{rate:.0}%")
+    }
+}
+
+/// Returns a standalone HTML trends page from the accumulated `hurl_results`.
+pub fn create_html_trends(now: &str, hurl_results: &[HTMLResult]) -> String {
+    let runs = group_by_run(hurl_results);
+    let files = group_by_file(hurl_results);
+    let css = include_str!("resources/trends.css");
+    let runs_svg = new_runs_chart(&runs).to_string();
+
+    let mut slowest = files.iter().collect::<Vec<_>>();
+    slowest.sort_by_key(|f| std::cmp::Reverse(f.mean_time_in_ms()));
+    let slowest_rows = slowest
+        .iter()
+        .take(TOP_FILES_COUNT)
+        .map(|f| create_html_slowest_row(f))
+        .collect::<Vec<String>>()
+        .join("");
+
+    let mut flakiest = files.iter().filter(|f| f.flips() > 0).collect::<Vec<_>>();
+    flakiest.sort_by_key(|f| std::cmp::Reverse(f.flips()));
+    let flakiest_rows = flakiest
+        .iter()
+        .take(TOP_FILES_COUNT)
+        .map(|f| create_html_flakiest_row(f))
+        .collect::<Vec<String>>()
+        .join("");
+
+    let files_rows = files
+        .iter()
+        .map(create_html_file_row)
+        .collect::<Vec<String>>()
+        .join("");
+
+    format!(
+        include_str!("resources/trends.html"),
+        now = now,
+        css = css,
+        count_runs = runs.len(),
+        runs_svg = runs_svg,
+        slowest_rows = slowest_rows,
+        flakiest_rows = flakiest_rows,
+        files_rows = files_rows,
+    )
+}
+
+/// Groups the results by run, in run order.
+fn group_by_run(hurl_results: &[HTMLResult]) -> Vec<Run<'_>> {
+    let mut runs: Vec<Run> = vec![];
+    for result in hurl_results {
+        match runs.iter_mut().find(|r| r.number == result.run) {
+            Some(run) => run.results.push(result),
+            None => runs.push(Run {
+                number: result.run,
+                results: vec![result],
+            }),
+        }
+    }
+    runs.sort_by_key(|r| r.number);
+    runs
+}
+
+/// Groups the results by file, in the order of their first run.
+fn group_by_file(hurl_results: &[HTMLResult]) -> Vec<FileTrend<'_>> {
+    let mut files: Vec<FileTrend> = vec![];
+    for result in hurl_results {
+        match files.iter_mut().find(|f| f.filename == result.filename) {
+            Some(file) => file.results.push(result),
+            None => files.push(FileTrend {
+                filename: &result.filename,
+                results: vec![result],
+            }),
+        }
+    }
+    for file in files.iter_mut() {
+        file.results.sort_by_key(|r| r.run);
+    }
+    files
+}
+
+/// Returns an SVG chart of the success and failure counts of each run, as stacked bars.
+fn new_runs_chart(runs: &[Run]) -> Element {
+    let width = 800.0;
+    let height = 160.0;
+    let bar_step = width / runs.len().max(1) as f64;
+    let bar_width = (bar_step * 0.6).min(40.0);
+    let max_count = runs.iter().map(|r| r.results.len()).max().unwrap_or(0);
+
+    let mut root = svg::new_svg();
+    root.add_attr(Class("runs-chart".to_string()));
+    root.add_attr(ViewBox(0.0, 0.0, width, height));
+    root.add_attr(Width("100%".to_string()));
+    root.add_attr(Height(height.to_string()));
+
+    for (index, run) in runs.iter().enumerate() {
+        let x = index as f64 * bar_step + (bar_step - bar_width) / 2.0;
+        let success_height = bar_height(run.count_success(), max_count, height);
+        let failure_height = bar_height(run.count_failure(), max_count, height);
+
+        let mut group = svg::new_group();
+        let title = format!(
+            "{}: {} succeeded, {} failed",
+            run.label(),
+            run.count_success(),
+            run.count_failure()
+        );
+        group.add_child(svg::new_title(&title));
+        let mut elt = svg::new_rect(
+            x,
+            height - success_height,
+            bar_width,
+            success_height,
+            "green",
+        );
+        elt.add_attr(Class("success".to_string()));
+        group.add_child(elt);
+        let mut elt = svg::new_rect(
+            x,
+            height - success_height - failure_height,
+            bar_width,
+            failure_height,
+            "red",
+        );
+        elt.add_attr(Class("failure".to_string()));
+        group.add_child(elt);
+        root.add_child(group);
+    }
+    root
+}
+
+/// Returns the height of a bar representing `count` in a chart of `height` where `max_count`
+/// is the highest bar.
+fn bar_height(count: usize, max_count: usize, height: f64) -> f64 {
+    if max_count == 0 {
+        return 0.0;
+    }
+    count as f64 * height / max_count as f64
+}
+
+/// Returns an SVG sparkline of the durations of `results`, failed runs being marked in red.
+fn new_sparkline(results: &[&HTMLResult]) -> Element {
+    let width = 120.0;
+    let height = 24.0;
+    let padding = 3.0;
+    let points = sparkline_points(results, width, height, padding);
+
+    let mut root = svg::new_svg();
+    root.add_attr(Class("sparkline".to_string()));
+    root.add_attr(ViewBox(0.0, 0.0, width, height));
+    root.add_attr(Width(width.to_string()));
+    root.add_attr(Height(height.to_string()));
+
+    let d = points
+        .iter()
+        .enumerate()
+        .map(|(i, (x, y))| {
+            let command = if i == 0 { "M" } else { "L" };
+            format!("{command}{x:.1} {y:.1}")
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    let mut path = svg::new_path(&d);
+    path.add_attr(Fill("none".to_string()));
+    path.add_attr(Stroke("royalblue".to_string()));
+    root.add_child(path);
+
+    for ((x, y), result) in points.iter().zip(results) {
+        if !result.success || points.len() == 1 {
+            let fill = if result.success { "royalblue" } else { "red" };
+            let mut elt = svg::new_rect(x - 1.5, y - 1.5, 3.0, 3.0, fill);
+            let class = if result.success { "success" } else { "failure" };
+            elt.add_attr(Class(class.to_string()));
+            root.add_child(elt);
+        }
+    }
+    root
+}
+
+/// Returns the coordinates of the points of a sparkline of `results` durations, in a box of
+/// `width` x `height` with a `padding`.
+fn sparkline_points(
+    results: &[&HTMLResult],
+    width: f64,
+    height: f64,
+    padding: f64,
+) -> Vec<(f64, f64)> {
+    let min = results.iter().map(|r| r.time_in_ms).min().unwrap_or(0);
+    let max = results.iter().map(|r| r.time_in_ms).max().unwrap_or(0);
+    let step = if results.len() > 1 {
+        (width - 2.0 * padding) / (results.len() - 1) as f64
+    } else {
+        0.0
+    };
+    results
+        .iter()
+        .enumerate()
+        .map(|(i, r)| {
+            let x = padding + i as f64 * step;
+            let ratio = if max == min {
+                0.5
+            } else {
+                (r.time_in_ms - min) as f64 / (max - min) as f64
+            };
+            let y = height - padding - ratio * (height - 2.0 * padding);
+            (x, y)
+        })
+        .collect()
+}
+
+/// Returns an SVG strip of the statuses of `results`, one square per run.
+fn new_status_strip(results: &[&HTMLResult]) -> Element {
+    let size = 10.0;
+    let gap = 2.0;
+    let width = results.len() as f64 * (size + gap);
+
+    let mut root = svg::new_svg();
+    root.add_attr(Class("status-strip".to_string()));
+    root.add_attr(ViewBox(0.0, 0.0, width, size));
+    root.add_attr(Width(width.to_string()));
+    root.add_attr(Height(size.to_string()));
+    for (index, result) in results.iter().enumerate() {
+        let (fill, class) = if result.success {
+            ("green", "success")
+        } else {
+            ("red", "failure")
+        };
+        let mut elt = svg::new_rect(index as f64 * (size + gap), 0.0, size, size, fill);
+        elt.add_attr(Class(class.to_string()));
+        root.add_child(elt);
+    }
+    root
+}
+
+/// Returns the HTML link of a `file` to the timeline of its last run.
+fn file_link(file: &FileTrend) -> String {
+    let filename = if file.filename == "-" {
+        "(standard input)".to_string()
+    } else {
+        html::html_escape(file.filename)
+    };
+    let id = &file.last().id;
+    format!(r#"<a href="store/{id}-timeline.html">{filename}</a>"#)
+}
+
+fn create_html_slowest_row(file: &FileTrend) -> String {
+    let link = file_link(file);
+    let runs = file.results.len();
+    let mean = file.mean_time_in_ms();
+    let last = file.last().time_in_ms;
+    let sparkline = new_sparkline(&file.results);
+    format!(
+        r#"<tr>
+    <td>{link}</td>
+    <td>{runs}</td>
+    <td>{mean} ms</td>
+    <td>{last} ms</td>
+    <td>{sparkline}</td>
+</tr>
+"#
+    )
+}
+
+fn create_html_flakiest_row(file: &FileTrend) -> String {
+    let link = file_link(file);
+    let runs = file.results.len();
+    let flips = file.flips();
+    let pass_rate = file.pass_rate();
+    let strip = new_status_strip(&file.results);
+    format!(
+        r#"<tr>
+    <td>{link}</td>
+    <td>{runs}</td>
+    <td>{flips}</td>
+    <td>{pass_rate}</td>
+    <td>{strip}</td>
+</tr>
+"#
+    )
+}
+
+fn create_html_file_row(file: &FileTrend) -> String {
+    let link = file_link(file);
+    let runs = file.results.len();
+    let pass_rate = file.pass_rate();
+    let status = if file.last().success {
+        "success"
+    } else {
+        "failure"
+    };
+    let sparkline = new_sparkline(&file.results);
+    format!(
+        r#"<tr>
+    <td>{link}</td>
+    <td>{runs}</td>
+    <td>{pass_rate}</td>
+    <td class="{status}">{status}</td>
+    <td>{sparkline}</td>
+</tr>
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(filename: &str, run: usize, success: bool, time_in_ms: u128) -> HTMLResult {
+        HTMLResult {
+            filename: filename.to_string(),
+            id: format!("{filename}-{run}"),
+            time_in_ms,
+            success,
+            timestamp: 0,
+            run,
+        }
+    }
+
+    #[test]
+    fn test_group_by_run_and_file() {
+        let results = vec![
+            result("a.hurl", 1, true, 10),
+            result("b.hurl", 1, false, 20),
+            result("b.hurl", 2, true, 30),
+            result("a.hurl", 2, false, 40),
+            result("a.hurl", 3, true, 50),
+        ];
+        let runs = group_by_run(&results);
+        assert_eq!(
+            runs.iter()
+                .map(|r| (r.number, r.count_success(), r.count_failure()))
+                .collect::<Vec<_>>(),
+            vec![(1, 1, 1), (2, 1, 1), (3, 1, 0)]
+        );
+        assert_eq!(runs[0].label(), "Run #1");
+
+        let files = group_by_file(&results);
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].filename, "a.hurl");
+        assert_eq!(files[0].flips(), 2);
+        assert_eq!(files[0].mean_time_in_ms(), 33);
+        assert_eq!(files[0].pass_rate(), "67%");
+        assert_eq!(files[0].last().id, "a.hurl-3");
+        assert_eq!(files[1].filename, "b.hurl");
+        assert_eq!(files[1].flips(), 1);
+    }
+
+    #[test]
+    fn test_sparkline_points() {
+        let results = [
+            result("a.hurl", 1, true, 10),
+            result("a.hurl", 2, true, 30),
+            result("a.hurl", 3, true, 20),
+        ];
+        let results = results.iter().collect::<Vec<_>>();
+        assert_eq!(
+            sparkline_points(&results, 120.0, 24.0, 3.0),
+            vec![(3.0, 21.0), (60.0, 3.0), (117.0, 12.0)]
+        );
+        assert_eq!(
+            sparkline_points(&results[..1], 120.0, 24.0, 3.0),
+            vec![(3.0, 12.0)]
+        );
+    }
+
+    #[test]
+    fn test_status_strip() {
+        let results = [
+            result("a.hurl", 1, true, 10),
+            result("a.hurl", 2, false, 10),
+        ];
+        let results = results.iter().collect::<Vec<_>>();
+        assert_eq!(
+            new_status_strip(&results).to_string(),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" class=\"status-strip\" viewBox=\"0 0 24 10\" width=\"24\" height=\"10\">\
+                <rect x=\"0\" y=\"0\" width=\"10\" height=\"10\" fill=\"green\" class=\"success\" />\
+                <rect x=\"12\" y=\"0\" width=\"10\" height=\"10\" fill=\"red\" class=\"failure\" />\
+            </svg>"
+        );
+    }
+}
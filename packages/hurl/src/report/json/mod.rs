@@ -31,7 +31,7 @@
 //!     ...
 //!     └── ce7f1326-2e2a-46e9-befd-ee0d85084814_response.json
 //! ```
-mod deserialize;
+pub mod deserialize;
 
 use std::fs::File;
 use std::io;
@@ -21,7 +21,10 @@
 //! The link below seems the most "official" spec
 //! <https://www.ibm.com/docs/fr/developer-for-zos/9.1.1?topic=formats-junit-xml-format>
 //!
-//! One Hurl file will result into one JUnit `<testcase>`.
+//! One Hurl file will result into one JUnit `<testcase>`. With an [`Granularity::Entry`]
+//! granularity, each entry of a Hurl file results into one `<testcase>` (with the Hurl file as
+//! `classname`): entries that have not been run are `<skipped/>`, captures are reported as
+//! `<properties>` and an excerpt of the HTTP request and response is written in `<system-out>`.
 //!
 //! The `<testcase>` can include `<error>` (for runtime error) or `<failure>` (for assert error)
 //! Each Hurl execution will generate its own `<testsuite>` within the root `<testsuites>`.
@@ -59,7 +62,7 @@ mod xml;
 use std::fs::File;
 use std::path::Path;
 
-pub use testcase::Testcase;
+pub use testcase::{Granularity, Testcase};
 
 use crate::report::junit::xml::{Element, XmlDocument};
 use crate::report::ReportError;
@@ -128,17 +131,24 @@ fn create_testsuite(testcases: &[Testcase], secrets: &[&str]) -> Element {
     let mut tests = 0;
     let mut errors = 0;
     let mut failures = 0;
+    let mut skipped = 0;
 
     for cases in testcases.iter() {
         tests += 1;
         errors += cases.get_error_count();
         failures += cases.get_fail_count();
+        if cases.is_skipped() {
+            skipped += 1;
+        }
     }
 
     let mut element = Element::new("testsuite")
         .attr("tests", &tests.to_string())
         .attr("errors", &errors.to_string())
         .attr("failures", &failures.to_string());
+    if skipped > 0 {
+        element = element.attr("skipped", &skipped.to_string());
+    }
 
     for testcase in testcases.iter() {
         let child = testcase.to_xml(secrets);
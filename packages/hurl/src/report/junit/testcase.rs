@@ -15,20 +15,44 @@
  * limitations under the License.
  *
  */
+use hurl_core::ast::SourceInfo;
 use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::input::Input;
 
+use crate::http::Call;
+use crate::report::entries::report_entries;
 use crate::report::junit::xml::Element;
-use crate::runner::HurlResult;
+use crate::runner::{EntryResult, HurlResult, RunnerError};
 use crate::util::redacted::Redact;
 
+/// Maximum size in characters of the response body excerpt in `<system-out>`.
+const MAX_BODY_EXCERPT_LEN: usize = 1024;
+
+/// Level of detail of the JUnit report.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Granularity {
+    /// One `<testcase>` per Hurl file.
+    #[default]
+    File,
+    /// One `<testcase>` per entry of a Hurl file.
+    Entry,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Testcase {
     id: String,
+    /// Name of the Hurl file of an entry testcase (`None` for a file testcase).
+    classname: Option<String>,
     name: String,
     time_in_ms: u128,
     failures: Vec<String>,
     errors: Vec<String>,
+    /// The entry has not been run.
+    skipped: bool,
+    /// Captured values of an entry testcase, as name / value pairs.
+    properties: Vec<(String, String)>,
+    /// Excerpt of the HTTP request and response of an entry testcase.
+    system_out: Option<String>,
 }
 
 impl Testcase {
@@ -41,12 +65,7 @@ impl Testcase {
         let mut errors = vec![];
 
         for (error, entry_src_info) in hurl_result.errors() {
-            let message = error.to_string(
-                &name,
-                content,
-                Some(entry_src_info),
-                OutputFormat::Terminal(false),
-            );
+            let message = error_message(error, entry_src_info, &name, content);
             if error.assert {
                 failures.push(message);
             } else {
@@ -55,22 +74,101 @@ impl Testcase {
         }
         Testcase {
             id,
+            classname: None,
             name,
             time_in_ms,
             failures,
             errors,
+            skipped: false,
+            properties: vec![],
+            system_out: None,
         }
     }
 
+    /// Creates a list of XML Junit &lt;testcase&gt;, one for each entry of an [`HurlResult`].
+    ///
+    /// Entries of the Hurl file `content` that have not been run are reported as skipped
+    /// testcases. If `content` can not be parsed, a single testcase for the whole file is
+    /// returned.
+    pub fn from_entries(
+        hurl_result: &HurlResult,
+        content: &str,
+        filename: &Input,
+    ) -> Vec<Testcase> {
+        let Some(entries) = report_entries(hurl_result, content) else {
+            return vec![Testcase::from(hurl_result, content, filename)];
+        };
+        let filename = filename.to_string();
+        entries
+            .into_iter()
+            .map(|entry| {
+                let results = entry.results;
+                let mut testcase = Testcase {
+                    id: format!("{filename}:{}", entry.index),
+                    classname: Some(filename.clone()),
+                    name: entry.name,
+                    time_in_ms: 0,
+                    failures: vec![],
+                    errors: vec![],
+                    skipped: results.is_empty(),
+                    properties: vec![],
+                    system_out: None,
+                };
+                let Some(last) = results.last() else {
+                    return testcase;
+                };
+                testcase.time_in_ms = results
+                    .iter()
+                    .flat_map(|e| &e.calls)
+                    .map(|c| c.timings.total.as_millis())
+                    .sum();
+                // Only the errors of the last run are effective, the previous ones have been retried.
+                for error in &last.errors {
+                    let message = error_message(error, last.source_info, &filename, content);
+                    if error.assert {
+                        testcase.failures.push(message);
+                    } else {
+                        testcase.errors.push(message);
+                    }
+                }
+                testcase.properties = last
+                    .captures
+                    .iter()
+                    .map(|c| (c.name.clone(), c.value.to_string()))
+                    .collect();
+                testcase.system_out = system_out(last);
+                testcase
+            })
+            .collect()
+    }
+
     /// Serializes this testcase to XML.
     pub fn to_xml(&self, secrets: &[&str]) -> Element {
         let time_in_seconds = format!("{:.3}", self.time_in_ms as f64 / 1000.0);
 
-        let mut element = Element::new("testcase")
-            .attr("id", &self.id)
-            .attr("name", &self.name)
+        let mut element = Element::new("testcase").attr("id", &self.id);
+        if let Some(classname) = &self.classname {
+            element = element.attr("classname", &classname.redact(secrets));
+        }
+        element = element
+            .attr("name", &self.name.redact(secrets))
             .attr("time", &time_in_seconds);
 
+        if self.skipped {
+            element = element.add_child(Element::new("skipped"));
+        }
+
+        if !self.properties.is_empty() {
+            let mut properties = Element::new("properties");
+            for (name, value) in self.properties.iter() {
+                let property = Element::new("property")
+                    .attr("name", name)
+                    .attr("value", &value.redact(secrets));
+                properties = properties.add_child(property);
+            }
+            element = element.add_child(properties);
+        }
+
         for failure in self.failures.iter() {
             let failure = failure.redact(secrets);
             element = element.add_child(Element::new("failure").text(&failure));
@@ -80,6 +178,11 @@ impl Testcase {
             let error = error.redact(secrets);
             element = element.add_child(Element::new("error").text(&error));
         }
+
+        if let Some(system_out) = &self.system_out {
+            let system_out = system_out.redact(secrets);
+            element = element.add_child(Element::new("system-out").text(&system_out));
+        }
         element
     }
 
@@ -90,20 +193,66 @@ impl Testcase {
     pub fn get_fail_count(&self) -> usize {
         self.failures.len()
     }
+
+    pub fn is_skipped(&self) -> bool {
+        self.skipped
+    }
+}
+
+/// Returns the message of a runner `error` of an entry, without colors.
+fn error_message(
+    error: &RunnerError,
+    entry_src_info: SourceInfo,
+    filename: &str,
+    content: &str,
+) -> String {
+    error.to_string(
+        filename,
+        content,
+        Some(entry_src_info),
+        OutputFormat::Terminal(false),
+    )
+}
+
+/// Returns an excerpt of the last HTTP request and response of an `entry_result`.
+fn system_out(entry_result: &EntryResult) -> Option<String> {
+    let Call {
+        request, response, ..
+    } = entry_result.calls.last()?;
+    let mut lines = vec![format!("{} {}", request.method, request.url)];
+    lines.extend(request.headers.iter().map(|h| h.to_string()));
+    lines.push(String::new());
+    lines.push(format!("{} {}", response.version, response.status));
+    lines.extend(response.headers.iter().map(|h| h.to_string()));
+    if let Ok(text) = response.text() {
+        if !text.is_empty() {
+            lines.push(String::new());
+            if text.chars().count() > MAX_BODY_EXCERPT_LEN {
+                let excerpt = text.chars().take(MAX_BODY_EXCERPT_LEN).collect::<String>();
+                lines.push(format!("{excerpt}..."));
+            } else {
+                lines.push(text);
+            }
+        }
+    }
+    Some(lines.join("\n"))
 }
 
 #[cfg(test)]
 mod test {
+    use std::str::FromStr;
     use std::time::Duration;
 
     use hurl_core::ast::SourceInfo;
     use hurl_core::input::Input;
     use hurl_core::reader::Pos;
 
-    use crate::http::HttpError;
+    use crate::http::{Call, HeaderVec, HttpError, HttpVersion, Request, Response, Timings, Url};
     use crate::report::junit::testcase::Testcase;
     use crate::report::junit::xml::XmlDocument;
-    use crate::runner::{EntryResult, HurlResult, RunnerError, RunnerErrorKind};
+    use crate::runner::{
+        CaptureResult, EntryResult, HurlResult, RunnerError, RunnerErrorKind, Value,
+    };
 
     #[test]
     fn test_create_testcase_success() {
@@ -163,6 +312,143 @@ HTTP/1.0 200
         );
     }
 
+    #[test]
+    fn test_create_testcases_from_entries() {
+        let content = r#"GET http://localhost:8000/hello
+HTTP 200
+[Captures]
+token: body
+
+GET http://localhost:8000/{{path}}
+HTTP 200
+
+GET http://localhost:8000/skipped
+"#;
+        let filename = Input::new("test.hurl");
+        let secrets = ["s3cr3t"];
+        let call = Call {
+            request: Request {
+                url: Url::from_str("http://localhost:8000/hello").unwrap(),
+                method: "GET".to_string(),
+                headers: HeaderVec::new(),
+                body: vec![],
+            },
+            response: Response {
+                version: HttpVersion::Http11,
+                status: 200,
+                headers: HeaderVec::new(),
+                body: b"s3cr3t".to_vec(),
+                duration: Duration::from_millis(120),
+                url: Url::from_str("http://localhost:8000/hello").unwrap(),
+                certificate: None,
+                ip_addr: Default::default(),
+                messages: vec![],
+            },
+            timings: Timings {
+                total: Duration::from_millis(120),
+                ..Default::default()
+            },
+        };
+        // Entries are named after the URL actually called.
+        let mut world_call = call.clone();
+        world_call.request.url = Url::from_str("http://localhost:8000/world").unwrap();
+        world_call.response.status = 404;
+        let assert_error = RunnerError::new(
+            SourceInfo::new(Pos::new(7, 6), Pos::new(7, 9)),
+            RunnerErrorKind::AssertStatus {
+                actual: "404".to_string(),
+            },
+            true,
+        );
+        let hurl_result = HurlResult {
+            entries: vec![
+                EntryResult {
+                    entry_index: 1,
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 32)),
+                    calls: vec![call],
+                    captures: vec![CaptureResult {
+                        name: "token".to_string(),
+                        value: Value::String("s3cr3t".to_string()),
+                    }],
+                    ..Default::default()
+                },
+                // The second entry has been retried once.
+                EntryResult {
+                    entry_index: 2,
+                    source_info: SourceInfo::new(Pos::new(6, 1), Pos::new(6, 35)),
+                    calls: vec![world_call.clone()],
+                    errors: vec![assert_error.clone()],
+                    ..Default::default()
+                },
+                EntryResult {
+                    entry_index: 2,
+                    source_info: SourceInfo::new(Pos::new(6, 1), Pos::new(6, 35)),
+                    calls: vec![world_call],
+                    errors: vec![assert_error],
+                    ..Default::default()
+                },
+            ],
+            success: false,
+            ..Default::default()
+        };
+
+        let testcases = Testcase::from_entries(&hurl_result, content, &filename);
+        assert_eq!(testcases.len(), 3);
+        let xml = testcases
+            .iter()
+            .map(|t| XmlDocument::new(t.to_xml(&secrets)).to_string().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            xml[0],
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl:1" classname="test.hurl" name="GET http://localhost:8000/hello" time="0.120"><properties><property name="token" value="***" /></properties><system-out>GET http://localhost:8000/hello
+
+HTTP/1.1 200
+
+***</system-out></testcase>"#
+        );
+        assert_eq!(
+            xml[1],
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl:2" classname="test.hurl" name="GET http://localhost:8000/world" time="0.240"><failure>Assert status code
+  --&gt; test.hurl:7:6
+   |
+   | GET http://localhost:8000/{{path}}
+ 7 | HTTP 200
+   |      ^^^ actual value is &lt;404&gt;
+   |</failure><system-out>GET http://localhost:8000/world
+
+HTTP/1.1 404
+
+***</system-out></testcase>"#
+        );
+        assert_eq!(
+            xml[2],
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl:3" classname="test.hurl" name="GET http://localhost:8000/skipped" time="0.000"><skipped /></testcase>"#
+        );
+    }
+
+    #[test]
+    fn test_create_testcases_from_entries_unparsable() {
+        let content = "GET http://localhost:8000/hello\nHTTP 200\n[Asserts\n";
+        let filename = Input::new("test.hurl");
+        let hurl_result = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: 1,
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 32)),
+                ..Default::default()
+            }],
+            duration: Duration::from_millis(50),
+            success: true,
+            ..Default::default()
+        };
+        let testcases = Testcase::from_entries(&hurl_result, content, &filename);
+        assert_eq!(testcases.len(), 1);
+        let doc = XmlDocument::new(testcases[0].to_xml(&[]));
+        assert_eq!(
+            doc.to_string().unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.050" />"#
+        );
+    }
+
     #[test]
     fn test_create_testcase_error() {
         let content = "GET http://unknown";
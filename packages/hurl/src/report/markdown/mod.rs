@@ -0,0 +1,27 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Markdown summary report, to be used in CI job summaries (GitHub, GitLab etc...) or pull
+//! request comments.
+//!
+//! The report includes the totals of the run, a table of the executed files with their status and
+//! duration, and collapsible details for each failure.
+mod report;
+mod testcase;
+
+pub use self::report::write_report;
+pub use self::testcase::Testcase;
@@ -0,0 +1,242 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use super::testcase::Failure;
+use super::Testcase;
+use crate::report::ReportError;
+use crate::util::path::create_dir_all;
+use crate::util::redacted::Redact;
+
+/// Creates a Markdown report from a list of `testcases`.
+///
+/// `secrets` strings are redacted from the produced Markdown. Contrary to the other reports, an
+/// existing Markdown report is overwritten.
+pub fn write_report(
+    filename: &Path,
+    testcases: &[Testcase],
+    secrets: &[&str],
+) -> Result<(), ReportError> {
+    if let Err(err) = create_dir_all(filename) {
+        return Err(ReportError::from_error(
+            err,
+            filename,
+            "Issue writing Markdown report",
+        ));
+    }
+    let mut file = match File::create(filename) {
+        Ok(f) => f,
+        Err(e) => {
+            return Err(ReportError::from_error(
+                e,
+                filename,
+                "Issue writing Markdown report",
+            ))
+        }
+    };
+    let s = create_markdown(testcases).redact(secrets);
+    match file.write_all(s.as_bytes()) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(ReportError::from_error(
+            e,
+            filename,
+            "Issue writing Markdown report",
+        )),
+    }
+}
+
+/// Returns the Markdown summary of a list of `testcases`.
+fn create_markdown(testcases: &[Testcase]) -> String {
+    let total_files = testcases.len();
+    let total_requests = testcases.iter().map(|t| t.requests_count).sum::<usize>();
+    let success_files = testcases.iter().filter(|t| t.success).count();
+    let failed_files = total_files - success_files;
+    let percent = |count: usize| {
+        if total_files == 0 {
+            0.0
+        } else {
+            100.0 * count as f32 / total_files as f32
+        }
+    };
+
+    let mut s = String::new();
+    s.push_str("# Hurl Report\n\n");
+    s.push_str("| Executed files | Executed requests | Succeeded files | Failed files |\n");
+    s.push_str("| ---: | ---: | ---: | ---: |\n");
+    s.push_str(&format!(
+        "| {total_files} | {total_requests} | {success_files} ({:.1}%) | {failed_files} ({:.1}%) |\n",
+        percent(success_files),
+        percent(failed_files),
+    ));
+    s.push('\n');
+
+    s.push_str("| File | Status | Requests | Duration |\n");
+    s.push_str("| --- | --- | ---: | ---: |\n");
+    for testcase in testcases {
+        let status = if testcase.success {
+            "✅ Success"
+        } else {
+            "❌ Failure"
+        };
+        s.push_str(&format!(
+            "| `{}` | {status} | {} | {} ms |\n",
+            escape_table_cell(&testcase.filename),
+            testcase.requests_count,
+            testcase.duration.as_millis(),
+        ));
+    }
+
+    let failures = testcases
+        .iter()
+        .flat_map(|t| &t.failures)
+        .collect::<Vec<_>>();
+    if !failures.is_empty() {
+        s.push_str("\n## Failures\n");
+        for failure in failures {
+            s.push('\n');
+            s.push_str(&failure_details(failure));
+        }
+    }
+    s
+}
+
+/// Returns the collapsible details of a `failure`.
+fn failure_details(failure: &Failure) -> String {
+    let mut s = String::new();
+    s.push_str("<details>\n");
+    s.push_str(&format!(
+        "<summary><code>{}</code> {}</summary>\n\n",
+        escape_html(&failure.location),
+        escape_html(&failure.description),
+    ));
+    s.push_str(&code_block(&failure.message, ""));
+    if let Some(diff) = &failure.diff {
+        s.push('\n');
+        s.push_str(&code_block(diff, "diff"));
+    }
+    s.push_str("\n</details>\n");
+    s
+}
+
+/// Returns a fenced code block of `content`, with a `lang` info string.
+///
+/// The fence is longer than any sequence of backticks in `content`.
+fn code_block(content: &str, lang: &str) -> String {
+    let mut max_backticks = 0;
+    let mut backticks = 0;
+    for c in content.chars() {
+        if c == '`' {
+            backticks += 1;
+            max_backticks = max_backticks.max(backticks);
+        } else {
+            backticks = 0;
+        }
+    }
+    let fence = "`".repeat(max_backticks.max(2) + 1);
+    let content = content.trim_end_matches('\n');
+    format!("{fence}{lang}\n{content}\n{fence}\n")
+}
+
+/// Escapes the pipes of a Markdown table cell `text`.
+fn escape_table_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_create_markdown() {
+        let testcases = vec![
+            Testcase {
+                filename: "tests/hello.hurl".to_string(),
+                success: true,
+                duration: Duration::from_millis(120),
+                requests_count: 2,
+                failures: vec![],
+            },
+            Testcase {
+                filename: "tests/error_body.hurl".to_string(),
+                success: false,
+                duration: Duration::from_millis(30),
+                requests_count: 1,
+                failures: vec![Failure {
+                    location: "tests/error_body.hurl:3:1".to_string(),
+                    description: "Assert body value".to_string(),
+                    message: "Assert body value\n  --> tests/error_body.hurl:3:1\n   |\n 3 | `Hello`\n   |"
+                        .to_string(),
+                    diff: Some("-Hello\n+Goodbye\n".to_string()),
+                }],
+            },
+        ];
+        assert_eq!(
+            create_markdown(&testcases),
+            r#"# Hurl Report
+
+| Executed files | Executed requests | Succeeded files | Failed files |
+| ---: | ---: | ---: | ---: |
+| 2 | 3 | 1 (50.0%) | 1 (50.0%) |
+
+| File | Status | Requests | Duration |
+| --- | --- | ---: | ---: |
+| `tests/hello.hurl` | ✅ Success | 2 | 120 ms |
+| `tests/error_body.hurl` | ❌ Failure | 1 | 30 ms |
+
+## Failures
+
+<details>
+<summary><code>tests/error_body.hurl:3:1</code> Assert body value</summary>
+
+```
+Assert body value
+  --> tests/error_body.hurl:3:1
+   |
+ 3 | `Hello`
+   |
+```
+
+```diff
+-Hello
++Goodbye
+```
+
+</details>
+"#
+        );
+    }
+
+    #[test]
+    fn test_code_block() {
+        assert_eq!(code_block("a\n", ""), "```\na\n```\n");
+        assert_eq!(
+            code_block("```\na\n```", "hurl"),
+            "````hurl\n```\na\n```\n````\n"
+        );
+    }
+}
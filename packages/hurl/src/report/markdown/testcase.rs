@@ -0,0 +1,93 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::time::Duration;
+
+use hurl_core::error::{DisplaySourceError, OutputFormat};
+use hurl_core::input::Input;
+
+use crate::runner::{HurlResult, RunnerErrorKind};
+
+/// A Hurl file run, as displayed in the Markdown report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Testcase {
+    pub(crate) filename: String,
+    pub(crate) success: bool,
+    pub(crate) duration: Duration,
+    /// Number of executed HTTP requests (including retries and redirections).
+    pub(crate) requests_count: usize,
+    pub(crate) failures: Vec<Failure>,
+}
+
+/// An effective error of a Hurl file run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Failure {
+    /// Position of the error in the Hurl file, as `filename:line:column`.
+    pub(crate) location: String,
+    /// Short description of the error (ex: "Assert status code").
+    pub(crate) description: String,
+    /// Error message, with the source excerpt and the expected / actual values.
+    pub(crate) message: String,
+    /// All the diff hunks of a body assert failure.
+    pub(crate) diff: Option<String>,
+}
+
+impl Testcase {
+    /// Creates a Markdown testcase from an [`HurlResult`].
+    pub fn from(hurl_result: &HurlResult, content: &str, filename: &Input) -> Testcase {
+        let filename = filename.to_string();
+        let requests_count = hurl_result.entries.iter().map(|e| e.calls.len()).sum();
+        let failures = hurl_result
+            .errors()
+            .into_iter()
+            .map(|(error, entry_src_info)| {
+                let pos = error.source_info.start;
+                let location = format!("{filename}:{}:{}", pos.line, pos.column);
+                let message = error.to_string(
+                    &filename,
+                    content,
+                    Some(entry_src_info),
+                    OutputFormat::Terminal(false),
+                );
+                // Only the first hunk is displayed in the error message, the whole diff is kept.
+                let diff = match &error.kind {
+                    RunnerErrorKind::AssertBodyDiffError { hunks, .. } => Some(
+                        hunks
+                            .iter()
+                            .map(|h| h.content.to_string(hurl_core::text::Format::Plain))
+                            .collect::<Vec<_>>()
+                            .join(""),
+                    ),
+                    _ => None,
+                };
+                Failure {
+                    location,
+                    description: error.description(),
+                    message,
+                    diff,
+                }
+            })
+            .collect();
+        Testcase {
+            filename,
+            success: hurl_result.success,
+            duration: hurl_result.duration,
+            requests_count,
+            failures,
+        }
+    }
+}
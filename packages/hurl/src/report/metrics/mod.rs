@@ -0,0 +1,26 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Metrics of a run in OpenMetrics text format, that can be scraped by Prometheus (with the node
+//! exporter textfile collector for instance).
+//!
+//! See <https://github.com/prometheus/OpenMetrics/blob/main/specification/OpenMetrics.md>.
+mod report;
+mod testcase;
+
+pub use self::report::write_report;
+pub use self::testcase::Testcase;
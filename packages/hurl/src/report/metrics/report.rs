@@ -0,0 +1,415 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use super::Testcase;
+use crate::http::Timings;
+use crate::report::ReportError;
+use crate::util::path::create_dir_all;
+use crate::util::redacted::Redact;
+
+/// Upper bounds (in seconds) of the request duration histogram buckets, these are the default
+/// Prometheus buckets.
+const DURATION_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Creates a metrics report in OpenMetrics text format from a list of `testcases`.
+///
+/// The runs of a same Hurl file (with `--repeat` for instance) are aggregated: gauges are
+/// the values of the last run, while counters and histograms are computed over all the runs.
+/// `secrets` strings are redacted from the labels values. An existing report file is overwritten.
+pub fn write_report(
+    filename: &Path,
+    testcases: &[Testcase],
+    secrets: &[&str],
+) -> Result<(), ReportError> {
+    if let Err(err) = create_dir_all(filename) {
+        return Err(ReportError::from_error(
+            err,
+            filename,
+            "Issue writing metrics report",
+        ));
+    }
+    let mut file = match File::create(filename) {
+        Ok(f) => f,
+        Err(e) => {
+            return Err(ReportError::from_error(
+                e,
+                filename,
+                "Issue writing metrics report",
+            ))
+        }
+    };
+    let s = metrics(testcases, secrets);
+    match file.write_all(s.as_bytes()) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(ReportError::from_error(
+            e,
+            filename,
+            "Issue writing metrics report",
+        )),
+    }
+}
+
+/// Returns the metrics of `testcases` in OpenMetrics text format.
+///
+/// In OpenMetrics, all the samples of a metric family must be contiguous, so each family is
+/// written in turn for all the Hurl files.
+fn metrics(testcases: &[Testcase], secrets: &[&str]) -> String {
+    let files = group_by_file(testcases, secrets);
+    let mut s = String::new();
+
+    metadata(
+        &mut s,
+        "hurl_file_success",
+        "gauge",
+        None,
+        "Success of the last run of a Hurl file (1 if successful, 0 otherwise).",
+    );
+    for (file, runs) in &files {
+        let success = runs.last().is_some_and(|t| t.success);
+        sample(
+            &mut s,
+            "hurl_file_success",
+            &[("file", file)],
+            success as u8,
+        );
+    }
+
+    metadata(
+        &mut s,
+        "hurl_entry_success",
+        "gauge",
+        None,
+        "Success of the last run of an entry (1 if successful, 0 otherwise).",
+    );
+    for (file, runs) in &files {
+        let Some(last) = runs.last() else {
+            continue;
+        };
+        let mut entries = last.entries.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|e| e.index);
+        for entry in entries {
+            let index = entry.index.to_string();
+            let labels = [("file", file.as_str()), ("entry", &index)];
+            sample(&mut s, "hurl_entry_success", &labels, entry.success as u8);
+        }
+    }
+
+    metadata(
+        &mut s,
+        "hurl_assert_failures",
+        "counter",
+        None,
+        "Number of assert failures of an entry.",
+    );
+    for (file, runs) in &files {
+        let mut failures = BTreeMap::new();
+        for entry in runs.iter().flat_map(|t| t.entries.iter()) {
+            *failures.entry(entry.index).or_insert(0) += entry.assert_failures;
+        }
+        for (index, count) in failures {
+            let index = index.to_string();
+            let labels = [("file", file.as_str()), ("entry", &index)];
+            sample(&mut s, "hurl_assert_failures_total", &labels, count);
+        }
+    }
+
+    metadata(
+        &mut s,
+        "hurl_request_duration_seconds",
+        "histogram",
+        Some("seconds"),
+        "Duration of the HTTP requests from the start of the transfer to the end of each phase.",
+    );
+    for (file, runs) in &files {
+        for (index, (phase, _)) in phases(&Timings::default()).into_iter().enumerate() {
+            // A phase that has not been run (a name lookup on a reused connection, a TLS handshake
+            // without TLS...) has a zero duration and is not observed.
+            let observations = runs
+                .iter()
+                .flat_map(|t| t.timings.iter())
+                .map(|t| phases(t)[index].1)
+                .filter(|d| !d.is_zero())
+                .map(|d| d.as_secs_f64())
+                .collect::<Vec<_>>();
+            histogram(
+                &mut s,
+                "hurl_request_duration_seconds",
+                &[("file", file), ("phase", phase)],
+                &observations,
+            );
+        }
+    }
+
+    metadata(
+        &mut s,
+        "hurl_certificate_expiry_timestamp_seconds",
+        "gauge",
+        Some("seconds"),
+        "Expiration date of the server certificate, in seconds since the UNIX epoch.",
+    );
+    for (file, runs) in &files {
+        let mut certificates: Vec<(String, String, i64)> = vec![];
+        for (host, certificate) in runs.iter().flat_map(|t| t.certificates.iter()) {
+            let host = host.redact(secrets);
+            let subject = certificate.subject.redact(secrets);
+            let expiry = certificate.expire_date.timestamp();
+            // We keep the last certificate received from a host.
+            match certificates
+                .iter_mut()
+                .find(|(h, s, _)| *h == host && *s == subject)
+            {
+                Some(c) => c.2 = expiry,
+                None => certificates.push((host, subject, expiry)),
+            }
+        }
+        for (host, subject, expiry) in certificates {
+            let labels = [
+                ("file", file.as_str()),
+                ("host", &host),
+                ("subject", &subject),
+            ];
+            sample(
+                &mut s,
+                "hurl_certificate_expiry_timestamp_seconds",
+                &labels,
+                expiry,
+            );
+        }
+    }
+
+    s.push_str("# EOF\n");
+    s
+}
+
+/// Returns the durations of the phases of an HTTP transfer, from the start of the transfer.
+///
+/// Phases are named after the libcurl timings, see <https://hurl.dev/docs/response.html#timings>.
+fn phases(timings: &Timings) -> [(&'static str, Duration); 6] {
+    [
+        ("name_lookup", timings.name_lookup),
+        ("connect", timings.connect),
+        ("app_connect", timings.app_connect),
+        ("pre_transfer", timings.pre_transfer),
+        ("start_transfer", timings.start_transfer),
+        ("total", timings.total),
+    ]
+}
+
+/// Groups `testcases` by Hurl file, in the order of their first run, with redacted file names.
+fn group_by_file<'a>(
+    testcases: &'a [Testcase],
+    secrets: &[&str],
+) -> Vec<(String, Vec<&'a Testcase>)> {
+    let mut files: Vec<(String, Vec<&Testcase>)> = vec![];
+    for testcase in testcases {
+        let file = testcase.filename.redact(secrets);
+        match files.iter_mut().find(|(f, _)| *f == file) {
+            Some((_, runs)) => runs.push(testcase),
+            None => files.push((file, vec![testcase])),
+        }
+    }
+    files
+}
+
+/// Writes the metadata of a metric family `name`.
+fn metadata(s: &mut String, name: &str, kind: &str, unit: Option<&str>, help: &str) {
+    s.push_str(&format!("# HELP {name} {help}\n"));
+    s.push_str(&format!("# TYPE {name} {kind}\n"));
+    if let Some(unit) = unit {
+        s.push_str(&format!("# UNIT {name} {unit}\n"));
+    }
+}
+
+/// Writes the samples of a histogram `name` for a list of `observations`.
+fn histogram(s: &mut String, name: &str, labels: &[(&str, &str)], observations: &[f64]) {
+    for bucket in DURATION_BUCKETS {
+        let count = observations.iter().filter(|o| **o <= bucket).count();
+        let le = format!("{bucket:?}");
+        let bucket_labels = [labels, &[("le", le.as_str())]].concat();
+        sample(s, &format!("{name}_bucket"), &bucket_labels, count);
+    }
+    let bucket_labels = [labels, &[("le", "+Inf")]].concat();
+    sample(
+        s,
+        &format!("{name}_bucket"),
+        &bucket_labels,
+        observations.len(),
+    );
+    sample(s, &format!("{name}_count"), labels, observations.len());
+    // The sum of an empty iterator of floats is -0.0, so we start the sum at 0.0.
+    let sum = observations.iter().fold(0.0, |acc, o| acc + o);
+    sample(s, &format!("{name}_sum"), labels, format!("{sum:?}"));
+}
+
+/// Writes a sample of the metric `name`.
+fn sample(s: &mut String, name: &str, labels: &[(&str, &str)], value: impl std::fmt::Display) {
+    let labels = labels
+        .iter()
+        .map(|(name, value)| format!("{name}=\"{}\"", escape_label_value(value)))
+        .collect::<Vec<_>>()
+        .join(",");
+    s.push_str(&format!("{name}{{{labels}}} {value}\n"));
+}
+
+/// Escapes a label value: backslashes, double quotes and line feeds must be escaped.
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use super::*;
+    use crate::http::Certificate;
+    use crate::report::metrics::testcase::Entry;
+
+    #[test]
+    fn test_escape_label_value() {
+        assert_eq!(
+            escape_label_value("C:\\tests\\\"a\"\nb.hurl"),
+            "C:\\\\tests\\\\\\\"a\\\"\\nb.hurl"
+        );
+    }
+
+    #[test]
+    fn test_histogram() {
+        let mut s = String::new();
+        histogram(
+            &mut s,
+            "duration_seconds",
+            &[("phase", "total")],
+            &[0.004, 0.03],
+        );
+        assert_eq!(
+            s,
+            r#"duration_seconds_bucket{phase="total",le="0.005"} 1
+duration_seconds_bucket{phase="total",le="0.01"} 1
+duration_seconds_bucket{phase="total",le="0.025"} 1
+duration_seconds_bucket{phase="total",le="0.05"} 2
+duration_seconds_bucket{phase="total",le="0.1"} 2
+duration_seconds_bucket{phase="total",le="0.25"} 2
+duration_seconds_bucket{phase="total",le="0.5"} 2
+duration_seconds_bucket{phase="total",le="1.0"} 2
+duration_seconds_bucket{phase="total",le="2.5"} 2
+duration_seconds_bucket{phase="total",le="5.0"} 2
+duration_seconds_bucket{phase="total",le="10.0"} 2
+duration_seconds_bucket{phase="total",le="+Inf"} 2
+duration_seconds_count{phase="total"} 2
+duration_seconds_sum{phase="total"} 0.034
+"#
+        );
+
+        let mut s = String::new();
+        histogram(&mut s, "duration_seconds", &[], &[]);
+        assert!(s.ends_with("duration_seconds_count{} 0\nduration_seconds_sum{} 0.0\n"));
+    }
+
+    #[test]
+    fn test_metrics() {
+        let certificate = Certificate {
+            subject: "CN=localhost".to_string(),
+            issuer: "CN=localhost".to_string(),
+            start_date: DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            expire_date: DateTime::from_timestamp(1_800_000_000, 0).unwrap(),
+            serial_number: "01".to_string(),
+        };
+        let timings = Timings {
+            name_lookup: Duration::from_millis(2),
+            connect: Duration::from_millis(3),
+            pre_transfer: Duration::from_millis(4),
+            start_transfer: Duration::from_millis(20),
+            total: Duration::from_millis(30),
+            ..Default::default()
+        };
+        // tests/hello.hurl is run twice: the first run fails, the second one succeeds.
+        let testcases = vec![
+            Testcase {
+                filename: "tests/hello.hurl".to_string(),
+                success: false,
+                entries: vec![Entry {
+                    index: 1,
+                    success: false,
+                    assert_failures: 2,
+                }],
+                timings: vec![timings.clone()],
+                certificates: vec![],
+            },
+            Testcase {
+                filename: "tests/hello.hurl".to_string(),
+                success: true,
+                entries: vec![Entry {
+                    index: 1,
+                    success: true,
+                    assert_failures: 0,
+                }],
+                timings: vec![timings],
+                certificates: vec![("localhost".to_string(), certificate)],
+            },
+        ];
+        let metrics = metrics(&testcases, &[]);
+        let lines = metrics
+            .lines()
+            .filter(|l| !l.contains("_bucket{"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                "# HELP hurl_file_success Success of the last run of a Hurl file (1 if successful, 0 otherwise).",
+                "# TYPE hurl_file_success gauge",
+                r#"hurl_file_success{file="tests/hello.hurl"} 1"#,
+                "# HELP hurl_entry_success Success of the last run of an entry (1 if successful, 0 otherwise).",
+                "# TYPE hurl_entry_success gauge",
+                r#"hurl_entry_success{file="tests/hello.hurl",entry="1"} 1"#,
+                "# HELP hurl_assert_failures Number of assert failures of an entry.",
+                "# TYPE hurl_assert_failures counter",
+                r#"hurl_assert_failures_total{file="tests/hello.hurl",entry="1"} 2"#,
+                "# HELP hurl_request_duration_seconds Duration of the HTTP requests from the start of the transfer to the end of each phase.",
+                "# TYPE hurl_request_duration_seconds histogram",
+                "# UNIT hurl_request_duration_seconds seconds",
+                r#"hurl_request_duration_seconds_count{file="tests/hello.hurl",phase="name_lookup"} 2"#,
+                r#"hurl_request_duration_seconds_sum{file="tests/hello.hurl",phase="name_lookup"} 0.004"#,
+                r#"hurl_request_duration_seconds_count{file="tests/hello.hurl",phase="connect"} 2"#,
+                r#"hurl_request_duration_seconds_sum{file="tests/hello.hurl",phase="connect"} 0.006"#,
+                r#"hurl_request_duration_seconds_count{file="tests/hello.hurl",phase="app_connect"} 0"#,
+                r#"hurl_request_duration_seconds_sum{file="tests/hello.hurl",phase="app_connect"} 0.0"#,
+                r#"hurl_request_duration_seconds_count{file="tests/hello.hurl",phase="pre_transfer"} 2"#,
+                r#"hurl_request_duration_seconds_sum{file="tests/hello.hurl",phase="pre_transfer"} 0.008"#,
+                r#"hurl_request_duration_seconds_count{file="tests/hello.hurl",phase="start_transfer"} 2"#,
+                r#"hurl_request_duration_seconds_sum{file="tests/hello.hurl",phase="start_transfer"} 0.04"#,
+                r#"hurl_request_duration_seconds_count{file="tests/hello.hurl",phase="total"} 2"#,
+                r#"hurl_request_duration_seconds_sum{file="tests/hello.hurl",phase="total"} 0.06"#,
+                "# HELP hurl_certificate_expiry_timestamp_seconds Expiration date of the server certificate, in seconds since the UNIX epoch.",
+                "# TYPE hurl_certificate_expiry_timestamp_seconds gauge",
+                "# UNIT hurl_certificate_expiry_timestamp_seconds seconds",
+                r#"hurl_certificate_expiry_timestamp_seconds{file="tests/hello.hurl",host="localhost",subject="CN=localhost"} 1800000000"#,
+                "# EOF",
+            ]
+        );
+    }
+}
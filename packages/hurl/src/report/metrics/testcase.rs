@@ -0,0 +1,79 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::input::Input;
+
+use crate::http::{Certificate, Timings};
+use crate::runner::HurlResult;
+
+/// A Hurl file run, as exported in the metrics report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Testcase {
+    pub(crate) filename: String,
+    pub(crate) success: bool,
+    /// The executed entries, with the result of their last execution when they have been retried.
+    pub(crate) entries: Vec<Entry>,
+    /// Timings of all the HTTP calls of the run, including redirections and retries.
+    pub(crate) timings: Vec<Timings>,
+    /// Server certificates of the HTTPS calls, with the host of the call.
+    pub(crate) certificates: Vec<(String, Certificate)>,
+}
+
+/// The result of an executed entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Entry {
+    /// 1-based index of the entry in the file.
+    pub(crate) index: usize,
+    pub(crate) success: bool,
+    pub(crate) assert_failures: usize,
+}
+
+impl Testcase {
+    /// Creates a metrics testcase from an [`HurlResult`].
+    pub fn from(hurl_result: &HurlResult, filename: &Input) -> Testcase {
+        let mut entries: Vec<Entry> = vec![];
+        for entry_result in &hurl_result.entries {
+            let entry = Entry {
+                index: entry_result.entry_index,
+                success: entry_result.errors.is_empty(),
+                assert_failures: entry_result.errors.iter().filter(|e| e.assert).count(),
+            };
+            // A retried entry is executed more than once: we only keep its last execution.
+            match entries.iter_mut().find(|e| e.index == entry.index) {
+                Some(e) => *e = entry,
+                None => entries.push(entry),
+            }
+        }
+        let calls = hurl_result.entries.iter().flat_map(|e| e.calls.iter());
+        let timings = calls.clone().map(|c| c.timings.clone()).collect();
+        let certificates = calls
+            .filter_map(|c| {
+                c.response
+                    .certificate
+                    .as_ref()
+                    .map(|cert| (c.request.url.host(), cert.clone()))
+            })
+            .collect();
+        Testcase {
+            filename: filename.to_string(),
+            success: hurl_result.success,
+            entries,
+            timings,
+            certificates,
+        }
+    }
+}
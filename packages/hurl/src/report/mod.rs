@@ -20,10 +20,16 @@
 //! a single unit.
 
 pub mod curl;
+mod entries;
 mod error;
+pub mod har;
 pub mod html;
 pub mod json;
 pub mod junit;
+pub mod markdown;
+pub mod metrics;
+pub mod otlp;
+pub mod sarif;
 pub mod tap;
 
 pub use error::ReportError;
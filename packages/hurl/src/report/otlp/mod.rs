@@ -0,0 +1,30 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! OpenTelemetry trace export of a run, in OTLP-JSON format, that can be sent as is to an
+//! OpenTelemetry collector.
+//!
+//! Each Hurl file is a trace, with a span for the file run. The file span has a child span for
+//! each entry, and each entry span has a child span for each of its HTTP calls (including
+//! redirections and retries).
+//!
+//! See <https://opentelemetry.io/docs/specs/otlp/#json-protobuf-encoding>.
+mod report;
+mod testcase;
+
+pub use self::report::write_report;
+pub use self::testcase::Testcase;
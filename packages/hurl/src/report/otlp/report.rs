@@ -0,0 +1,620 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+
+use super::testcase::Entry;
+use super::Testcase;
+use crate::http::{random_span_id, random_trace_id, Call, TraceParent, TRACEPARENT};
+use crate::report::ReportError;
+use crate::util::path::create_dir_all;
+use crate::util::redacted::Redact;
+
+/// Creates an OpenTelemetry trace report, in OTLP-JSON format, from a list of `testcases`.
+///
+/// `secrets` strings are redacted from the URLs. An existing report file is overwritten.
+pub fn write_report(
+    filename: &Path,
+    testcases: &[Testcase],
+    secrets: &[&str],
+) -> Result<(), ReportError> {
+    if let Err(err) = create_dir_all(filename) {
+        return Err(ReportError::from_error(
+            err,
+            filename,
+            "Issue writing OpenTelemetry report",
+        ));
+    }
+    let mut file = match File::create(filename) {
+        Ok(f) => f,
+        Err(e) => {
+            return Err(ReportError::from_error(
+                e,
+                filename,
+                "Issue writing OpenTelemetry report",
+            ))
+        }
+    };
+    let traces = TracesData::new(testcases, secrets);
+    let s = serde_json::to_string_pretty(&traces)?;
+    let s = format!("{s}\n");
+    match file.write_all(s.as_bytes()) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(ReportError::from_error(
+            e,
+            filename,
+            "Issue writing OpenTelemetry report",
+        )),
+    }
+}
+
+/// These structures represent the OTLP-JSON encoding of traces, see
+/// <https://github.com/open-telemetry/opentelemetry-proto/blob/main/opentelemetry/proto/trace/v1/trace.proto>.
+///
+/// Trace and span ids are encoded in hex, and 64-bit integers (like timestamps) as decimal strings.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TracesData {
+    resource_spans: Vec<ResourceSpans>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResourceSpans {
+    resource: Resource,
+    scope_spans: Vec<ScopeSpans>,
+}
+
+#[derive(Serialize)]
+struct Resource {
+    attributes: Vec<KeyValue>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScopeSpans {
+    scope: Scope,
+    spans: Vec<Span>,
+}
+
+#[derive(Serialize)]
+struct Scope {
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Span {
+    trace_id: String,
+    span_id: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    parent_span_id: String,
+    name: String,
+    kind: SpanKind,
+    start_time_unix_nano: String,
+    end_time_unix_nano: String,
+    attributes: Vec<KeyValue>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    events: Vec<Event>,
+    status: Status,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Event {
+    time_unix_nano: String,
+    name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<KeyValue>,
+}
+
+#[derive(Serialize)]
+struct Status {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    message: String,
+    code: StatusCode,
+}
+
+#[derive(Serialize)]
+struct KeyValue {
+    key: &'static str,
+    value: AnyValue,
+}
+
+#[derive(Serialize)]
+enum AnyValue {
+    #[serde(rename = "stringValue")]
+    String(String),
+    #[serde(rename = "intValue")]
+    Int(String),
+    #[serde(rename = "boolValue")]
+    Bool(bool),
+}
+
+/// Kind of span, serialized as its protobuf enum value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SpanKind {
+    Internal = 1,
+    Client = 3,
+}
+
+/// Status code of a span, serialized as its protobuf enum value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum StatusCode {
+    Unset = 0,
+    Ok = 1,
+    Error = 2,
+}
+
+impl Serialize for SpanKind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+impl Serialize for StatusCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(*self as u8)
+    }
+}
+
+impl KeyValue {
+    fn string(key: &'static str, value: &str) -> KeyValue {
+        KeyValue {
+            key,
+            value: AnyValue::String(value.to_string()),
+        }
+    }
+
+    fn int(key: &'static str, value: usize) -> KeyValue {
+        KeyValue {
+            key,
+            value: AnyValue::Int(value.to_string()),
+        }
+    }
+
+    fn bool(key: &'static str, value: bool) -> KeyValue {
+        KeyValue {
+            key,
+            value: AnyValue::Bool(value),
+        }
+    }
+}
+
+impl TracesData {
+    /// Creates the OpenTelemetry traces of `testcases`, a trace for each testcase.
+    fn new(testcases: &[Testcase], secrets: &[&str]) -> TracesData {
+        let spans = testcases
+            .iter()
+            .flat_map(|t| testcase_spans(t, secrets))
+            .collect();
+        TracesData {
+            resource_spans: vec![ResourceSpans {
+                resource: Resource {
+                    attributes: vec![KeyValue::string("service.name", "hurl")],
+                },
+                scope_spans: vec![ScopeSpans {
+                    scope: Scope {
+                        name: "hurl",
+                        version: env!("CARGO_PKG_VERSION"),
+                    },
+                    spans,
+                }],
+            }],
+        }
+    }
+}
+
+/// Returns the spans of a `testcase`: the file span first, then the spans of each entry.
+fn testcase_spans(testcase: &Testcase, secrets: &[&str]) -> Vec<Span> {
+    // When requests have been sent with a `traceparent` header, we reuse its trace id so the
+    // server spans belong to this trace.
+    let trace_id = testcase
+        .entries
+        .iter()
+        .flat_map(|e| e.calls.iter())
+        .find_map(traceparent)
+        .map(|t| t.trace_id)
+        .unwrap_or_else(random_trace_id);
+    let file_span_id = random_span_id();
+
+    let mut spans = vec![];
+    let mut end = testcase.begin;
+    for entry in &testcase.entries {
+        let (entry_start, entry_end) = entry_interval(entry, end);
+        spans.extend(entry_spans(
+            entry,
+            &trace_id,
+            &file_span_id,
+            entry_start,
+            entry_end,
+            secrets,
+        ));
+        end = end.max(entry_end);
+    }
+    let duration = TimeDelta::from_std(testcase.duration).unwrap_or_default();
+    let end = end.max(testcase.begin + duration);
+
+    let status = if testcase.success {
+        StatusCode::Ok
+    } else {
+        StatusCode::Error
+    };
+    let file_span = Span {
+        trace_id,
+        span_id: file_span_id,
+        parent_span_id: String::new(),
+        name: testcase.filename.clone(),
+        kind: SpanKind::Internal,
+        start_time_unix_nano: unix_nano(testcase.begin),
+        end_time_unix_nano: unix_nano(end),
+        attributes: vec![
+            KeyValue::string("hurl.file", &testcase.filename),
+            KeyValue::bool("hurl.success", testcase.success),
+        ],
+        events: vec![],
+        status: Status {
+            message: String::new(),
+            code: status,
+        },
+    };
+    spans.insert(0, file_span);
+    spans
+}
+
+/// Returns the start and the end of an `entry`, from its first to its last HTTP call.
+///
+/// An entry without HTTP call (because of a runtime error for instance) starts and ends at `begin`.
+fn entry_interval(entry: &Entry, begin: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+    let start = entry
+        .calls
+        .first()
+        .map(|c| c.timings.begin_call)
+        .unwrap_or(begin);
+    let end = entry
+        .calls
+        .last()
+        .map(|c| c.timings.end_call)
+        .unwrap_or(start);
+    (start, end)
+}
+
+/// Returns the spans of an `entry`, running from `start` to `end`: the entry span first, then the
+/// span of each HTTP call.
+fn entry_spans(
+    entry: &Entry,
+    trace_id: &str,
+    parent_span_id: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    secrets: &[&str],
+) -> Vec<Span> {
+    let entry_span_id = random_span_id();
+
+    let mut attributes = vec![
+        KeyValue::int("hurl.entry.index", entry.index),
+        KeyValue::int("hurl.entry.line", entry.line),
+    ];
+    if let Some(call) = entry.calls.first() {
+        attributes.push(KeyValue::string(
+            "http.request.method",
+            &call.request.method,
+        ));
+        attributes.push(KeyValue::string(
+            "url.full",
+            &call.request.url.to_string().redact(secrets),
+        ));
+    }
+    if let Some(call) = entry.calls.last() {
+        attributes.push(KeyValue::int(
+            "http.response.status_code",
+            call.response.status as usize,
+        ));
+    }
+    let assert_failures = entry.errors.iter().filter(|e| e.assert).count();
+    attributes.push(KeyValue::int("hurl.assert.failures", assert_failures));
+
+    // Errors are recorded as exception events, see <https://opentelemetry.io/docs/specs/semconv/exceptions/exceptions-spans/>.
+    let events = entry
+        .errors
+        .iter()
+        .map(|e| Event {
+            time_unix_nano: unix_nano(end),
+            name: "exception".to_string(),
+            attributes: vec![
+                KeyValue::string("exception.type", &e.description),
+                KeyValue::string("exception.message", &e.message),
+                KeyValue::int("hurl.error.line", e.line),
+                KeyValue::bool("hurl.error.assert", e.assert),
+            ],
+        })
+        .collect();
+    let status = match entry.errors.first() {
+        Some(error) => Status {
+            message: error.description.clone(),
+            code: StatusCode::Error,
+        },
+        None => Status {
+            message: String::new(),
+            code: StatusCode::Ok,
+        },
+    };
+
+    let mut spans = vec![Span {
+        trace_id: trace_id.to_string(),
+        span_id: entry_span_id.clone(),
+        parent_span_id: parent_span_id.to_string(),
+        name: format!("Entry {}", entry.index),
+        kind: SpanKind::Internal,
+        start_time_unix_nano: unix_nano(start),
+        end_time_unix_nano: unix_nano(end),
+        attributes,
+        events,
+        status,
+    }];
+    spans.extend(
+        entry
+            .calls
+            .iter()
+            .map(|c| call_span(c, trace_id, &entry_span_id, secrets)),
+    );
+    spans
+}
+
+/// Returns the span of an HTTP `call`, following the OpenTelemetry semantic conventions for HTTP
+/// client spans (see <https://opentelemetry.io/docs/specs/semconv/http/http-spans/>).
+///
+/// If the request has been sent with a `traceparent` header, the span id is the parent id of this
+/// header.
+fn call_span(call: &Call, trace_id: &str, parent_span_id: &str, secrets: &[&str]) -> Span {
+    let span_id = traceparent(call)
+        .filter(|t| t.trace_id == trace_id)
+        .map(|t| t.parent_id)
+        .unwrap_or_else(random_span_id);
+    let request = &call.request;
+    let response = &call.response;
+    let version = response.version.to_string();
+    let mut attributes = vec![
+        KeyValue::string("http.request.method", &request.method),
+        KeyValue::string("url.full", &request.url.to_string().redact(secrets)),
+        KeyValue::int("http.response.status_code", response.status as usize),
+        KeyValue::string(
+            "network.protocol.version",
+            version.trim_start_matches("HTTP/"),
+        ),
+    ];
+    let ip_addr = response.ip_addr.to_string();
+    if !ip_addr.is_empty() {
+        attributes.push(KeyValue::string("network.peer.address", &ip_addr));
+    }
+    // For client spans, 4xx and 5xx responses are errors.
+    let status = if response.status >= 400 {
+        StatusCode::Error
+    } else {
+        StatusCode::Unset
+    };
+    Span {
+        trace_id: trace_id.to_string(),
+        span_id,
+        parent_span_id: parent_span_id.to_string(),
+        name: request.method.clone(),
+        kind: SpanKind::Client,
+        start_time_unix_nano: unix_nano(call.timings.begin_call),
+        end_time_unix_nano: unix_nano(call.timings.end_call),
+        attributes,
+        events: timings_events(call),
+        status: Status {
+            message: String::new(),
+            code: status,
+        },
+    }
+}
+
+/// Returns an event for the end of each phase of the transfer of a `call`.
+///
+/// Events are named after the libcurl timings (see <https://hurl.dev/docs/response.html#timings>).
+/// A phase that has not been run (name lookup on a reused connection, TLS handshake without TLS...)
+/// ends at the start of the call.
+fn timings_events(call: &Call) -> Vec<Event> {
+    let timings = &call.timings;
+    [
+        ("name_lookup", timings.name_lookup),
+        ("connect", timings.connect),
+        ("app_connect", timings.app_connect),
+        ("pre_transfer", timings.pre_transfer),
+        ("start_transfer", timings.start_transfer),
+    ]
+    .into_iter()
+    .map(|(name, duration)| {
+        let duration = TimeDelta::from_std(duration).unwrap_or_default();
+        Event {
+            time_unix_nano: unix_nano(timings.begin_call + duration),
+            name: name.to_string(),
+            attributes: vec![],
+        }
+    })
+    .collect()
+}
+
+/// Returns the trace context of the `traceparent` header sent with this `call`, if any.
+fn traceparent(call: &Call) -> Option<TraceParent> {
+    call.request
+        .headers
+        .get(TRACEPARENT)
+        .and_then(|h| TraceParent::parse(&h.value))
+}
+
+/// Returns a date as a number of nanoseconds since the UNIX epoch, encoded as a decimal string.
+fn unix_nano(date: DateTime<Utc>) -> String {
+    date.timestamp_nanos_opt().unwrap_or_default().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::http::{Header, HeaderVec, HttpVersion, Request, Response, Timings, Url};
+    use crate::report::otlp::testcase::Error;
+
+    fn call(traceparent: Option<&str>, status: u32) -> Call {
+        let mut headers = HeaderVec::new();
+        if let Some(traceparent) = traceparent {
+            headers.push(Header::new(TRACEPARENT, traceparent));
+        }
+        let begin_call = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        Call {
+            request: Request {
+                url: Url::from_str("http://localhost:8000/hello?token=s3cr3t").unwrap(),
+                method: "GET".to_string(),
+                headers,
+                body: vec![],
+            },
+            response: Response {
+                version: HttpVersion::Http11,
+                status,
+                headers: HeaderVec::new(),
+                body: vec![],
+                duration: Duration::from_millis(12),
+                url: Url::from_str("http://localhost:8000/hello?token=s3cr3t").unwrap(),
+                certificate: None,
+                ip_addr: Default::default(),
+                messages: vec![],
+            },
+            timings: Timings {
+                begin_call,
+                end_call: begin_call + TimeDelta::milliseconds(12),
+                pre_transfer: Duration::from_millis(1),
+                start_transfer: Duration::from_millis(10),
+                total: Duration::from_millis(12),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_timings_events() {
+        let events = timings_events(&call(None, 200));
+        let events = events
+            .iter()
+            .map(|e| (e.name.as_str(), e.time_unix_nano.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            vec![
+                ("name_lookup", "1700000000000000000"),
+                ("connect", "1700000000000000000"),
+                ("app_connect", "1700000000000000000"),
+                ("pre_transfer", "1700000000001000000"),
+                ("start_transfer", "1700000000010000000"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_create_traces() {
+        let trace_id = "4bf92f3577b34da6a3ce929d0e0e4736";
+        let testcases = vec![Testcase {
+            filename: "tests/hello.hurl".to_string(),
+            success: false,
+            begin: DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            duration: Duration::from_millis(20),
+            entries: vec![Entry {
+                index: 1,
+                line: 1,
+                calls: vec![call(
+                    Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"),
+                    404,
+                )],
+                errors: vec![Error {
+                    description: "Assert status code".to_string(),
+                    message: "Assert status code\n  --> tests/hello.hurl:2:10".to_string(),
+                    line: 2,
+                    assert: true,
+                }],
+            }],
+        }];
+        let traces = TracesData::new(&testcases, &["s3cr3t"]);
+        let value = serde_json::to_value(&traces).unwrap();
+        let resource_spans = &value["resourceSpans"][0];
+        assert_eq!(
+            resource_spans["resource"]["attributes"][0]["value"]["stringValue"],
+            "hurl"
+        );
+        let spans = resource_spans["scopeSpans"][0]["spans"].as_array().unwrap();
+        assert_eq!(spans.len(), 3);
+
+        // File span
+        let file_span = &spans[0];
+        assert_eq!(file_span["traceId"], trace_id);
+        assert!(file_span.get("parentSpanId").is_none());
+        assert_eq!(file_span["name"], "tests/hello.hurl");
+        assert_eq!(file_span["startTimeUnixNano"], "1700000000000000000");
+        assert_eq!(file_span["endTimeUnixNano"], "1700000000020000000");
+        assert_eq!(file_span["status"], serde_json::json!({ "code": 2 }));
+
+        // Entry span
+        let entry_span = &spans[1];
+        assert_eq!(entry_span["traceId"], trace_id);
+        assert_eq!(entry_span["parentSpanId"], file_span["spanId"]);
+        assert_eq!(entry_span["name"], "Entry 1");
+        assert_eq!(entry_span["kind"], 1);
+        assert_eq!(entry_span["endTimeUnixNano"], "1700000000012000000");
+        assert_eq!(
+            entry_span["attributes"],
+            serde_json::json!([
+                { "key": "hurl.entry.index", "value": { "intValue": "1" } },
+                { "key": "hurl.entry.line", "value": { "intValue": "1" } },
+                { "key": "http.request.method", "value": { "stringValue": "GET" } },
+                { "key": "url.full", "value": { "stringValue": "http://localhost:8000/hello?token=***" } },
+                { "key": "http.response.status_code", "value": { "intValue": "404" } },
+                { "key": "hurl.assert.failures", "value": { "intValue": "1" } }
+            ])
+        );
+        assert_eq!(entry_span["events"][0]["name"], "exception");
+        assert_eq!(
+            entry_span["status"],
+            serde_json::json!({ "message": "Assert status code", "code": 2 })
+        );
+
+        // HTTP call span: its id is the parent id of the `traceparent` header.
+        let call_span = &spans[2];
+        assert_eq!(call_span["traceId"], trace_id);
+        assert_eq!(call_span["spanId"], "00f067aa0ba902b7");
+        assert_eq!(call_span["parentSpanId"], entry_span["spanId"]);
+        assert_eq!(call_span["name"], "GET");
+        assert_eq!(call_span["kind"], 3);
+        assert_eq!(
+            call_span["attributes"],
+            serde_json::json!([
+                { "key": "http.request.method", "value": { "stringValue": "GET" } },
+                { "key": "url.full", "value": { "stringValue": "http://localhost:8000/hello?token=***" } },
+                { "key": "http.response.status_code", "value": { "intValue": "404" } },
+                { "key": "network.protocol.version", "value": { "stringValue": "1.1" } }
+            ])
+        );
+        assert_eq!(call_span["events"].as_array().unwrap().len(), 5);
+        assert_eq!(call_span["status"], serde_json::json!({ "code": 2 }));
+    }
+}
@@ -0,0 +1,115 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use hurl_core::error::{DisplaySourceError, OutputFormat};
+use hurl_core::input::Input;
+
+use crate::http::Call;
+use crate::runner::HurlResult;
+use crate::util::redacted::Redact;
+
+/// A Hurl file run, as exported in the OpenTelemetry report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Testcase {
+    pub(crate) filename: String,
+    pub(crate) success: bool,
+    /// Start of the run.
+    pub(crate) begin: DateTime<Utc>,
+    /// Total duration of the run.
+    pub(crate) duration: Duration,
+    /// The executed entries of the run, an entry being executed more than once when it's retried.
+    pub(crate) entries: Vec<Entry>,
+}
+
+/// An executed entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Entry {
+    /// 1-based index of the entry in the file.
+    pub(crate) index: usize,
+    pub(crate) line: usize,
+    /// All the HTTP calls of the entry, including redirections.
+    pub(crate) calls: Vec<Call>,
+    pub(crate) errors: Vec<Error>,
+}
+
+/// An error of an executed entry, with its messages already redacted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Error {
+    pub(crate) description: String,
+    pub(crate) message: String,
+    pub(crate) line: usize,
+    /// `true` if the error is an assert failure, `false` if it's a runtime error.
+    pub(crate) assert: bool,
+}
+
+impl Testcase {
+    /// Creates an OpenTelemetry testcase from an [`HurlResult`].
+    ///
+    /// `content` is the source of the Hurl file, used to render the error messages, and
+    /// `secrets` strings are redacted from these messages.
+    pub fn from(
+        hurl_result: &HurlResult,
+        content: &str,
+        filename: &Input,
+        secrets: &[&str],
+    ) -> Testcase {
+        let filename = filename.to_string();
+        let entries = hurl_result
+            .entries
+            .iter()
+            .map(|e| {
+                let errors = e
+                    .errors
+                    .iter()
+                    .map(|error| Error {
+                        description: error.description(),
+                        message: error
+                            .to_string(&filename, content, Some(e.source_info), OutputFormat::Plain)
+                            .redact(secrets),
+                        line: error.source_info.start.line,
+                        assert: error.assert,
+                    })
+                    .collect();
+                Entry {
+                    index: e.entry_index,
+                    line: e.source_info.start.line,
+                    calls: e.calls.clone(),
+                    errors,
+                }
+            })
+            .collect::<Vec<_>>();
+        // The run timestamp has only a precision of one second: the first HTTP call gives a more
+        // accurate start.
+        let begin = entries
+            .iter()
+            .flat_map(|e| e.calls.first())
+            .map(|c| c.timings.begin_call)
+            .next()
+            .or(DateTime::from_timestamp(hurl_result.timestamp, 0))
+            .unwrap_or_default();
+        Testcase {
+            filename,
+            success: hurl_result.success,
+            begin,
+            duration: hurl_result.duration,
+            entries,
+        }
+    }
+}
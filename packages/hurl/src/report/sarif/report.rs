@@ -0,0 +1,361 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use hurl_core::ast::SourceInfo;
+use serde::Serialize;
+
+use super::testcase::Issue;
+use super::Testcase;
+use crate::report::ReportError;
+use crate::util::path::create_dir_all;
+use crate::util::redacted::Redact;
+
+/// Creates a SARIF 2.1 report from a list of `testcases`.
+///
+/// `secrets` strings are redacted from the errors messages. An existing SARIF report is
+/// overwritten.
+pub fn write_report(
+    filename: &Path,
+    testcases: &[Testcase],
+    secrets: &[&str],
+) -> Result<(), ReportError> {
+    if let Err(err) = create_dir_all(filename) {
+        return Err(ReportError::from_error(
+            err,
+            filename,
+            "Issue writing SARIF report",
+        ));
+    }
+    let mut file = match File::create(filename) {
+        Ok(f) => f,
+        Err(e) => {
+            return Err(ReportError::from_error(
+                e,
+                filename,
+                "Issue writing SARIF report",
+            ))
+        }
+    };
+    let log = Log::new(testcases, secrets);
+    let s = serde_json::to_string_pretty(&log)?;
+    let s = format!("{s}\n");
+    match file.write_all(s.as_bytes()) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(ReportError::from_error(
+            e,
+            filename,
+            "Issue writing SARIF report",
+        )),
+    }
+}
+
+/// Root object of a SARIF 2.1 file, see <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>.
+///
+/// Only the subset of the format needed to locate errors in Hurl files is implemented.
+#[derive(Serialize)]
+struct Log {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+struct Run {
+    tool: Tool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: String,
+    short_description: Message,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: &'static str,
+    message: Message,
+    locations: Vec<Location>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_column: Option<usize>,
+    end_line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_column: Option<usize>,
+}
+
+impl Log {
+    /// Creates a SARIF log with a single run, whose results are the issues of `testcases`.
+    fn new(testcases: &[Testcase], secrets: &[&str]) -> Log {
+        let mut rules: Vec<Rule> = vec![];
+        let mut results = vec![];
+        for testcase in testcases {
+            for issue in &testcase.issues {
+                let rule_id = rule_id(&issue.description);
+                let rule_index = match rules.iter().position(|r| r.id == rule_id) {
+                    Some(index) => index,
+                    None => {
+                        rules.push(Rule {
+                            id: rule_id.clone(),
+                            short_description: Message {
+                                text: issue.description.clone(),
+                            },
+                        });
+                        rules.len() - 1
+                    }
+                };
+                results.push(SarifResult::new(
+                    issue,
+                    &testcase.filename,
+                    rule_id,
+                    rule_index,
+                    secrets,
+                ));
+            }
+        }
+        let driver = Driver {
+            name: "hurl",
+            version: env!("CARGO_PKG_VERSION"),
+            information_uri: "https://hurl.dev",
+            rules,
+        };
+        Log {
+            schema: "https://json.schemastore.org/sarif-2.1.0.json",
+            version: "2.1.0",
+            runs: vec![Run {
+                tool: Tool { driver },
+                results,
+            }],
+        }
+    }
+}
+
+impl SarifResult {
+    fn new(
+        issue: &Issue,
+        filename: &str,
+        rule_id: String,
+        rule_index: usize,
+        secrets: &[&str],
+    ) -> SarifResult {
+        let region = Region::new(issue.source_info);
+        let location = Location {
+            physical_location: PhysicalLocation {
+                artifact_location: ArtifactLocation {
+                    uri: filename.to_string(),
+                },
+                region,
+            },
+        };
+        SarifResult {
+            rule_id,
+            rule_index,
+            level: "error",
+            message: Message {
+                text: issue.message.redact(secrets),
+            },
+            locations: vec![location],
+        }
+    }
+}
+
+impl Region {
+    fn new(source_info: SourceInfo) -> Region {
+        let start = source_info.start;
+        let end = source_info.end;
+        // A column of 0 means that the error spans the whole line (for instance, a failing
+        // predicate): SARIF columns start at 1 so we only use lines for this region.
+        if start.column == 0 {
+            return Region {
+                start_line: start.line,
+                start_column: None,
+                end_line: start.line,
+                end_column: None,
+            };
+        }
+        // An error can be located on a single position (end is equal to start), SARIF regions
+        // must have at least one character.
+        let (end_line, end_column) = if end.line > start.line {
+            (end.line, end.column)
+        } else {
+            (start.line, end.column.max(start.column + 1))
+        };
+        Region {
+            start_line: start.line,
+            start_column: Some(start.column),
+            end_line,
+            end_column: Some(end_column),
+        }
+    }
+}
+
+/// Returns a SARIF rule identifier from an error description (ex: "Assert status code" gives
+/// "assert-status-code").
+fn rule_id(description: &str) -> String {
+    description
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::reader::Pos;
+
+    use super::*;
+
+    #[test]
+    fn test_rule_id() {
+        assert_eq!(rule_id("Assert status code"), "assert-status-code");
+        assert_eq!(rule_id("Assert HTTP version"), "assert-http-version");
+        assert_eq!(rule_id("Parsing method"), "parsing-method");
+    }
+
+    #[test]
+    fn test_region() {
+        let region = Region::new(SourceInfo::new(Pos::new(4, 11), Pos::new(4, 11)));
+        assert_eq!(
+            (region.start_column, region.end_line, region.end_column),
+            (Some(11), 4, Some(12))
+        );
+        let region = Region::new(SourceInfo::new(Pos::new(2, 5), Pos::new(4, 2)));
+        assert_eq!(
+            (region.start_column, region.end_line, region.end_column),
+            (Some(5), 4, Some(2))
+        );
+    }
+
+    #[test]
+    fn test_create_sarif() {
+        let testcases = vec![
+            Testcase {
+                filename: "tests/hello.hurl".to_string(),
+                issues: vec![],
+            },
+            Testcase {
+                filename: "tests/login.hurl".to_string(),
+                issues: vec![
+                    Issue {
+                        description: "Assert status code".to_string(),
+                        message: "Assert status code\n  --> tests/login.hurl:4:6\n".to_string(),
+                        source_info: SourceInfo::new(Pos::new(4, 6), Pos::new(4, 9)),
+                    },
+                    Issue {
+                        description: "Assert status code".to_string(),
+                        message: "token 12345 not found".to_string(),
+                        source_info: SourceInfo::new(Pos::new(9, 0), Pos::new(9, 0)),
+                    },
+                ],
+            },
+        ];
+        let log = Log::new(&testcases, &["12345"]);
+        let value = serde_json::to_value(&log).unwrap();
+        assert_eq!(value["version"], "2.1.0");
+
+        let run = &value["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "hurl");
+        assert_eq!(
+            run["tool"]["driver"]["rules"],
+            serde_json::json!([{
+                "id": "assert-status-code",
+                "shortDescription": { "text": "Assert status code" }
+            }])
+        );
+        assert_eq!(
+            run["results"],
+            serde_json::json!([
+                {
+                    "ruleId": "assert-status-code",
+                    "ruleIndex": 0,
+                    "level": "error",
+                    "message": { "text": "Assert status code\n  --> tests/login.hurl:4:6\n" },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": "tests/login.hurl" },
+                            "region": { "startLine": 4, "startColumn": 6, "endLine": 4, "endColumn": 9 }
+                        }
+                    }]
+                },
+                {
+                    "ruleId": "assert-status-code",
+                    "ruleIndex": 0,
+                    "level": "error",
+                    "message": { "text": "token *** not found" },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": "tests/login.hurl" },
+                            "region": { "startLine": 9, "endLine": 9 }
+                        }
+                    }]
+                }
+            ])
+        );
+    }
+}
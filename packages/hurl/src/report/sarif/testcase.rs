@@ -0,0 +1,79 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+use hurl_core::error::{DisplaySourceError, OutputFormat};
+use hurl_core::input::Input;
+use hurl_core::parser::ParseError;
+
+use crate::runner::HurlResult;
+
+/// A Hurl file run, with the errors reported in the SARIF report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Testcase {
+    pub(crate) filename: String,
+    pub(crate) issues: Vec<Issue>,
+}
+
+/// An error of a Hurl file, as a SARIF result.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Issue {
+    /// Short description of the error (ex: "Assert status code").
+    pub(crate) description: String,
+    /// Error message, with the source excerpt and the expected / actual values.
+    pub(crate) message: String,
+    /// Position of the error in the Hurl file.
+    pub(crate) source_info: SourceInfo,
+}
+
+impl Testcase {
+    /// Creates a SARIF testcase from the effective errors of an [`HurlResult`].
+    pub fn from(hurl_result: &HurlResult, content: &str, filename: &Input) -> Testcase {
+        let filename = filename.to_string();
+        let issues = hurl_result
+            .errors()
+            .into_iter()
+            .map(|(error, entry_src_info)| {
+                Issue::new(error, &filename, content, Some(entry_src_info))
+            })
+            .collect();
+        Testcase { filename, issues }
+    }
+
+    /// Creates a SARIF testcase from the parsing `error` of a Hurl file.
+    pub fn from_parsing_error(error: &ParseError, content: &str, filename: &Input) -> Testcase {
+        let filename = filename.to_string();
+        let issues = vec![Issue::new(error, &filename, content, None)];
+        Testcase { filename, issues }
+    }
+}
+
+impl Issue {
+    fn new<E: DisplaySourceError>(
+        error: &E,
+        filename: &str,
+        content: &str,
+        entry_src_info: Option<SourceInfo>,
+    ) -> Issue {
+        let message = error.to_string(filename, content, entry_src_info, OutputFormat::Plain);
+        Issue {
+            description: error.description(),
+            message,
+            source_info: error.source_info(),
+        }
+    }
+}
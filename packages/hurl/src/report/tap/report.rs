@@ -22,12 +22,17 @@ use std::path::Path;
 
 use regex::Regex;
 
+use super::testcase::{escape_description, SUBTEST_INDENT};
 use super::Testcase;
 use crate::report::ReportError;
 use crate::util::path::create_dir_all;
 
+/// See <https://testanything.org/tap-version-14-specification.html>
+const TAP_REPORT_VERSION_MARKER: &str = "TAP version 14";
+
+/// Reports created by older Hurl versions, without subtests, can still be appended.
 /// See <https://testanything.org/tap-version-13-specification.html>
-const TAP_REPORT_VERSION_MARKER: &str = "TAP version 13";
+const TAP_13_REPORT_VERSION_MARKER: &str = "TAP version 13";
 
 /// Creates/Append a Tap report from a list of `testcases`
 pub fn write_report(filename: &Path, testcases: &[Testcase]) -> Result<(), ReportError> {
@@ -62,6 +67,19 @@ fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), Report
             ))
         }
     };
+    let s = tap_report(testcases);
+    match file.write_all(s.as_bytes()) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(ReportError::from_error(
+            e,
+            filename,
+            "Issue writing TAP report",
+        )),
+    }
+}
+
+/// Returns the TAP report of a list of `testcases`.
+fn tap_report(testcases: &[&Testcase]) -> String {
     let start = 1;
     let end = testcases.len();
 
@@ -71,17 +89,17 @@ fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), Report
     for (i, testcase) in testcases.iter().enumerate() {
         let state = if testcase.success { "ok" } else { "not ok" };
         let number = i + 1;
-        let description = &testcase.description;
+        let description = escape_description(&testcase.description);
+        // The subtest is written before its parent test point.
+        if !testcase.subtest.is_empty() {
+            s.push_str(format!("# Subtest: {description}\n").as_str());
+            for line in &testcase.subtest {
+                s.push_str(format!("{SUBTEST_INDENT}{line}\n").as_str());
+            }
+        }
         s.push_str(format!("{state} {number} - {description}\n").as_str());
     }
-    match file.write_all(s.as_bytes()) {
-        Ok(_) => Ok(()),
-        Err(e) => Err(ReportError::from_error(
-            e,
-            filename,
-            "Issue writing TAP report",
-        )),
-    }
+    s
 }
 
 /// Parse Tap report file
@@ -109,7 +127,9 @@ fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, ReportError> {
     if !lines.is_empty() {
         let mut header = lines.remove(0);
         // A tap report may have a protocol version header as per TAP
-        if header.eq_ignore_ascii_case(TAP_REPORT_VERSION_MARKER) {
+        if header.eq_ignore_ascii_case(TAP_REPORT_VERSION_MARKER)
+            || header.eq_ignore_ascii_case(TAP_13_REPORT_VERSION_MARKER)
+        {
             header = lines.remove(0);
         }
         let re = Regex::new(r"^1\.\.\d+.*$").unwrap();
@@ -118,12 +138,20 @@ fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, ReportError> {
                 "Invalid TAP Header <{header}>"
             )));
         }
+        // Indented lines are the subtest of the next test point.
+        let mut subtest = vec![];
         for line in lines {
+            if let Some(line) = line.strip_prefix(SUBTEST_INDENT) {
+                subtest.push(line.to_string());
+                continue;
+            }
             let line = line.trim();
-            if !line.is_empty() {
-                let testcase = Testcase::parse(line)?;
-                testcases.push(testcase);
+            if line.is_empty() || line.starts_with('#') {
+                continue;
             }
+            let mut testcase = Testcase::parse(line)?;
+            testcase.subtest = std::mem::take(&mut subtest);
+            testcases.push(testcase);
         }
     }
     Ok(testcases)
@@ -146,15 +174,18 @@ not ok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    subtest: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    subtest: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    subtest: vec![],
                 }
             ]
         );
@@ -174,15 +205,18 @@ not ok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    subtest: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    subtest: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    subtest: vec![],
                 }
             ]
         );
@@ -200,28 +234,96 @@ ok 5 - test.5.hurl
             vec![
                 Testcase {
                     description: "test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    subtest: vec![],
                 },
                 Testcase {
                     description: "test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    subtest: vec![],
                 },
                 Testcase {
                     description: "test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    subtest: vec![],
                 },
                 Testcase {
                     description: "test.4.hurl".to_string(),
-                    success: false
+                    success: false,
+                    subtest: vec![],
                 },
                 Testcase {
                     description: "test.5.hurl".to_string(),
-                    success: true
+                    success: true,
+                    subtest: vec![],
                 }
             ]
         );
     }
 
+    #[test]
+    fn test_parse_tap_report_with_subtests() {
+        let s = r#"TAP version 14
+1..2
+# Subtest: tests_ok/test.1.hurl
+    1..1
+    ok 1 - GET http://localhost:8000/hello
+ok 1 - tests_ok/test.1.hurl
+# Subtest: tests_ok/test.2.hurl
+    1..2
+    ok 1 - GET http://localhost:8000/hello
+    not ok 2 - GET http://localhost:8000/hello
+      ---
+      message: |
+        Assert status code
+      severity: fail
+      at:
+        file: "tests_ok/test.2.hurl"
+        line: 9
+        column: 6
+      found: "404"
+      ...
+not ok 2 - tests_ok/test.2.hurl
+"#;
+        let testcases = parse_tap_report(s).unwrap();
+        assert_eq!(
+            testcases,
+            vec![
+                Testcase {
+                    description: "tests_ok/test.1.hurl".to_string(),
+                    success: true,
+                    subtest: vec![
+                        "1..1".to_string(),
+                        "ok 1 - GET http://localhost:8000/hello".to_string(),
+                    ],
+                },
+                Testcase {
+                    description: "tests_ok/test.2.hurl".to_string(),
+                    success: false,
+                    subtest: vec![
+                        "1..2".to_string(),
+                        "ok 1 - GET http://localhost:8000/hello".to_string(),
+                        "not ok 2 - GET http://localhost:8000/hello".to_string(),
+                        "  ---".to_string(),
+                        "  message: |".to_string(),
+                        "    Assert status code".to_string(),
+                        "  severity: fail".to_string(),
+                        "  at:".to_string(),
+                        "    file: \"tests_ok/test.2.hurl\"".to_string(),
+                        "    line: 9".to_string(),
+                        "    column: 6".to_string(),
+                        "  found: \"404\"".to_string(),
+                        "  ...".to_string(),
+                    ],
+                }
+            ]
+        );
+
+        // Appending testcases rewrites the subtests as is.
+        let testcases = testcases.iter().collect::<Vec<_>>();
+        assert_eq!(tap_report(&testcases), s);
+    }
+
     #[test]
     fn test_parse_error() {
         let s = r#"Dummy header
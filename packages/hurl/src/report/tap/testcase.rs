@@ -15,25 +15,47 @@
  * limitations under the License.
  *
  */
+use hurl_core::ast::SourceInfo;
+use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::input::Input;
 
+use crate::report::entries::report_entries;
 use crate::report::ReportError;
-use crate::runner::HurlResult;
+use crate::runner::{HurlResult, RunnerError, RunnerErrorKind};
+use crate::util::redacted::Redact;
+
+/// Indentation of the lines of a subtest.
+///
+/// See <https://testanything.org/tap-version-14-specification.html#subtests>
+pub(crate) const SUBTEST_INDENT: &str = "    ";
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Testcase {
     pub(crate) description: String,
     pub(crate) success: bool,
+    /// Lines of the subtest of this testcase, with one test point per entry, without indentation.
+    /// Testcases read from a TAP version 13 report have no subtest.
+    pub(crate) subtest: Vec<String>,
 }
 
 impl Testcase {
     /// Creates an Tap &lt;testcase&gt; from an [`HurlResult`].
-    pub fn from(hurl_result: &HurlResult, filename: &Input) -> Testcase {
+    ///
+    /// Each entry of the Hurl file `content` is a test point of the testcase subtest, failing entries
+    /// being described by a YAML diagnostic block. `secrets` strings are redacted from the subtest.
+    pub fn from(
+        hurl_result: &HurlResult,
+        content: &str,
+        filename: &Input,
+        secrets: &[&str],
+    ) -> Testcase {
         let description = filename.to_string();
         let success = hurl_result.errors().is_empty();
+        let subtest = subtest_lines(hurl_result, content, &description, secrets);
         Testcase {
             description,
             success,
+            subtest,
         }
     }
 
@@ -66,16 +88,121 @@ impl Testcase {
                         "Invalid TAP line <{line}> - missing test number"
                     )));
                 }
-                line.split_at(index).1[1..].trim().to_string()
+                unescape_description(line.split_at(index).1[1..].trim())
             }
         };
         Ok(Testcase {
             description,
             success,
+            subtest: vec![],
         })
     }
 }
 
+/// Returns the lines of the subtest of a Hurl file `content`: a plan followed by a test point for
+/// each entry.
+///
+/// Only the last run of an entry is reported (previous runs have been retried), entries that have
+/// not been run are skipped.
+fn subtest_lines(
+    hurl_result: &HurlResult,
+    content: &str,
+    filename: &str,
+    secrets: &[&str],
+) -> Vec<String> {
+    let Some(entries) = report_entries(hurl_result, content) else {
+        return vec![];
+    };
+    let mut lines = vec![format!("1..{}", entries.len())];
+    for entry in entries {
+        let number = entry.index;
+        let description = escape_description(&entry.name.redact(secrets));
+        let Some(last) = entry.results.last() else {
+            lines.push(format!("ok {number} - {description} # SKIP not run"));
+            continue;
+        };
+        match last.errors.first() {
+            None => lines.push(format!("ok {number} - {description}")),
+            Some(error) => {
+                lines.push(format!("not ok {number} - {description}"));
+                let diagnostic =
+                    diagnostic_lines(error, last.source_info, filename, content, secrets);
+                lines.extend(diagnostic);
+            }
+        }
+    }
+    lines
+}
+
+/// Returns the YAML diagnostic block describing the runtime `error` of a test point.
+///
+/// See <https://testanything.org/tap-version-14-specification.html#yaml-diagnostics>
+fn diagnostic_lines(
+    error: &RunnerError,
+    entry_src_info: SourceInfo,
+    filename: &str,
+    content: &str,
+    secrets: &[&str],
+) -> Vec<String> {
+    let message = error
+        .to_string(filename, content, Some(entry_src_info), OutputFormat::Plain)
+        .redact(secrets);
+    let severity = if error.assert { "fail" } else { "error" };
+    let mut lines = vec!["  ---".to_string(), "  message: |".to_string()];
+    for line in message.lines() {
+        lines.push(format!("    {line}"));
+    }
+    lines.push(format!("  severity: {severity}"));
+    lines.push("  at:".to_string());
+    lines.push(format!("    file: {}", yaml_string(filename)));
+    lines.push(format!("    line: {}", error.source_info.start.line));
+    // A column of 0 means the whole line.
+    let column = error.source_info.start.column;
+    if column > 0 {
+        lines.push(format!("    column: {column}"));
+    }
+    let (found, wanted) = found_wanted(&error.kind);
+    if let Some(found) = found {
+        let found = found.to_string().redact(secrets);
+        lines.push(format!("  found: {}", yaml_string(&found)));
+    }
+    if let Some(wanted) = wanted {
+        let wanted = wanted.to_string().redact(secrets);
+        lines.push(format!("  wanted: {}", yaml_string(&wanted)));
+    }
+    lines.push("  ...".to_string());
+    lines
+}
+
+/// Returns the actual and expected values of an assert error `kind`.
+fn found_wanted(kind: &RunnerErrorKind) -> (Option<&str>, Option<&str>) {
+    match kind {
+        RunnerErrorKind::AssertBodyValueError { actual, expected }
+        | RunnerErrorKind::AssertFailure {
+            actual, expected, ..
+        } => (Some(actual), Some(expected)),
+        RunnerErrorKind::AssertHeaderValueError { actual }
+        | RunnerErrorKind::AssertStatus { actual }
+        | RunnerErrorKind::AssertVersion { actual } => (Some(actual), None),
+        _ => (None, None),
+    }
+}
+
+/// Returns `s` as a YAML double-quoted scalar (a JSON string is a valid YAML scalar).
+fn yaml_string(s: &str) -> String {
+    serde_json::Value::String(s.to_string()).to_string()
+}
+
+/// Escapes a test point `description`: `#` would otherwise start a directive.
+pub(crate) fn escape_description(description: &str) -> String {
+    description.replace('#', "\\#")
+}
+
+/// Unescapes a test point `description`, see [`escape_description`].
+fn unescape_description(description: &str) -> String {
+    description.replace("\\#", "#")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,8 +218,23 @@ mod tests {
             Testcase::parse("ok 1 - tests_ok/test.1.hurl").unwrap(),
             Testcase {
                 description: "tests_ok/test.1.hurl".to_string(),
-                success: true
+                success: true,
+                subtest: vec![],
             }
         );
     }
+
+    #[test]
+    fn test_escape_description() {
+        let description = r"GET http://localhost:8000/a#b";
+        let escaped = escape_description(description);
+        assert_eq!(escaped, r"GET http://localhost:8000/a\#b");
+        assert_eq!(unescape_description(&escaped), description);
+    }
+
+    #[test]
+    fn test_yaml_string() {
+        assert_eq!(yaml_string("integer <5>"), r#""integer <5>""#);
+        assert_eq!(yaml_string("a \"b\"\n"), r#""a \"b\"\n""#);
+    }
 }
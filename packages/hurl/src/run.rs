@@ -18,6 +18,7 @@
 use std::cmp::min;
 use std::path::Path;
 
+use hurl::http::OAuth2TokenCache;
 use hurl::parallel::job::{Job, JobResult};
 use hurl::parallel::runner::ParallelRunner;
 use hurl::runner::{HurlResult, Output, VariableSet};
@@ -48,6 +49,9 @@ pub fn run_seq(
     // it on subsequent write.
     let mut append = false;
 
+    // OAuth2 access tokens are shared by all the files of the run.
+    let oauth2_tokens = OAuth2TokenCache::new();
+
     for filename in queue {
         let content = filename.read_to_string();
         let content = match content {
@@ -67,10 +71,11 @@ pub fn run_seq(
         // Run our Hurl file now, we can only fail if there is a parsing error.
         // The parsing error is displayed in the `execute` call, that's why we gobble the error
         // string.
-        let Ok(hurl_result) = runner::run(
+        let Ok(hurl_result) = runner::run_with_oauth2_tokens(
             &content,
             Some(&filename),
             &runner_options,
+            &oauth2_tokens,
             &variables,
             &logger_options,
         ) else {
@@ -187,6 +192,8 @@ pub fn run_par(
         .to_output_type(options.include, options.color);
     let max_width = terminal_size::terminal_size().map(|(w, _)| w.0 as usize);
 
+    // OAuth2 access tokens are shared by all the files of the run, across workers.
+    let oauth2_tokens = OAuth2TokenCache::new();
     let jobs = files
         .iter()
         .enumerate()
@@ -205,6 +212,7 @@ pub fn run_par(
         options.progress_bar,
         options.color,
         max_width,
+        &oauth2_tokens,
     );
     let results = runner.run(&jobs)?;
     let results = results.into_iter().map(HurlRun::from).collect();
@@ -150,10 +150,17 @@ pub fn eval_explicit_assert(
     assert: &Assert,
     variables: &VariableSet,
     http_responses: &[&http::Response],
+    cookie_storage: &[http::Cookie],
     cache: &mut BodyCache,
     context_dir: &ContextDir,
 ) -> AssertResult {
-    let query_result = eval_query(&assert.query, variables, http_responses, cache);
+    let query_result = eval_query(
+        &assert.query,
+        variables,
+        http_responses,
+        cookie_storage,
+        cache,
+    );
 
     let actual = if assert.filters.is_empty() {
         query_result
@@ -192,6 +199,7 @@ pub fn eval_explicit_assert(
             &assert.predicate,
             variables,
             actual,
+            http_responses.last().copied(),
             context_dir,
         )),
     };
@@ -275,6 +283,7 @@ pub mod tests {
                 &assert_count_user(),
                 &variables,
                 &[&xml_three_users_http_response()],
+                &[],
                 &mut cache,
                 &context_dir
             ),
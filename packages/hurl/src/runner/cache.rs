@@ -17,9 +17,10 @@
  */
 use serde_json::Value;
 
+use crate::http::ServerSentEvent;
 use crate::runner::xpath::Document;
 
-/// This is a cache to hold parsed structured data (XML/JSON/text), computed from an HTTP response
+/// This is a cache to hold parsed structured data (XML/JSON/text/event stream), computed from an HTTP response
 /// body bytes. This cache lives for a given request, and allows reusing parsed response for
 /// multiple queries of the same type (for instance, two XPath queries will share their XML document
 /// through this cache).
@@ -29,6 +30,8 @@ pub struct BodyCache {
     xml: Option<Document>,
     /// The parsed JSON body
     json: Option<Value>,
+    /// The parsed Server-Sent Events.
+    sse: Option<Vec<ServerSentEvent>>,
 }
 
 impl BodyCache {
@@ -56,6 +59,16 @@ impl BodyCache {
     pub fn set_json(&mut self, json: Value) {
         self.json = Some(json);
     }
+
+    /// Returns a reference to cached Server-Sent Events.
+    pub fn sse(&self) -> Option<&[ServerSentEvent]> {
+        self.sse.as_deref()
+    }
+
+    /// Caches parsed Server-Sent Events.
+    pub fn set_sse(&mut self, events: Vec<ServerSentEvent>) {
+        self.sse = Some(events);
+    }
 }
 
 #[cfg(test)]
@@ -35,10 +35,17 @@ pub fn eval_capture(
     capture: &Capture,
     variables: &VariableSet,
     http_responses: &[&http::Response],
+    cookie_storage: &[http::Cookie],
     cache: &mut BodyCache,
 ) -> Result<CaptureResult, RunnerError> {
     let name = eval_template(&capture.name, variables)?;
-    let value = eval_query(&capture.query, variables, http_responses, cache)?;
+    let value = eval_query(
+        &capture.query,
+        variables,
+        http_responses,
+        cookie_storage,
+        cache,
+    )?;
     let value = match value {
         None => {
             return Err(RunnerError::new(
@@ -187,6 +194,7 @@ pub mod tests {
             &capture,
             &variables,
             &[&http::xml_three_users_http_response()],
+            &[],
             &mut cache,
         )
         .err()
@@ -252,6 +260,7 @@ pub mod tests {
                 &user_count_capture(),
                 &variables,
                 &[&http::xml_three_users_http_response()],
+                &[],
                 &mut cache,
             )
             .unwrap(),
@@ -266,6 +275,7 @@ pub mod tests {
                 &duration_capture(),
                 &variables,
                 &[&http::json_http_response()],
+                &[],
                 &mut cache
             )
             .unwrap(),
@@ -15,15 +15,17 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{Entry, PredicateFuncValue, Response, SourceInfo};
+use std::path::Path;
+
+use hurl_core::ast::{Entry, PredicateFuncValue, QueryValue, Response, SourceInfo};
 
 use crate::http;
-use crate::http::{ClientOptions, CurlCmd};
+use crate::http::{ClientOptions, CurlCmd, Header, OAuth2TokenCache, AUTHORIZATION};
 use crate::runner::cache::BodyCache;
 use crate::runner::error::RunnerError;
 use crate::runner::result::{AssertResult, EntryResult};
 use crate::runner::runner_options::RunnerOptions;
-use crate::runner::{request, response, CaptureResult, RunnerErrorKind, VariableSet};
+use crate::runner::{graphql, request, response, CaptureResult, RunnerErrorKind, VariableSet};
 use crate::util::logger::{Logger, Verbosity};
 use crate::util::term::WriteMode;
 
@@ -37,6 +39,7 @@ pub fn run(
     entry: &Entry,
     entry_index: usize,
     http_client: &mut http::Client,
+    oauth2_tokens: &OAuth2TokenCache,
     variables: &mut VariableSet,
     runner_options: &RunnerOptions,
     logger: &mut Logger,
@@ -69,7 +72,7 @@ pub fn run(
     }
 
     // Evaluates our source requests given our set of variables
-    let http_request = match request::eval_request(&entry.request, variables, context_dir) {
+    let mut http_request = match request::eval_request(&entry.request, variables, context_dir) {
         Ok(r) => r,
         Err(error) => {
             return EntryResult {
@@ -82,13 +85,76 @@ pub fn run(
         }
     };
 
+    // GraphQL queries are validated against the schema before being sent.
+    let graphql = graphql::request_graphql(&entry.request);
+    if let (Some(graphql), Some(schema_file)) = (graphql, &runner_options.graphql_schema) {
+        if let Err(errors) = graphql::validate_query(graphql, variables, Path::new(schema_file)) {
+            return EntryResult {
+                entry_index,
+                source_info,
+                errors,
+                compressed,
+                ..Default::default()
+            };
+        }
+    }
+
     let client_options = ClientOptions::from(runner_options, logger.verbosity);
 
+    // With OAuth2, an access token is obtained (or reused from a previous entry) and sent as a
+    // bearer token, unless the request has its own `Authorization` header.
+    if let Some(oauth2) = &runner_options.oauth2 {
+        oauth2
+            .secrets()
+            .into_iter()
+            .for_each(|secret| variables.add_secret(secret));
+        logger.set_secrets(variables.secrets());
+        let token = match oauth2_tokens.token(oauth2, http_client, &client_options, logger) {
+            Ok(token) => token,
+            Err(http_error) => {
+                let error_source_info = entry.request.url.source_info;
+                let error =
+                    RunnerError::new(error_source_info, RunnerErrorKind::Http(http_error), false);
+                return EntryResult {
+                    entry_index,
+                    source_info,
+                    errors: vec![error],
+                    compressed,
+                    ..Default::default()
+                };
+            }
+        };
+        token
+            .secrets()
+            .into_iter()
+            .for_each(|secret| variables.add_secret(secret));
+        logger.set_secrets(variables.secrets());
+        if !http_request.headers.contains_key(AUTHORIZATION) {
+            let value = format!("Bearer {}", token.access_token);
+            http_request
+                .headers
+                .push(Header::new(AUTHORIZATION, &value));
+        }
+    }
+
     // Experimental features with cookie storage
     use std::str::FromStr;
     if let Some(s) = request::cookie_storage_set(&entry.request) {
         if let Ok(cookie) = http::Cookie::from_str(s.as_str()) {
-            http_client.add_cookie(&cookie, logger);
+            if let Err(http_error) = http_client.add_cookie(&cookie, logger) {
+                let error = RunnerError::new(
+                    entry.request.source_info,
+                    RunnerErrorKind::Http(http_error),
+                    false,
+                );
+                return EntryResult {
+                    entry_index,
+                    source_info,
+                    errors: vec![error],
+                    compressed,
+                    ..Default::default()
+                };
+            }
         } else {
             logger.warning(&format!("Cookie string can not be parsed: '{s}'"));
         }
@@ -140,6 +206,14 @@ pub fn run(
     // 3. finally, run the remaining asserts
     let mut cache = BodyCache::new();
     let mut asserts = vec![];
+    // The cookie storage, after the requests have been run, is used by `cookiejar` queries. As
+    // reading the cookie storage is not free, it's only done if the response has such queries.
+    let cookie_storage = match &entry.response {
+        Some(response_spec) if has_cookiejar_query(response_spec) => {
+            http_client.cookie_storage(logger)
+        }
+        _ => vec![],
+    };
 
     if !runner_options.ignore_asserts {
         if let Some(response_spec) = &entry.response {
@@ -167,7 +241,13 @@ pub fn run(
     let captures = match &entry.response {
         None => vec![],
         Some(response_spec) => {
-            match response::eval_captures(response_spec, &responses, &mut cache, variables) {
+            match response::eval_captures(
+                response_spec,
+                &responses,
+                &cookie_storage,
+                &mut cache,
+                variables,
+            ) {
                 Ok(captures) => captures,
                 Err(e) => {
                     return EntryResult {
@@ -201,6 +281,7 @@ pub fn run(
                 response_spec,
                 variables,
                 &responses,
+                &cookie_storage,
                 &mut cache,
                 context_dir,
             );
@@ -208,7 +289,14 @@ pub fn run(
         }
     };
 
-    let errors = asserts_to_errors(&asserts);
+    let mut errors = asserts_to_errors(&asserts);
+
+    // GraphQL responses with errors make the entry fail, unless explicitly allowed.
+    if !runner_options.ignore_asserts && !runner_options.graphql_allow_errors {
+        if let Some(error) = graphql.and_then(|g| graphql::eval_response_errors(g, http_response)) {
+            errors.push(error);
+        }
+    }
 
     EntryResult {
         entry_index,
@@ -242,17 +330,28 @@ impl ClientOptions {
     fn from(runner_options: &RunnerOptions, verbosity: Option<Verbosity>) -> Self {
         ClientOptions {
             allow_reuse: runner_options.allow_reuse,
+            auth_method: runner_options.auth_method,
             aws_sigv4: runner_options.aws_sigv4.clone(),
             cacert_file: runner_options.cacert_file.clone(),
+            cert_status: runner_options.cert_status,
             client_cert_file: runner_options.client_cert_file.clone(),
             client_key_file: runner_options.client_key_file.clone(),
+            compress_body: runner_options.compress_body,
             compressed: runner_options.compressed,
             connect_timeout: runner_options.connect_timeout,
             connects_to: runner_options.connects_to.clone(),
-            cookie_input_file: runner_options.cookie_input_file.clone(),
+            content_digest: runner_options.content_digest,
+            // JSON cookie jars are loaded in the cookie storage by the runner, not by libcurl.
+            cookie_input_file: runner_options
+                .cookie_input_file
+                .clone()
+                .filter(|f| !http::is_json_cookie_jar(f)),
+            crl_file: runner_options.crl_file.clone(),
             follow_location: runner_options.follow_location,
             follow_location_trusted: runner_options.follow_location_trusted,
+            grpc_proto: runner_options.grpc_proto.clone(),
             headers: runner_options.headers.clone(),
+            http_signature: runner_options.http_signature.clone(),
             http_version: runner_options.http_version,
             ip_resolve: runner_options.ip_resolve,
             max_filesize: runner_options.max_filesize,
@@ -263,12 +362,23 @@ impl ClientOptions {
             netrc_file: runner_options.netrc_file.clone(),
             netrc_optional: runner_options.netrc_optional,
             path_as_is: runner_options.path_as_is,
+            pinned_pubkey: runner_options.pinned_pubkey.clone(),
             proxy: runner_options.proxy.clone(),
+            proxy_auth_method: runner_options.proxy_auth_method,
+            proxy_cacert_file: runner_options.proxy_cacert_file.clone(),
+            proxy_headers: runner_options.proxy_headers.clone(),
+            proxy_insecure: runner_options.proxy_insecure,
+            proxy_user: runner_options.proxy_user.clone(),
             no_proxy: runner_options.no_proxy.clone(),
             insecure: runner_options.insecure,
             resolves: runner_options.resolves.clone(),
+            sse_duration: runner_options.sse_duration,
+            sse_events: runner_options.sse_events,
             ssl_no_revoke: runner_options.ssl_no_revoke,
             timeout: runner_options.timeout,
+            tls_max_version: runner_options.tls_max_version,
+            tls_min_version: runner_options.tls_min_version,
+            traceparent: runner_options.traceparent,
             unix_socket: runner_options.unix_socket.clone(),
             user: runner_options.user.clone(),
             user_agent: runner_options.user_agent.clone(),
@@ -277,6 +387,8 @@ impl ClientOptions {
                 Some(Verbosity::VeryVerbose) => Some(http::Verbosity::VeryVerbose),
                 _ => None,
             },
+            websocket_messages: runner_options.websocket_messages,
+            websocket_timeout: runner_options.websocket_timeout,
         }
     }
 }
@@ -342,6 +454,15 @@ fn log_captures(captures: &[CaptureResult], logger: &mut Logger) {
 }
 
 /// Warns some deprecation on this `response`.
+/// Returns `true` if this `response_spec` has captures or asserts using a `cookiejar` query.
+fn has_cookiejar_query(response_spec: &Response) -> bool {
+    let captures = response_spec.captures().iter().map(|c| &c.query);
+    let asserts = response_spec.asserts().iter().map(|a| &a.query);
+    captures
+        .chain(asserts)
+        .any(|q| matches!(q.value, QueryValue::CookieJar { .. }))
+}
+
 fn warn_deprecated(response_spec: &Response, logger: &mut Logger) {
     if response_spec.asserts().iter().any(|a| {
         matches!(
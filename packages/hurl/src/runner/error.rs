@@ -89,10 +89,49 @@ pub enum RunnerErrorKind {
     FilterInvalidInput(String),
     FilterInvalidFormatSpecifier(String),
     FilterMissingInput,
+    /// The GraphQL response has a non-empty list of `errors`.
+    GraphQlErrors {
+        messages: Vec<String>,
+    },
+    /// The GraphQL query is not valid against the GraphQL schema.
+    GraphQlInvalidQuery {
+        message: String,
+    },
+    /// The GraphQL schema file `path` can not be parsed.
+    GraphQlInvalidSchema {
+        path: PathBuf,
+        message: String,
+    },
     Http(HttpError),
+    /// The parameters of the `http-signature` option or `signature` predicate are not valid, or
+    /// a signature can not be checked.
+    InvalidHttpSignature {
+        message: String,
+    },
+    /// The content encoding of the `compress-body` option is not valid.
+    InvalidContentEncoding {
+        message: String,
+    },
+    /// The mode of the `cookie-storage` option is not valid.
+    InvalidCookieStorageMode {
+        message: String,
+    },
+    /// The algorithm of the `content-digest` option is not valid, or a digest field can not be
+    /// checked.
+    InvalidDigest {
+        message: String,
+    },
     InvalidJson {
         value: String,
     },
+    /// The parameters of the `oauth2` option are not valid.
+    InvalidOAuth2 {
+        message: String,
+    },
+    /// The TLS version of the `tls-min` or `tls-max` option is not valid.
+    InvalidTlsVersion {
+        message: String,
+    },
     InvalidRegex,
     InvalidUrl {
         url: String,
@@ -107,6 +146,9 @@ pub enum RunnerErrorKind {
     QueryInvalidXpathEval,
     QueryInvalidXml,
     QueryInvalidJson,
+    QueryInvalidSse {
+        message: String,
+    },
     ReadOnlySecret {
         name: String,
     },
@@ -146,8 +188,21 @@ impl DisplaySourceError for RunnerError {
             RunnerErrorKind::FilterInvalidInput { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidFormatSpecifier { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterMissingInput => "Filter error".to_string(),
+            RunnerErrorKind::GraphQlErrors { .. } => "GraphQL errors".to_string(),
+            RunnerErrorKind::GraphQlInvalidQuery { .. } => "Invalid GraphQL query".to_string(),
+            RunnerErrorKind::GraphQlInvalidSchema { .. } => "Invalid GraphQL schema".to_string(),
             RunnerErrorKind::Http(http_error) => http_error.description(),
+            RunnerErrorKind::InvalidHttpSignature { .. } => "Invalid HTTP signature".to_string(),
+            RunnerErrorKind::InvalidContentEncoding { .. } => {
+                "Invalid content encoding".to_string()
+            }
+            RunnerErrorKind::InvalidDigest { .. } => "Invalid digest".to_string(),
+            RunnerErrorKind::InvalidCookieStorageMode { .. } => {
+                "Invalid cookie storage mode".to_string()
+            }
             RunnerErrorKind::InvalidJson { .. } => "Invalid JSON".to_string(),
+            RunnerErrorKind::InvalidOAuth2 { .. } => "Invalid OAuth2 option".to_string(),
+            RunnerErrorKind::InvalidTlsVersion { .. } => "Invalid TLS version".to_string(),
             RunnerErrorKind::InvalidUrl { .. } => "Invalid URL".to_string(),
             RunnerErrorKind::InvalidRegex => "Invalid regex".to_string(),
             RunnerErrorKind::NoQueryResult => "No query result".to_string(),
@@ -157,6 +212,7 @@ impl DisplaySourceError for RunnerError {
             RunnerErrorKind::QueryInvalidJsonpathExpression { .. } => {
                 "Invalid JSONPath".to_string()
             }
+            RunnerErrorKind::QueryInvalidSse { .. } => "Invalid Server-Sent Events".to_string(),
             RunnerErrorKind::QueryInvalidXml => "Invalid XML".to_string(),
             RunnerErrorKind::QueryInvalidXpathEval => "Invalid XPath expression".to_string(),
             RunnerErrorKind::ReadOnlySecret { .. } => "Readonly secret".to_string(),
@@ -256,16 +312,62 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::GraphQlErrors { messages } => {
+                let message = messages
+                    .iter()
+                    .map(|m| format!("- {m}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let message = &format!("the GraphQL response has errors:\n{message}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::GraphQlInvalidQuery { message } => {
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::GraphQlInvalidSchema { path, message } => {
+                let message = &format!(
+                    "GraphQL schema {} can not be parsed: {message}",
+                    path.to_string_lossy()
+                );
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::Http(http_error) => {
                 let message = http_error.message();
                 let message = error::add_carets(&message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::InvalidHttpSignature { message } => {
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::InvalidContentEncoding { message } => {
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::InvalidCookieStorageMode { message } => {
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::InvalidTlsVersion { message } => {
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::InvalidDigest { message } => {
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::InvalidJson { value } => {
                 let message = &format!("actual value is <{value}>");
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::InvalidOAuth2 { message } => {
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::InvalidUrl { url, message } => {
                 let message = &format!("invalid URL <{url}> ({message})");
                 let message = error::add_carets(message, self.source_info, content);
@@ -301,6 +403,10 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::QueryInvalidSse { message } => {
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::QueryInvalidXml => {
                 let message = "the HTTP response is not a valid XML";
                 let message = error::add_carets(message, self.source_info, content);
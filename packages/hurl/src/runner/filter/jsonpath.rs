@@ -22,6 +22,9 @@ use crate::runner::template::eval_template;
 use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
 /// Evaluates a JSONPath expression `expr` against a `value`.
+///
+/// `value` can be a string (parsed as JSON), or a list / object (for instance, the events of a
+/// `sse` query).
 pub fn eval_jsonpath(
     value: &Value,
     expr: &Template,
@@ -43,6 +46,9 @@ pub fn eval_jsonpath(
             };
             eval_jsonpath_json(&json, expr, variables)
         }
+        Value::List(_) | Value::Object(_) => {
+            eval_jsonpath_json(&value.to_json(&[]), expr, variables)
+        }
         v => {
             let kind = RunnerErrorKind::FilterInvalidInput(v.kind().to_string());
             Err(RunnerError::new(source_info, kind, assert))
@@ -117,4 +123,37 @@ mod tests {
             Value::String("Hello".to_string())
         );
     }
+
+    #[test]
+    fn eval_filter_jsonpath_object() {
+        let variables = VariableSet::new();
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonPath {
+                expr: Template::new(
+                    Some('"'),
+                    vec![TemplateElement::String {
+                        value: "$.data".to_string(),
+                        source: "$.data".to_source(),
+                    }],
+                    SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                ),
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        let event = Value::Object(vec![
+            ("event".to_string(), Value::String("message".to_string())),
+            ("data".to_string(), Value::String("Hello".to_string())),
+        ]);
+        assert_eq!(
+            eval_filter(&filter, &event, &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::String("Hello".to_string())
+        );
+    }
 }
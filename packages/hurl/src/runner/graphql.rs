@@ -0,0 +1,768 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2025 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! GraphQL support: static validation of GraphQL queries against a schema (SDL), and detection of
+//! errors in GraphQL responses.
+//!
+//! The validation is a subset of the [GraphQL specification validation](https://spec.graphql.org/October2021/#sec-Validation):
+//! it checks that selected fields and arguments exist, that required arguments are provided,
+//! that leaf and composite fields are correctly selected, and that fragments and variables are
+//! defined.
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use graphql_parser::query::{
+    Definition, FragmentDefinition, OperationDefinition, Selection, SelectionSet, TypeCondition,
+    Value as QueryValue, VariableDefinition,
+};
+use graphql_parser::schema::{self, TypeDefinition, TypeExtension};
+use graphql_parser::Pos as GraphQlPos;
+use hurl_core::ast::{Bytes, GraphQl, MultilineString, MultilineStringKind, Request, SourceInfo};
+use hurl_core::reader::Pos;
+use regex::Regex;
+
+use crate::http;
+use crate::runner::error::{RunnerError, RunnerErrorKind};
+use crate::runner::template::eval_template;
+use crate::runner::VariableSet;
+
+/// Scalar types that are implicitly defined by every schema.
+const BUILTIN_SCALARS: [&str; 5] = ["Boolean", "Float", "ID", "Int", "String"];
+
+/// An error found in a GraphQL query, with its position in the query (one-based line and column).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueryError {
+    pub line: usize,
+    pub column: usize,
+    /// Number of characters of the query in error.
+    pub width: usize,
+    pub message: String,
+}
+
+impl QueryError {
+    fn new(pos: GraphQlPos, width: usize, message: String) -> Self {
+        QueryError {
+            line: pos.line,
+            column: pos.column,
+            width,
+            message,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TypeKind {
+    Scalar,
+    Enum,
+    Object,
+    Interface,
+    Union,
+    InputObject,
+}
+
+/// A named type of a schema, with its fields (for objects and interfaces).
+#[derive(Clone, Debug)]
+struct TypeDef {
+    kind: TypeKind,
+    fields: HashMap<String, FieldDef>,
+}
+
+impl TypeDef {
+    fn new(kind: TypeKind) -> Self {
+        TypeDef {
+            kind,
+            fields: HashMap::new(),
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        matches!(self.kind, TypeKind::Scalar | TypeKind::Enum)
+    }
+
+    fn is_composite(&self) -> bool {
+        matches!(
+            self.kind,
+            TypeKind::Object | TypeKind::Interface | TypeKind::Union
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct FieldDef {
+    /// The field type, as written in the schema (ex: `[User!]!`).
+    type_: String,
+    /// The underlying named type (ex: `User`).
+    named_type: String,
+    args: Vec<ArgDef>,
+}
+
+#[derive(Clone, Debug)]
+struct ArgDef {
+    name: String,
+    type_: String,
+    /// True for a non-null argument without default value.
+    required: bool,
+}
+
+/// A GraphQL schema, built from a SDL (Schema Definition Language) document.
+#[derive(Clone, Debug)]
+pub struct Schema {
+    types: HashMap<String, TypeDef>,
+    query: Option<String>,
+    mutation: Option<String>,
+    subscription: Option<String>,
+}
+
+impl Schema {
+    /// Parses a SDL document and returns a new schema.
+    pub fn parse(sdl: &str) -> Result<Schema, String> {
+        let document = schema::parse_schema::<String>(sdl).map_err(|e| e.to_string())?;
+        let mut types = HashMap::new();
+        for name in BUILTIN_SCALARS {
+            types.insert(name.to_string(), TypeDef::new(TypeKind::Scalar));
+        }
+        let mut roots = None;
+
+        for definition in &document.definitions {
+            match definition {
+                schema::Definition::SchemaDefinition(s) => {
+                    roots = Some((s.query.clone(), s.mutation.clone(), s.subscription.clone()));
+                }
+                schema::Definition::TypeDefinition(t) => {
+                    let (name, type_def) = match t {
+                        TypeDefinition::Scalar(t) => (&t.name, TypeDef::new(TypeKind::Scalar)),
+                        TypeDefinition::Enum(t) => (&t.name, TypeDef::new(TypeKind::Enum)),
+                        TypeDefinition::Union(t) => (&t.name, TypeDef::new(TypeKind::Union)),
+                        TypeDefinition::InputObject(t) => {
+                            (&t.name, TypeDef::new(TypeKind::InputObject))
+                        }
+                        TypeDefinition::Object(t) => {
+                            let mut type_def = TypeDef::new(TypeKind::Object);
+                            type_def.fields = field_defs(&t.fields);
+                            (&t.name, type_def)
+                        }
+                        TypeDefinition::Interface(t) => {
+                            let mut type_def = TypeDef::new(TypeKind::Interface);
+                            type_def.fields = field_defs(&t.fields);
+                            (&t.name, type_def)
+                        }
+                    };
+                    types.insert(name.clone(), type_def);
+                }
+                schema::Definition::TypeExtension(_)
+                | schema::Definition::DirectiveDefinition(_) => {}
+            }
+        }
+
+        // Type extensions can add fields to objects and interfaces defined anywhere in the document.
+        for definition in &document.definitions {
+            let schema::Definition::TypeExtension(extension) = definition else {
+                continue;
+            };
+            let (name, fields) = match extension {
+                TypeExtension::Object(t) => (&t.name, &t.fields),
+                TypeExtension::Interface(t) => (&t.name, &t.fields),
+                _ => continue,
+            };
+            if let Some(type_def) = types.get_mut(name) {
+                type_def.fields.extend(field_defs(fields));
+            }
+        }
+
+        // Without schema definition, root operation types have their default names.
+        let (query, mutation, subscription) = roots.unwrap_or_else(|| {
+            let root = |name: &str| types.contains_key(name).then(|| name.to_string());
+            (root("Query"), root("Mutation"), root("Subscription"))
+        });
+
+        Ok(Schema {
+            types,
+            query,
+            mutation,
+            subscription,
+        })
+    }
+
+    /// Validates a GraphQL `query` against this schema, and returns the list of errors.
+    pub fn validate(&self, query: &str) -> Vec<QueryError> {
+        let document = match graphql_parser::parse_query::<String>(query) {
+            Ok(document) => document,
+            Err(e) => return vec![syntax_error(&e.to_string())],
+        };
+        let fragments = document
+            .definitions
+            .iter()
+            .filter_map(|d| match d {
+                Definition::Fragment(f) => Some((f.name.clone(), f)),
+                Definition::Operation(_) => None,
+            })
+            .collect::<HashMap<_, _>>();
+        let mut validator = Validator {
+            schema: self,
+            fragments: &fragments,
+            variables: None,
+            errors: vec![],
+        };
+
+        for definition in &document.definitions {
+            match definition {
+                Definition::Operation(operation) => validator.validate_operation(operation),
+                Definition::Fragment(fragment) => validator.validate_fragment(fragment),
+            }
+        }
+        validator.errors
+    }
+}
+
+/// Returns the definitions of a list of schema `fields`, indexed by name.
+fn field_defs(fields: &[schema::Field<String>]) -> HashMap<String, FieldDef> {
+    fields
+        .iter()
+        .map(|f| {
+            let args = f
+                .arguments
+                .iter()
+                .map(|a| ArgDef {
+                    name: a.name.clone(),
+                    type_: a.value_type.to_string(),
+                    required: matches!(a.value_type, schema::Type::NonNullType(_))
+                        && a.default_value.is_none(),
+                })
+                .collect();
+            let field_def = FieldDef {
+                type_: f.field_type.to_string(),
+                named_type: named_type(&f.field_type).to_string(),
+                args,
+            };
+            (f.name.clone(), field_def)
+        })
+        .collect()
+}
+
+/// Returns the underlying named type of a (list or non-null) `type_`.
+fn named_type<'a>(type_: &'a schema::Type<String>) -> &'a str {
+    match type_ {
+        schema::Type::NamedType(name) => name,
+        schema::Type::ListType(t) | schema::Type::NonNullType(t) => named_type(t),
+    }
+}
+
+/// Converts a parse error `message` (ex: `query parse error: Parse error at 2:5\nUnexpected...`)
+/// to a [`QueryError`].
+fn syntax_error(message: &str) -> QueryError {
+    let message = message
+        .strip_prefix("query parse error: ")
+        .unwrap_or(message);
+    let mut lines = message.lines();
+    let pos = lines
+        .next()
+        .and_then(|l| l.strip_prefix("Parse error at "))
+        .and_then(|p| p.split_once(':'))
+        .and_then(|(line, column)| Some((line.parse().ok()?, column.parse().ok()?)));
+    let (line, column) = pos.unwrap_or((1, 1));
+    // Tokens are displayed with their kind (ex: `}[Punctuator]`), which we remove.
+    let re = Regex::new(r"\[[A-Z][A-Za-z]*\]`").unwrap();
+    let details = lines
+        .filter(|l| !l.is_empty())
+        .map(|l| re.replace_all(l, "`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    QueryError {
+        line,
+        column,
+        width: 1,
+        message: format!("syntax error: {details}"),
+    }
+}
+
+/// Validates the selections of a query document, accumulating errors.
+struct Validator<'a> {
+    schema: &'a Schema,
+    fragments: &'a HashMap<String, &'a FragmentDefinition<'a, String>>,
+    /// Variables defined by the current operation (`None` when validating fragments, as fragments
+    /// can be used by different operations).
+    variables: Option<HashSet<String>>,
+    errors: Vec<QueryError>,
+}
+
+impl Validator<'_> {
+    fn validate_operation(&mut self, operation: &OperationDefinition<String>) {
+        let (pos, kind, root, variable_definitions, selection_set) = match operation {
+            OperationDefinition::SelectionSet(s) => {
+                (s.span.0, "query", &self.schema.query, &[][..], s)
+            }
+            OperationDefinition::Query(q) => (
+                q.position,
+                "query",
+                &self.schema.query,
+                &q.variable_definitions[..],
+                &q.selection_set,
+            ),
+            OperationDefinition::Mutation(m) => (
+                m.position,
+                "mutation",
+                &self.schema.mutation,
+                &m.variable_definitions[..],
+                &m.selection_set,
+            ),
+            OperationDefinition::Subscription(s) => (
+                s.position,
+                "subscription",
+                &self.schema.subscription,
+                &s.variable_definitions[..],
+                &s.selection_set,
+            ),
+        };
+        let Some(root) = root else {
+            let message = format!("schema is not configured to execute {kind} operation");
+            self.errors.push(QueryError::new(pos, kind.len(), message));
+            return;
+        };
+        self.variables = Some(variable_names(variable_definitions));
+        self.validate_selection_set(selection_set, root);
+        self.variables = None;
+    }
+
+    fn validate_fragment(&mut self, fragment: &FragmentDefinition<String>) {
+        let TypeCondition::On(type_name) = &fragment.type_condition;
+        match self.schema.types.get(type_name) {
+            Some(t) if t.is_composite() => {
+                self.validate_selection_set(&fragment.selection_set, type_name);
+            }
+            Some(_) => {
+                let message = format!(
+                    "fragment <{}> can not condition on non composite type <{type_name}>",
+                    fragment.name
+                );
+                self.errors.push(QueryError::new(
+                    fragment.position,
+                    "fragment".len(),
+                    message,
+                ));
+            }
+            None => {
+                let message = format!("unknown type <{type_name}>");
+                self.errors.push(QueryError::new(
+                    fragment.position,
+                    "fragment".len(),
+                    message,
+                ));
+            }
+        }
+    }
+
+    fn validate_selection_set(&mut self, selection_set: &SelectionSet<String>, type_name: &str) {
+        for selection in &selection_set.items {
+            match selection {
+                Selection::Field(field) => {
+                    let width = field.alias.as_ref().unwrap_or(&field.name).chars().count();
+                    let error = |message: String| QueryError::new(field.position, width, message);
+
+                    // Meta-fields are implicitly defined.
+                    if field.name == "__typename" {
+                        continue;
+                    }
+                    let is_query_root = self.schema.query.as_deref() == Some(type_name);
+                    if is_query_root && (field.name == "__schema" || field.name == "__type") {
+                        continue;
+                    }
+
+                    let Some(field_def) = self
+                        .schema
+                        .types
+                        .get(type_name)
+                        .and_then(|t| t.fields.get(&field.name))
+                    else {
+                        let message = format!(
+                            "field <{}> does not exist on type <{type_name}>",
+                            field.name
+                        );
+                        self.errors.push(error(message));
+                        continue;
+                    };
+
+                    for (name, value) in &field.arguments {
+                        if !field_def.args.iter().any(|a| &a.name == name) {
+                            let message = format!(
+                                "unknown argument <{name}> on field <{type_name}.{}>",
+                                field.name
+                            );
+                            self.errors.push(error(message));
+                        }
+                        self.validate_variables(value, field.position, width);
+                    }
+                    for arg in field_def.args.iter().filter(|a| a.required) {
+                        if !field.arguments.iter().any(|(name, _)| name == &arg.name) {
+                            let message = format!(
+                                "argument <{}> of type <{}> is required on field <{type_name}.{}>",
+                                arg.name, arg.type_, field.name
+                            );
+                            self.errors.push(error(message));
+                        }
+                    }
+
+                    let Some(field_type) = self.schema.types.get(&field_def.named_type) else {
+                        continue;
+                    };
+                    let has_selection = !field.selection_set.items.is_empty();
+                    if field_type.is_leaf() && has_selection {
+                        let message = format!(
+                            "field <{}> of type <{}> must not have a selection of subfields",
+                            field.name, field_def.type_
+                        );
+                        self.errors.push(error(message));
+                    } else if field_type.is_composite() && !has_selection {
+                        let message = format!(
+                            "field <{}> of type <{}> must have a selection of subfields",
+                            field.name, field_def.type_
+                        );
+                        self.errors.push(error(message));
+                    } else if has_selection {
+                        self.validate_selection_set(&field.selection_set, &field_def.named_type);
+                    }
+                }
+                Selection::FragmentSpread(spread) => {
+                    if !self.fragments.contains_key(&spread.fragment_name) {
+                        let message = format!("unknown fragment <{}>", spread.fragment_name);
+                        let width = spread.fragment_name.chars().count();
+                        self.errors
+                            .push(QueryError::new(spread.position, width, message));
+                    }
+                }
+                Selection::InlineFragment(fragment) => {
+                    let type_name = match &fragment.type_condition {
+                        Some(TypeCondition::On(name)) => {
+                            if !self.schema.types.contains_key(name) {
+                                let message = format!("unknown type <{name}>");
+                                let width = "on ".len() + name.chars().count();
+                                self.errors.push(QueryError::new(
+                                    fragment.position,
+                                    width,
+                                    message,
+                                ));
+                                continue;
+                            }
+                            name.as_str()
+                        }
+                        None => type_name,
+                    };
+                    self.validate_selection_set(&fragment.selection_set, type_name);
+                }
+            }
+        }
+    }
+
+    /// Checks that the variables used in an argument `value` are defined by the current operation.
+    fn validate_variables(&mut self, value: &QueryValue<String>, pos: GraphQlPos, width: usize) {
+        let Some(variables) = &self.variables else {
+            return;
+        };
+        match value {
+            QueryValue::Variable(name) if !variables.contains(name) => {
+                let message = format!("variable <${name}> is not defined");
+                self.errors.push(QueryError::new(pos, width, message));
+            }
+            QueryValue::List(values) => {
+                for value in values {
+                    self.validate_variables(value, pos, width);
+                }
+            }
+            QueryValue::Object(values) => {
+                for value in values.values() {
+                    self.validate_variables(value, pos, width);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn variable_names(definitions: &[VariableDefinition<String>]) -> HashSet<String> {
+    definitions.iter().map(|v| v.name.clone()).collect()
+}
+
+/// Returns the GraphQL body of this `request`, if any.
+pub fn request_graphql(request: &Request) -> Option<&GraphQl> {
+    match &request.body.as_ref()?.value {
+        Bytes::MultilineString(MultilineString {
+            kind: MultilineStringKind::GraphQl(graphql),
+            ..
+        }) => Some(graphql),
+        _ => None,
+    }
+}
+
+/// Validates the query of this `graphql` body (rendered with `variables`) against the SDL schema
+/// file `schema_file`.
+///
+/// Errors in the query are reported at their position in the Hurl file.
+pub fn validate_query(
+    graphql: &GraphQl,
+    variables: &VariableSet,
+    schema_file: &Path,
+) -> Result<(), Vec<RunnerError>> {
+    let source_info = graphql.value.source_info;
+    let query = eval_template(&graphql.value, variables).map_err(|e| vec![e])?;
+    let Ok(sdl) = std::fs::read_to_string(schema_file) else {
+        let kind = RunnerErrorKind::FileReadAccess {
+            path: schema_file.to_path_buf(),
+        };
+        return Err(vec![RunnerError::new(source_info, kind, false)]);
+    };
+    let schema = Schema::parse(&sdl).map_err(|message| {
+        let kind = RunnerErrorKind::GraphQlInvalidSchema {
+            path: schema_file.to_path_buf(),
+            message,
+        };
+        vec![RunnerError::new(source_info, kind, false)]
+    })?;
+
+    let errors = schema
+        .validate(&query)
+        .into_iter()
+        .map(|e| {
+            // The query starts on the line following the GraphQL multiline string header.
+            let start = source_info.start;
+            let line = start.line + e.line - 1;
+            let column = if e.line == 1 {
+                start.column + e.column - 1
+            } else {
+                e.column
+            };
+            let source_info =
+                SourceInfo::new(Pos::new(line, column), Pos::new(line, column + e.width));
+            let kind = RunnerErrorKind::GraphQlInvalidQuery { message: e.message };
+            RunnerError::new(source_info, kind, false)
+        })
+        .collect::<Vec<_>>();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Checks that the GraphQL `response` to this `graphql` request has no errors, ie the
+/// `errors` entry of the response is empty or absent.
+///
+/// Responses that are not JSON are not checked.
+pub fn eval_response_errors(graphql: &GraphQl, response: &http::Response) -> Option<RunnerError> {
+    let text = response.text().ok()?;
+    let json = serde_json::from_str::<serde_json::Value>(&text).ok()?;
+    let errors = json.get("errors")?.as_array()?;
+    if errors.is_empty() {
+        return None;
+    }
+    let messages = errors
+        .iter()
+        .map(|e| match e.get("message").and_then(|m| m.as_str()) {
+            Some(message) => message.to_string(),
+            None => e.to_string(),
+        })
+        .collect();
+
+    // Errors are reported on the first line of the query.
+    let start = graphql.value.source_info.start;
+    let width = graphql
+        .value
+        .to_string()
+        .lines()
+        .next()
+        .map_or(1, |l| l.chars().count().max(1));
+    let source_info = SourceInfo::new(start, Pos::new(start.line, start.column + width));
+    let kind = RunnerErrorKind::GraphQlErrors { messages };
+    Some(RunnerError::new(source_info, kind, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"
+        type Query {
+          hero(episode: Episode): Character
+          human(id: ID!): Human
+          search(text: String!, first: Int = 10): [SearchResult!]!
+        }
+
+        type Mutation {
+          createReview(episode: Episode, stars: Int!): Review
+        }
+
+        enum Episode { NEWHOPE EMPIRE JEDI }
+
+        interface Character {
+          id: ID!
+          name: String!
+          friends: [Character]
+        }
+
+        type Human implements Character {
+          id: ID!
+          name: String!
+          friends: [Character]
+          height(unit: String): Float
+        }
+
+        type Droid implements Character {
+          id: ID!
+          name: String!
+          friends: [Character]
+        }
+
+        type Review {
+          stars: Int!
+        }
+
+        union SearchResult = Human | Droid
+
+        extend type Review {
+          commentary: String
+        }
+    "#;
+
+    fn validate(query: &str) -> Vec<QueryError> {
+        Schema::parse(SCHEMA).unwrap().validate(query)
+    }
+
+    fn error(line: usize, column: usize, width: usize, message: &str) -> QueryError {
+        QueryError {
+            line,
+            column,
+            width,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_valid_queries() {
+        assert!(validate("{ hero { name } }").is_empty());
+        assert!(validate(
+            r#"
+            query HeroAndFriends($episode: Episode) {
+              hero(episode: $episode) {
+                __typename
+                name
+                friends { ...characterFields }
+                ... on Human { height(unit: "METER") }
+              }
+              search(text: "an") { ... on Droid { id } }
+            }
+
+            fragment characterFields on Character { id name }
+
+            mutation { createReview(stars: 5) { stars commentary } }
+            "#
+        )
+        .is_empty());
+        assert!(validate("{ __schema { types { name } } }").is_empty());
+    }
+
+    #[test]
+    fn test_invalid_fields() {
+        assert_eq!(
+            validate("{\n  hero {\n    name\n    age\n  }\n}"),
+            vec![error(
+                4,
+                5,
+                3,
+                "field <age> does not exist on type <Character>"
+            )]
+        );
+        assert_eq!(
+            validate("{ hero { name { first } } search(text: \"a\") { id } }"),
+            vec![
+                error(
+                    1,
+                    10,
+                    4,
+                    "field <name> of type <String!> must not have a selection of subfields"
+                ),
+                error(1, 47, 2, "field <id> does not exist on type <SearchResult>"),
+            ]
+        );
+        assert_eq!(
+            validate("{ h: hero }"),
+            vec![error(
+                1,
+                3,
+                1,
+                "field <hero> of type <Character> must have a selection of subfields"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        assert_eq!(
+            validate(
+                "query Q($id: ID!) { human(name: \"Luke\") { name } hero(episode: $ep) { id } }"
+            ),
+            vec![
+                error(1, 21, 5, "unknown argument <name> on field <Query.human>"),
+                error(
+                    1,
+                    21,
+                    5,
+                    "argument <id> of type <ID!> is required on field <Query.human>"
+                ),
+                error(1, 50, 4, "variable <$ep> is not defined"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_operations_and_fragments() {
+        let schema = Schema::parse("type Query { a: Int }").unwrap();
+        assert_eq!(
+            schema.validate("mutation { a }"),
+            vec![error(
+                1,
+                1,
+                8,
+                "schema is not configured to execute mutation operation"
+            )]
+        );
+        assert_eq!(
+            validate("{ hero { ...unknown ... on Wookie { id } } }\nfragment f on Episode { id }"),
+            vec![
+                error(1, 13, 7, "unknown fragment <unknown>"),
+                error(1, 25, 9, "unknown type <Wookie>"),
+                error(
+                    2,
+                    1,
+                    8,
+                    "fragment <f> can not condition on non composite type <Episode>"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        assert_eq!(
+            validate("{\n  hero {\n    name(\n  }\n}"),
+            vec![error(
+                4,
+                3,
+                1,
+                "syntax error: Unexpected `}`, Expected Name"
+            )]
+        );
+        assert!(Schema::parse("type Query {").is_err());
+    }
+}
@@ -25,10 +25,15 @@ use hurl_core::input::Input;
 use hurl_core::parser;
 use hurl_core::typing::Count;
 
-use crate::http::{Call, Client};
+use crate::http::{
+    cookies_from_json, is_json_cookie_jar, is_pem, pem_secrets, proxy_header_secrets,
+    proxy_url_secrets, proxy_user_secrets, Call, Client, CookieStorageMode, OAuth2TokenCache,
+};
 use crate::runner::event::EventListener;
 use crate::runner::runner_options::RunnerOptions;
-use crate::runner::{entry, options, EntryResult, HurlResult, VariableSet};
+use crate::runner::{
+    entry, options, EntryResult, HurlResult, RunnerError, RunnerErrorKind, VariableSet,
+};
 use crate::util::logger::{ErrorFormat, Logger, LoggerOptions};
 use crate::util::term::{Stderr, Stdout, WriteMode};
 
@@ -86,6 +91,29 @@ pub fn run(
     runner_options: &RunnerOptions,
     variables: &VariableSet,
     logger_options: &LoggerOptions,
+) -> Result<HurlResult, String> {
+    let oauth2_tokens = OAuth2TokenCache::new();
+    run_with_oauth2_tokens(
+        content,
+        filename,
+        runner_options,
+        &oauth2_tokens,
+        variables,
+        logger_options,
+    )
+}
+
+/// Runs a Hurl `content` and returns a [`HurlResult`] upon completion, like [`run`].
+///
+/// OAuth2 access tokens are obtained and cached in `oauth2_tokens`, so runs that share this cache
+/// share their access tokens.
+pub fn run_with_oauth2_tokens(
+    content: &str,
+    filename: Option<&Input>,
+    runner_options: &RunnerOptions,
+    oauth2_tokens: &OAuth2TokenCache,
+    variables: &VariableSet,
+    logger_options: &LoggerOptions,
 ) -> Result<HurlResult, String> {
     // In this method, we run Hurl content sequentially. Standard output and standard error messages
     // are written immediately (in parallel mode, we'll use buffered standard output and error).
@@ -113,6 +141,7 @@ pub fn run(
         content,
         filename,
         runner_options,
+        oauth2_tokens,
         variables,
         &mut stdout,
         None,
@@ -134,11 +163,15 @@ pub fn run(
 /// rich error messages with annotated source code.
 /// New entry run events are reported to `progress` and are usually used to display a progress bar
 /// in test mode.
+///
+/// OAuth2 access tokens are obtained once and shared through `oauth2_tokens`, which can be shared
+/// by several runs.
 pub fn run_entries(
     entries: &[Entry],
     content: &str,
     filename: Option<&Input>,
     runner_options: &RunnerOptions,
+    oauth2_tokens: &OAuth2TokenCache,
     variables: &VariableSet,
     stdout: &mut Stdout,
     listener: Option<&dyn EventListener>,
@@ -170,8 +203,25 @@ pub fn run_entries(
         );
     }
 
+    // Proxy credentials and private keys are redacted, even when they're not given by secret variables.
+    let options_secrets = get_options_secrets(runner_options);
+    if !options_secrets.is_empty() {
+        options_secrets
+            .into_iter()
+            .for_each(|secret| variables.add_secret(secret));
+        logger.set_secrets(variables.secrets());
+    }
+
     log_run_info(entries, runner_options, &variables, logger);
 
+    // Netscape cookie files are read by libcurl, JSON cookie jars are loaded once in the cookie
+    // storage before running the first entry.
+    if let Some(filename) = &runner_options.cookie_input_file {
+        if is_json_cookie_jar(filename) {
+            load_json_cookie_jar(&mut http_client, filename, logger);
+        }
+    }
+
     // Main loop processing each entry.
     // The `entry_index` is not always incremented of each loop tick: an entry can be retried upon
     // errors for instance. Each entry is executed with options that are computed from the global
@@ -255,21 +305,52 @@ pub fn run_entries(
             thread::sleep(delay);
         };
 
+        // The cookie storage can be cleared for this entry, or put aside and restored after the
+        // entry has been run.
+        let saved_cookies = match options.cookie_storage {
+            Some(CookieStorageMode::Clear) => {
+                http_client.clear_cookie_storage(logger);
+                None
+            }
+            Some(CookieStorageMode::Isolate) => {
+                let cookies = http_client.cookie_storage(logger);
+                http_client.clear_cookie_storage(logger);
+                Some(cookies)
+            }
+            None => None,
+        };
+
         // Loop for executing HTTP run requests, with optional retry. Only "HTTP" errors in options
         // are taken into account for retry (errors while computing entry options and output error
         // are not retried).
-        let results = run_request(
+        let mut results = run_request(
             entry,
             entry_index,
             content,
             filename,
             &mut http_client,
+            oauth2_tokens,
             &options,
             &mut variables,
             stdout,
             logger,
         );
 
+        if let Some(cookies) = saved_cookies {
+            http_client.clear_cookie_storage(logger);
+            let restored = cookies
+                .iter()
+                .try_for_each(|cookie| http_client.add_cookie(cookie, logger));
+            if let (Err(http_error), Some(result)) = (restored, results.last_mut()) {
+                let error = RunnerError::new(
+                    entry.request.source_info,
+                    RunnerErrorKind::Http(http_error),
+                    false,
+                );
+                result.errors.push(error);
+            }
+        }
+
         let has_error = results.last().is_some_and(|r| !r.errors.is_empty());
 
         entries_result.extend(results);
@@ -320,6 +401,23 @@ pub fn run_entries(
     }
 }
 
+/// Adds the cookies of the JSON cookie jar `filename` to the cookie storage of `http_client`.
+fn load_json_cookie_jar(http_client: &mut Client, filename: &str, logger: &mut Logger) {
+    let cookies = match std::fs::read_to_string(filename) {
+        Ok(content) => cookies_from_json(&content),
+        Err(e) => Err(e.to_string()),
+    };
+    let added = cookies.and_then(|cookies| {
+        cookies
+            .iter()
+            .try_for_each(|cookie| http_client.add_cookie(cookie, logger))
+            .map_err(|e| e.message())
+    });
+    if let Err(e) = added {
+        logger.warning(&format!("Cookie jar <{filename}> can not be read: {e}"));
+    }
+}
+
 /// Runs an HTTP request and optional retry it until there are no HTTP errors. Returns a list of
 /// [`EntryResult`].
 #[allow(clippy::too_many_arguments)]
@@ -329,6 +427,7 @@ fn run_request(
     content: &str,
     filename: Option<&Input>,
     http_client: &mut Client,
+    oauth2_tokens: &OAuth2TokenCache,
     options: &RunnerOptions,
     variables: &mut VariableSet,
     stdout: &mut Stdout,
@@ -338,7 +437,15 @@ fn run_request(
     let mut retry_count = 1;
 
     loop {
-        let mut result = entry::run(entry, entry_index, http_client, variables, options, logger);
+        let mut result = entry::run(
+            entry,
+            entry_index,
+            http_client,
+            oauth2_tokens,
+            variables,
+            options,
+            logger,
+        );
 
         // Check if we need to retry.
         let mut has_error = !result.errors.is_empty();
@@ -444,6 +551,26 @@ fn is_success(entries: &[EntryResult]) -> bool {
     true
 }
 
+/// Returns the values of the options (proxy credentials, PEM private key) that must never be logged.
+fn get_options_secrets(options: &RunnerOptions) -> Vec<String> {
+    let mut secrets = vec![];
+    if let Some(key) = &options.client_key_file {
+        if is_pem(key) {
+            secrets.extend(pem_secrets(key));
+        }
+    }
+    if let Some(proxy) = &options.proxy {
+        secrets.extend(proxy_url_secrets(proxy));
+    }
+    if let Some(proxy_user) = &options.proxy_user {
+        secrets.extend(proxy_user_secrets(proxy_user));
+    }
+    for header in &options.proxy_headers {
+        secrets.extend(proxy_header_secrets(header));
+    }
+    secrets
+}
+
 // Returns the list of options that have non-default values.
 fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String)> {
     let default_options = RunnerOptions::default();
@@ -480,6 +607,10 @@ fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String
         }
     }
 
+    if options.proxy_insecure != default_options.proxy_insecure {
+        non_default_options.push(("proxy insecure", options.proxy_insecure.to_string()));
+    }
+
     if options.retry != default_options.retry {
         let value = match options.retry {
             Some(retry) => retry.to_string(),
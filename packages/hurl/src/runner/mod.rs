@@ -22,9 +22,9 @@ pub use self::error::{RunnerError, RunnerErrorKind};
 #[doc(hidden)]
 pub use self::event::EventListener;
 pub use self::http_response::HttpResponse;
-pub use self::hurl_file::run;
 #[doc(hidden)]
 pub use self::hurl_file::run_entries;
+pub use self::hurl_file::{run, run_with_oauth2_tokens};
 pub use self::number::Number;
 pub use self::output::Output;
 pub use self::result::{AssertResult, CaptureResult, EntryResult, HurlResult};
@@ -43,6 +43,7 @@ mod event;
 mod expr;
 mod filter;
 mod function;
+mod graphql;
 pub mod hex;
 mod http_response;
 mod hurl_file;
@@ -15,18 +15,26 @@
  * limitations under the License.
  *
  */
+use std::path::PathBuf;
+use std::str::FromStr;
+
 use hurl_core::ast::{
     BooleanOption, CountOption, DurationOption, Entry, NaturalOption, Number as AstNumber,
-    OptionKind, Placeholder, SectionValue, VariableDefinition, VariableValue,
+    OptionKind, Placeholder, SectionValue, Template, VariableDefinition, VariableValue,
 };
 use hurl_core::typing::{BytesPerSec, Count, DurationUnit};
 
-use crate::http::{IpResolve, RequestedHttpVersion};
+use crate::http::{
+    is_pem, pem_secrets, proxy_header_secrets, proxy_url_secrets, proxy_user_secrets, AuthMethod,
+    ContentEncoding, CookieStorageMode, DigestAlgorithm, HttpSignature, IpResolve, OAuth2,
+    RequestedHttpVersion, TlsVersion,
+};
 use crate::runner::template::eval_template;
 use crate::runner::{
     expr, Number, Output, RunnerError, RunnerErrorKind, RunnerOptions, Value, VariableSet,
 };
 use crate::util::logger::{Logger, Verbosity};
+use crate::util::path::ContextDir;
 
 /// Returns a new [`RunnerOptions`] based on the `entry` optional Options section
 /// and a default `runner_options`.
@@ -56,6 +64,11 @@ pub fn get_entry_options(
         if let SectionValue::Options(options) = &section.value {
             for option in options.iter() {
                 match &option.kind {
+                    OptionKind::AnyAuth(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.auth_method =
+                            toggle_auth_method(entry_options.auth_method, AuthMethod::Any, value);
+                    }
                     OptionKind::AwsSigV4(value) => {
                         let value = eval_template(value, variables)?;
                         entry_options.aws_sigv4 = Some(value);
@@ -64,14 +77,32 @@ pub fn get_entry_options(
                         let value = eval_template(filename, variables)?;
                         entry_options.cacert_file = Some(value);
                     }
+                    OptionKind::CertStatus(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.cert_status = value;
+                    }
                     OptionKind::ClientCert(filename) => {
                         let value = eval_template(filename, variables)?;
                         entry_options.client_cert_file = Some(value);
                     }
                     OptionKind::ClientKey(filename) => {
                         let value = eval_template(filename, variables)?;
+                        if is_pem(&value) {
+                            add_secrets(pem_secrets(&value), variables, logger);
+                        }
                         entry_options.client_key_file = Some(value);
                     }
+                    OptionKind::CompressBody(value) => {
+                        let encoding = eval_template(value, variables)?;
+                        let encoding = match ContentEncoding::from_str(&encoding) {
+                            Ok(encoding) => encoding,
+                            Err(message) => {
+                                let kind = RunnerErrorKind::InvalidContentEncoding { message };
+                                return Err(RunnerError::new(value.source_info, kind, false));
+                            }
+                        };
+                        entry_options.compress_body = Some(encoding);
+                    }
                     OptionKind::Compressed(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.compressed = value;
@@ -85,15 +116,91 @@ pub fn get_entry_options(
                             eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
                         entry_options.connect_timeout = value;
                     }
+                    OptionKind::ContentDigest(value) => {
+                        let algorithm = eval_template(value, variables)?;
+                        let algorithm = match DigestAlgorithm::from_str(&algorithm) {
+                            Ok(algorithm) => algorithm,
+                            Err(message) => {
+                                let kind = RunnerErrorKind::InvalidDigest { message };
+                                return Err(RunnerError::new(value.source_info, kind, false));
+                            }
+                        };
+                        entry_options.content_digest = Some(algorithm);
+                    }
+                    OptionKind::CookieStorage(value) => {
+                        let mode = eval_template(value, variables)?;
+                        let mode = match CookieStorageMode::from_str(&mode) {
+                            Ok(mode) => mode,
+                            Err(message) => {
+                                let kind = RunnerErrorKind::InvalidCookieStorageMode { message };
+                                return Err(RunnerError::new(value.source_info, kind, false));
+                            }
+                        };
+                        entry_options.cookie_storage = Some(mode);
+                    }
+                    OptionKind::CrlFile(filename) => {
+                        let value = eval_template(filename, variables)?;
+                        entry_options.crl_file = Some(value);
+                    }
                     OptionKind::Delay(value) => {
                         let value =
                             eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
                         entry_options.delay = value;
                     }
+                    OptionKind::Digest(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.auth_method = toggle_auth_method(
+                            entry_options.auth_method,
+                            AuthMethod::Digest,
+                            value,
+                        );
+                    }
+                    OptionKind::GraphQlAllowErrors(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.graphql_allow_errors = value;
+                    }
+                    OptionKind::GraphQlSchema(filename) => {
+                        let value = eval_template(filename, variables)?;
+                        let path = PathBuf::from(value);
+                        if !entry_options.context_dir.is_access_allowed(&path) {
+                            let kind = RunnerErrorKind::UnauthorizedFileAccess { path };
+                            return Err(RunnerError::new(filename.source_info, kind, false));
+                        }
+                        let path = entry_options.context_dir.resolved_path(&path);
+                        entry_options.graphql_schema = Some(path.to_string_lossy().to_string());
+                    }
+                    OptionKind::GrpcProto(filename) => {
+                        let value = eval_template(filename, variables)?;
+                        let path = PathBuf::from(value);
+                        if !entry_options.context_dir.is_access_allowed(&path) {
+                            let kind = RunnerErrorKind::UnauthorizedFileAccess { path };
+                            return Err(RunnerError::new(filename.source_info, kind, false));
+                        }
+                        let path = entry_options.context_dir.resolved_path(&path);
+                        entry_options.grpc_proto = Some(path.to_string_lossy().to_string());
+                    }
                     OptionKind::Header(value) => {
                         let value = eval_template(value, variables)?;
                         entry_options.headers.push(value);
                     }
+                    OptionKind::HttpSignature(value) => {
+                        let params = eval_template(value, variables)?;
+                        let mut http_signature = match HttpSignature::from_str(&params) {
+                            Ok(http_signature) => http_signature,
+                            Err(message) => {
+                                let kind = RunnerErrorKind::InvalidHttpSignature { message };
+                                return Err(RunnerError::new(value.source_info, kind, false));
+                            }
+                        };
+                        let path = PathBuf::from(&http_signature.key_file);
+                        if !entry_options.context_dir.is_access_allowed(&path) {
+                            let kind = RunnerErrorKind::UnauthorizedFileAccess { path };
+                            return Err(RunnerError::new(value.source_info, kind, false));
+                        }
+                        let path = entry_options.context_dir.resolved_path(&path);
+                        http_signature.key_file = path.to_string_lossy().to_string();
+                        entry_options.http_signature = Some(http_signature);
+                    }
                     // HTTP version options (such as http1.0, http1.1, http2 etc...) are activated
                     // through a flag. In an `[Options]` section, the signification of such a flag is:
                     //
@@ -196,10 +303,27 @@ pub fn get_entry_options(
                         entry_options.max_send_speed = Some(BytesPerSec(value));
                         entry_options.max_recv_speed = Some(BytesPerSec(value));
                     }
+                    OptionKind::MaxFileSize(value) => {
+                        let value = eval_natural_option(value, variables)?;
+                        entry_options.max_filesize = Some(value);
+                    }
                     OptionKind::MaxRedirect(value) => {
                         let value = eval_count_option(value, variables)?;
                         entry_options.max_redirect = value;
                     }
+                    OptionKind::MaxTime(value) => {
+                        let value =
+                            eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
+                        entry_options.timeout = value;
+                    }
+                    OptionKind::Negotiate(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.auth_method = toggle_auth_method(
+                            entry_options.auth_method,
+                            AuthMethod::Negotiate,
+                            value,
+                        );
+                    }
                     OptionKind::NetRc(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.netrc = value;
@@ -212,6 +336,29 @@ pub fn get_entry_options(
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.netrc_optional = value;
                     }
+                    OptionKind::Ntlm(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.auth_method =
+                            toggle_auth_method(entry_options.auth_method, AuthMethod::Ntlm, value);
+                    }
+                    OptionKind::OAuth2(value) => {
+                        let params = eval_template(value, variables)?;
+                        let oauth2 = match OAuth2::from_str(&params) {
+                            Ok(oauth2) => oauth2,
+                            Err(message) => {
+                                let kind = RunnerErrorKind::InvalidOAuth2 { message };
+                                return Err(RunnerError::new(value.source_info, kind, false));
+                            }
+                        };
+                        // Client credentials are redacted, even when they're not given by secret
+                        // variables.
+                        oauth2
+                            .secrets()
+                            .into_iter()
+                            .for_each(|secret| variables.add_secret(secret));
+                        logger.set_secrets(variables.secrets());
+                        entry_options.oauth2 = Some(oauth2);
+                    }
                     OptionKind::Output(output) => {
                         let filename = eval_template(output, variables)?;
                         let output = Output::new(&filename);
@@ -221,10 +368,68 @@ pub fn get_entry_options(
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.path_as_is = value;
                     }
+                    OptionKind::PinnedPublicKey(value) => {
+                        let context_dir = &entry_options.context_dir;
+                        let value = eval_pinned_pubkey(value, variables, context_dir)?;
+                        entry_options.pinned_pubkey = Some(value);
+                    }
                     OptionKind::Proxy(value) => {
                         let value = eval_template(value, variables)?;
+                        // Credentials are redacted, even when they're not given by secret
+                        // variables.
+                        add_secrets(proxy_url_secrets(&value), variables, logger);
                         entry_options.proxy = Some(value);
                     }
+                    OptionKind::ProxyAnyAuth(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.proxy_auth_method = toggle_auth_method(
+                            entry_options.proxy_auth_method,
+                            AuthMethod::Any,
+                            value,
+                        );
+                    }
+                    OptionKind::ProxyCaCertificate(filename) => {
+                        let value = eval_template(filename, variables)?;
+                        entry_options.proxy_cacert_file = Some(value);
+                    }
+                    OptionKind::ProxyDigest(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.proxy_auth_method = toggle_auth_method(
+                            entry_options.proxy_auth_method,
+                            AuthMethod::Digest,
+                            value,
+                        );
+                    }
+                    OptionKind::ProxyHeader(value) => {
+                        let value = eval_template(value, variables)?;
+                        add_secrets(proxy_header_secrets(&value), variables, logger);
+                        entry_options.proxy_headers.push(value);
+                    }
+                    OptionKind::ProxyInsecure(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.proxy_insecure = value;
+                    }
+                    OptionKind::ProxyNegotiate(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.proxy_auth_method = toggle_auth_method(
+                            entry_options.proxy_auth_method,
+                            AuthMethod::Negotiate,
+                            value,
+                        );
+                    }
+                    OptionKind::ProxyNtlm(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.proxy_auth_method = toggle_auth_method(
+                            entry_options.proxy_auth_method,
+                            AuthMethod::Ntlm,
+                            value,
+                        );
+                    }
+                    OptionKind::ProxyUser(value) => {
+                        let value = eval_template(value, variables)?;
+                        add_secrets(proxy_user_secrets(&value), variables, logger);
+                        entry_options.proxy_user = Some(value);
+                    }
                     OptionKind::Repeat(value) => {
                         let value = eval_count_option(value, variables)?;
                         entry_options.repeat = Some(value);
@@ -246,6 +451,23 @@ pub fn get_entry_options(
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.skip = value;
                     }
+                    OptionKind::SseDuration(value) => {
+                        let value =
+                            eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
+                        entry_options.sse_duration = Some(value);
+                    }
+                    OptionKind::SseEvents(value) => {
+                        let value = eval_count_option(value, variables)?;
+                        entry_options.sse_events = value;
+                    }
+                    OptionKind::TlsMax(value) => {
+                        let version = eval_tls_version(value, variables)?;
+                        entry_options.tls_max_version = Some(version);
+                    }
+                    OptionKind::TlsMin(value) => {
+                        let version = eval_tls_version(value, variables)?;
+                        entry_options.tls_min_version = Some(version);
+                    }
                     OptionKind::UnixSocket(value) => {
                         let value = eval_template(value, variables)?;
                         entry_options.unix_socket = Some(value);
@@ -267,6 +489,15 @@ pub fn get_entry_options(
                     OptionKind::VeryVerbose(value) => {
                         eval_boolean_option(value, variables)?;
                     }
+                    OptionKind::WebSocketMessages(value) => {
+                        let value = eval_count_option(value, variables)?;
+                        entry_options.websocket_messages = value;
+                    }
+                    OptionKind::WebSocketTimeout(value) => {
+                        let value =
+                            eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
+                        entry_options.websocket_timeout = value;
+                    }
                 }
                 logger.debug(&option.kind.to_string());
             }
@@ -284,6 +515,30 @@ fn has_options(entry: &Entry) -> bool {
         .any(|s| matches!(s.value, SectionValue::Options(_)))
 }
 
+/// Returns the authentication method after enabling (`value` is true) or disabling `method`.
+///
+/// Disabling a method only resets `current` to Basic if `method` is the current method.
+fn toggle_auth_method(current: AuthMethod, method: AuthMethod, value: bool) -> AuthMethod {
+    if value {
+        method
+    } else if current == method {
+        AuthMethod::Basic
+    } else {
+        current
+    }
+}
+
+/// Adds `secrets` to the `variables` secrets, so they're redacted by the `logger`.
+fn add_secrets(secrets: Vec<String>, variables: &mut VariableSet, logger: &mut Logger) {
+    if secrets.is_empty() {
+        return;
+    }
+    secrets
+        .into_iter()
+        .for_each(|secret| variables.add_secret(secret));
+    logger.set_secrets(variables.secrets());
+}
+
 /// Returns the overridden `entry` verbosity, or the default `verbosity` file.
 pub fn get_entry_verbosity(
     entry: &Entry,
@@ -448,6 +703,35 @@ fn eval_duration_option(
     Ok(std::time::Duration::from_millis(millis))
 }
 
+/// Evaluates a TLS version option (`tls-min` or `tls-max`).
+fn eval_tls_version(value: &Template, variables: &VariableSet) -> Result<TlsVersion, RunnerError> {
+    let version = eval_template(value, variables)?;
+    TlsVersion::from_str(&version).map_err(|message| {
+        let kind = RunnerErrorKind::InvalidTlsVersion { message };
+        RunnerError::new(value.source_info, kind, false)
+    })
+}
+
+/// Evaluates a `pinnedpubkey` option: either `sha256//` hashes, or a public key file resolved
+/// against `context_dir`.
+fn eval_pinned_pubkey(
+    value: &Template,
+    variables: &VariableSet,
+    context_dir: &ContextDir,
+) -> Result<String, RunnerError> {
+    let pinned_pubkey = eval_template(value, variables)?;
+    if pinned_pubkey.starts_with("sha256//") {
+        return Ok(pinned_pubkey);
+    }
+    let path = PathBuf::from(pinned_pubkey);
+    if !context_dir.is_access_allowed(&path) {
+        let kind = RunnerErrorKind::UnauthorizedFileAccess { path };
+        return Err(RunnerError::new(value.source_info, kind, false));
+    }
+    let path = context_dir.resolved_path(&path);
+    Ok(path.to_string_lossy().to_string())
+}
+
 fn eval_variable_value(
     variable_value: &VariableValue,
     variables: &mut VariableSet,
@@ -473,7 +757,11 @@ fn eval_number(number: &AstNumber) -> Value {
 
 #[cfg(test)]
 mod tests {
-    use hurl_core::ast::{Expr, ExprKind, Placeholder, SourceInfo, Variable, Whitespace, U64};
+    use std::path::Path;
+
+    use hurl_core::ast::{
+        Expr, ExprKind, Placeholder, SourceInfo, TemplateElement, Variable, Whitespace, U64,
+    };
     use hurl_core::reader::Pos;
     use hurl_core::typing::{Duration, DurationUnit, ToSource};
 
@@ -522,6 +810,22 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_toggle_auth_method() {
+        assert_eq!(
+            toggle_auth_method(AuthMethod::Basic, AuthMethod::Digest, true),
+            AuthMethod::Digest
+        );
+        assert_eq!(
+            toggle_auth_method(AuthMethod::Digest, AuthMethod::Digest, false),
+            AuthMethod::Basic
+        );
+        assert_eq!(
+            toggle_auth_method(AuthMethod::Ntlm, AuthMethod::Digest, false),
+            AuthMethod::Ntlm
+        );
+    }
+
     #[test]
     fn test_eval_boolean_option() {
         let mut variables = VariableSet::default();
@@ -585,4 +889,42 @@ mod tests {
             std::time::Duration::from_millis(10)
         );
     }
+
+    fn template(value: &str) -> Template {
+        Template::new(
+            None,
+            vec![TemplateElement::String {
+                value: value.to_string(),
+                source: value.to_source(),
+            }],
+            SourceInfo::new(Pos::new(1, 15), Pos::new(1, 15 + value.len())),
+        )
+    }
+
+    #[test]
+    fn test_eval_pinned_pubkey() {
+        let variables = VariableSet::default();
+        let context_dir = ContextDir::new(Path::new("/home"), Path::new("file_root"));
+
+        let hashes = "sha256//YhKJKSzoTt2b5FP18fvpHo7fJYqQCjAa3HWY3tvRMwE=;sha256//2Pdd70VDk/2utfbDTinCDc66k3vjUGj4d0F/gwkEKCc=";
+        assert_eq!(
+            eval_pinned_pubkey(&template(hashes), &variables, &context_dir).unwrap(),
+            hashes
+        );
+        assert_eq!(
+            eval_pinned_pubkey(&template("certs/pubkey.pem"), &variables, &context_dir).unwrap(),
+            Path::new("file_root")
+                .join("certs/pubkey.pem")
+                .to_string_lossy()
+        );
+
+        let error =
+            eval_pinned_pubkey(&template("../pubkey.pem"), &variables, &context_dir).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::UnauthorizedFileAccess {
+                path: PathBuf::from("../pubkey.pem")
+            }
+        );
+    }
 }
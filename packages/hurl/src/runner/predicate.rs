@@ -16,10 +16,14 @@
  *
  */
 use std::cmp::Ordering;
+use std::path::Path;
+use std::str::FromStr;
 
 use hurl_core::ast::{Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SourceInfo};
 use hurl_core::reader::Pos;
 
+use crate::http;
+use crate::http::SignatureVerifier;
 use crate::runner::error::RunnerError;
 use crate::runner::predicate_value::{eval_predicate_value, eval_predicate_value_template};
 use crate::runner::value::{EvalError, Value};
@@ -48,12 +52,14 @@ pub fn eval_predicate(
     predicate: &Predicate,
     variables: &VariableSet,
     value: &Option<Value>,
+    http_response: Option<&http::Response>,
     context_dir: &ContextDir,
 ) -> Result<(), RunnerError> {
     let result = eval_predicate_func(
         &predicate.predicate_func,
         variables,
         value.as_ref(),
+        http_response,
         context_dir,
     )?;
 
@@ -190,6 +196,7 @@ fn expected_no_value(
             let expected = eval_predicate_value_template(expected, variables)?;
             Ok(format!("matches regex <{expected}>"))
         }
+        PredicateFuncValue::Signature { .. } => Ok("valid signature".to_string()),
         PredicateFuncValue::IsInteger => Ok("integer".to_string()),
         PredicateFuncValue::IsFloat => Ok("float".to_string()),
         PredicateFuncValue::IsBoolean => Ok("boolean".to_string()),
@@ -202,16 +209,19 @@ fn expected_no_value(
         PredicateFuncValue::IsNumber => Ok("number".to_string()),
         PredicateFuncValue::IsIpv4 => Ok("ipv4".to_string()),
         PredicateFuncValue::IsIpv6 => Ok("ipv6".to_string()),
+        PredicateFuncValue::IsDigest => Ok("digest of the response body".to_string()),
     }
 }
 
 /// Evaluates a `predicate_func` against an actual `value`.
 /// The `predicate_func` is a test with an expected value. The expected value can
-/// use a set of `variables`.
+/// use a set of `variables`. Some predicates (like `signature`) also check the last HTTP response
+/// `http_response`.
 fn eval_predicate_func(
     predicate_func: &PredicateFunc,
     variables: &VariableSet,
     value: Option<&Value>,
+    http_response: Option<&http::Response>,
     context_dir: &ContextDir,
 ) -> Result<PredicateResult, RunnerError> {
     let value = match value {
@@ -267,6 +277,16 @@ fn eval_predicate_func(
             value,
             context_dir,
         ),
+        PredicateFuncValue::Signature {
+            value: expected, ..
+        } => eval_signature(
+            expected,
+            predicate_func.source_info,
+            variables,
+            value,
+            http_response,
+            context_dir,
+        ),
         PredicateFuncValue::IsInteger => eval_is_integer(value),
         PredicateFuncValue::IsFloat => eval_is_float(value),
         PredicateFuncValue::IsBoolean => eval_is_boolean(value),
@@ -279,6 +299,9 @@ fn eval_predicate_func(
         PredicateFuncValue::IsNumber => eval_is_number(value),
         PredicateFuncValue::IsIpv4 => eval_is_ipv4(value),
         PredicateFuncValue::IsIpv6 => eval_is_ipv6(value),
+        PredicateFuncValue::IsDigest => {
+            eval_is_digest(value, http_response, predicate_func.source_info)
+        }
     }
 }
 
@@ -473,6 +496,58 @@ fn eval_match(
     }
 }
 
+/// Evaluates if an `actual` signature header value is a valid signature of an `http_response`.
+/// The `expected` value holds the verification parameters (key file, algorithm etc...).
+fn eval_signature(
+    expected: &PredicateValue,
+    source_info: SourceInfo,
+    variables: &VariableSet,
+    actual: &Value,
+    http_response: Option<&http::Response>,
+    context_dir: &ContextDir,
+) -> Result<PredicateResult, RunnerError> {
+    let source_info = match expected {
+        PredicateValue::String(template) => template.source_info,
+        _ => {
+            let message = "verification parameters must be a string".to_string();
+            let kind = RunnerErrorKind::InvalidHttpSignature { message };
+            return Err(RunnerError::new(source_info, kind, true));
+        }
+    };
+    let params = eval_predicate_value_template(expected, variables)?;
+    let error = |message| {
+        let kind = RunnerErrorKind::InvalidHttpSignature { message };
+        RunnerError::new(source_info, kind, true)
+    };
+    let mut verifier = SignatureVerifier::from_str(&params).map_err(error)?;
+    let path = Path::new(&verifier.key_file).to_path_buf();
+    if !context_dir.is_access_allowed(&path) {
+        let kind = RunnerErrorKind::UnauthorizedFileAccess { path };
+        return Err(RunnerError::new(source_info, kind, true));
+    }
+    let expected_display = format!("valid signature for key <{}>", verifier.key_file);
+    verifier.key_file = context_dir
+        .resolved_path(&path)
+        .to_string_lossy()
+        .to_string();
+
+    let (Value::String(signature), Some(http_response)) = (actual, http_response) else {
+        return Ok(PredicateResult {
+            success: false,
+            actual: actual.repr(),
+            expected: expected_display,
+            type_mismatch: true,
+        });
+    };
+    let success = verifier.verify(signature, http_response).map_err(error)?;
+    Ok(PredicateResult {
+        success,
+        actual: actual.repr(),
+        expected: expected_display,
+        type_mismatch: false,
+    })
+}
+
 /// Evaluates if an `actual` value is an integer.
 fn eval_is_integer(actual: &Value) -> Result<PredicateResult, RunnerError> {
     Ok(PredicateResult {
@@ -642,6 +717,34 @@ fn eval_is_ipv6(actual: &Value) -> Result<PredicateResult, RunnerError> {
     }
 }
 
+/// Evaluates if an `actual` digest field value (from a `Content-Digest` or `Repr-Digest` header)
+/// is a valid digest of the body of an `http_response`.
+fn eval_is_digest(
+    actual: &Value,
+    http_response: Option<&http::Response>,
+    source_info: SourceInfo,
+) -> Result<PredicateResult, RunnerError> {
+    let expected = "digest of the response body".to_string();
+    let (Value::String(digest), Some(http_response)) = (actual, http_response) else {
+        return Ok(PredicateResult {
+            success: false,
+            actual: actual.repr(),
+            expected,
+            type_mismatch: true,
+        });
+    };
+    let success = http::verify_digest(digest, http_response).map_err(|message| {
+        let kind = RunnerErrorKind::InvalidDigest { message };
+        RunnerError::new(source_info, kind, true)
+    })?;
+    Ok(PredicateResult {
+        success,
+        actual: actual.repr(),
+        expected,
+        type_mismatch: false,
+    })
+}
+
 fn assert_values_equal(actual: &Value, expected: &Value) -> PredicateResult {
     let success = actual == expected;
     let actual = actual.repr();
@@ -817,6 +920,7 @@ mod tests {
             &predicate,
             &variables,
             &Some(Value::Bool(true)),
+            None,
             &context_dir
         )
         .is_ok());
@@ -825,6 +929,7 @@ mod tests {
             &predicate,
             &variables,
             &Some(Value::Number(Number::Integer(10))),
+            None,
             &context_dir,
         )
         .unwrap_err();
@@ -845,6 +950,7 @@ mod tests {
             &predicate,
             &variables,
             &Some(Value::Number(Number::Integer(1))),
+            None,
             &context_dir
         )
         .is_ok());
@@ -953,13 +1059,15 @@ mod tests {
         };
 
         let value = Some(&Value::Unit);
-        let result = eval_predicate_func(&pred_func, &variables, value, &context_dir).unwrap();
+        let result =
+            eval_predicate_func(&pred_func, &variables, value, None, &context_dir).unwrap();
         assert!(result.success);
         assert_eq!(result.actual.as_str(), "unit");
         assert_eq!(result.expected.as_str(), "something");
 
         let value = None;
-        let result = eval_predicate_func(&pred_func, &variables, value, &context_dir).unwrap();
+        let result =
+            eval_predicate_func(&pred_func, &variables, value, None, &context_dir).unwrap();
         assert!(!result.success);
         assert!(!result.type_mismatch);
         assert_eq!(result.actual, "none");
@@ -1291,6 +1399,7 @@ mod tests {
             &predicate,
             &variables,
             &Some(Value::Number(Number::Integer(1))),
+            None,
             &context_dir
         )
         .is_ok());
@@ -1320,6 +1429,7 @@ mod tests {
             &predicate,
             &variables,
             &Some(Value::Number(Number::Integer(1))),
+            None,
             &context_dir,
         )
         .unwrap_err();
@@ -1376,7 +1486,7 @@ mod tests {
             },
         };
 
-        let error = eval_predicate(&predicate, &variables, &None, &context_dir)
+        let error = eval_predicate(&predicate, &variables, &None, None, &context_dir)
             .err()
             .unwrap();
         assert_eq!(
@@ -1402,7 +1512,7 @@ mod tests {
         };
 
         let variables = VariableSet::new();
-        assert!(eval_predicate(&predicate, &variables, &None, &context_dir).is_ok());
+        assert!(eval_predicate(&predicate, &variables, &None, None, &context_dir).is_ok());
     }
 
     #[test]
@@ -1428,6 +1538,33 @@ mod tests {
         assert_eq!(result.expected, "matches regex </a{3}/>");
     }
 
+    #[test]
+    fn test_predicate_signature_not_string() {
+        let variables = VariableSet::new();
+        let context_dir = ContextDir::default();
+
+        // predicate: `signature true`
+        let expected = PredicateValue::Bool(true);
+        let value = Value::String("sig1=:abc:".to_string());
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10));
+        let error = eval_signature(
+            &expected,
+            source_info,
+            &variables,
+            &value,
+            None,
+            &context_dir,
+        )
+        .unwrap_err();
+        assert_eq!(error.source_info, source_info);
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::InvalidHttpSignature {
+                message: "verification parameters must be a string".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_predicate_is_iso_date() {
         let value = Value::String("2020-03-09T22:18:26.625Z".to_string());
@@ -17,13 +17,14 @@ use chrono::Utc;
  *
  */
 use hurl_core::ast::{
-    CertificateAttributeName, CookieAttribute, CookieAttributeName, CookiePath, Query, QueryValue,
-    RegexValue, SourceInfo, Template,
+    CertificateAttributeName, CookieAttribute, CookieAttributeName, CookiePath, GraphQlField,
+    Query, QueryValue, RegexValue, SourceInfo, Template,
 };
 use regex::Regex;
 use sha2::Digest;
 
 use crate::http;
+use crate::http::ServerSentEvent;
 use crate::runner::cache::BodyCache;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
 use crate::runner::template::eval_template;
@@ -37,6 +38,7 @@ pub fn eval_query(
     query: &Query,
     variables: &VariableSet,
     responses: &[&http::Response],
+    cookie_storage: &[http::Cookie],
     cache: &mut BodyCache,
 ) -> QueryResult {
     let last_response = responses.last().unwrap();
@@ -49,6 +51,10 @@ pub fn eval_query(
             expr: CookiePath { name, attribute },
             ..
         } => eval_query_cookie(last_response, name, attribute, variables),
+        QueryValue::CookieJar {
+            expr: CookiePath { name, attribute },
+            ..
+        } => eval_query_cookiejar(last_response, cookie_storage, name, attribute, variables),
         QueryValue::Body => eval_query_body(last_response, query.source_info),
         QueryValue::Xpath { expr, .. } => {
             eval_query_xpath(last_response, cache, expr, variables, query.source_info)
@@ -70,6 +76,12 @@ pub fn eval_query(
         } => eval_query_certificate(last_response, *field),
         QueryValue::Ip => eval_ip(last_response),
         QueryValue::Redirects => eval_redirects(responses),
+        QueryValue::Messages => eval_messages(last_response),
+        QueryValue::Sse => eval_query_sse(last_response, cache, query.source_info),
+        QueryValue::GrpcStatus => eval_query_grpc_status(last_response),
+        QueryValue::GraphQl { field, .. } => {
+            eval_query_graphql(last_response, cache, *field, query.source_info)
+        }
     }
 }
 
@@ -141,6 +153,50 @@ fn eval_query_cookie(
     }
 }
 
+/// Evaluates a cookie query `name` with optional attributes, on the HTTP client `cookie_storage`
+/// given a set of `variables`.
+///
+/// Only the cookies whose domain and path match the URL of the `response` are queried.
+fn eval_query_cookiejar(
+    response: &http::Response,
+    cookie_storage: &[http::Cookie],
+    name: &Template,
+    attribute: &Option<CookieAttribute>,
+    variables: &VariableSet,
+) -> QueryResult {
+    let name = eval_template(name, variables)?;
+    let Some(cookie) = cookie_storage
+        .iter()
+        .find(|c| c.name == name && http::match_cookie(c, &response.url))
+    else {
+        return Ok(None);
+    };
+    let attribute_name = if let Some(attribute) = attribute {
+        attribute.name.clone()
+    } else {
+        CookieAttributeName::Value("Value".to_string())
+    };
+    let value = match attribute_name {
+        CookieAttributeName::Value(_) => Some(Value::String(cookie.value.clone())),
+        // A session cookie has no expiration date in the cookie storage.
+        CookieAttributeName::Expires(_) => cookie
+            .expires
+            .parse::<i64>()
+            .ok()
+            .filter(|t| *t > 0)
+            .and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+            .map(Value::Date),
+        // The cookie storage only keeps the expiration date of a cookie.
+        CookieAttributeName::MaxAge(_) => None,
+        CookieAttributeName::Domain(_) => Some(Value::String(cookie.domain.clone())),
+        CookieAttributeName::Path(_) => Some(Value::String(cookie.path.clone())),
+        CookieAttributeName::Secure(_) => (cookie.https == "TRUE").then_some(Value::Unit),
+        CookieAttributeName::HttpOnly(_) => cookie.http_only.then_some(Value::Unit),
+        CookieAttributeName::SameSite(_) => cookie.same_site.clone().map(Value::String),
+    };
+    Ok(value)
+}
+
 /// Evaluates the HTTP `response` body as text.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
@@ -261,6 +317,68 @@ fn parse_cache_json<'cache>(
     Ok(cache.json().unwrap())
 }
 
+/// Evaluates the Server-Sent Events of the HTTP `response`.
+///
+/// Events are returned as a list of objects with `id`, `event`, `data` and `retry` fields.
+/// `query_source_info` is the source position of the query, used if an error is returned.
+fn eval_query_sse(
+    response: &http::Response,
+    cache: &mut BodyCache,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    let events = match cache.sse() {
+        Some(e) => e,
+        None => parse_cache_sse(response, cache, query_source_info)?,
+    };
+    let events = events
+        .iter()
+        .map(|e| {
+            let id = e.id.clone().map_or(Value::Null, Value::String);
+            let retry = match e.retry {
+                Some(r) => {
+                    let Ok(r) = i64::try_from(r) else {
+                        let message = format!("the event retry value <{r}> is too large");
+                        let kind = RunnerErrorKind::QueryInvalidSse { message };
+                        return Err(RunnerError::new(query_source_info, kind, false));
+                    };
+                    Value::Number(Number::Integer(r))
+                }
+                None => Value::Null,
+            };
+            Ok(Value::Object(vec![
+                ("id".to_string(), id),
+                ("event".to_string(), Value::String(e.event.clone())),
+                ("data".to_string(), Value::String(e.data.clone())),
+                ("retry".to_string(), retry),
+            ]))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Some(Value::List(events)))
+}
+
+/// Parse this HTTP `response` body to Server-Sent Events, and store the events to the response
+/// `cache`.
+///
+/// `query_source_info` is used for error reporting.
+fn parse_cache_sse<'cache>(
+    response: &http::Response,
+    cache: &'cache mut BodyCache,
+    query_source_info: SourceInfo,
+) -> Result<&'cache [ServerSentEvent], RunnerError> {
+    let events = match response.server_sent_events() {
+        Ok(events) => events,
+        Err(e) => {
+            return Err(RunnerError::new(
+                query_source_info,
+                RunnerErrorKind::Http(e),
+                false,
+            ))
+        }
+    };
+    cache.set_sse(events);
+    Ok(cache.sse().unwrap())
+}
+
 /// Evaluates a regex query on the HTTP `response` body, given a set of `variables`.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
@@ -414,6 +532,50 @@ fn eval_redirects(responses: &[&http::Response]) -> QueryResult {
     Ok(Some(Value::List(values)))
 }
 
+/// Evaluates the messages received on a WebSocket connection, as a list of strings (for text
+/// messages) and bytes (for binary messages).
+fn eval_messages(response: &http::Response) -> QueryResult {
+    let values = response
+        .messages
+        .iter()
+        .map(|m| match m {
+            http::WebSocketMessage::Text(text) => Value::String(text.clone()),
+            http::WebSocketMessage::Binary(bytes) => Value::Bytes(bytes.clone()),
+        })
+        .collect();
+    Ok(Some(Value::List(values)))
+}
+
+/// Evaluates the gRPC status code of the HTTP `response`.
+///
+/// The status is sent by the server in a `grpc-status` trailer (or header for "trailers-only"
+/// responses).
+fn eval_query_grpc_status(response: &http::Response) -> QueryResult {
+    let Some(value) = response.headers.values("grpc-status").pop() else {
+        return Ok(None);
+    };
+    let value = match value.trim().parse::<i64>() {
+        Ok(code) => Value::Number(Number::Integer(code)),
+        Err(_) => Value::String(value.to_string()),
+    };
+    Ok(Some(value))
+}
+
+/// Evaluates a top-level `field` (`data`, `errors` or `extensions`) of a GraphQL `response`.
+fn eval_query_graphql(
+    response: &http::Response,
+    cache: &mut BodyCache,
+    field: GraphQlField,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    let json = match cache.json() {
+        Some(j) => j,
+        None => parse_cache_json(response, cache, query_source_info)?,
+    };
+    let value = json.get(field.identifier()).map(Value::from_json);
+    Ok(value)
+}
+
 fn eval_cookie_attribute_name(
     cookie_attribute_name: CookieAttributeName,
     cookie: http::ResponseCookie,
@@ -506,7 +668,7 @@ pub mod tests {
     use hurl_core::typing::ToSource;
 
     use super::*;
-    use crate::http::{HeaderVec, HttpError, HttpVersion};
+    use crate::http::{Header, HeaderVec, HttpError, HttpVersion};
 
     fn default_response() -> http::Response {
         http::Response {
@@ -518,6 +680,7 @@ pub mod tests {
             url: "http://localhost".parse().unwrap(),
             certificate: None,
             ip_addr: Default::default(),
+            messages: vec![],
         }
     }
 
@@ -731,6 +894,7 @@ pub mod tests {
                 },
                 &variables,
                 &[&http::hello_http_response()],
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -770,6 +934,7 @@ pub mod tests {
                 &query_header,
                 &variables,
                 &[&http::hello_http_response()],
+                &[],
                 &mut cache
             )
             .unwrap(),
@@ -805,6 +970,7 @@ pub mod tests {
                 &query_header,
                 &variables,
                 &[&http::hello_http_response()],
+                &[],
                 &mut cache
             )
             .unwrap()
@@ -851,7 +1017,7 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &responses, &mut cache)
+            eval_query(&query, &variables, &responses, &[], &mut cache)
                 .unwrap()
                 .unwrap(),
             Value::String("DQAAAKEaem_vYg".to_string())
@@ -880,7 +1046,7 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &responses, &mut cache)
+            eval_query(&query, &variables, &responses, &[], &mut cache)
                 .unwrap()
                 .unwrap(),
             Value::String("/accounts".to_string())
@@ -909,7 +1075,7 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &responses, &mut cache)
+            eval_query(&query, &variables, &responses, &[], &mut cache)
                 .unwrap()
                 .unwrap(),
             Value::Unit
@@ -938,7 +1104,146 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &responses, &mut cache).unwrap(),
+            eval_query(&query, &variables, &responses, &[], &mut cache).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_query_cookiejar() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let response = default_response();
+        let responses = vec![&response];
+        let cookie = |domain: &str, path: &str, name: &str, value: &str| http::Cookie {
+            domain: domain.to_string(),
+            include_subdomain: "FALSE".to_string(),
+            path: path.to_string(),
+            https: "FALSE".to_string(),
+            expires: "1893456000".to_string(),
+            name: name.to_string(),
+            value: value.to_string(),
+            http_only: true,
+            same_site: Some("Strict".to_string()),
+        };
+        let cookie_storage = vec![
+            cookie("example.org", "/", "session", "abcde"),
+            cookie("localhost", "/", "session", "12345"),
+            cookie("localhost", "/admin", "admin", "xyz"),
+        ];
+        let space = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let query = |name: &str, attribute: Option<CookieAttributeName>| Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::CookieJar {
+                space0: space.clone(),
+                expr: CookiePath {
+                    name: Template::new(
+                        Some('"'),
+                        vec![TemplateElement::String {
+                            value: name.to_string(),
+                            source: name.to_source(),
+                        }],
+                        SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    ),
+                    attribute: attribute.map(|name| CookieAttribute {
+                        space0: space.clone(),
+                        name,
+                        space1: space.clone(),
+                    }),
+                },
+            },
+        };
+
+        // cookiejar "session"
+        assert_eq!(
+            eval_query(
+                &query("session", None),
+                &variables,
+                &responses,
+                &cookie_storage,
+                &mut cache
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("12345".to_string())
+        );
+
+        // cookiejar "session[Expires]"
+        assert_eq!(
+            eval_query(
+                &query(
+                    "session",
+                    Some(CookieAttributeName::Expires("Expires".to_string()))
+                ),
+                &variables,
+                &responses,
+                &cookie_storage,
+                &mut cache
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Date(chrono::DateTime::from_timestamp(1893456000, 0).unwrap())
+        );
+
+        // cookiejar "session[SameSite]"
+        assert_eq!(
+            eval_query(
+                &query(
+                    "session",
+                    Some(CookieAttributeName::SameSite("SameSite".to_string()))
+                ),
+                &variables,
+                &responses,
+                &cookie_storage,
+                &mut cache
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("Strict".to_string())
+        );
+
+        // cookiejar "session[Secure]"
+        assert_eq!(
+            eval_query(
+                &query(
+                    "session",
+                    Some(CookieAttributeName::Secure("Secure".to_string()))
+                ),
+                &variables,
+                &responses,
+                &cookie_storage,
+                &mut cache
+            )
+            .unwrap(),
+            None
+        );
+
+        // cookiejar "unknown"
+        assert_eq!(
+            eval_query(
+                &query("unknown", None),
+                &variables,
+                &responses,
+                &cookie_storage,
+                &mut cache
+            )
+            .unwrap(),
+            None
+        );
+
+        // cookiejar "admin", the cookie path doesn't match the response URL
+        assert_eq!(
+            eval_query(
+                &query("admin", None),
+                &variables,
+                &responses,
+                &cookie_storage,
+                &mut cache
+            )
+            .unwrap(),
             None
         );
     }
@@ -1092,6 +1397,7 @@ pub mod tests {
                 },
                 &variables,
                 &[&http::hello_http_response()],
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -1105,6 +1411,7 @@ pub mod tests {
             },
             &variables,
             &[&http::bytes_http_response()],
+            &[],
             &mut cache,
         )
         .err()
@@ -1130,9 +1437,15 @@ pub mod tests {
             body: vec![200],
             ..default_response()
         };
-        let error = eval_query(&xpath_users(), &variables, &[&http_response], &mut cache)
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &xpath_users(),
+            &variables,
+            &[&http_response],
+            &[],
+            &mut cache,
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
         assert_eq!(
             error.kind,
@@ -1169,6 +1482,7 @@ pub mod tests {
             &query,
             &variables,
             &[&http::xml_two_users_http_response()],
+            &[],
             &mut cache,
         )
         .unwrap_err();
@@ -1186,6 +1500,7 @@ pub mod tests {
                 &xpath_users(),
                 &variables,
                 &[&http::xml_two_users_http_response()],
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -1197,6 +1512,7 @@ pub mod tests {
                 &xpath_count_user_query(),
                 &variables,
                 &[&http::xml_two_users_http_response()],
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -1238,6 +1554,7 @@ pub mod tests {
                 &xpath_html_charset(),
                 &variables,
                 &[&http::html_http_response()],
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -1274,6 +1591,7 @@ pub mod tests {
             &jsonpath_query,
             &variables,
             &[&http::json_http_response()],
+            &[],
             &mut cache,
         )
         .unwrap_err();
@@ -1304,6 +1622,7 @@ pub mod tests {
             &jsonpath_success(),
             &variables,
             &[&http_response],
+            &[],
             &mut cache,
         )
         .err()
@@ -1326,6 +1645,7 @@ pub mod tests {
                 &jsonpath_success(),
                 &variables,
                 &[&http_response],
+                &[],
                 &mut cache
             )
             .unwrap(),
@@ -1343,6 +1663,7 @@ pub mod tests {
                 &jsonpath_success(),
                 &variables,
                 &[&http::json_http_response()],
+                &[],
                 &mut cache
             )
             .unwrap()
@@ -1354,6 +1675,7 @@ pub mod tests {
                 &jsonpath_errors(),
                 &variables,
                 &[&http::json_http_response()],
+                &[],
                 &mut cache
             )
             .unwrap()
@@ -1381,6 +1703,7 @@ pub mod tests {
                 &regex_name(),
                 &variables,
                 &[&http::hello_http_response()],
+                &[],
                 &mut cache
             )
             .unwrap()
@@ -1392,6 +1715,7 @@ pub mod tests {
             &regex_invalid(),
             &variables,
             &[&http::hello_http_response()],
+            &[],
             &mut cache,
         )
         .err()
@@ -1416,6 +1740,7 @@ pub mod tests {
                 },
                 &variables,
                 &[&http::hello_http_response()],
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -1447,6 +1772,7 @@ pub mod tests {
                     body: vec![0xff],
                     ..default_response()
                 }],
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -1458,6 +1784,121 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_sse() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let response = http::Response {
+            body: b"id: 1\ndata: Hello\n\nevent: update\ndata: {\"count\":2}\nretry: 500\n\n"
+                .to_vec(),
+            ..default_response()
+        };
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::Sse,
+        };
+
+        assert_eq!(
+            eval_query(&query, &variables, &[&response], &[], &mut cache)
+                .unwrap()
+                .unwrap(),
+            Value::List(vec![
+                Value::Object(vec![
+                    ("id".to_string(), Value::String("1".to_string())),
+                    ("event".to_string(), Value::String("message".to_string())),
+                    ("data".to_string(), Value::String("Hello".to_string())),
+                    ("retry".to_string(), Value::Null),
+                ]),
+                Value::Object(vec![
+                    ("id".to_string(), Value::String("1".to_string())),
+                    ("event".to_string(), Value::String("update".to_string())),
+                    (
+                        "data".to_string(),
+                        Value::String("{\"count\":2}".to_string())
+                    ),
+                    ("retry".to_string(), Value::Number(Number::Integer(500))),
+                ]),
+            ])
+        );
+        assert_eq!(cache.sse().unwrap().len(), 2);
+
+        // A retry value that doesn't fit in an integer value is an error.
+        let mut cache = BodyCache::new();
+        let response = http::Response {
+            body: b"data: Hello\nretry: 18446744073709551615\n\n".to_vec(),
+            ..default_response()
+        };
+        let error = eval_query(&query, &variables, &[&response], &[], &mut cache).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryInvalidSse {
+                message: "the event retry value <18446744073709551615> is too large".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_query_grpc_status() {
+        assert!(eval_query_grpc_status(&default_response())
+            .unwrap()
+            .is_none());
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Type", "application/grpc"));
+        headers.push(Header::new("grpc-status", "5"));
+        headers.push(Header::new("grpc-message", "not found"));
+        let response = http::Response {
+            headers,
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query_grpc_status(&response).unwrap().unwrap(),
+            Value::Number(Number::Integer(5))
+        );
+    }
+
+    #[test]
+    fn test_query_graphql() {
+        let mut cache = BodyCache::new();
+        let response = http::Response {
+            body: br#"{"data":{"hero":null},"errors":[{"message":"not found"}]}"#.to_vec(),
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query_graphql(
+                &response,
+                &mut cache,
+                GraphQlField::Data,
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1))
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Object(vec![("hero".to_string(), Value::Null)])
+        );
+        assert_eq!(
+            eval_query_graphql(
+                &response,
+                &mut cache,
+                GraphQlField::Errors,
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1))
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![Value::Object(vec![(
+                "message".to_string(),
+                Value::String("not found".to_string())
+            )])])
+        );
+        assert!(eval_query_graphql(
+            &response,
+            &mut cache,
+            GraphQlField::Extensions,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1))
+        )
+        .unwrap()
+        .is_none());
+    }
+
     #[test]
     fn test_query_certificate() {
         assert!(eval_query_certificate(
@@ -61,6 +61,7 @@ pub fn eval_asserts(
     response: &Response,
     variables: &VariableSet,
     http_responses: &[&http::Response],
+    cookie_storage: &[http::Cookie],
     cache: &mut BodyCache,
     context_dir: &ContextDir,
 ) -> Vec<AssertResult> {
@@ -148,8 +149,14 @@ pub fn eval_asserts(
 
     // Then, checks all the explicit asserts.
     for assert in response.asserts() {
-        let assert_result =
-            assert::eval_explicit_assert(assert, variables, http_responses, cache, context_dir);
+        let assert_result = assert::eval_explicit_assert(
+            assert,
+            variables,
+            http_responses,
+            cookie_storage,
+            cache,
+            context_dir,
+        );
         asserts.push(assert_result);
     }
     asserts
@@ -352,12 +359,14 @@ fn eval_implicit_body_asserts(
 pub fn eval_captures(
     response: &Response,
     http_responses: &[&http::Response],
+    cookie_storage: &[http::Cookie],
     cache: &mut BodyCache,
     variables: &mut VariableSet,
 ) -> Result<Vec<CaptureResult>, RunnerError> {
     let mut captures = vec![];
     for capture in response.captures() {
-        let capture_result = capture::eval_capture(capture, variables, http_responses, cache)?;
+        let capture_result =
+            capture::eval_capture(capture, variables, http_responses, cookie_storage, cache)?;
         // Update variables now so the captures set is ready in case
         // the next captures reference this new variable.
         let name = capture_result.name.clone();
@@ -451,6 +460,7 @@ mod tests {
                 &user_response(),
                 &variables,
                 &[&http::xml_two_users_http_response()],
+                &[],
                 &mut cache,
                 &context_dir,
             ),
@@ -498,6 +508,7 @@ mod tests {
             eval_captures(
                 &user_response(),
                 &[&http::xml_two_users_http_response()],
+                &[],
                 &mut cache,
                 &mut variables,
             )
@@ -20,28 +20,41 @@ use std::time::Duration;
 use hurl_core::ast::Entry;
 use hurl_core::typing::{BytesPerSec, Count};
 
-use crate::http::{IpResolve, RequestedHttpVersion};
+use crate::http::{
+    AuthMethod, ContentEncoding, CookieStorageMode, DigestAlgorithm, HttpSignature, IpResolve,
+    OAuth2, RequestedHttpVersion, TlsVersion,
+};
 use crate::runner::Output;
 use crate::util::path::ContextDir;
 
 /// Build a [`RunnerOptions`] instance.
 pub struct RunnerOptionsBuilder {
     allow_reuse: bool,
+    auth_method: AuthMethod,
     aws_sigv4: Option<String>,
     cacert_file: Option<String>,
+    cert_status: bool,
     client_cert_file: Option<String>,
     client_key_file: Option<String>,
+    compress_body: Option<ContentEncoding>,
     compressed: bool,
     connect_timeout: Duration,
     connects_to: Vec<String>,
+    content_digest: Option<DigestAlgorithm>,
     context_dir: ContextDir,
     continue_on_error: bool,
     cookie_input_file: Option<String>,
+    cookie_storage: Option<CookieStorageMode>,
+    crl_file: Option<String>,
     delay: Duration,
     follow_location: bool,
     follow_location_trusted: bool,
     from_entry: Option<usize>,
+    graphql_allow_errors: bool,
+    graphql_schema: Option<String>,
+    grpc_proto: Option<String>,
     headers: Vec<String>,
+    http_signature: Option<HttpSignature>,
     http_version: RequestedHttpVersion,
     ignore_asserts: bool,
     insecure: bool,
@@ -54,43 +67,67 @@ pub struct RunnerOptionsBuilder {
     netrc_file: Option<String>,
     netrc_optional: bool,
     no_proxy: Option<String>,
+    oauth2: Option<OAuth2>,
     output: Option<Output>,
     path_as_is: bool,
+    pinned_pubkey: Option<String>,
     post_entry: Option<fn() -> bool>,
     pre_entry: Option<fn(&Entry) -> bool>,
     proxy: Option<String>,
+    proxy_auth_method: AuthMethod,
+    proxy_cacert_file: Option<String>,
+    proxy_headers: Vec<String>,
+    proxy_insecure: bool,
+    proxy_user: Option<String>,
     repeat: Option<Count>,
     resolves: Vec<String>,
     retry: Option<Count>,
     retry_interval: Duration,
     skip: bool,
+    sse_duration: Option<Duration>,
+    sse_events: Count,
     ssl_no_revoke: bool,
     timeout: Duration,
+    tls_max_version: Option<TlsVersion>,
+    tls_min_version: Option<TlsVersion>,
     to_entry: Option<usize>,
+    traceparent: bool,
     unix_socket: Option<String>,
     user: Option<String>,
     user_agent: Option<String>,
+    websocket_messages: Count,
+    websocket_timeout: Duration,
 }
 
 impl Default for RunnerOptionsBuilder {
     fn default() -> Self {
         RunnerOptionsBuilder {
             allow_reuse: true,
+            auth_method: AuthMethod::default(),
             aws_sigv4: None,
             cacert_file: None,
+            cert_status: false,
             client_cert_file: None,
             client_key_file: None,
+            compress_body: None,
             compressed: false,
             connect_timeout: Duration::from_secs(300),
             connects_to: vec![],
+            content_digest: None,
             context_dir: ContextDir::default(),
             continue_on_error: false,
             cookie_input_file: None,
+            cookie_storage: None,
+            crl_file: None,
             delay: Duration::from_millis(0),
             follow_location: false,
             follow_location_trusted: false,
             from_entry: None,
+            graphql_allow_errors: false,
+            graphql_schema: None,
+            grpc_proto: None,
             headers: vec![],
+            http_signature: None,
             http_version: RequestedHttpVersion::default(),
             ignore_asserts: false,
             insecure: false,
@@ -103,22 +140,36 @@ impl Default for RunnerOptionsBuilder {
             netrc_file: None,
             netrc_optional: false,
             no_proxy: None,
+            oauth2: None,
             output: None,
             path_as_is: false,
+            pinned_pubkey: None,
             post_entry: None,
             pre_entry: None,
             proxy: None,
+            proxy_auth_method: AuthMethod::default(),
+            proxy_cacert_file: None,
+            proxy_headers: vec![],
+            proxy_insecure: false,
+            proxy_user: None,
             repeat: None,
             resolves: vec![],
             retry: None,
             retry_interval: Duration::from_millis(1000),
             skip: false,
+            sse_duration: None,
+            sse_events: Count::Infinite,
             ssl_no_revoke: false,
             timeout: Duration::from_secs(300),
+            tls_max_version: None,
+            tls_min_version: None,
             to_entry: None,
+            traceparent: false,
             unix_socket: None,
             user: None,
             user_agent: None,
+            websocket_messages: Count::Finite(1),
+            websocket_timeout: Duration::from_secs(10),
         }
     }
 }
@@ -140,6 +191,12 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the authentication method used with the user credentials (Basic by default).
+    pub fn auth_method(&mut self, auth_method: AuthMethod) -> &mut Self {
+        self.auth_method = auth_method;
+        self
+    }
+
     /// Specifies the AWS SigV4 option
     pub fn aws_sigv4(&mut self, aws_sigv4: Option<String>) -> &mut Self {
         self.aws_sigv4 = aws_sigv4;
@@ -153,6 +210,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Requires the server certificate status to be verified with OCSP stapling.
+    ///
+    /// Default is `false`.
+    pub fn cert_status(&mut self, cert_status: bool) -> &mut Self {
+        self.cert_status = cert_status;
+        self
+    }
+
     /// Sets Client certificate file and password.
     pub fn client_cert_file(&mut self, client_cert_file: Option<String>) -> &mut Self {
         self.client_cert_file = client_cert_file;
@@ -165,6 +230,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the content encoding used to compress request bodies.
+    ///
+    /// Default is `None` (request bodies are not compressed).
+    pub fn compress_body(&mut self, compress_body: Option<ContentEncoding>) -> &mut Self {
+        self.compress_body = compress_body;
+        self
+    }
+
     /// Requests a compressed response using one of the algorithms br, gzip, deflate and
     /// automatically decompress the content.
     pub fn compressed(&mut self, compressed: bool) -> &mut Self {
@@ -189,6 +262,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the algorithm of the `Content-Digest` header added to requests with a body.
+    ///
+    /// Default is `None` (no `Content-Digest` header is added).
+    pub fn content_digest(&mut self, content_digest: Option<DigestAlgorithm>) -> &mut Self {
+        self.content_digest = content_digest;
+        self
+    }
+
     /// Sets delay (timeout) before the request.
     ///
     /// Default is 0 ms.
@@ -214,12 +295,27 @@ impl RunnerOptionsBuilder {
         self
     }
 
-    /// Reads cookies from this file (using the Netscape cookie file format).
+    /// Reads cookies from this file (using the Netscape cookie file format, or the JSON format if
+    /// the file has a `.json` extension).
     pub fn cookie_input_file(&mut self, cookie_input_file: Option<String>) -> &mut Self {
         self.cookie_input_file = cookie_input_file;
         self
     }
 
+    /// Clears or isolates the cookie storage while running a request.
+    ///
+    /// Default is `None` (the cookie storage is shared by all the requests).
+    pub fn cookie_storage(&mut self, cookie_storage: Option<CookieStorageMode>) -> &mut Self {
+        self.cookie_storage = cookie_storage;
+        self
+    }
+
+    /// Sets the Certificate Revocation List file (PEM format) used to verify the server certificate.
+    pub fn crl_file(&mut self, crl_file: Option<String>) -> &mut Self {
+        self.crl_file = crl_file;
+        self
+    }
+
     /// Sets stopping or continuing executing requests to the end of the Hurl file even when an assert error occurs.
     ///
     /// By default, Hurl exits after an assert error in the HTTP response. Note that this option does
@@ -251,12 +347,50 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the GraphQL allow errors flag.
+    ///
+    /// By default, an entry with a GraphQL request body fails if the response has a non-empty
+    /// list of `errors`. When set, GraphQL errors in responses are allowed. Default is `false`.
+    pub fn graphql_allow_errors(&mut self, graphql_allow_errors: bool) -> &mut Self {
+        self.graphql_allow_errors = graphql_allow_errors;
+        self
+    }
+
+    /// Sets the GraphQL schema file (SDL) used to validate GraphQL queries.
+    ///
+    /// When set, GraphQL queries are validated against this schema before being sent. Default is
+    /// `None`.
+    pub fn graphql_schema(&mut self, graphql_schema: Option<String>) -> &mut Self {
+        self.graphql_schema = graphql_schema;
+        self
+    }
+
+    /// Sets the `.proto` file (or the binary descriptor set) used to encode and decode gRPC
+    /// messages.
+    ///
+    /// When set, requests are sent as gRPC calls: the JSON request body is encoded to protobuf and
+    /// the protobuf response messages are decoded to JSON. Default is `None`.
+    pub fn grpc_proto(&mut self, grpc_proto: Option<String>) -> &mut Self {
+        self.grpc_proto = grpc_proto;
+        self
+    }
+
     /// Sets additional headers (overrides if a header already exists).
     pub fn headers(&mut self, header: &[String]) -> &mut Self {
         self.headers = header.to_vec();
         self
     }
 
+    /// Sets the parameters to sign requests with an HTTP message signature (RFC 9421 or cavage
+    /// draft).
+    ///
+    /// The signature is computed over the final request, once all its headers are set. Default
+    /// is `None`.
+    pub fn http_signature(&mut self, http_signature: Option<HttpSignature>) -> &mut Self {
+        self.http_signature = http_signature;
+        self
+    }
+
     /// Set requested HTTP version (can be different of the effective HTTP version).
     pub fn http_version(&mut self, version: RequestedHttpVersion) -> &mut Self {
         self.http_version = version;
@@ -313,6 +447,13 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the public key the server certificate must match: sha256 hashes (`sha256//...`) separated
+    /// by `;`, or a PEM/DER public key file.
+    pub fn pinned_pubkey(&mut self, pinned_pubkey: Option<String>) -> &mut Self {
+        self.pinned_pubkey = pinned_pubkey;
+        self
+    }
+
     /// Sets the netrc flag.
     pub fn netrc(&mut self, netrc: bool) -> &mut Self {
         self.netrc = netrc;
@@ -337,6 +478,13 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the OAuth2 parameters used to obtain an access token, sent in the `Authorization`
+    /// header of each request.
+    pub fn oauth2(&mut self, oauth2: Option<OAuth2>) -> &mut Self {
+        self.oauth2 = oauth2;
+        self
+    }
+
     /// Specifies the file to output the HTTP response instead of stdout.
     pub fn output(&mut self, output: Option<Output>) -> &mut Self {
         self.output = output;
@@ -365,6 +513,38 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the authentication method used with the proxy credentials (Basic by default).
+    pub fn proxy_auth_method(&mut self, proxy_auth_method: AuthMethod) -> &mut Self {
+        self.proxy_auth_method = proxy_auth_method;
+        self
+    }
+
+    /// Sets the certificate file used to verify an HTTPS proxy.
+    pub fn proxy_cacert_file(&mut self, proxy_cacert_file: Option<String>) -> &mut Self {
+        self.proxy_cacert_file = proxy_cacert_file;
+        self
+    }
+
+    /// Sets the headers sent only to the proxy.
+    pub fn proxy_headers(&mut self, proxy_headers: &[String]) -> &mut Self {
+        self.proxy_headers = proxy_headers.to_vec();
+        self
+    }
+
+    /// Allows insecure connections to an HTTPS proxy.
+    ///
+    /// Default is `false`.
+    pub fn proxy_insecure(&mut self, proxy_insecure: bool) -> &mut Self {
+        self.proxy_insecure = proxy_insecure;
+        self
+    }
+
+    /// Sets the proxy credentials (`user:password`).
+    pub fn proxy_user(&mut self, proxy_user: Option<String>) -> &mut Self {
+        self.proxy_user = proxy_user;
+        self
+    }
+
     /// Set the number of repetition for a given entry.
     pub fn repeat(&mut self, repeat: Option<Count>) -> &mut Self {
         self.repeat = repeat;
@@ -399,6 +579,24 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum duration to read a `text/event-stream` response.
+    ///
+    /// Once elapsed, the transfer is stopped and the events received so far are kept. Default is
+    /// `None`, the stream being read until the server closes the connection.
+    pub fn sse_duration(&mut self, sse_duration: Option<Duration>) -> &mut Self {
+        self.sse_duration = sse_duration;
+        self
+    }
+
+    /// Sets the number of events to read from a `text/event-stream` response.
+    ///
+    /// Once received, the transfer is stopped. Default is [`Count::Infinite`], the stream being
+    /// read until the server closes the connection.
+    pub fn sse_events(&mut self, sse_events: Count) -> &mut Self {
+        self.sse_events = sse_events;
+        self
+    }
+
     /// Disables certificate revocation checks for SSL backends where such behavior is present.
     pub fn ssl_no_revoke(&mut self, ssl_no_revoke: bool) -> &mut Self {
         self.ssl_no_revoke = ssl_no_revoke;
@@ -413,12 +611,31 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum TLS version allowed.
+    pub fn tls_max_version(&mut self, tls_max_version: Option<TlsVersion>) -> &mut Self {
+        self.tls_max_version = tls_max_version;
+        self
+    }
+
+    /// Sets the minimum TLS version allowed.
+    pub fn tls_min_version(&mut self, tls_min_version: Option<TlsVersion>) -> &mut Self {
+        self.tls_min_version = tls_min_version;
+        self
+    }
+
     /// Executes Hurl file to `to_entry` (starting at 1), ignores the remaining of the file.
     pub fn to_entry(&mut self, to_entry: Option<usize>) -> &mut Self {
         self.to_entry = to_entry;
         self
     }
 
+    /// Adds a W3C `traceparent` header to each request, all the requests of a Hurl file sharing
+    /// the same trace id. Default is false.
+    pub fn traceparent(&mut self, traceparent: bool) -> &mut Self {
+        self.traceparent = traceparent;
+        self
+    }
+
     /// Sets the specified unix domain socket to connect through, instead of using the network.
     pub fn unix_socket(&mut self, unix_socket: Option<String>) -> &mut Self {
         self.unix_socket = unix_socket;
@@ -437,25 +654,51 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the number of messages to wait for on a WebSocket connection.
+    ///
+    /// Default is 1, [`Count::Infinite`] waits until the server closes the connection.
+    pub fn websocket_messages(&mut self, websocket_messages: Count) -> &mut Self {
+        self.websocket_messages = websocket_messages;
+        self
+    }
+
+    /// Sets maximum time allowed for a WebSocket exchange.
+    ///
+    /// Default is 10 seconds.
+    pub fn websocket_timeout(&mut self, websocket_timeout: Duration) -> &mut Self {
+        self.websocket_timeout = websocket_timeout;
+        self
+    }
+
     /// Create an instance of [`RunnerOptions`].
     pub fn build(&self) -> RunnerOptions {
         RunnerOptions {
             allow_reuse: self.allow_reuse,
+            auth_method: self.auth_method,
             aws_sigv4: self.aws_sigv4.clone(),
             cacert_file: self.cacert_file.clone(),
+            cert_status: self.cert_status,
             client_cert_file: self.client_cert_file.clone(),
             client_key_file: self.client_key_file.clone(),
+            compress_body: self.compress_body,
             compressed: self.compressed,
             connect_timeout: self.connect_timeout,
             connects_to: self.connects_to.clone(),
+            content_digest: self.content_digest,
             delay: self.delay,
             context_dir: self.context_dir.clone(),
             continue_on_error: self.continue_on_error,
             cookie_input_file: self.cookie_input_file.clone(),
+            cookie_storage: self.cookie_storage,
+            crl_file: self.crl_file.clone(),
             follow_location: self.follow_location,
             follow_location_trusted: self.follow_location_trusted,
             from_entry: self.from_entry,
+            graphql_allow_errors: self.graphql_allow_errors,
+            graphql_schema: self.graphql_schema.clone(),
+            grpc_proto: self.grpc_proto.clone(),
             headers: self.headers.clone(),
+            http_signature: self.http_signature.clone(),
             http_version: self.http_version,
             ignore_asserts: self.ignore_asserts,
             insecure: self.insecure,
@@ -468,22 +711,36 @@ impl RunnerOptionsBuilder {
             netrc_file: self.netrc_file.clone(),
             netrc_optional: self.netrc_optional,
             no_proxy: self.no_proxy.clone(),
+            oauth2: self.oauth2.clone(),
             output: self.output.clone(),
             path_as_is: self.path_as_is,
+            pinned_pubkey: self.pinned_pubkey.clone(),
             post_entry: self.post_entry,
             pre_entry: self.pre_entry,
             proxy: self.proxy.clone(),
+            proxy_auth_method: self.proxy_auth_method,
+            proxy_cacert_file: self.proxy_cacert_file.clone(),
+            proxy_headers: self.proxy_headers.clone(),
+            proxy_insecure: self.proxy_insecure,
+            proxy_user: self.proxy_user.clone(),
             repeat: self.repeat,
             resolves: self.resolves.clone(),
             retry: self.retry,
             retry_interval: self.retry_interval,
             skip: self.skip,
+            sse_duration: self.sse_duration,
+            sse_events: self.sse_events,
             ssl_no_revoke: self.ssl_no_revoke,
             timeout: self.timeout,
+            tls_max_version: self.tls_max_version,
+            tls_min_version: self.tls_min_version,
             to_entry: self.to_entry,
+            traceparent: self.traceparent,
             unix_socket: self.unix_socket.clone(),
             user: self.user.clone(),
             user_agent: self.user_agent.clone(),
+            websocket_messages: self.websocket_messages,
+            websocket_timeout: self.websocket_timeout,
         }
     }
 }
@@ -496,14 +753,20 @@ impl RunnerOptionsBuilder {
 pub struct RunnerOptions {
     /// Allow reusing internal connections.
     pub(crate) allow_reuse: bool,
+    /// Specifies the authentication method used with the user credentials.
+    pub(crate) auth_method: AuthMethod,
     /// Specifies the AWS SigV4 option.
     pub(crate) aws_sigv4: Option<String>,
     /// Specifies the certificate file for peer verification.
     pub(crate) cacert_file: Option<String>,
+    /// Requires the server certificate status to be verified with OCSP stapling.
+    pub(crate) cert_status: bool,
     /// Sets Client certificate file and password.
     pub(crate) client_cert_file: Option<String>,
     /// Sets private key file name.
     pub(crate) client_key_file: Option<String>,
+    /// Compresses request bodies with this content encoding.
+    pub(crate) compress_body: Option<ContentEncoding>,
     /// Requests a compressed response using one of the algorithms br, gzip, deflate and
     /// automatically decompress the content.
     pub(crate) compressed: bool,
@@ -511,22 +774,36 @@ pub struct RunnerOptions {
     pub(crate) connect_timeout: Duration,
     /// Sets hosts mappings.
     pub(crate) connects_to: Vec<String>,
+    /// Adds a `Content-Digest` header computed with this algorithm to requests with a body.
+    pub(crate) content_digest: Option<DigestAlgorithm>,
     /// Sets delay (timeout) before the request.
     pub(crate) delay: Duration,
     /// Sets root file system to import files in Hurl.
     pub(crate) context_dir: ContextDir,
     /// Sets stopping or continuing executing requests to the end of the Hurl file even when an error occurs.
     pub(crate) continue_on_error: bool,
-    /// Reads cookies from this file (using the Netscape cookie file format).
+    /// Reads cookies from this file (using the Netscape cookie file format, or the JSON format).
     pub(crate) cookie_input_file: Option<String>,
+    /// Clears or isolates the cookie storage while running a request.
+    pub(crate) cookie_storage: Option<CookieStorageMode>,
+    /// Specifies the Certificate Revocation List file used to verify the server certificate.
+    pub(crate) crl_file: Option<String>,
     /// Sets follow redirect.
     pub(crate) follow_location: bool,
     /// Sets follow redirect with trust.
     pub(crate) follow_location_trusted: bool,
     /// Executes Hurl file from from_entry (starting at 1), ignores the beginning of the file.
     pub(crate) from_entry: Option<usize>,
+    /// Allows GraphQL errors in responses to GraphQL requests.
+    pub(crate) graphql_allow_errors: bool,
+    /// Sets the GraphQL schema file used to validate GraphQL queries.
+    pub(crate) graphql_schema: Option<String>,
+    /// Sets the `.proto` file (or the binary descriptor set) used for gRPC requests.
+    pub(crate) grpc_proto: Option<String>,
     /// Sets additional headers (overrides if a header already exists).
     pub(crate) headers: Vec<String>,
+    /// Signs requests with an HTTP message signature.
+    pub(crate) http_signature: Option<HttpSignature>,
     /// Set requested HTTP version (can be different of the effective HTTP version).
     pub(crate) http_version: RequestedHttpVersion,
     /// Ignores all asserts defined in the Hurl file.
@@ -551,15 +828,29 @@ pub struct RunnerOptions {
     pub(crate) netrc_optional: bool,
     /// Sets list of hosts which do not use a proxy.
     pub(crate) no_proxy: Option<String>,
+    /// OAuth2 parameters used to obtain an access token.
+    pub(crate) oauth2: Option<OAuth2>,
     /// Specifies the file to output the HTTP response.
     pub(crate) output: Option<Output>,
     pub(crate) path_as_is: bool,
+    /// Specifies the public key the server certificate must match.
+    pub(crate) pinned_pubkey: Option<String>,
     /// Sets function to be executed before each entry execution.
     pub(crate) post_entry: Option<fn() -> bool>,
     /// Sets function to be executed after each entry execution.
     pub(crate) pre_entry: Option<fn(&Entry) -> bool>,
     /// Sets the specified proxy to be used.
     pub(crate) proxy: Option<String>,
+    /// Specifies the authentication method used with the proxy credentials.
+    pub(crate) proxy_auth_method: AuthMethod,
+    /// Specifies the certificate file used to verify an HTTPS proxy.
+    pub(crate) proxy_cacert_file: Option<String>,
+    /// Specifies the headers sent only to the proxy.
+    pub(crate) proxy_headers: Vec<String>,
+    /// Allows insecure connections to an HTTPS proxy.
+    pub(crate) proxy_insecure: bool,
+    /// Specifies the proxy credentials.
+    pub(crate) proxy_user: Option<String>,
     /// Set the number of repetition for a given entry.
     pub(crate) repeat: Option<Count>,
     /// Provides a custom address for a specific host and port pair.
@@ -570,18 +861,32 @@ pub struct RunnerOptions {
     pub(crate) retry_interval: Duration,
     /// Skip the run without executing any request.
     pub(crate) skip: bool,
+    /// Sets the maximum duration to read a `text/event-stream` response.
+    pub(crate) sse_duration: Option<Duration>,
+    /// Sets the number of events to read from a `text/event-stream` response.
+    pub(crate) sse_events: Count,
     /// Disables certificate revocation checks for SSL backends where such behavior is present.
     pub(crate) ssl_no_revoke: bool,
     /// Sets maximum time allowed for the transfer.
     pub(crate) timeout: Duration,
+    /// Sets the maximum TLS version allowed.
+    pub(crate) tls_max_version: Option<TlsVersion>,
+    /// Sets the minimum TLS version allowed.
+    pub(crate) tls_min_version: Option<TlsVersion>,
     /// Executes Hurl file to to_entry (starting at 1), ignores the remaining of the file.
     pub(crate) to_entry: Option<usize>,
+    /// Adds a W3C `traceparent` header to each request.
+    pub(crate) traceparent: bool,
     /// Sets the specified unix domain socket to connect through, instead of using the network.
     pub(crate) unix_socket: Option<String>,
     /// Adds basic Authentication header to each request.
     pub(crate) user: Option<String>,
     /// Specifies the User-Agent string to send to the HTTP server.
     pub(crate) user_agent: Option<String>,
+    /// Sets the number of messages to wait for on a WebSocket connection.
+    pub(crate) websocket_messages: Count,
+    /// Sets maximum time allowed for a WebSocket exchange.
+    pub(crate) websocket_timeout: Duration,
 }
 
 impl Default for RunnerOptions {
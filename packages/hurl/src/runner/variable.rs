@@ -116,6 +116,14 @@ impl VariableSet {
         self.variables.insert(name, variable);
     }
 
+    /// Adds a secret `value` that is not bound to any variable.
+    ///
+    /// This secret is redacted from logs and reports, like the values of secret variables (for
+    /// instance, an access token obtained at runtime).
+    pub fn add_secret(&mut self, value: String) {
+        self.secrets.insert(value);
+    }
+
     /// Returns a reference to the value corresponding to the variable named `name`.
     pub fn get(&self, name: &str) -> Option<&Variable> {
         self.variables.get(name)
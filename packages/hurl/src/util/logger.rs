@@ -29,6 +29,8 @@ use crate::util::term::{Stderr, WriteMode};
 pub enum ErrorFormat {
     Short,
     Long,
+    /// Short error messages, followed by a GitHub Actions `::error` workflow command.
+    Github,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -288,6 +290,10 @@ impl Logger {
         let filename = filename.map_or(String::new(), |f| f.to_string());
         let message = error.to_string(&filename, content, None, OutputFormat::Terminal(self.color));
         self.error_rich(&message);
+        if self.error_format == ErrorFormat::Github {
+            let message = error.to_string(&filename, content, None, OutputFormat::Terminal(false));
+            self.error_github(&filename, error, &message);
+        }
     }
 
     /// Prints a runtime error to this logger [`Stderr`] instance, no matter what is the verbosity.
@@ -306,6 +312,15 @@ impl Logger {
             OutputFormat::Terminal(self.color),
         );
         self.error_rich(&message);
+        if self.error_format == ErrorFormat::Github {
+            let message = error.to_string(
+                &filename,
+                content,
+                Some(entry_src_info),
+                OutputFormat::Terminal(false),
+            );
+            self.error_github(&filename, error, &message);
+        }
     }
 
     fn error_rich(&mut self, message: &str) {
@@ -318,6 +333,16 @@ impl Logger {
         self.eprintln(&s.to_string(fmt));
     }
 
+    /// Prints an error as a GitHub Actions [workflow command], so the error is displayed as an
+    /// annotation at the right line and column of the Hurl file.
+    ///
+    /// [workflow command]: https://docs.github.com/en/actions/reference/workflow-commands-for-github-actions#setting-an-error-message
+    fn error_github<E: DisplaySourceError>(&mut self, filename: &str, error: &E, message: &str) {
+        let command =
+            github_annotation(filename, error.source_info(), &error.description(), message);
+        self.eprintln(&command);
+    }
+
     /// Prints the request method and HTTP  version to this logger [`Stderr`] instance, in verbose and very verbose mode.
     pub fn debug_method_version_out(&mut self, line: &str) {
         if self.verbosity.is_none() {
@@ -371,3 +396,82 @@ impl Logger {
         self.stderr.eprintln(&redacted);
     }
 }
+
+/// Returns a GitHub Actions `::error` workflow command for an error located at `source_info`
+/// in `filename`.
+fn github_annotation(
+    filename: &str,
+    source_info: SourceInfo,
+    title: &str,
+    message: &str,
+) -> String {
+    let mut properties = vec![];
+    if !filename.is_empty() {
+        properties.push(format!("file={}", escape_github_property(filename)));
+    }
+    properties.push(format!("line={}", source_info.start.line));
+    // A column of 0 means that the error spans the whole line (for instance, a failing predicate).
+    if source_info.start.column > 0 {
+        properties.push(format!("col={}", source_info.start.column));
+        if source_info.end.line == source_info.start.line
+            && source_info.end.column > source_info.start.column
+        {
+            properties.push(format!("endColumn={}", source_info.end.column));
+        }
+    }
+    properties.push(format!("title={}", escape_github_property(title)));
+    format!(
+        "::error {}::{}",
+        properties.join(","),
+        escape_github_data(message.trim_end())
+    )
+}
+
+/// Escapes the message of a GitHub Actions workflow command.
+fn escape_github_data(data: &str) -> String {
+    data.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes a property value of a GitHub Actions workflow command.
+fn escape_github_property(value: &str) -> String {
+    escape_github_data(value)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::reader::Pos;
+
+    use super::*;
+
+    #[test]
+    fn test_github_annotation() {
+        let source_info = SourceInfo::new(Pos::new(8, 1), Pos::new(8, 17));
+        assert_eq!(
+            github_annotation(
+                "tests/hello.hurl",
+                source_info,
+                "Assert body value",
+                "Assert body value\n  --> tests/hello.hurl:8:1\n   | 100% ko\n",
+            ),
+            "::error file=tests/hello.hurl,line=8,col=1,endColumn=17,title=Assert body value::Assert body value%0A  --> tests/hello.hurl:8:1%0A   | 100%25 ko"
+        );
+    }
+
+    #[test]
+    fn test_github_annotation_whole_line() {
+        let source_info = SourceInfo::new(Pos::new(6, 0), Pos::new(6, 0));
+        assert_eq!(
+            github_annotation("-", source_info, "Assert failure", "Assert failure\n"),
+            "::error file=-,line=6,title=Assert failure::Assert failure"
+        );
+    }
+
+    #[test]
+    fn test_escape_github_property() {
+        assert_eq!(escape_github_property("a:b,c%"), "a%3Ab%2Cc%25");
+    }
+}
@@ -34,9 +34,33 @@ where
     }
 }
 
+/// Redacts a list of `secrets` in raw `bytes`, for instance a binary body.
+pub fn redact_bytes(bytes: &[u8], secrets: &[impl AsRef<str>]) -> Vec<u8> {
+    let mut value = bytes.to_vec();
+    for s in secrets {
+        let secret = s.as_ref().as_bytes();
+        if secret.is_empty() {
+            continue;
+        }
+        let mut redacted = Vec::with_capacity(value.len());
+        let mut i = 0;
+        while i < value.len() {
+            if value[i..].starts_with(secret) {
+                redacted.extend_from_slice(b"***");
+                i += secret.len();
+            } else {
+                redacted.push(value[i]);
+                i += 1;
+            }
+        }
+        value = redacted;
+    }
+    value
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::util::redacted::Redact;
+    use crate::util::redacted::{redact_bytes, Redact};
 
     #[test]
     fn redacted_string_hides_secret() {
@@ -52,4 +76,14 @@ mod tests {
         assert_eq(&"bar".redact(&secrets), "***");
         assert_eq(&"Baz is not secret".redact(&secrets), "Baz is not secret");
     }
+
+    #[test]
+    fn redacted_bytes_hides_secret() {
+        let secrets = ["foo", "bar"];
+        assert_eq!(
+            redact_bytes(b"\xff\x00foo\x01bar", &secrets),
+            b"\xff\x00***\x01***".to_vec()
+        );
+        assert_eq!(redact_bytes(b"\xfffo", &secrets), b"\xfffo".to_vec());
+    }
 }
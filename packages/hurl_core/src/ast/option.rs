@@ -34,15 +34,26 @@ pub struct EntryOption {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OptionKind {
+    AnyAuth(BooleanOption),
     AwsSigV4(Template),
     CaCertificate(Template),
+    CertStatus(BooleanOption),
     ClientCert(Template),
     ClientKey(Template),
+    CompressBody(Template),
     Compressed(BooleanOption),
     ConnectTo(Template),
     ConnectTimeout(DurationOption),
+    ContentDigest(Template),
+    CookieStorage(Template),
+    CrlFile(Template),
     Delay(DurationOption),
+    Digest(BooleanOption),
+    GraphQlAllowErrors(BooleanOption),
+    GraphQlSchema(Template),
+    GrpcProto(Template),
     Header(Template),
+    HttpSignature(Template),
     Http10(BooleanOption),
     Http11(BooleanOption),
     Http2(BooleanOption),
@@ -53,40 +64,71 @@ pub enum OptionKind {
     FollowLocation(BooleanOption),
     FollowLocationTrusted(BooleanOption),
     LimitRate(NaturalOption),
+    MaxFileSize(NaturalOption),
     MaxRedirect(CountOption),
+    MaxTime(DurationOption),
+    Negotiate(BooleanOption),
     NetRc(BooleanOption),
     NetRcFile(Template),
     NetRcOptional(BooleanOption),
+    Ntlm(BooleanOption),
+    OAuth2(Template),
     Output(Template),
     PathAsIs(BooleanOption),
+    PinnedPublicKey(Template),
     Proxy(Template),
+    ProxyAnyAuth(BooleanOption),
+    ProxyCaCertificate(Template),
+    ProxyDigest(BooleanOption),
+    ProxyHeader(Template),
+    ProxyInsecure(BooleanOption),
+    ProxyNegotiate(BooleanOption),
+    ProxyNtlm(BooleanOption),
+    ProxyUser(Template),
     Repeat(CountOption),
     Resolve(Template),
     Retry(CountOption),
     RetryInterval(DurationOption),
     Skip(BooleanOption),
+    SseDuration(DurationOption),
+    SseEvents(CountOption),
+    TlsMax(Template),
+    TlsMin(Template),
     UnixSocket(Template),
     User(Template),
     Variable(VariableDefinition),
     Verbose(BooleanOption),
     VeryVerbose(BooleanOption),
+    WebSocketMessages(CountOption),
+    WebSocketTimeout(DurationOption),
 }
 
 impl OptionKind {
     /// Returns the Hurl string identifier of this option.
     pub fn identifier(&self) -> &'static str {
         match self {
+            OptionKind::AnyAuth(_) => "anyauth",
             OptionKind::AwsSigV4(_) => "aws-sigv4",
             OptionKind::CaCertificate(_) => "cacert",
+            OptionKind::CertStatus(_) => "cert-status",
             OptionKind::ClientCert(_) => "cert",
             OptionKind::ClientKey(_) => "key",
+            OptionKind::CompressBody(_) => "compress-body",
             OptionKind::Compressed(_) => "compressed",
             OptionKind::ConnectTo(_) => "connect-to",
             OptionKind::ConnectTimeout(_) => "connect-timeout",
+            OptionKind::ContentDigest(_) => "content-digest",
+            OptionKind::CookieStorage(_) => "cookie-storage",
+            OptionKind::CrlFile(_) => "crlfile",
             OptionKind::Delay(_) => "delay",
+            OptionKind::Digest(_) => "digest",
             OptionKind::FollowLocation(_) => "location",
             OptionKind::FollowLocationTrusted(_) => "location-trusted",
+            OptionKind::GraphQlAllowErrors(_) => "graphql-allow-errors",
+            OptionKind::GraphQlSchema(_) => "graphql-schema",
+            OptionKind::GrpcProto(_) => "grpc-proto",
             OptionKind::Header(_) => "header",
+            OptionKind::HttpSignature(_) => "http-signature",
             OptionKind::Http10(_) => "http1.0",
             OptionKind::Http11(_) => "http1.1",
             OptionKind::Http2(_) => "http2",
@@ -95,23 +137,43 @@ impl OptionKind {
             OptionKind::IpV4(_) => "ipv4",
             OptionKind::IpV6(_) => "ipv6",
             OptionKind::LimitRate(_) => "limit-rate",
+            OptionKind::MaxFileSize(_) => "max-filesize",
             OptionKind::MaxRedirect(_) => "max-redirs",
+            OptionKind::MaxTime(_) => "max-time",
+            OptionKind::Negotiate(_) => "negotiate",
             OptionKind::NetRc(_) => "netrc",
             OptionKind::NetRcFile(_) => "netrc-file",
             OptionKind::NetRcOptional(_) => "netrc-optional",
+            OptionKind::Ntlm(_) => "ntlm",
+            OptionKind::OAuth2(_) => "oauth2",
             OptionKind::Output(_) => "output",
             OptionKind::PathAsIs(_) => "path-as-is",
+            OptionKind::PinnedPublicKey(_) => "pinnedpubkey",
             OptionKind::Proxy(_) => "proxy",
+            OptionKind::ProxyAnyAuth(_) => "proxy-anyauth",
+            OptionKind::ProxyCaCertificate(_) => "proxy-cacert",
+            OptionKind::ProxyDigest(_) => "proxy-digest",
+            OptionKind::ProxyHeader(_) => "proxy-header",
+            OptionKind::ProxyInsecure(_) => "proxy-insecure",
+            OptionKind::ProxyNegotiate(_) => "proxy-negotiate",
+            OptionKind::ProxyNtlm(_) => "proxy-ntlm",
+            OptionKind::ProxyUser(_) => "proxy-user",
             OptionKind::Repeat(_) => "repeat",
             OptionKind::Resolve(_) => "resolve",
             OptionKind::Retry(_) => "retry",
             OptionKind::RetryInterval(_) => "retry-interval",
             OptionKind::Skip(_) => "skip",
+            OptionKind::SseDuration(_) => "sse-duration",
+            OptionKind::SseEvents(_) => "sse-events",
+            OptionKind::TlsMax(_) => "tls-max",
+            OptionKind::TlsMin(_) => "tls-min",
             OptionKind::UnixSocket(_) => "unix-socket",
             OptionKind::User(_) => "user",
             OptionKind::Variable(_) => "variable",
             OptionKind::Verbose(_) => "verbose",
             OptionKind::VeryVerbose(_) => "very-verbose",
+            OptionKind::WebSocketMessages(_) => "websocket-messages",
+            OptionKind::WebSocketTimeout(_) => "websocket-timeout",
         }
     }
 }
@@ -119,17 +181,28 @@ impl OptionKind {
 impl fmt::Display for OptionKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let value = match self {
+            OptionKind::AnyAuth(value) => value.to_string(),
             OptionKind::AwsSigV4(value) => value.to_string(),
             OptionKind::CaCertificate(filename) => filename.to_string(),
+            OptionKind::CertStatus(value) => value.to_string(),
             OptionKind::ClientCert(filename) => filename.to_string(),
             OptionKind::ClientKey(filename) => filename.to_string(),
+            OptionKind::CompressBody(value) => value.to_string(),
             OptionKind::Compressed(value) => value.to_string(),
             OptionKind::ConnectTo(value) => value.to_string(),
             OptionKind::ConnectTimeout(value) => value.to_string(),
+            OptionKind::ContentDigest(value) => value.to_string(),
+            OptionKind::CookieStorage(value) => value.to_string(),
+            OptionKind::CrlFile(filename) => filename.to_string(),
             OptionKind::Delay(value) => value.to_string(),
+            OptionKind::Digest(value) => value.to_string(),
             OptionKind::FollowLocation(value) => value.to_string(),
             OptionKind::FollowLocationTrusted(value) => value.to_string(),
+            OptionKind::GraphQlAllowErrors(value) => value.to_string(),
+            OptionKind::GraphQlSchema(filename) => filename.to_string(),
+            OptionKind::GrpcProto(filename) => filename.to_string(),
             OptionKind::Header(value) => value.to_string(),
+            OptionKind::HttpSignature(value) => value.to_string(),
             OptionKind::Http10(value) => value.to_string(),
             OptionKind::Http11(value) => value.to_string(),
             OptionKind::Http2(value) => value.to_string(),
@@ -138,23 +211,43 @@ impl fmt::Display for OptionKind {
             OptionKind::IpV4(value) => value.to_string(),
             OptionKind::IpV6(value) => value.to_string(),
             OptionKind::LimitRate(value) => value.to_string(),
+            OptionKind::MaxFileSize(value) => value.to_string(),
             OptionKind::MaxRedirect(value) => value.to_string(),
+            OptionKind::MaxTime(value) => value.to_string(),
+            OptionKind::Negotiate(value) => value.to_string(),
             OptionKind::NetRc(value) => value.to_string(),
             OptionKind::NetRcFile(filename) => filename.to_string(),
             OptionKind::NetRcOptional(value) => value.to_string(),
+            OptionKind::Ntlm(value) => value.to_string(),
+            OptionKind::OAuth2(value) => value.to_string(),
             OptionKind::Output(filename) => filename.to_string(),
             OptionKind::PathAsIs(value) => value.to_string(),
+            OptionKind::PinnedPublicKey(value) => value.to_string(),
             OptionKind::Proxy(value) => value.to_string(),
+            OptionKind::ProxyAnyAuth(value) => value.to_string(),
+            OptionKind::ProxyCaCertificate(filename) => filename.to_string(),
+            OptionKind::ProxyDigest(value) => value.to_string(),
+            OptionKind::ProxyHeader(value) => value.to_string(),
+            OptionKind::ProxyInsecure(value) => value.to_string(),
+            OptionKind::ProxyNegotiate(value) => value.to_string(),
+            OptionKind::ProxyNtlm(value) => value.to_string(),
+            OptionKind::ProxyUser(value) => value.to_string(),
             OptionKind::Repeat(value) => value.to_string(),
             OptionKind::Resolve(value) => value.to_string(),
             OptionKind::Retry(value) => value.to_string(),
             OptionKind::RetryInterval(value) => value.to_string(),
             OptionKind::Skip(value) => value.to_string(),
+            OptionKind::SseDuration(value) => value.to_string(),
+            OptionKind::SseEvents(value) => value.to_string(),
+            OptionKind::TlsMax(value) => value.to_string(),
+            OptionKind::TlsMin(value) => value.to_string(),
             OptionKind::UnixSocket(value) => value.to_string(),
             OptionKind::User(value) => value.to_string(),
             OptionKind::Variable(value) => value.to_string(),
             OptionKind::Verbose(value) => value.to_string(),
             OptionKind::VeryVerbose(value) => value.to_string(),
+            OptionKind::WebSocketMessages(value) => value.to_string(),
+            OptionKind::WebSocketTimeout(value) => value.to_string(),
         };
         write!(f, "{}: {}", self.identifier(), value)
     }
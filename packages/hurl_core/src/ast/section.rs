@@ -148,6 +148,11 @@ pub enum QueryValue {
         space0: Whitespace,
         expr: CookiePath,
     },
+    /// A cookie of the HTTP client cookie storage (vs a cookie of the response `Set-Cookie` headers).
+    CookieJar {
+        space0: Whitespace,
+        expr: CookiePath,
+    },
     Body,
     Xpath {
         space0: Whitespace,
@@ -175,6 +180,13 @@ pub enum QueryValue {
     },
     Ip,
     Redirects,
+    Messages,
+    Sse,
+    GrpcStatus,
+    GraphQl {
+        space0: Whitespace,
+        field: GraphQlField,
+    },
 }
 
 impl QueryValue {
@@ -186,6 +198,7 @@ impl QueryValue {
             QueryValue::Url => "url",
             QueryValue::Header { .. } => "header",
             QueryValue::Cookie { .. } => "cookie",
+            QueryValue::CookieJar { .. } => "cookiejar",
             QueryValue::Body => "body",
             QueryValue::Xpath { .. } => "xpath",
             QueryValue::Jsonpath { .. } => "jsonpath",
@@ -198,6 +211,10 @@ impl QueryValue {
             QueryValue::Certificate { .. } => "certificate",
             QueryValue::Ip => "ip",
             QueryValue::Redirects => "redirects",
+            QueryValue::Messages => "messages",
+            QueryValue::Sse => "sse",
+            QueryValue::GrpcStatus => "grpc-status",
+            QueryValue::GraphQl { .. } => "graphql",
         }
     }
 }
@@ -296,6 +313,25 @@ impl CertificateAttributeName {
     }
 }
 
+/// A top-level field of a GraphQL response.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GraphQlField {
+    Data,
+    Errors,
+    Extensions,
+}
+
+impl GraphQlField {
+    /// Returns the Hurl string identifier of this GraphQL response field.
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            GraphQlField::Data => "data",
+            GraphQlField::Errors => "errors",
+            GraphQlField::Extensions => "extensions",
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Predicate {
     pub not: bool,
@@ -377,6 +413,10 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    Signature {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
     IsInteger,
     IsFloat,
     IsBoolean,
@@ -389,6 +429,7 @@ pub enum PredicateFuncValue {
     IsNumber,
     IsIpv4,
     IsIpv6,
+    IsDigest,
 }
 
 impl PredicateFuncValue {
@@ -406,6 +447,7 @@ impl PredicateFuncValue {
             PredicateFuncValue::Contain { .. } => "contains",
             PredicateFuncValue::Include { .. } => "includes",
             PredicateFuncValue::Match { .. } => "matches",
+            PredicateFuncValue::Signature { .. } => "signature",
             PredicateFuncValue::IsInteger => "isInteger",
             PredicateFuncValue::IsFloat => "isFloat",
             PredicateFuncValue::IsBoolean => "isBoolean",
@@ -418,6 +460,7 @@ impl PredicateFuncValue {
             PredicateFuncValue::IsNumber => "isNumber",
             PredicateFuncValue::IsIpv4 => "isIpv4",
             PredicateFuncValue::IsIpv6 => "isIpv6",
+            PredicateFuncValue::IsDigest => "isDigest",
         }
     }
 }
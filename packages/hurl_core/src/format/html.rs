@@ -20,8 +20,8 @@ use std::fmt::Display;
 use crate::ast::{
     Assert, Base64, Body, BooleanOption, Bytes, Capture, CertificateAttributeName, Comment, Cookie,
     CookieAttribute, CookiePath, CountOption, DurationOption, Entry, EntryOption, File,
-    FilenameParam, FilenameValue, Filter, FilterValue, Hex, HurlFile, JsonValue, KeyValue,
-    LineTerminator, Method, MultilineString, MultipartParam, NaturalOption, OptionKind,
+    FilenameParam, FilenameValue, Filter, FilterValue, GraphQlField, Hex, HurlFile, JsonValue,
+    KeyValue, LineTerminator, Method, MultilineString, MultipartParam, NaturalOption, OptionKind,
     Placeholder, Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, Query, QueryValue,
     Regex, RegexValue, Request, Response, Section, SectionValue, Status, Template,
     VariableDefinition, VariableValue, Version, Whitespace,
@@ -223,17 +223,28 @@ impl HtmlFormatter {
         self.buffer.push(':');
         self.fmt_space(&option.space2);
         match &option.kind {
+            OptionKind::AnyAuth(value) => self.fmt_bool_option(value),
             OptionKind::AwsSigV4(value) => self.fmt_template(value),
             OptionKind::CaCertificate(filename) => self.fmt_filename(filename),
+            OptionKind::CertStatus(value) => self.fmt_bool_option(value),
             OptionKind::ClientCert(filename) => self.fmt_filename(filename),
             OptionKind::ClientKey(filename) => self.fmt_filename(filename),
+            OptionKind::CompressBody(value) => self.fmt_template(value),
             OptionKind::Compressed(value) => self.fmt_bool_option(value),
             OptionKind::ConnectTo(value) => self.fmt_template(value),
             OptionKind::ConnectTimeout(value) => self.fmt_duration_option(value),
+            OptionKind::ContentDigest(value) => self.fmt_template(value),
+            OptionKind::CookieStorage(value) => self.fmt_template(value),
+            OptionKind::CrlFile(filename) => self.fmt_filename(filename),
             OptionKind::Delay(value) => self.fmt_duration_option(value),
+            OptionKind::Digest(value) => self.fmt_bool_option(value),
             OptionKind::FollowLocation(value) => self.fmt_bool_option(value),
             OptionKind::FollowLocationTrusted(value) => self.fmt_bool_option(value),
+            OptionKind::GraphQlAllowErrors(value) => self.fmt_bool_option(value),
+            OptionKind::GraphQlSchema(filename) => self.fmt_filename(filename),
+            OptionKind::GrpcProto(filename) => self.fmt_filename(filename),
             OptionKind::Header(value) => self.fmt_template(value),
+            OptionKind::HttpSignature(value) => self.fmt_template(value),
             OptionKind::Http10(value) => self.fmt_bool_option(value),
             OptionKind::Http11(value) => self.fmt_bool_option(value),
             OptionKind::Http2(value) => self.fmt_bool_option(value),
@@ -242,23 +253,43 @@ impl HtmlFormatter {
             OptionKind::IpV4(value) => self.fmt_bool_option(value),
             OptionKind::IpV6(value) => self.fmt_bool_option(value),
             OptionKind::LimitRate(value) => self.fmt_natural_option(value),
+            OptionKind::MaxFileSize(value) => self.fmt_natural_option(value),
             OptionKind::MaxRedirect(value) => self.fmt_count_option(value),
+            OptionKind::MaxTime(value) => self.fmt_duration_option(value),
+            OptionKind::Negotiate(value) => self.fmt_bool_option(value),
             OptionKind::NetRc(value) => self.fmt_bool_option(value),
             OptionKind::NetRcFile(filename) => self.fmt_filename(filename),
             OptionKind::NetRcOptional(value) => self.fmt_bool_option(value),
+            OptionKind::Ntlm(value) => self.fmt_bool_option(value),
+            OptionKind::OAuth2(value) => self.fmt_template(value),
             OptionKind::Output(filename) => self.fmt_filename(filename),
             OptionKind::PathAsIs(value) => self.fmt_bool_option(value),
+            OptionKind::PinnedPublicKey(value) => self.fmt_template(value),
             OptionKind::Proxy(value) => self.fmt_template(value),
+            OptionKind::ProxyAnyAuth(value) => self.fmt_bool_option(value),
+            OptionKind::ProxyCaCertificate(filename) => self.fmt_filename(filename),
+            OptionKind::ProxyDigest(value) => self.fmt_bool_option(value),
+            OptionKind::ProxyHeader(value) => self.fmt_template(value),
+            OptionKind::ProxyInsecure(value) => self.fmt_bool_option(value),
+            OptionKind::ProxyNegotiate(value) => self.fmt_bool_option(value),
+            OptionKind::ProxyNtlm(value) => self.fmt_bool_option(value),
+            OptionKind::ProxyUser(value) => self.fmt_template(value),
             OptionKind::Repeat(value) => self.fmt_count_option(value),
             OptionKind::Resolve(value) => self.fmt_template(value),
             OptionKind::Retry(value) => self.fmt_count_option(value),
             OptionKind::RetryInterval(value) => self.fmt_duration_option(value),
             OptionKind::Skip(value) => self.fmt_bool_option(value),
+            OptionKind::SseDuration(value) => self.fmt_duration_option(value),
+            OptionKind::SseEvents(value) => self.fmt_count_option(value),
+            OptionKind::TlsMax(value) => self.fmt_template(value),
+            OptionKind::TlsMin(value) => self.fmt_template(value),
             OptionKind::UnixSocket(value) => self.fmt_template(value),
             OptionKind::User(value) => self.fmt_template(value),
             OptionKind::Variable(value) => self.fmt_variable_definition(value),
             OptionKind::Verbose(value) => self.fmt_bool_option(value),
             OptionKind::VeryVerbose(value) => self.fmt_bool_option(value),
+            OptionKind::WebSocketMessages(value) => self.fmt_count_option(value),
+            OptionKind::WebSocketTimeout(value) => self.fmt_duration_option(value),
         };
         self.fmt_span_close();
         self.fmt_lt(&option.line_terminator0);
@@ -380,7 +411,7 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_template(name);
             }
-            QueryValue::Cookie { space0, expr } => {
+            QueryValue::Cookie { space0, expr } | QueryValue::CookieJar { space0, expr } => {
                 self.fmt_space(space0);
                 self.fmt_cookie_path(expr);
             }
@@ -407,6 +438,10 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_certificate_attribute_name(field);
             }
+            QueryValue::GraphQl { space0, field } => {
+                self.fmt_space(space0);
+                self.fmt_graphql_field(field);
+            }
             QueryValue::Status
             | QueryValue::Version
             | QueryValue::Url
@@ -416,7 +451,10 @@ impl HtmlFormatter {
             | QueryValue::Sha256
             | QueryValue::Md5
             | QueryValue::Ip
-            | QueryValue::Redirects => {}
+            | QueryValue::Redirects
+            | QueryValue::Messages
+            | QueryValue::Sse
+            | QueryValue::GrpcStatus => {}
         }
     }
 
@@ -454,6 +492,14 @@ impl HtmlFormatter {
         self.fmt_span_close();
     }
 
+    fn fmt_graphql_field(&mut self, field: &GraphQlField) {
+        self.fmt_span_open("string");
+        self.buffer.push('"');
+        self.buffer.push_str(field.identifier());
+        self.buffer.push('"');
+        self.fmt_span_close();
+    }
+
     fn fmt_assert(&mut self, assert: &Assert) {
         self.fmt_lts(&assert.line_terminators);
         self.fmt_span_open("line");
@@ -531,6 +577,10 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_predicate_value(value);
             }
+            PredicateFuncValue::Signature { space0, value } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+            }
             PredicateFuncValue::IsInteger => {}
             PredicateFuncValue::IsFloat => {}
             PredicateFuncValue::IsBoolean => {}
@@ -543,6 +593,7 @@ impl HtmlFormatter {
             PredicateFuncValue::IsNumber => {}
             PredicateFuncValue::IsIpv4 => {}
             PredicateFuncValue::IsIpv6 => {}
+            PredicateFuncValue::IsDigest => {}
         }
     }
 
@@ -156,14 +156,25 @@ impl DisplaySourceError for ParseError {
             }
             ParseErrorKind::InvalidOption(name) => {
                 let valid_values = [
+                    "anyauth",
                     "aws-sigv4",
                     "cacert",
                     "cert",
+                    "cert-status",
+                    "compress-body",
                     "compressed",
                     "connect-to",
+                    "content-digest",
+                    "cookie-storage",
+                    "crlfile",
                     "delay",
+                    "digest",
+                    "graphql-allow-errors",
+                    "graphql-schema",
+                    "grpc-proto",
                     "insecure",
                     "header",
+                    "http-signature",
                     "http1.0",
                     "http1.1",
                     "http2",
@@ -172,19 +183,39 @@ impl DisplaySourceError for ParseError {
                     "ipv6",
                     "key",
                     "location",
+                    "max-filesize",
                     "max-redirs",
+                    "max-time",
+                    "negotiate",
+                    "ntlm",
+                    "oauth2",
                     "output",
                     "path-as-is",
+                    "pinnedpubkey",
                     "proxy",
+                    "proxy-anyauth",
+                    "proxy-cacert",
+                    "proxy-digest",
+                    "proxy-header",
+                    "proxy-insecure",
+                    "proxy-negotiate",
+                    "proxy-ntlm",
+                    "proxy-user",
                     "redirects",
                     "resolve",
                     "retry",
                     "retry-interval",
                     "skip",
+                    "sse-duration",
+                    "sse-events",
+                    "tls-max",
+                    "tls-min",
                     "unix-socket",
                     "variable",
                     "verbose",
                     "very-verbose",
+                    "websocket-messages",
+                    "websocket-timeout",
                 ];
                 let default = format!("Valid values are {}", valid_values.join(", "));
                 let did_you_mean = did_you_mean(&valid_values, name.as_str(), &default);
@@ -46,15 +46,26 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
     try_literal(":", reader)?;
     let space2 = zero_or_more_spaces(reader)?;
     let kind = match option.as_str() {
+        "anyauth" => option_anyauth(reader)?,
         "aws-sigv4" => option_aws_sigv4(reader)?,
         "cacert" => option_cacert(reader)?,
         "cert" => option_cert(reader)?,
+        "cert-status" => option_cert_status(reader)?,
+        "compress-body" => option_compress_body(reader)?,
         "compressed" => option_compressed(reader)?,
         "connect-to" => option_connect_to(reader)?,
         "connect-timeout" => option_connect_timeout(reader)?,
+        "content-digest" => option_content_digest(reader)?,
+        "cookie-storage" => option_cookie_storage(reader)?,
+        "crlfile" => option_crlfile(reader)?,
         "delay" => option_delay(reader)?,
+        "digest" => option_digest(reader)?,
+        "graphql-allow-errors" => option_graphql_allow_errors(reader)?,
+        "graphql-schema" => option_graphql_schema(reader)?,
+        "grpc-proto" => option_grpc_proto(reader)?,
         "insecure" => option_insecure(reader)?,
         "header" => option_header(reader)?,
+        "http-signature" => option_http_signature(reader)?,
         "http1.0" => option_http_10(reader)?,
         "http1.1" => option_http_11(reader)?,
         "http2" => option_http_2(reader)?,
@@ -65,23 +76,43 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
         "limit-rate" => option_limit_rate(reader)?,
         "location" => option_follow_location(reader)?,
         "location-trusted" => option_follow_location_trusted(reader)?,
+        "max-filesize" => option_max_filesize(reader)?,
         "max-redirs" => option_max_redirect(reader)?,
+        "max-time" => option_max_time(reader)?,
+        "negotiate" => option_negotiate(reader)?,
         "netrc" => option_netrc(reader)?,
         "netrc-file" => option_netrc_file(reader)?,
         "netrc-optional" => option_netrc_optional(reader)?,
+        "ntlm" => option_ntlm(reader)?,
+        "oauth2" => option_oauth2(reader)?,
         "output" => option_output(reader)?,
         "path-as-is" => option_path_as_is(reader)?,
+        "pinnedpubkey" => option_pinnedpubkey(reader)?,
         "proxy" => option_proxy(reader)?,
+        "proxy-anyauth" => option_proxy_anyauth(reader)?,
+        "proxy-cacert" => option_proxy_cacert(reader)?,
+        "proxy-digest" => option_proxy_digest(reader)?,
+        "proxy-header" => option_proxy_header(reader)?,
+        "proxy-insecure" => option_proxy_insecure(reader)?,
+        "proxy-negotiate" => option_proxy_negotiate(reader)?,
+        "proxy-ntlm" => option_proxy_ntlm(reader)?,
+        "proxy-user" => option_proxy_user(reader)?,
         "repeat" => option_repeat(reader)?,
         "resolve" => option_resolve(reader)?,
         "retry" => option_retry(reader)?,
         "retry-interval" => option_retry_interval(reader)?,
         "skip" => option_skip(reader)?,
+        "sse-duration" => option_sse_duration(reader)?,
+        "sse-events" => option_sse_events(reader)?,
+        "tls-max" => option_tls_max(reader)?,
+        "tls-min" => option_tls_min(reader)?,
         "unix-socket" => option_unix_socket(reader)?,
         "user" => option_user(reader)?,
         "variable" => option_variable(reader)?,
         "verbose" => option_verbose(reader)?,
         "very-verbose" => option_very_verbose(reader)?,
+        "websocket-messages" => option_websocket_messages(reader)?,
+        "websocket-timeout" => option_websocket_timeout(reader)?,
         _ => {
             return Err(ParseError::new(
                 start.pos,
@@ -102,6 +133,11 @@ pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
     })
 }
 
+fn option_anyauth(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::AnyAuth(value))
+}
+
 fn option_aws_sigv4(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::AwsSigV4(value))
@@ -112,6 +148,11 @@ fn option_cacert(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::CaCertificate(value))
 }
 
+fn option_cert_status(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::CertStatus(value))
+}
+
 fn option_cert(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = filename_password::parse(reader)?;
     Ok(OptionKind::ClientCert(value))
@@ -122,11 +163,31 @@ fn option_compressed(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Compressed(value))
 }
 
+fn option_compress_body(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::CompressBody(value))
+}
+
 fn option_connect_to(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::ConnectTo(value))
 }
 
+fn option_content_digest(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::ContentDigest(value))
+}
+
+fn option_cookie_storage(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::CookieStorage(value))
+}
+
+fn option_crlfile(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = filename::parse(reader)?;
+    Ok(OptionKind::CrlFile(value))
+}
+
 fn option_connect_timeout(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = duration_option(reader)?;
     Ok(OptionKind::ConnectTimeout(value))
@@ -137,6 +198,11 @@ fn option_delay(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Delay(value))
 }
 
+fn option_digest(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::Digest(value))
+}
+
 fn option_follow_location(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::FollowLocation(value))
@@ -147,11 +213,31 @@ fn option_follow_location_trusted(reader: &mut Reader) -> ParseResult<OptionKind
     Ok(OptionKind::FollowLocationTrusted(value))
 }
 
+fn option_graphql_allow_errors(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::GraphQlAllowErrors(value))
+}
+
+fn option_graphql_schema(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = filename::parse(reader)?;
+    Ok(OptionKind::GraphQlSchema(value))
+}
+
+fn option_grpc_proto(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = filename::parse(reader)?;
+    Ok(OptionKind::GrpcProto(value))
+}
+
 fn option_header(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::Header(value))
 }
 
+fn option_http_signature(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::HttpSignature(value))
+}
+
 fn option_http_10(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::Http10(value))
@@ -197,11 +283,26 @@ fn option_limit_rate(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::LimitRate(value))
 }
 
+fn option_max_filesize(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(natural_option, reader)?;
+    Ok(OptionKind::MaxFileSize(value))
+}
+
 fn option_max_redirect(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(count_option, reader)?;
     Ok(OptionKind::MaxRedirect(value))
 }
 
+fn option_max_time(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = duration_option(reader)?;
+    Ok(OptionKind::MaxTime(value))
+}
+
+fn option_negotiate(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::Negotiate(value))
+}
+
 fn option_netrc(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::NetRc(value))
@@ -217,6 +318,16 @@ fn option_netrc_optional(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::NetRcOptional(value))
 }
 
+fn option_ntlm(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::Ntlm(value))
+}
+
+fn option_oauth2(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::OAuth2(value))
+}
+
 fn option_output(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = filename::parse(reader)?;
     Ok(OptionKind::Output(value))
@@ -227,11 +338,56 @@ fn option_path_as_is(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::PathAsIs(value))
 }
 
+fn option_pinnedpubkey(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::PinnedPublicKey(value))
+}
+
 fn option_proxy(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::Proxy(value))
 }
 
+fn option_proxy_anyauth(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::ProxyAnyAuth(value))
+}
+
+fn option_proxy_cacert(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = filename::parse(reader)?;
+    Ok(OptionKind::ProxyCaCertificate(value))
+}
+
+fn option_proxy_digest(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::ProxyDigest(value))
+}
+
+fn option_proxy_header(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::ProxyHeader(value))
+}
+
+fn option_proxy_insecure(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::ProxyInsecure(value))
+}
+
+fn option_proxy_negotiate(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::ProxyNegotiate(value))
+}
+
+fn option_proxy_ntlm(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::ProxyNtlm(value))
+}
+
+fn option_proxy_user(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::ProxyUser(value))
+}
+
 fn option_repeat(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(count_option, reader)?;
     Ok(OptionKind::Repeat(value))
@@ -257,6 +413,26 @@ fn option_skip(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Skip(value))
 }
 
+fn option_sse_duration(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(duration_option, reader)?;
+    Ok(OptionKind::SseDuration(value))
+}
+
+fn option_sse_events(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(count_option, reader)?;
+    Ok(OptionKind::SseEvents(value))
+}
+
+fn option_tls_max(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::TlsMax(value))
+}
+
+fn option_tls_min(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::TlsMin(value))
+}
+
 fn option_user(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::User(value))
@@ -282,6 +458,16 @@ fn option_very_verbose(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::VeryVerbose(value))
 }
 
+fn option_websocket_messages(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(count_option, reader)?;
+    Ok(OptionKind::WebSocketMessages(value))
+}
+
+fn option_websocket_timeout(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(duration_option, reader)?;
+    Ok(OptionKind::WebSocketTimeout(value))
+}
+
 fn count(reader: &mut Reader) -> ParseResult<Count> {
     let start = reader.cursor();
     let value = non_recover(integer, reader)?;
@@ -644,6 +830,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_option_max_time() {
+        let mut reader = Reader::new("max-time: 30s");
+        let option = parse(&mut reader).unwrap();
+        assert!(matches!(
+            option.kind,
+            OptionKind::MaxTime(DurationOption::Literal(_))
+        ));
+        assert_eq!(option.kind.to_string(), "max-time: 30s");
+
+        let mut reader = Reader::new("max-time: {{timeout}}");
+        let option = parse(&mut reader).unwrap();
+        assert!(matches!(
+            option.kind,
+            OptionKind::MaxTime(DurationOption::Placeholder(_))
+        ));
+    }
+
+    #[test]
+    fn test_option_max_time_error() {
+        let mut reader = Reader::new("max-time: ###");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 11
+            }
+        );
+    }
+
+    #[test]
+    fn test_option_max_filesize() {
+        let mut reader = Reader::new("max-filesize: 1000000");
+        let option = parse(&mut reader).unwrap();
+        assert!(matches!(
+            option.kind,
+            OptionKind::MaxFileSize(NaturalOption::Literal(_))
+        ));
+        assert_eq!(option.kind.to_string(), "max-filesize: 1000000");
+    }
+
+    #[test]
+    fn test_option_max_filesize_error() {
+        let mut reader = Reader::new("max-filesize: -1");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 15
+            }
+        );
+    }
+
     #[test]
     fn test_variable_definition() {
         let mut reader = Reader::new("a=1");
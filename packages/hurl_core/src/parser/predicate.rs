@@ -82,6 +82,7 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             contain_predicate,
             include_predicate,
             match_predicate,
+            signature_predicate,
             integer_predicate,
             float_predicate,
             boolean_predicate,
@@ -94,6 +95,7 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             is_number_predicate,
             is_ipv4_predicate,
             is_ipv6_predicate,
+            is_digest_predicate,
         ],
         reader,
     ) {
@@ -258,6 +260,21 @@ fn match_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::Match { space0, value })
 }
 
+fn signature_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("signature", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !matches!(value, PredicateValue::String(_)) {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::Signature { space0, value })
+}
+
 fn integer_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("isInteger", reader)?;
     Ok(PredicateFuncValue::IsInteger)
@@ -318,6 +335,11 @@ fn is_ipv6_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::IsIpv6)
 }
 
+fn is_digest_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isDigest", reader)?;
+    Ok(PredicateFuncValue::IsDigest)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -15,7 +15,9 @@
  * limitations under the License.
  *
  */
-use crate::ast::{CertificateAttributeName, Query, QueryValue, RegexValue, SourceInfo};
+use crate::ast::{
+    CertificateAttributeName, GraphQlField, Query, QueryValue, RegexValue, SourceInfo,
+};
 use crate::combinator::{choice, ParseError as ParseErrorTrait};
 use crate::parser::cookiepath::cookiepath;
 use crate::parser::primitives::{literal, one_or_more_spaces, regex, try_literal};
@@ -40,6 +42,7 @@ fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
             version_query,
             url_query,
             header_query,
+            cookiejar_query,
             cookie_query,
             body_query,
             xpath_query,
@@ -53,6 +56,10 @@ fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
             certificate_query,
             ip_query,
             redirects_query,
+            messages_query,
+            sse_query,
+            grpc_status_query,
+            graphql_query,
         ],
         reader,
     )
@@ -98,6 +105,19 @@ fn cookie_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Cookie { space0, expr })
 }
 
+fn cookiejar_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("cookiejar", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+
+    let start = reader.cursor();
+    let s = quoted_oneline_string(reader)?;
+    let pos = Pos::new(start.pos.line, start.pos.column + 1);
+    let mut cookiepath_reader = Reader::with_pos(s.as_str(), pos);
+    let expr = cookiepath(&mut cookiepath_reader)?;
+
+    Ok(QueryValue::CookieJar { space0, expr })
+}
+
 fn body_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("body", reader)?;
     Ok(QueryValue::Body)
@@ -207,6 +227,28 @@ fn redirects_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Redirects)
 }
 
+fn messages_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("messages", reader)?;
+    Ok(QueryValue::Messages)
+}
+
+fn sse_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("sse", reader)?;
+    Ok(QueryValue::Sse)
+}
+
+fn grpc_status_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("grpc-status", reader)?;
+    Ok(QueryValue::GrpcStatus)
+}
+
+fn graphql_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("graphql", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let field = graphql_field(reader)?;
+    Ok(QueryValue::GraphQl { space0, field })
+}
+
 fn certificate_field(reader: &mut Reader) -> ParseResult<CertificateAttributeName> {
     literal("\"", reader)?;
     if try_literal(r#"Subject""#, reader).is_ok() {
@@ -228,6 +270,22 @@ fn certificate_field(reader: &mut Reader) -> ParseResult<CertificateAttributeNam
     }
 }
 
+fn graphql_field(reader: &mut Reader) -> ParseResult<GraphQlField> {
+    literal("\"", reader)?;
+    if try_literal(r#"data""#, reader).is_ok() {
+        Ok(GraphQlField::Data)
+    } else if try_literal(r#"errors""#, reader).is_ok() {
+        Ok(GraphQlField::Errors)
+    } else if try_literal(r#"extensions""#, reader).is_ok() {
+        Ok(GraphQlField::Extensions)
+    } else {
+        let value = "Field <data>, <errors> or <extensions>".to_string();
+        let kind = ParseErrorKind::Expecting { value };
+        let cur = reader.cursor();
+        Err(ParseError::new(cur.pos, false, kind))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -324,6 +382,36 @@ mod tests {
         //let mut reader = Reader::init("cookie \"cookie\u{31}\"");
     }
 
+    #[test]
+    fn test_cookiejar_query() {
+        let mut reader = Reader::new("cookiejar \"Foo\"");
+        assert_eq!(
+            cookiejar_query(&mut reader).unwrap(),
+            QueryValue::CookieJar {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 11)),
+                },
+                expr: CookiePath {
+                    name: Template::new(
+                        None,
+                        vec![TemplateElement::String {
+                            value: "Foo".to_string(),
+                            source: "Foo".to_source(),
+                        }],
+                        SourceInfo::new(Pos::new(1, 12), Pos::new(1, 15))
+                    ),
+                    attribute: None,
+                },
+            }
+        );
+        assert_eq!(reader.cursor().index, 15);
+
+        // A cookie query is not a cookie jar query.
+        let mut reader = Reader::new("cookie \"Foo\"");
+        assert!(cookiejar_query(&mut reader).is_err());
+    }
+
     #[test]
     fn test_xpath_query() {
         let mut reader = Reader::new("xpath \"normalize-space(//head/title)\"");
@@ -401,6 +489,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_graphql_query() {
+        let mut reader = Reader::new("graphql \"errors\"");
+        assert_eq!(
+            graphql_query(&mut reader).unwrap(),
+            QueryValue::GraphQl {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                },
+                field: GraphQlField::Errors,
+            }
+        );
+
+        let mut reader = Reader::new("graphql \"error\"");
+        let error = graphql_query(&mut reader).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 10));
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "Field <data>, <errors> or <extensions>".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_query_with_filters() {
         let mut reader = Reader::new("body urlDecode ");
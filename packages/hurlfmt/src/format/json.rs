@@ -19,11 +19,11 @@ use base64::engine::general_purpose;
 use base64::Engine;
 use hurl_core::ast::{
     Assert, Base64, Body, BooleanOption, Bytes, Capture, CertificateAttributeName, Comment, Cookie,
-    CountOption, DurationOption, Entry, EntryOption, File, FilenameParam, Filter, FilterValue, Hex,
-    HurlFile, JsonListElement, JsonValue, KeyValue, MultilineString, MultilineStringKind,
-    MultipartParam, NaturalOption, OptionKind, Placeholder, Predicate, PredicateFuncValue,
-    PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response, StatusValue,
-    VersionValue,
+    CountOption, DurationOption, Entry, EntryOption, File, FilenameParam, Filter, FilterValue,
+    GraphQlField, Hex, HurlFile, JsonListElement, JsonValue, KeyValue, MultilineString,
+    MultilineStringKind, MultipartParam, NaturalOption, OptionKind, Placeholder, Predicate,
+    PredicateFuncValue, PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response,
+    StatusValue, VersionValue,
 };
 use hurl_core::typing::{Count, Duration, ToSource};
 
@@ -307,17 +307,28 @@ impl ToJson for Cookie {
 impl ToJson for EntryOption {
     fn to_json(&self) -> JValue {
         let value = match &self.kind {
+            OptionKind::AnyAuth(value) => value.to_json(),
             OptionKind::AwsSigV4(value) => JValue::String(value.to_string()),
             OptionKind::CaCertificate(filename) => JValue::String(filename.to_string()),
+            OptionKind::CertStatus(value) => value.to_json(),
             OptionKind::ClientCert(filename) => JValue::String(filename.to_string()),
             OptionKind::ClientKey(filename) => JValue::String(filename.to_string()),
+            OptionKind::CompressBody(value) => JValue::String(value.to_string()),
             OptionKind::Compressed(value) => value.to_json(),
             OptionKind::ConnectTo(value) => JValue::String(value.to_string()),
             OptionKind::ConnectTimeout(value) => value.to_json(),
+            OptionKind::ContentDigest(value) => JValue::String(value.to_string()),
+            OptionKind::CookieStorage(value) => JValue::String(value.to_string()),
+            OptionKind::CrlFile(filename) => JValue::String(filename.to_string()),
             OptionKind::Delay(value) => value.to_json(),
+            OptionKind::Digest(value) => value.to_json(),
             OptionKind::FollowLocation(value) => value.to_json(),
             OptionKind::FollowLocationTrusted(value) => value.to_json(),
+            OptionKind::GraphQlAllowErrors(value) => value.to_json(),
+            OptionKind::GraphQlSchema(filename) => JValue::String(filename.to_string()),
+            OptionKind::GrpcProto(filename) => JValue::String(filename.to_string()),
             OptionKind::Header(value) => JValue::String(value.to_string()),
+            OptionKind::HttpSignature(value) => JValue::String(value.to_string()),
             OptionKind::Http10(value) => value.to_json(),
             OptionKind::Http11(value) => value.to_json(),
             OptionKind::Http2(value) => value.to_json(),
@@ -326,18 +337,36 @@ impl ToJson for EntryOption {
             OptionKind::IpV4(value) => value.to_json(),
             OptionKind::IpV6(value) => value.to_json(),
             OptionKind::LimitRate(value) => value.to_json(),
+            OptionKind::MaxFileSize(value) => value.to_json(),
             OptionKind::MaxRedirect(value) => value.to_json(),
+            OptionKind::MaxTime(value) => value.to_json(),
+            OptionKind::Negotiate(value) => value.to_json(),
             OptionKind::NetRc(value) => value.to_json(),
             OptionKind::NetRcFile(filename) => JValue::String(filename.to_string()),
             OptionKind::NetRcOptional(value) => value.to_json(),
+            OptionKind::Ntlm(value) => value.to_json(),
+            OptionKind::OAuth2(value) => JValue::String(value.to_string()),
             OptionKind::Output(filename) => JValue::String(filename.to_string()),
             OptionKind::PathAsIs(value) => value.to_json(),
+            OptionKind::PinnedPublicKey(value) => JValue::String(value.to_string()),
             OptionKind::Proxy(value) => JValue::String(value.to_string()),
+            OptionKind::ProxyAnyAuth(value) => value.to_json(),
+            OptionKind::ProxyCaCertificate(filename) => JValue::String(filename.to_string()),
+            OptionKind::ProxyDigest(value) => value.to_json(),
+            OptionKind::ProxyHeader(value) => JValue::String(value.to_string()),
+            OptionKind::ProxyInsecure(value) => value.to_json(),
+            OptionKind::ProxyNegotiate(value) => value.to_json(),
+            OptionKind::ProxyNtlm(value) => value.to_json(),
+            OptionKind::ProxyUser(value) => JValue::String(value.to_string()),
             OptionKind::Repeat(value) => value.to_json(),
             OptionKind::Resolve(value) => JValue::String(value.to_string()),
             OptionKind::Retry(value) => value.to_json(),
             OptionKind::RetryInterval(value) => value.to_json(),
             OptionKind::Skip(value) => value.to_json(),
+            OptionKind::SseDuration(value) => value.to_json(),
+            OptionKind::SseEvents(value) => value.to_json(),
+            OptionKind::TlsMax(value) => JValue::String(value.to_string()),
+            OptionKind::TlsMin(value) => JValue::String(value.to_string()),
             OptionKind::UnixSocket(value) => JValue::String(value.to_string()),
             OptionKind::User(value) => JValue::String(value.to_string()),
             OptionKind::Variable(value) => {
@@ -345,6 +374,8 @@ impl ToJson for EntryOption {
             }
             OptionKind::Verbose(value) => value.to_json(),
             OptionKind::VeryVerbose(value) => value.to_json(),
+            OptionKind::WebSocketMessages(value) => value.to_json(),
+            OptionKind::WebSocketTimeout(value) => value.to_json(),
         };
 
         // If the value contains the unit such as `{ "value": 10, "unit": "second" }`
@@ -466,7 +497,7 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Header { name, .. } => {
             attributes.push(("name".to_string(), JValue::String(name.to_string())));
         }
-        QueryValue::Cookie { expr, .. } => {
+        QueryValue::Cookie { expr, .. } | QueryValue::CookieJar { expr, .. } => {
             attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
         }
         QueryValue::Xpath { expr, .. } => {
@@ -484,6 +515,9 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         } => {
             attributes.push(("expr".to_string(), field.to_json()));
         }
+        QueryValue::GraphQl { field, .. } => {
+            attributes.push(("expr".to_string(), field.to_json()));
+        }
         _ => {}
     };
     attributes
@@ -514,6 +548,12 @@ impl ToJson for CertificateAttributeName {
     }
 }
 
+impl ToJson for GraphQlField {
+    fn to_json(&self) -> JValue {
+        JValue::String(self.identifier().to_string())
+    }
+}
+
 impl ToJson for Predicate {
     fn to_json(&self) -> JValue {
         let mut attributes = vec![];
@@ -555,6 +595,9 @@ impl ToJson for Predicate {
             PredicateFuncValue::Match { value, .. } => {
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::Signature { value, .. } => {
+                add_predicate_value(&mut attributes, value);
+            }
             PredicateFuncValue::IsInteger
             | PredicateFuncValue::IsFloat
             | PredicateFuncValue::IsBoolean
@@ -566,7 +609,8 @@ impl ToJson for Predicate {
             | PredicateFuncValue::IsEmpty
             | PredicateFuncValue::IsNumber
             | PredicateFuncValue::IsIpv4
-            | PredicateFuncValue::IsIpv6 => {}
+            | PredicateFuncValue::IsIpv6
+            | PredicateFuncValue::IsDigest => {}
         }
         JValue::Object(attributes)
     }
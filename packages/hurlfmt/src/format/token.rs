@@ -18,13 +18,13 @@
 use hurl_core::ast::{
     Assert, Base64, Body, BooleanOption, Bytes, Capture, CertificateAttributeName, Comment, Cookie,
     CookieAttribute, CookiePath, CountOption, DurationOption, Entry, EntryOption, Expr, ExprKind,
-    File, FilenameParam, FilenameValue, Filter, FilterValue, Function, GraphQl, GraphQlVariables,
-    Hex, HurlFile, JsonListElement, JsonObjectElement, JsonValue, KeyValue, LineTerminator, Method,
-    MultilineString, MultilineStringAttribute, MultilineStringKind, MultipartParam, NaturalOption,
-    OptionKind, Placeholder, Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, Query,
-    QueryValue, Regex, RegexValue, Request, Response, Section, SectionValue, Status, StatusValue,
-    Template, TemplateElement, Variable, VariableDefinition, VariableValue, Version, Whitespace,
-    I64, U64,
+    File, FilenameParam, FilenameValue, Filter, FilterValue, Function, GraphQl, GraphQlField,
+    GraphQlVariables, Hex, HurlFile, JsonListElement, JsonObjectElement, JsonValue, KeyValue,
+    LineTerminator, Method, MultilineString, MultilineStringAttribute, MultilineStringKind,
+    MultipartParam, NaturalOption, OptionKind, Placeholder, Predicate, PredicateFunc,
+    PredicateFuncValue, PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response,
+    Section, SectionValue, Status, StatusValue, Template, TemplateElement, Variable,
+    VariableDefinition, VariableValue, Version, Whitespace, I64, U64,
 };
 use hurl_core::typing::{Count, Duration, ToSource};
 
@@ -426,7 +426,7 @@ impl Tokenizable for QueryValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut name.tokenize());
             }
-            QueryValue::Cookie { space0, expr } => {
+            QueryValue::Cookie { space0, expr } | QueryValue::CookieJar { space0, expr } => {
                 tokens.append(&mut space0.tokenize());
                 tokens.push(Token::CodeDelimiter("\"".to_string()));
                 tokens.append(&mut expr.tokenize());
@@ -455,6 +455,10 @@ impl Tokenizable for QueryValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut field.tokenize());
             }
+            QueryValue::GraphQl { space0, field } => {
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut field.tokenize());
+            }
             _ => {}
         }
         tokens
@@ -502,6 +506,16 @@ impl Tokenizable for CertificateAttributeName {
     }
 }
 
+impl Tokenizable for GraphQlField {
+    fn tokenize(&self) -> Vec<Token> {
+        vec![
+            Token::StringDelimiter("\"".to_string()),
+            Token::String(self.identifier().to_string()),
+            Token::StringDelimiter("\"".to_string()),
+        ]
+    }
+}
+
 impl Tokenizable for Predicate {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
@@ -580,6 +594,11 @@ impl Tokenizable for PredicateFuncValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut value.tokenize());
             }
+            PredicateFuncValue::Signature { space0, value } => {
+                tokens.push(Token::PredicateType(name));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
             PredicateFuncValue::IsInteger => {
                 tokens.push(Token::PredicateType(name));
             }
@@ -616,6 +635,9 @@ impl Tokenizable for PredicateFuncValue {
             PredicateFuncValue::IsIpv6 => {
                 tokens.push(Token::PredicateType(name));
             }
+            PredicateFuncValue::IsDigest => {
+                tokens.push(Token::PredicateType(name));
+            }
         }
         tokens
     }
@@ -895,17 +917,28 @@ impl Tokenizable for EntryOption {
 impl Tokenizable for OptionKind {
     fn tokenize(&self) -> Vec<Token> {
         match self {
+            OptionKind::AnyAuth(value) => value.tokenize(),
             OptionKind::AwsSigV4(value) => value.tokenize(),
             OptionKind::CaCertificate(filename) => filename.tokenize(),
+            OptionKind::CertStatus(value) => value.tokenize(),
             OptionKind::ClientCert(filename) => filename.tokenize(),
             OptionKind::ClientKey(filename) => filename.tokenize(),
+            OptionKind::CompressBody(value) => value.tokenize(),
             OptionKind::Compressed(value) => value.tokenize(),
             OptionKind::ConnectTo(value) => value.tokenize(),
             OptionKind::ConnectTimeout(value) => value.tokenize(),
+            OptionKind::ContentDigest(value) => value.tokenize(),
+            OptionKind::CookieStorage(value) => value.tokenize(),
+            OptionKind::CrlFile(filename) => filename.tokenize(),
             OptionKind::Delay(value) => value.tokenize(),
+            OptionKind::Digest(value) => value.tokenize(),
             OptionKind::FollowLocation(value) => value.tokenize(),
             OptionKind::FollowLocationTrusted(value) => value.tokenize(),
+            OptionKind::GraphQlAllowErrors(value) => value.tokenize(),
+            OptionKind::GraphQlSchema(filename) => filename.tokenize(),
+            OptionKind::GrpcProto(filename) => filename.tokenize(),
             OptionKind::Header(value) => value.tokenize(),
+            OptionKind::HttpSignature(value) => value.tokenize(),
             OptionKind::Http10(value) => value.tokenize(),
             OptionKind::Http11(value) => value.tokenize(),
             OptionKind::Http2(value) => value.tokenize(),
@@ -914,23 +947,43 @@ impl Tokenizable for OptionKind {
             OptionKind::IpV4(value) => value.tokenize(),
             OptionKind::IpV6(value) => value.tokenize(),
             OptionKind::LimitRate(value) => value.tokenize(),
+            OptionKind::MaxFileSize(value) => value.tokenize(),
             OptionKind::MaxRedirect(value) => value.tokenize(),
+            OptionKind::MaxTime(value) => value.tokenize(),
+            OptionKind::Negotiate(value) => value.tokenize(),
             OptionKind::NetRc(value) => value.tokenize(),
             OptionKind::NetRcFile(filename) => filename.tokenize(),
             OptionKind::NetRcOptional(value) => value.tokenize(),
+            OptionKind::Ntlm(value) => value.tokenize(),
+            OptionKind::OAuth2(value) => value.tokenize(),
             OptionKind::Output(filename) => filename.tokenize(),
             OptionKind::PathAsIs(value) => value.tokenize(),
+            OptionKind::PinnedPublicKey(value) => value.tokenize(),
             OptionKind::Proxy(value) => value.tokenize(),
+            OptionKind::ProxyAnyAuth(value) => value.tokenize(),
+            OptionKind::ProxyCaCertificate(filename) => filename.tokenize(),
+            OptionKind::ProxyDigest(value) => value.tokenize(),
+            OptionKind::ProxyHeader(value) => value.tokenize(),
+            OptionKind::ProxyInsecure(value) => value.tokenize(),
+            OptionKind::ProxyNegotiate(value) => value.tokenize(),
+            OptionKind::ProxyNtlm(value) => value.tokenize(),
+            OptionKind::ProxyUser(value) => value.tokenize(),
             OptionKind::Repeat(value) => value.tokenize(),
             OptionKind::Resolve(value) => value.tokenize(),
             OptionKind::Retry(value) => value.tokenize(),
             OptionKind::RetryInterval(value) => value.tokenize(),
             OptionKind::Skip(value) => value.tokenize(),
+            OptionKind::SseDuration(value) => value.tokenize(),
+            OptionKind::SseEvents(value) => value.tokenize(),
+            OptionKind::TlsMax(value) => value.tokenize(),
+            OptionKind::TlsMin(value) => value.tokenize(),
             OptionKind::UnixSocket(value) => value.tokenize(),
             OptionKind::User(value) => value.tokenize(),
             OptionKind::Variable(value) => value.tokenize(),
             OptionKind::Verbose(value) => value.tokenize(),
             OptionKind::VeryVerbose(value) => value.tokenize(),
+            OptionKind::WebSocketMessages(value) => value.tokenize(),
+            OptionKind::WebSocketTimeout(value) => value.tokenize(),
         }
     }
 }
@@ -223,6 +223,19 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
                 },
             }
         }
+        QueryValue::CookieJar {
+            expr: CookiePath { name, attribute },
+            ..
+        } => {
+            let attribute = attribute.as_ref().map(lint_cookie_attribute);
+            QueryValue::CookieJar {
+                space0: one_whitespace(),
+                expr: CookiePath {
+                    name: name.clone(),
+                    attribute,
+                },
+            }
+        }
         QueryValue::Body => QueryValue::Body,
         QueryValue::Xpath { expr, .. } => QueryValue::Xpath {
             expr: expr.clone(),
@@ -253,6 +266,13 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
         },
         QueryValue::Ip => QueryValue::Ip,
         QueryValue::Redirects => QueryValue::Redirects,
+        QueryValue::Messages => QueryValue::Messages,
+        QueryValue::Sse => QueryValue::Sse,
+        QueryValue::GrpcStatus => QueryValue::GrpcStatus,
+        QueryValue::GraphQl { field, .. } => QueryValue::GraphQl {
+            field: *field,
+            space0: one_whitespace(),
+        },
     }
 }
 
@@ -348,6 +368,10 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::Signature { value, .. } => PredicateFuncValue::Signature {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+        },
         PredicateFuncValue::StartWith { value, .. } => PredicateFuncValue::StartWith {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
@@ -368,6 +392,7 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
         PredicateFuncValue::IsNumber => PredicateFuncValue::IsNumber,
         PredicateFuncValue::IsIpv4 => PredicateFuncValue::IsIpv4,
         PredicateFuncValue::IsIpv6 => PredicateFuncValue::IsIpv6,
+        PredicateFuncValue::IsDigest => PredicateFuncValue::IsDigest,
     }
 }
 
@@ -618,7 +643,13 @@ fn lint_option_kind(option_kind: &OptionKind) -> OptionKind {
         OptionKind::RetryInterval(duration) => {
             OptionKind::RetryInterval(lint_duration_option(duration, DurationUnit::MilliSecond))
         }
+        OptionKind::SseDuration(duration) => {
+            OptionKind::SseDuration(lint_duration_option(duration, DurationUnit::MilliSecond))
+        }
         OptionKind::Variable(var_def) => OptionKind::Variable(lint_variable_definition(var_def)),
+        OptionKind::WebSocketTimeout(duration) => {
+            OptionKind::WebSocketTimeout(lint_duration_option(duration, DurationUnit::MilliSecond))
+        }
         _ => option_kind.clone(),
     }
 }